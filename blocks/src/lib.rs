@@ -1927,6 +1927,7 @@ define_blocks! {
         material material::NON_SOLID,
         model { ("minecraft", "ladder") },
         variant format!("facing={}", facing.as_string()),
+        collision ladder_collision(facing),
         hardness 0.4,
         best_tools [ Tool::Axe(_), ],
     }
@@ -6874,6 +6875,55 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn ladder_collision() {
+        let ladder = |facing| {
+            Block::Ladder {
+                facing,
+                waterlogged: false,
+            }
+            .get_collision_boxes()
+        };
+        // Ladders sit against the side of the block opposite where they face
+        assert_eq!(
+            ladder(Direction::North),
+            vec![Aabb3::new(
+                Point3::new(0.0, 0.0, 13.0 / 16.0),
+                Point3::new(1.0, 1.0, 1.0)
+            )]
+        );
+        assert_eq!(
+            ladder(Direction::South),
+            vec![Aabb3::new(
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 3.0 / 16.0)
+            )]
+        );
+        assert_eq!(
+            ladder(Direction::West),
+            vec![Aabb3::new(
+                Point3::new(13.0 / 16.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 1.0)
+            )]
+        );
+        assert_eq!(
+            ladder(Direction::East),
+            vec![Aabb3::new(
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(3.0 / 16.0, 1.0, 1.0)
+            )]
+        );
+        // Waterlogging doesn't change the shape
+        assert_eq!(
+            Block::Ladder {
+                facing: Direction::East,
+                waterlogged: true,
+            }
+            .get_collision_boxes(),
+            ladder(Direction::East)
+        );
+    }
 }
 
 fn can_burn<W: WorldAccess>(world: &W, pos: Position) -> bool {
@@ -7274,6 +7324,21 @@ fn trapdoor_collision(facing: Direction, half: BlockHalf, open: bool) -> Vec<Aab
     )]
 }
 
+fn ladder_collision(facing: Direction) -> Vec<Aabb3<f64>> {
+    let (min_x, min_z, max_x, max_z) = match facing {
+        Direction::North => (0.0, 13.0 / 16.0, 1.0, 1.0),
+        Direction::South => (0.0, 0.0, 1.0, 3.0 / 16.0),
+        Direction::West => (13.0 / 16.0, 0.0, 1.0, 1.0),
+        Direction::East => (0.0, 0.0, 3.0 / 16.0, 1.0),
+        _ => unreachable!(),
+    };
+
+    vec![Aabb3::new(
+        Point3::new(min_x, 0.0, min_z),
+        Point3::new(max_x, 1.0, max_z),
+    )]
+}
+
 fn fence_collision(north: bool, south: bool, west: bool, east: bool) -> Vec<Aabb3<f64>> {
     let mut collision = vec![Aabb3::new(
         Point3::new(3.0 / 8.0, 0.0, 3.0 / 8.0),
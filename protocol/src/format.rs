@@ -18,6 +18,11 @@ use std::mem;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Component {
     Text(TextComponent),
+    Translate(TranslateComponent),
+    Score(ScoreComponent),
+    Selector(SelectorComponent),
+    Keybind(KeybindComponent),
+    Nbt(NbtComponent),
 }
 
 impl Component {
@@ -41,49 +46,75 @@ impl Component {
                 text: val.to_owned(),
                 modifier,
             })
+        } else if v.is_number() || v.is_boolean() {
+            Component::Text(TextComponent {
+                text: v.to_string(),
+                modifier,
+            })
+        } else if let Some(values) = v.as_array() {
+            // A list of components is the first one with the rest as
+            // its extra
+            let mut components = values.iter().map(Component::from_value);
+            let mut first = components.next().unwrap_or_default();
+            let rest: Vec<_> = components.collect();
+            if !rest.is_empty() {
+                first
+                    .modifier_mut()
+                    .extra
+                    .get_or_insert_with(Vec::new)
+                    .extend(rest);
+            }
+            first
         } else if v.get("text").is_some() {
             Component::Text(TextComponent::from_value(v, modifier))
-        } else if let Some(translate) = v.get("translate") {
-            let translate_key = translate.as_str().unwrap_or_default();
-            if let Some(serde_json::Value::Array(args)) = v.get("with") {
-                // TODO: recursively build components, avoid throwing away all but "text"
-                let text_args: Vec<&str> = args
-                    .iter()
-                    .map(|v| {
-                        if let serde_json::Value::Object(obj) = v {
-                            // Usernames might be in "extra":["text":"foo"] and "text":"" empty for
-                            // some reason; use extra instead if present TODO: use both
-                            if let Some(serde_json::Value::Array(extra)) = obj.get("extra") {
-                                if let Some(item) = extra.get(0) {
-                                    if let Some(text) = item.get("text") {
-                                        return text.as_str().unwrap_or_default();
-                                    }
-                                }
-                            }
-
-                            if let Some(text) = obj.get("text") {
-                                text.as_str().unwrap_or_default()
-                            } else {
-                                Default::default()
-                            }
-                        } else {
-                            v.as_str().unwrap_or_default()
-                        }
-                    })
-                    .collect();
-                // TODO: translations, https://wiki.vg/Chat#Translation_component
-                Component::Text(TextComponent::new(
-                    match translate_key {
-                        "chat.type.text" => format!("<{}> {}", text_args[0], text_args[1]),
-                        "chat.type.announcement" => format!("[{}] {}", text_args[0], text_args[1]),
-                        _ => format!("unhandled: {}", translate_key),
-                    }
-                    .as_str(),
-                ))
-            } else {
-                // TODO
-                Component::Text(TextComponent::new(translate_key))
-            }
+        } else if let Some(key) = v.get("translate") {
+            Component::Translate(TranslateComponent {
+                key: key.as_str().unwrap_or_default().to_owned(),
+                with: v
+                    .get("with")
+                    .and_then(|v| v.as_array())
+                    .map(|args| args.iter().map(Component::from_value).collect())
+                    .unwrap_or_default(),
+                modifier,
+            })
+        } else if let Some(score) = v.get("score") {
+            let get = |key| {
+                score
+                    .get(key)
+                    .and_then(|v| v.as_str())
+                    .map(|v| v.to_owned())
+            };
+            Component::Score(ScoreComponent {
+                name: get("name").unwrap_or_default(),
+                objective: get("objective").unwrap_or_default(),
+                value: get("value"),
+                modifier,
+            })
+        } else if let Some(selector) = v.get("selector") {
+            Component::Selector(SelectorComponent {
+                selector: selector.as_str().unwrap_or_default().to_owned(),
+                modifier,
+            })
+        } else if let Some(keybind) = v.get("keybind") {
+            Component::Keybind(KeybindComponent {
+                keybind: keybind.as_str().unwrap_or_default().to_owned(),
+                modifier,
+            })
+        } else if let Some(nbt) = v.get("nbt") {
+            let source = ["block", "entity", "storage"].iter().find_map(|&kind| {
+                v.get(kind)
+                    .and_then(|v| v.as_str())
+                    .map(|target| (kind.to_owned(), target.to_owned()))
+            });
+            Component::Nbt(NbtComponent {
+                nbt: nbt.as_str().unwrap_or_default().to_owned(),
+                interpret: v
+                    .get("interpret")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false),
+                source,
+                modifier,
+            })
         } else {
             modifier.color = Some(Color::RGB(255, 0, 0));
             Component::Text(TextComponent {
@@ -94,7 +125,105 @@ impl Component {
     }
 
     pub fn to_value(&self) -> serde_json::Value {
-        unimplemented!()
+        let mut value = self.modifier().to_value();
+        let map = value.as_object_mut().unwrap();
+        match *self {
+            Component::Text(ref c) => {
+                map.insert("text".to_owned(), c.text.clone().into());
+            }
+            Component::Translate(ref c) => {
+                map.insert("translate".to_owned(), c.key.clone().into());
+                if !c.with.is_empty() {
+                    map.insert(
+                        "with".to_owned(),
+                        c.with.iter().map(Component::to_value).collect(),
+                    );
+                }
+            }
+            Component::Score(ref c) => {
+                let mut score = serde_json::Map::new();
+                score.insert("name".to_owned(), c.name.clone().into());
+                score.insert("objective".to_owned(), c.objective.clone().into());
+                if let Some(ref value) = c.value {
+                    score.insert("value".to_owned(), value.clone().into());
+                }
+                map.insert("score".to_owned(), score.into());
+            }
+            Component::Selector(ref c) => {
+                map.insert("selector".to_owned(), c.selector.clone().into());
+            }
+            Component::Keybind(ref c) => {
+                map.insert("keybind".to_owned(), c.keybind.clone().into());
+            }
+            Component::Nbt(ref c) => {
+                map.insert("nbt".to_owned(), c.nbt.clone().into());
+                if c.interpret {
+                    map.insert("interpret".to_owned(), true.into());
+                }
+                if let Some((ref kind, ref target)) = c.source {
+                    map.insert(kind.clone(), target.clone().into());
+                }
+            }
+        }
+        value
+    }
+
+    pub fn modifier(&self) -> &Modifier {
+        match *self {
+            Component::Text(ref c) => &c.modifier,
+            Component::Translate(ref c) => &c.modifier,
+            Component::Score(ref c) => &c.modifier,
+            Component::Selector(ref c) => &c.modifier,
+            Component::Keybind(ref c) => &c.modifier,
+            Component::Nbt(ref c) => &c.modifier,
+        }
+    }
+
+    pub fn modifier_mut(&mut self) -> &mut Modifier {
+        match *self {
+            Component::Text(ref mut c) => &mut c.modifier,
+            Component::Translate(ref mut c) => &mut c.modifier,
+            Component::Score(ref mut c) => &mut c.modifier,
+            Component::Selector(ref mut c) => &mut c.modifier,
+            Component::Keybind(ref mut c) => &mut c.modifier,
+            Component::Nbt(ref mut c) => &mut c.modifier,
+        }
+    }
+
+    /// Returns the component as the plain text components it displays
+    /// as, looking up translation and keybind keys with `translate`.
+    pub fn resolve(&self, translate: &dyn Fn(&str) -> Option<String>) -> TextComponent {
+        let (text, mut parts) = match *self {
+            Component::Text(ref c) => (c.text.clone(), vec![]),
+            Component::Translate(ref c) => {
+                let format = translate(&c.key)
+                    .or_else(|| fallback_translation(&c.key).map(|v| v.to_owned()))
+                    .unwrap_or_else(|| c.key.clone());
+                let args: Vec<_> = c
+                    .with
+                    .iter()
+                    .map(|arg| Component::Text(arg.resolve(translate)))
+                    .collect();
+                (String::new(), format_translation(&format, &args))
+            }
+            Component::Score(ref c) => (c.value.clone().unwrap_or_default(), vec![]),
+            Component::Selector(ref c) => (c.selector.clone(), vec![]),
+            Component::Keybind(ref c) => (
+                translate(&c.keybind).unwrap_or_else(|| c.keybind.clone()),
+                vec![],
+            ),
+            // Only the server can look up the data, so there is nothing
+            // to show if it hasn't already
+            Component::Nbt(_) => (String::new(), vec![]),
+        };
+        let mut modifier = self.modifier().clone();
+        if let Some(extra) = modifier.extra.take() {
+            parts.extend(extra.iter().map(|c| Component::Text(c.resolve(translate))));
+        }
+        if !parts.is_empty() {
+            modifier.extra = Some(parts);
+        }
+        TextComponent { text, modifier }
     }
 }
 
@@ -102,6 +231,7 @@ impl fmt::Display for Component {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Component::Text(ref txt) => write!(f, "{}", txt),
+            _ => write!(f, "{}", self.resolve(&|_| None)),
         }
     }
 }
@@ -115,6 +245,68 @@ impl Default for Component {
     }
 }
 
+// Used when nothing else provides a translation, so the most common
+// messages stay readable
+fn fallback_translation(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "chat.type.text" => "<%s> %s",
+        "chat.type.announcement" => "[%s] %s",
+        "chat.type.emote" => "* %s %s",
+        "multiplayer.player.joined" => "%s joined the game",
+        "multiplayer.player.left" => "%s left the game",
+        _ => return None,
+    })
+}
+
+/// Splits the translation format into text and the arguments it
+/// refers to, supporting `%s`, positional `%1$s` and escaped `%%`.
+fn format_translation(format: &str, args: &[Component]) -> Vec<Component> {
+    let mut parts = vec![];
+    let mut text = String::new();
+    let mut next_arg = 0;
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            text.push(c);
+            continue;
+        }
+        let mut spec = String::new();
+        while let Some(&c) = chars.peek() {
+            if !c.is_ascii_digit() && c != '$' {
+                break;
+            }
+            spec.push(c);
+            chars.next();
+        }
+        let index = match chars.next() {
+            Some('%') if spec.is_empty() => {
+                text.push('%');
+                continue;
+            }
+            Some('s') | Some('d') => match spec.strip_suffix('$') {
+                Some(position) => position.parse::<usize>().ok().map(|v| v.saturating_sub(1)),
+                None if spec.is_empty() => {
+                    next_arg += 1;
+                    Some(next_arg - 1)
+                }
+                None => None,
+            },
+            _ => None,
+        };
+        if !text.is_empty() {
+            parts.push(Component::Text(TextComponent::new(&text)));
+            text.clear();
+        }
+        if let Some(arg) = index.and_then(|index| args.get(index)) {
+            parts.push(arg.clone());
+        }
+    }
+    if !text.is_empty() {
+        parts.push(Component::Text(TextComponent::new(&text)));
+    }
+    parts
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Modifier {
     pub extra: Option<Vec<Component>>,
@@ -124,10 +316,13 @@ pub struct Modifier {
     pub strikethrough: Option<bool>,
     pub obfuscated: Option<bool>,
     pub color: Option<Color>,
+    pub click_event: Option<ClickEvent>,
+    pub hover_event: Option<HoverEvent>,
+    /// Text added to the chat input when the component is shift
+    /// clicked.
+    pub insertion: Option<String>,
 }
 
-// TODO: Missing events click/hover/insert
-
 impl Modifier {
     pub fn from_value(v: &serde_json::Value) -> Self {
         let mut m = Modifier {
@@ -140,6 +335,12 @@ impl Modifier {
                 .get("color")
                 .and_then(|v| v.as_str())
                 .map(|v| Color::from_string(&v.to_owned())),
+            click_event: v.get("clickEvent").and_then(ClickEvent::from_value),
+            hover_event: v.get("hoverEvent").and_then(HoverEvent::from_value),
+            insertion: v
+                .get("insertion")
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_owned()),
             extra: Option::None,
         };
         if let Some(extra) = v.get("extra") {
@@ -154,8 +355,237 @@ impl Modifier {
         m
     }
 
+    /// Returns the modifier as a json object, leaving out anything
+    /// that isn't set.
     pub fn to_value(&self) -> serde_json::Value {
-        unimplemented!()
+        let mut map = serde_json::Map::new();
+        for (key, value) in [
+            ("bold", self.bold),
+            ("italic", self.italic),
+            ("underlined", self.underlined),
+            ("strikethrough", self.strikethrough),
+            ("obfuscated", self.obfuscated),
+        ] {
+            if let Some(value) = value {
+                map.insert(key.to_owned(), value.into());
+            }
+        }
+        if let Some(color) = self.color {
+            map.insert("color".to_owned(), color.to_string().into());
+        }
+        if let Some(ref event) = self.click_event {
+            map.insert("clickEvent".to_owned(), event.to_value());
+        }
+        if let Some(ref event) = self.hover_event {
+            map.insert("hoverEvent".to_owned(), event.to_value());
+        }
+        if let Some(ref insertion) = self.insertion {
+            map.insert("insertion".to_owned(), insertion.clone().into());
+        }
+        if let Some(ref extra) = self.extra {
+            map.insert(
+                "extra".to_owned(),
+                extra.iter().map(Component::to_value).collect(),
+            );
+        }
+        map.into()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClickEvent {
+    OpenUrl(String),
+    RunCommand(String),
+    SuggestCommand(String),
+    CopyToClipboard(String),
+    /// Turns to the page of the book being read.
+    ChangePage(i32),
+}
+
+impl ClickEvent {
+    pub fn from_value(v: &serde_json::Value) -> Option<Self> {
+        let value = v.get("value")?;
+        let text = || value.as_str().map(|v| v.to_owned());
+        Some(match v.get("action")?.as_str()? {
+            "open_url" => ClickEvent::OpenUrl(text()?),
+            "run_command" => ClickEvent::RunCommand(text()?),
+            "suggest_command" => ClickEvent::SuggestCommand(text()?),
+            "copy_to_clipboard" => ClickEvent::CopyToClipboard(text()?),
+            "change_page" => ClickEvent::ChangePage(match value.as_i64() {
+                Some(page) => page as i32,
+                None => text()?.parse().ok()?,
+            }),
+            _ => return None,
+        })
+    }
+
+    pub fn to_value(&self) -> serde_json::Value {
+        let (action, value) = match *self {
+            ClickEvent::OpenUrl(ref url) => ("open_url", url.clone()),
+            ClickEvent::RunCommand(ref command) => ("run_command", command.clone()),
+            ClickEvent::SuggestCommand(ref command) => ("suggest_command", command.clone()),
+            ClickEvent::CopyToClipboard(ref text) => ("copy_to_clipboard", text.clone()),
+            ClickEvent::ChangePage(page) => ("change_page", page.to_string()),
+        };
+        serde_json::json!({ "action": action, "value": value })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HoverEvent {
+    ShowText(Box<Component>),
+    ShowItem {
+        id: String,
+        count: i32,
+    },
+    ShowEntity {
+        name: Option<Box<Component>>,
+        kind: String,
+        id: String,
+    },
+}
+
+impl HoverEvent {
+    /// Parses the event from either the `contents` used by 1.16+ or
+    /// the older `value`, which holds items and entities as NBT text.
+    pub fn from_value(v: &serde_json::Value) -> Option<Self> {
+        let action = v.get("action")?.as_str()?;
+        if let Some(contents) = v.get("contents") {
+            let get = |key| contents.get(key).and_then(|v| v.as_str());
+            return Some(match action {
+                "show_text" => HoverEvent::ShowText(Box::new(Component::from_value(contents))),
+                "show_item" => HoverEvent::ShowItem {
+                    id: contents.as_str().or_else(|| get("id"))?.to_owned(),
+                    count: contents.get("count").and_then(|v| v.as_i64()).unwrap_or(1) as i32,
+                },
+                "show_entity" => HoverEvent::ShowEntity {
+                    name: contents
+                        .get("name")
+                        .map(|v| Box::new(Component::from_value(v))),
+                    kind: get("type").unwrap_or_default().to_owned(),
+                    id: get("id").unwrap_or_default().to_owned(),
+                },
+                _ => return None,
+            });
+        }
+
+        let value = Component::from_value(v.get("value")?);
+        if action == "show_text" {
+            return Some(HoverEvent::ShowText(Box::new(value)));
+        }
+        let nbt = format!("{}", value);
+        Some(match action {
+            "show_item" => HoverEvent::ShowItem {
+                id: nbt_field(&nbt, "id")?,
+                count: nbt_field(&nbt, "Count")
+                    .and_then(|v| v.trim_end_matches('b').parse().ok())
+                    .unwrap_or(1),
+            },
+            "show_entity" => HoverEvent::ShowEntity {
+                name: nbt_field(&nbt, "name").map(|v| Box::new(Component::from_string(&v))),
+                kind: nbt_field(&nbt, "type").unwrap_or_default(),
+                id: nbt_field(&nbt, "id").unwrap_or_default(),
+            },
+            _ => return None,
+        })
+    }
+
+    /// Returns the event in the 1.16+ format, using `contents`.
+    pub fn to_value(&self) -> serde_json::Value {
+        match *self {
+            HoverEvent::ShowText(ref text) => serde_json::json!({
+                "action": "show_text",
+                "contents": text.to_value(),
+            }),
+            HoverEvent::ShowItem { ref id, count } => serde_json::json!({
+                "action": "show_item",
+                "contents": { "id": id, "count": count },
+            }),
+            HoverEvent::ShowEntity {
+                ref name,
+                ref kind,
+                ref id,
+            } => {
+                let mut contents = serde_json::json!({ "type": kind, "id": id });
+                if let Some(name) = name {
+                    contents["name"] = name.to_value();
+                }
+                serde_json::json!({ "action": "show_entity", "contents": contents })
+            }
+        }
+    }
+}
+
+/// Returns the value of a top level field in NBT text, without any
+/// quotes around it.
+fn nbt_field(nbt: &str, key: &str) -> Option<String> {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut field_start = true;
+    for (i, c) in nbt.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '{' | '[' => {
+                depth += 1;
+                field_start = true;
+                continue;
+            }
+            '}' | ']' => depth -= 1,
+            ',' => {
+                field_start = true;
+                continue;
+            }
+            _ if c.is_whitespace() => continue,
+            _ => {}
+        }
+        if depth == 1 && field_start && nbt[i..].starts_with(key) {
+            let rest = nbt[i + key.len()..].trim_start();
+            if let Some(rest) = rest.strip_prefix(':') {
+                return Some(nbt_value(rest.trim_start()));
+            }
+        }
+        field_start = false;
+    }
+    None
+}
+
+fn nbt_value(rest: &str) -> String {
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(q) if q == '"' || q == '\'' => {
+            let mut value = String::new();
+            let mut escaped = false;
+            for c in chars {
+                if escaped {
+                    value.push(c);
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == q {
+                    break;
+                } else {
+                    value.push(c);
+                }
+            }
+            value
+        }
+        _ => rest
+            .split([',', '}'])
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_owned(),
     }
 }
 
@@ -183,7 +613,12 @@ impl TextComponent {
     }
 
     pub fn to_value(&self) -> serde_json::Value {
-        unimplemented!()
+        let mut value = self.modifier.to_value();
+        value
+            .as_object_mut()
+            .unwrap()
+            .insert("text".to_owned(), self.text.clone().into());
+        value
     }
 }
 
@@ -199,6 +634,49 @@ impl fmt::Display for TextComponent {
     }
 }
 
+/// Text looked up from the language file, with `with` filling in
+/// its placeholders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslateComponent {
+    pub key: String,
+    pub with: Vec<Component>,
+    pub modifier: Modifier,
+}
+
+/// The score of an entry for an objective. Servers normally fill in
+/// the value before sending it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreComponent {
+    pub name: String,
+    pub objective: String,
+    pub value: Option<String>,
+    pub modifier: Modifier,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorComponent {
+    pub selector: String,
+    pub modifier: Modifier,
+}
+
+/// The key bound to a control, such as `key.jump`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeybindComponent {
+    pub keybind: String,
+    pub modifier: Modifier,
+}
+
+/// Data read from a block, entity or storage by the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NbtComponent {
+    pub nbt: String,
+    pub interpret: bool,
+    /// The kind of source (`block`, `entity` or `storage`) and the
+    /// position, selector or id of it.
+    pub source: Option<(String, String)>,
+    pub modifier: Modifier,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Color {
     Black,
@@ -333,10 +811,156 @@ fn test_color_from() {
     }
 }
 
+#[test]
+fn test_translate_component() {
+    let component = Component::from_string(
+        r#"{"translate":"chat.type.text","with":[{"text":"","extra":[{"text":"steve"}]},"hi"]}"#,
+    );
+    match component {
+        Component::Translate(ref c) => {
+            assert_eq!(c.key, "chat.type.text");
+            assert_eq!(c.with.len(), 2);
+        }
+        _ => panic!("Wrong type"),
+    }
+    assert_eq!(format!("{}", component), "<steve> hi");
+
+    let component = Component::from_string(
+        r#"{"translate":"custom","with":["a",{"selector":"@p"},{"score":{"name":"x","objective":"y","value":"3"}}]}"#,
+    );
+    assert_eq!(format!("{}", component), "custom");
+    let translated = component.resolve(&|key| match key {
+        "custom" => Some("%3$s%% %2$s, %s".to_owned()),
+        _ => None,
+    });
+    assert_eq!(format!("{}", translated), "3% @p, a");
+}
+
+#[test]
+fn test_other_components() {
+    let component =
+        Component::from_string(r#"[{"keybind":"key.jump"},{"nbt":"Items","block":"1 2 3"}]"#);
+    match component {
+        Component::Keybind(ref c) => {
+            assert_eq!(c.keybind, "key.jump");
+            match c.modifier.extra.as_deref() {
+                Some([Component::Nbt(nbt)]) => {
+                    assert_eq!(nbt.nbt, "Items");
+                    assert_eq!(nbt.source, Some(("block".to_owned(), "1 2 3".to_owned())));
+                }
+                _ => panic!("Wrong extra"),
+            }
+        }
+        _ => panic!("Wrong type"),
+    }
+    assert_eq!(format!("{}", component), "key.jump");
+}
+
+#[test]
+fn test_events() {
+    let component = Component::from_string(
+        r#"{"text":"a","insertion":"b","clickEvent":{"action":"run_command","value":"/help"},"hoverEvent":{"action":"show_text","value":{"text":"hi"}}}"#,
+    );
+    let modifier = component.modifier();
+    assert_eq!(modifier.insertion.as_deref(), Some("b"));
+    assert_eq!(
+        modifier.click_event,
+        Some(ClickEvent::RunCommand("/help".to_owned()))
+    );
+    match modifier.hover_event {
+        Some(HoverEvent::ShowText(ref text)) => assert_eq!(format!("{}", text), "hi"),
+        _ => panic!("Wrong hover event"),
+    }
+
+    let legacy = Component::from_string(
+        r#"{"text":"","hoverEvent":{"action":"show_item","value":"{tag:{display:{Name:\"x,y\"}},id:\"minecraft:stone\",Count:3b}"}}"#,
+    );
+    assert_eq!(
+        legacy.modifier().hover_event,
+        Some(HoverEvent::ShowItem {
+            id: "minecraft:stone".to_owned(),
+            count: 3,
+        })
+    );
+
+    let contents = Component::from_string(
+        r#"{"text":"","hoverEvent":{"action":"show_entity","contents":{"type":"minecraft:pig","id":"1-2","name":"Bob"}}}"#,
+    );
+    match contents.modifier().hover_event {
+        Some(HoverEvent::ShowEntity {
+            ref name,
+            ref kind,
+            ref id,
+        }) => {
+            assert_eq!(
+                name.as_ref().map(|v| format!("{}", v)).as_deref(),
+                Some("Bob")
+            );
+            assert_eq!(kind, "minecraft:pig");
+            assert_eq!(id, "1-2");
+        }
+        _ => panic!("Wrong hover event"),
+    }
+}
+
+#[test]
+fn test_to_value_round_trip() {
+    let json = [
+        r##"{"text":"Hi ","color":"#12AB34","bold":true,"extra":[{"text":"there","italic":false,"underlined":true}]}"##,
+        r#"{"translate":"chat.type.text","with":[{"text":"steve","insertion":"steve","clickEvent":{"action":"suggest_command","value":"/tell steve "}},{"text":"hi","color":"gold"}]}"#,
+        r#"{"score":{"name":"steve","objective":"kills","value":"3"},"strikethrough":true}"#,
+        r#"{"selector":"@p","obfuscated":false}"#,
+        r#"{"keybind":"key.jump","clickEvent":{"action":"change_page","value":"2"}}"#,
+        r#"{"nbt":"Items","interpret":true,"entity":"@s"}"#,
+        r#"{"text":"","hoverEvent":{"action":"show_text","contents":{"text":"tip","color":"red"}}}"#,
+        r#"{"text":"","hoverEvent":{"action":"show_item","contents":{"id":"minecraft:stone","count":2}}}"#,
+        r#"{"text":"","hoverEvent":{"action":"show_entity","contents":{"type":"minecraft:pig","id":"1-2","name":{"text":"Bob"}}}}"#,
+    ];
+    for json in json {
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        let component = Component::from_value(&value);
+        assert_eq!(component.to_value(), value);
+        assert_eq!(Component::from_value(&component.to_value()), component);
+    }
+
+    let mut legacy = Component::Text(TextComponent::new("a\u{a7}cb\u{a7}lc\u{a7}rd"));
+    convert_legacy(&mut legacy);
+    assert_eq!(
+        legacy.to_value(),
+        serde_json::json!({"text": "", "extra": [
+            {"text": "a"},
+            {"text": "b", "color": "red"},
+            {"text": "c", "color": "red", "bold": true},
+            {"text": "d"},
+        ]})
+    );
+    assert_eq!(Component::from_value(&legacy.to_value()), legacy);
+}
+
 const LEGACY_CHAR: char = '§';
 
 pub fn convert_legacy(c: &mut Component) {
     match *c {
+        Component::Translate(ref mut translate) => {
+            for arg in &mut translate.with {
+                convert_legacy(arg);
+            }
+            if let Some(ref mut extra) = translate.modifier.extra.as_mut() {
+                for e in extra.iter_mut() {
+                    convert_legacy(e);
+                }
+            }
+        }
+        Component::Score(_)
+        | Component::Selector(_)
+        | Component::Keybind(_)
+        | Component::Nbt(_) => {
+            if let Some(ref mut extra) = c.modifier_mut().extra.as_mut() {
+                for e in extra.iter_mut() {
+                    convert_legacy(e);
+                }
+            }
+        }
         Component::Text(ref mut txt) => {
             if let Some(ref mut extra) = txt.modifier.extra.as_mut() {
                 for e in extra.iter_mut() {
@@ -359,8 +983,11 @@ pub fn convert_legacy(c: &mut Component) {
                             current.text = txt.text[last..i].to_owned();
                             last = next.0 + 1;
 
+                            // Colours and resets clear the formatting codes
+                            // before them
                             let mut modifier = if ('a'..='f').contains(&color_char)
                                 || ('0'..='9').contains(&color_char)
+                                || color_char == 'r'
                             {
                                 Default::default()
                             } else {
@@ -419,3 +1046,74 @@ pub fn convert_legacy(c: &mut Component) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> Component {
+        Component::Text(TextComponent::new(text))
+    }
+
+    fn resolve(json: &str, translate: &dyn Fn(&str) -> Option<String>) -> String {
+        Component::from_string(json).resolve(translate).to_string()
+    }
+
+    #[test]
+    fn substitution() {
+        let args = [text("Steve"), text("Alex")];
+        assert_eq!(
+            format_translation("%s joined the game", &args),
+            vec![text("Steve"), text(" joined the game")]
+        );
+        assert_eq!(
+            format_translation("%2$s and %1$s", &args),
+            vec![text("Alex"), text(" and "), text("Steve")]
+        );
+        assert_eq!(
+            format_translation("100%% %d", &args),
+            vec![text("100% "), text("Steve")]
+        );
+        // Missing arguments are left out
+        assert_eq!(
+            format_translation("%s, %s and %s", &args),
+            vec![text("Steve"), text(", "), text("Alex"), text(" and ")]
+        );
+    }
+
+    #[test]
+    fn translate_fallback() {
+        let json = r#"{"translate":"chat.type.text","with":["Steve","hello"]}"#;
+        assert_eq!(resolve(json, &|_| None), "<Steve> hello");
+        assert_eq!(
+            resolve(json, &|key| {
+                assert_eq!(key, "chat.type.text");
+                Some("%s: %s".to_owned())
+            }),
+            "Steve: hello"
+        );
+        // Keys without any translation are shown as they are
+        assert_eq!(
+            resolve(r#"{"translate":"death.attack.generic"}"#, &|_| None),
+            "death.attack.generic"
+        );
+    }
+
+    #[test]
+    fn nested_translations() {
+        let json = r#"{
+            "translate":"multiplayer.player.joined",
+            "with":[{"translate":"entity.minecraft.pig"}],
+            "extra":[{"text":"!"}]
+        }"#;
+        let translate = |key: &str| match key {
+            "entity.minecraft.pig" => Some("Pig".to_owned()),
+            _ => None,
+        };
+        assert_eq!(resolve(json, &translate), "Pig joined the game!");
+        assert_eq!(
+            resolve(r#"{"keybind":"key.jump"}"#, &|_| Some("Space".to_owned())),
+            "Space"
+        );
+    }
+}
@@ -76,6 +76,10 @@ state_packets!(
             packet TabComplete_NoAssume_NoTarget {
                 field text: String =,
             }
+            packet TabComplete_Transaction {
+                field transaction_id: VarInt =,
+                field text: String =,
+            }
             /// ChatMessage is sent by the client when it sends a chat message or
             /// executes a command (prefixed by '/').
             packet ChatMessage {
@@ -98,6 +102,16 @@ state_packets!(
                 field main_hand: VarInt =,
                 field disable_text_filtering: bool =,
             }
+            packet ClientSettings_Filtering_ServerListings {
+                field locale: String =,
+                field view_distance: u8 =,
+                field chat_mode: VarInt =,
+                field chat_colors: bool =,
+                field displayed_skin_parts: u8 =,
+                field main_hand: VarInt =,
+                field disable_text_filtering: bool =,
+                field allow_server_listings: bool =,
+            }
             packet ClientSettings {
                 field locale: String =,
                 field view_distance: u8 =,
@@ -907,6 +921,12 @@ state_packets!(
             packet TabCompleteReply {
                 field matches: LenPrefixed<VarInt, String> =,
             }
+            packet TabCompleteReply_Transaction {
+                field transaction_id: VarInt =,
+                field start: VarInt =,
+                field length: VarInt =,
+                field matches: LenPrefixed<VarInt, packet::CommandMatch> =,
+            }
             packet DeclareCommands {
                 field nodes: LenPrefixed<VarInt, packet::CommandNode> =,
                 field root_index: VarInt =,
@@ -929,9 +949,7 @@ state_packets!(
                 field message: format::Component =,
             }
             packet ClearTitles {
-                field json_data: String =,
-                field position: u8 =,
-                field sender: UUID =,
+                field reset: bool =,
             }
             /// MultiBlockChange is used to update a batch of blocks in a single packet.
             packet MultiBlockChange_Packed {
@@ -2045,7 +2063,6 @@ state_packets!(
             packet Teams_u8 {
                 field name: String =,
                 field mode: u8 =,
-                field data: Vec<u8> =,
                 field display_name: Option<String> = when(|p: &Teams_u8| p.mode == 0 || p.mode == 2),
                 field prefix: Option<String> = when(|p: &Teams_u8| p.mode == 0 || p.mode == 2),
                 field suffix: Option<String> = when(|p: &Teams_u8| p.mode == 0 || p.mode == 2),
@@ -2055,6 +2072,17 @@ state_packets!(
                 field color: Option<i8> = when(|p: &Teams_u8| p.mode == 0 || p.mode == 2),
                 field players: Option<LenPrefixed<VarInt, String>> = when(|p: &Teams_u8| p.mode == 0 || p.mode == 3 || p.mode == 4),
             }
+            packet Teams_NoCollision {
+                field name: String =,
+                field mode: u8 =,
+                field display_name: Option<String> = when(|p: &Teams_NoCollision| p.mode == 0 || p.mode == 2),
+                field prefix: Option<String> = when(|p: &Teams_NoCollision| p.mode == 0 || p.mode == 2),
+                field suffix: Option<String> = when(|p: &Teams_NoCollision| p.mode == 0 || p.mode == 2),
+                field flags: Option<u8> = when(|p: &Teams_NoCollision| p.mode == 0 || p.mode == 2),
+                field name_tag_visibility: Option<String> = when(|p: &Teams_NoCollision| p.mode == 0 || p.mode == 2),
+                field color: Option<i8> = when(|p: &Teams_NoCollision| p.mode == 0 || p.mode == 2),
+                field players: Option<LenPrefixed<VarInt, String>> = when(|p: &Teams_NoCollision| p.mode == 0 || p.mode == 3 || p.mode == 4),
+            }
             packet Teams_NoVisColor {
                 field name: String =,
                 field mode: u8 =,
@@ -2137,9 +2165,12 @@ state_packets!(
                 field action: VarInt =,
                 field title: Option<format::Component> = when(|p: &Title_notext_component| p.action.0 == 0),
                 field sub_title: Option<format::Component> = when(|p: &Title_notext_component| p.action.0 == 1),
-                field fade_in: Option<format::Component> = when(|p: &Title_notext_component| p.action.0 == 2),
-                field fade_stay: Option<format::Component> = when(|p: &Title_notext_component| p.action.0 == 2),
-                field fade_out: Option<format::Component> = when(|p: &Title_notext_component| p.action.0 == 2),
+                field fade_in: Option<i32> = when(|p: &Title_notext_component| p.action.0 == 2),
+                field fade_stay: Option<i32> = when(|p: &Title_notext_component| p.action.0 == 2),
+                field fade_out: Option<i32> = when(|p: &Title_notext_component| p.action.0 == 2),
+            }
+            packet TitleText {
+                field title: String =,
             }
             packet TitleSubtitle {
                 field subtitle_text: String =,
@@ -3338,6 +3369,36 @@ impl Serializable for Trade {
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CommandMatch {
+    pub text: String,
+    pub tooltip: Option<format::Component>,
+}
+
+impl Serializable for CommandMatch {
+    fn read_from<R: io::Read>(buf: &mut R) -> Result<Self, Error> {
+        let text = Serializable::read_from(buf)?;
+        let has_tooltip: bool = Serializable::read_from(buf)?;
+        Ok(CommandMatch {
+            text,
+            tooltip: if has_tooltip {
+                Some(Serializable::read_from(buf)?)
+            } else {
+                None
+            },
+        })
+    }
+
+    fn write_to<W: io::Write>(&self, buf: &mut W) -> Result<(), Error> {
+        self.text.write_to(buf)?;
+        self.tooltip.is_some().write_to(buf)?;
+        if let Some(tooltip) = &self.tooltip {
+            tooltip.write_to(buf)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct CommandNode {
     pub flags: u8,
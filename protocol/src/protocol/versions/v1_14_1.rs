@@ -14,7 +14,7 @@ protocol_packet_ids!(
             0x03 => ChatMessage
             0x04 => ClientStatus
             0x05 => ClientSettings
-            0x06 => TabComplete
+            0x06 => TabComplete_Transaction
             0x07 => ConfirmTransactionServerbound
             0x08 => ClickWindowButton
             0x09 => ClickWindow
@@ -72,7 +72,7 @@ protocol_packet_ids!(
             0x0d => ServerDifficulty_Locked
             0x0e => ServerMessage_Position
             0x0f => MultiBlockChange_VarInt
-            0x10 => TabCompleteReply
+            0x10 => TabCompleteReply_Transaction
             0x11 => DeclareCommands
             0x12 => ConfirmTransaction
             0x13 => WindowClose
@@ -14,7 +14,7 @@ protocol_packet_ids!(
             0x03 => ChatMessage
             0x04 => ClientStatus
             0x05 => ClientSettings
-            0x06 => TabComplete
+            0x06 => TabComplete_Transaction
             0x07 => ConfirmTransactionServerbound
             0x08 => ClickWindowButton
             0x09 => ClickWindow
@@ -73,7 +73,7 @@ protocol_packet_ids!(
             0x0e => ServerDifficulty_Locked
             0x0f => ServerMessage_Position
             0x10 => MultiBlockChange_VarInt
-            0x11 => TabCompleteReply
+            0x11 => TabCompleteReply_Transaction
             0x12 => DeclareCommands
             0x13 => ConfirmTransaction
             0x14 => WindowClose
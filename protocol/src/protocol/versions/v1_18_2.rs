@@ -13,8 +13,8 @@ protocol_packet_ids!(
             0x02 => SetDifficulty
             0x03 => ChatMessage
             0x04 => ClientStatus
-            0x05 => ClientSettings_Filtering
-            0x06 => TabComplete
+            0x05 => ClientSettings_Filtering_ServerListings
+            0x06 => TabComplete_Transaction
             0x07 => ClickWindowButton
             0x08 => ClickWindow_State
             0x09 => CloseWindow
@@ -75,7 +75,7 @@ protocol_packet_ids!(
             0x0e => ServerDifficulty_Locked
             0x0f => ServerMessage_Sender
             0x10 => ClearTitles
-            0x11 => TabCompleteReply
+            0x11 => TabCompleteReply_Transaction
             0x12 => DeclareCommands
             0x13 => WindowClose
             0x14 => WindowItems_StateCarry
@@ -148,7 +148,7 @@ protocol_packet_ids!(
             0x57 => UpdateSimulationDistance
             0x58 => TitleSubtitle
             0x59 => TimeUpdate
-            0x5a => Title
+            0x5a => TitleText
             0x5b => TitleTimes
             0x5c => EntitySoundEffect
             0x5d => SoundEffect
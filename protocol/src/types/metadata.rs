@@ -56,11 +56,18 @@ impl Metadata {
         self.map.get(&key.index).map(T::unwrap)
     }
 
+    /// Returns the value at the given index without knowing its type,
+    /// for fields whose index differs between versions.
+    pub fn get_raw(&self, index: i32) -> Option<&Value> {
+        self.map.get(&index)
+    }
+
     pub fn put<T: MetaValue>(&mut self, key: &MetadataKey<T>, val: T) {
         self.map.insert(key.index, val.wrap());
     }
 
-    fn put_raw<T: MetaValue>(&mut self, index: i32, val: T) {
+    /// Sets the value at the given index, the counterpart of `get_raw`.
+    pub fn put_raw<T: MetaValue>(&mut self, index: i32, val: T) {
         self.map.insert(index, val.wrap());
     }
 
@@ -56,6 +56,10 @@ impl ChunkBuilder {
         }
     }
 
+    pub fn models(&self) -> Arc<RwLock<model::Factory>> {
+        self.models.clone()
+    }
+
     pub fn tick(
         &mut self,
         world: &mut world::World,
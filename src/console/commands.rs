@@ -0,0 +1,426 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use super::Vars;
+use crate::format::{Color, Component, TextComponent};
+use crate::screen;
+
+type CommandFunc = fn(&mut crate::Game, &[&str]) -> Result<(), String>;
+
+pub struct Command {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub description: &'static str,
+    pub func: CommandFunc,
+    /// Whether the arguments of the command are cvar names
+    pub completes_vars: bool,
+}
+
+pub const CMD_CONNECT: Command = Command {
+    name: "connect",
+    usage: "connect <address>",
+    description: "Connects to the server at the given address",
+    func: cmd_connect,
+    completes_vars: false,
+};
+
+pub const CMD_DISCONNECT: Command = Command {
+    name: "disconnect",
+    usage: "disconnect",
+    description: "Disconnects from the current server",
+    func: cmd_disconnect,
+    completes_vars: false,
+};
+
+pub const CMD_QUIT: Command = Command {
+    name: "quit",
+    usage: "quit",
+    description: "Closes the game",
+    func: cmd_quit,
+    completes_vars: false,
+};
+
+pub const CMD_SAY: Command = Command {
+    name: "say",
+    usage: "say <message>",
+    description: "Sends a chat message, or a command if prefixed by '/'",
+    func: cmd_say,
+    completes_vars: false,
+};
+
+pub const CMD_SET: Command = Command {
+    name: "set",
+    usage: "set <cvar> <value>",
+    description: "Changes the value of a cvar",
+    func: cmd_set,
+    completes_vars: true,
+};
+
+pub const CMD_GET: Command = Command {
+    name: "get",
+    usage: "get <cvar>",
+    description: "Prints the value of a cvar",
+    func: cmd_get,
+    completes_vars: true,
+};
+
+pub const CMD_RESET: Command = Command {
+    name: "reset",
+    usage: "reset <cvar>",
+    description: "Resets a cvar to its default value",
+    func: cmd_reset,
+    completes_vars: true,
+};
+
+pub const CMD_HELP: Command = Command {
+    name: "help",
+    usage: "help [command]",
+    description: "Lists the available commands or describes one",
+    func: cmd_help,
+    completes_vars: false,
+};
+
+pub const CMD_CVARLIST: Command = Command {
+    name: "cvarlist",
+    usage: "cvarlist [prefix]",
+    description: "Lists the registered cvars and their values",
+    func: cmd_cvarlist,
+    completes_vars: false,
+};
+
+pub fn register_commands(commands: &mut Commands) {
+    commands.register(CMD_CONNECT);
+    commands.register(CMD_DISCONNECT);
+    commands.register(CMD_QUIT);
+    commands.register(CMD_SAY);
+    commands.register(CMD_SET);
+    commands.register(CMD_GET);
+    commands.register(CMD_RESET);
+    commands.register(CMD_HELP);
+    commands.register(CMD_CVARLIST);
+}
+
+#[derive(Default)]
+pub struct Commands {
+    commands: BTreeMap<&'static str, Command>,
+}
+
+impl Commands {
+    pub fn new() -> Commands {
+        Default::default()
+    }
+
+    pub fn register(&mut self, command: Command) {
+        if self.commands.contains_key(command.name) {
+            panic!("Command registered twice {}", command.name);
+        }
+        self.commands.insert(command.name, command);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Command> {
+        self.commands.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Command> {
+        self.commands.values()
+    }
+
+    /// Runs a line entered into the console.
+    pub fn execute(&self, game: &mut crate::Game, line: &str) -> Result<(), String> {
+        let args = line.split_whitespace().collect::<Vec<_>>();
+        let (name, args) = match args.split_first() {
+            Some(val) => val,
+            None => return Ok(()),
+        };
+        let command = self
+            .commands
+            .get(*name)
+            .ok_or_else(|| format!("Unknown command: {}, try 'help'", name))?;
+        (command.func)(game, args)
+    }
+
+    /// Returns the part of the input to keep and the possible
+    /// completions of the word currently being typed.
+    pub fn complete(&self, vars: &Vars, input: &str) -> (String, Vec<String>) {
+        let (prefix, word) = match input.rfind(' ') {
+            Some(pos) => input.split_at(pos + 1),
+            None => ("", input),
+        };
+        let args = prefix.split_whitespace().collect::<Vec<_>>();
+        let candidates = match args.as_slice() {
+            [] => self
+                .commands
+                .keys()
+                .filter(|v| v.starts_with(word))
+                .map(|v| v.to_string())
+                .collect(),
+            [name] if self.get(name).map_or(false, |v| v.completes_vars) => vars
+                .names()
+                .into_iter()
+                .filter(|v| v.starts_with(word))
+                .map(|v| v.to_string())
+                .collect(),
+            _ => vec![],
+        };
+        (prefix.to_owned(), candidates)
+    }
+}
+
+/// Returns the value of a cvar for display, hiding session secrets
+/// so they don't end up on screen.
+fn display_value(vars: &Vars, name: &str) -> Option<String> {
+    let value = vars.get_string(name)?;
+    if name.starts_with("auth_") {
+        Some("<hidden>".to_owned())
+    } else {
+        Some(value)
+    }
+}
+
+fn print(game: &crate::Game, msg: &str) {
+    game.console
+        .lock()
+        .unwrap()
+        .print(Component::Text(TextComponent::new(msg)));
+}
+
+fn print_colored(game: &crate::Game, msg: &str, color: Color) {
+    let mut msg = TextComponent::new(msg);
+    msg.modifier.color = Some(color);
+    game.console.lock().unwrap().print(Component::Text(msg));
+}
+
+fn cmd_connect(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let address = match args {
+        [address] => *address,
+        _ => return Err(format!("Usage: {}", CMD_CONNECT.usage)),
+    };
+    if game.server.is_connected() {
+        game.server.disconnect(None);
+    }
+    game.focused = false;
+    game.screen_sys
+        .replace_screen(Box::new(screen::connecting::Connecting::new(address)));
+    game.connect_to(address);
+    Ok(())
+}
+
+fn cmd_disconnect(game: &mut crate::Game, _args: &[&str]) -> Result<(), String> {
+    if !game.server.is_connected() {
+        return Err("Not connected to a server".to_owned());
+    }
+    game.server.disconnect(None);
+    game.focused = false;
+    game.screen_sys
+        .replace_screen(Box::new(screen::ServerList::new(None)));
+    Ok(())
+}
+
+fn cmd_quit(game: &mut crate::Game, _args: &[&str]) -> Result<(), String> {
+    game.should_close = true;
+    Ok(())
+}
+
+fn cmd_say(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    if args.is_empty() {
+        return Err(format!("Usage: {}", CMD_SAY.usage));
+    }
+    if !game.server.is_connected() {
+        return Err("Not connected to a server".to_owned());
+    }
+    game.server.send_chat(&args.join(" "));
+    Ok(())
+}
+
+fn cmd_set(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let (name, value) = match args.split_first() {
+        Some((name, value)) if !value.is_empty() => (*name, value.join(" ")),
+        _ => return Err(format!("Usage: {}", CMD_SET.usage)),
+    };
+    game.vars.set_string(name, &value)?;
+    print(
+        game,
+        &format!("{} = {}", name, display_value(&game.vars, name).unwrap()),
+    );
+    Ok(())
+}
+
+fn cmd_get(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let name = match args {
+        [name] => *name,
+        _ => return Err(format!("Usage: {}", CMD_GET.usage)),
+    };
+    let value = display_value(&game.vars, name).ok_or_else(|| format!("Unknown cvar: {}", name))?;
+    print(game, &format!("{} = {}", name, value));
+    if let Some(description) = game.vars.description(name) {
+        print_colored(game, description, Color::Gray);
+    }
+    Ok(())
+}
+
+fn cmd_reset(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let name = match args {
+        [name] => *name,
+        _ => return Err(format!("Usage: {}", CMD_RESET.usage)),
+    };
+    game.vars.reset(name)?;
+    print(
+        game,
+        &format!("{} = {}", name, display_value(&game.vars, name).unwrap()),
+    );
+    Ok(())
+}
+
+fn cmd_help(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let commands = game.commands.clone();
+    match args {
+        [] => {
+            for command in commands.iter() {
+                print(
+                    game,
+                    &format!("{} - {}", command.usage, command.description),
+                );
+            }
+        }
+        [name] => {
+            let command = commands
+                .get(name)
+                .ok_or_else(|| format!("Unknown command: {}", name))?;
+            print(game, &format!("Usage: {}", command.usage));
+            print_colored(game, command.description, Color::Gray);
+        }
+        _ => return Err(format!("Usage: {}", CMD_HELP.usage)),
+    }
+    Ok(())
+}
+
+fn cmd_cvarlist(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let prefix = match args {
+        [] => "",
+        [prefix] => *prefix,
+        _ => return Err(format!("Usage: {}", CMD_CVARLIST.usage)),
+    };
+    for name in game.vars.names() {
+        if !name.starts_with(prefix) {
+            continue;
+        }
+        let value = display_value(&game.vars, name).unwrap_or_default();
+        print(game, &format!("{} = {}", name, value));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::CVar;
+    use std::marker::PhantomData;
+
+    const TEST_NAME: CVar<String> = CVar {
+        ty: PhantomData,
+        name: "cl_name",
+        description: "",
+        mutable: true,
+        serializable: false,
+        default: &|| "steven".to_owned(),
+    };
+
+    const TEST_NAME_COLOR: CVar<i64> = CVar {
+        ty: PhantomData,
+        name: "cl_name_color",
+        description: "",
+        mutable: true,
+        serializable: false,
+        default: &|| 0,
+    };
+
+    fn commands() -> Commands {
+        let mut commands = Commands::new();
+        register_commands(&mut commands);
+        commands
+    }
+
+    fn vars() -> Vars {
+        let mut vars = Vars::new();
+        vars.register(TEST_NAME);
+        vars.register(TEST_NAME_COLOR);
+        vars
+    }
+
+    #[test]
+    fn registered_commands() {
+        let commands = commands();
+        assert_eq!(commands.get("set").map(|v| v.usage), Some(CMD_SET.usage));
+        assert!(commands.get("get").map_or(false, |v| v.completes_vars));
+        assert!(commands.get("reset").map_or(false, |v| v.completes_vars));
+        assert!(commands.get("connect").map_or(false, |v| !v.completes_vars));
+        assert!(commands.get("unknown").is_none());
+        // Listed in name order
+        let names = commands.iter().map(|v| v.name).collect::<Vec<_>>();
+        let mut sorted = names.clone();
+        sorted.sort_unstable();
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    #[should_panic(expected = "Command registered twice set")]
+    fn registering_twice_panics() {
+        commands().register(CMD_SET);
+    }
+
+    #[test]
+    fn completes_command_names() {
+        let (commands, vars) = (commands(), vars());
+        assert_eq!(
+            commands.complete(&vars, "c"),
+            (
+                "".to_owned(),
+                vec!["connect".to_owned(), "cvarlist".to_owned()]
+            )
+        );
+        assert_eq!(
+            commands.complete(&vars, "q"),
+            ("".to_owned(), vec!["quit".to_owned()])
+        );
+        assert_eq!(commands.complete(&vars, "x"), ("".to_owned(), vec![]));
+    }
+
+    #[test]
+    fn completes_cvar_names() {
+        let (commands, vars) = (commands(), vars());
+        assert_eq!(
+            commands.complete(&vars, "set cl_n"),
+            (
+                "set ".to_owned(),
+                vec!["cl_name".to_owned(), "cl_name_color".to_owned()]
+            )
+        );
+        assert_eq!(
+            commands.complete(&vars, "reset  cl_name_"),
+            ("reset  ".to_owned(), vec!["cl_name_color".to_owned()])
+        );
+        // Only the first argument is a cvar, and only for commands that
+        // take one
+        assert_eq!(
+            commands.complete(&vars, "set cl_name cl_"),
+            ("set cl_name ".to_owned(), vec![])
+        );
+        assert_eq!(
+            commands.complete(&vars, "connect cl_"),
+            ("connect ".to_owned(), vec![])
+        );
+    }
+}
@@ -24,6 +24,9 @@ use crate::format::{Color, Component, TextComponent};
 use crate::render;
 use crate::ui;
 
+pub mod commands;
+pub use self::commands::{register_commands, Command, Commands};
+
 #[cfg(target_arch = "wasm32")]
 use web_sys;
 #[cfg(target_arch = "wasm32")]
@@ -43,6 +46,9 @@ fn println_level(_level: log::Level, s: String) {
     println!("{}", s);
 }
 
+// Space reserved at the bottom of the console for the input line
+const INPUT_HEIGHT: f64 = 18.0;
+
 const FILTERED_CRATES: &[&str] = &[
     //"reqwest", // TODO: needed?
     "mime",
@@ -98,8 +104,15 @@ impl Var for CVar<i64> {
         val.downcast_ref::<i64>().unwrap().to_string()
     }
 
-    fn deserialize(&self, input: &str) -> Box<dyn Any> {
-        Box::new(input.parse::<i64>().unwrap())
+    fn deserialize(&self, input: &str) -> Option<Box<dyn Any>> {
+        input
+            .parse::<i64>()
+            .ok()
+            .map(|v| Box::new(v) as Box<dyn Any>)
+    }
+
+    fn default_value(&self) -> Box<dyn Any> {
+        Box::new((self.default)())
     }
 
     fn description(&self) -> &'static str {
@@ -109,6 +122,10 @@ impl Var for CVar<i64> {
     fn can_serialize(&self) -> bool {
         self.serializable
     }
+
+    fn is_mutable(&self) -> bool {
+        self.mutable
+    }
 }
 
 impl Var for CVar<bool> {
@@ -116,8 +133,15 @@ impl Var for CVar<bool> {
         val.downcast_ref::<bool>().unwrap().to_string()
     }
 
-    fn deserialize(&self, input: &str) -> Box<dyn Any> {
-        Box::new(input.parse::<bool>().unwrap())
+    fn deserialize(&self, input: &str) -> Option<Box<dyn Any>> {
+        input
+            .parse::<bool>()
+            .ok()
+            .map(|v| Box::new(v) as Box<dyn Any>)
+    }
+
+    fn default_value(&self) -> Box<dyn Any> {
+        Box::new((self.default)())
     }
 
     fn description(&self) -> &'static str {
@@ -127,6 +151,10 @@ impl Var for CVar<bool> {
     fn can_serialize(&self) -> bool {
         self.serializable
     }
+
+    fn is_mutable(&self) -> bool {
+        self.mutable
+    }
 }
 
 impl Var for CVar<String> {
@@ -134,8 +162,17 @@ impl Var for CVar<String> {
         format!("\"{}\"", val.downcast_ref::<String>().unwrap())
     }
 
-    fn deserialize(&self, input: &str) -> Box<dyn Any> {
-        Box::new(input[1..input.len() - 1].to_owned())
+    fn deserialize(&self, input: &str) -> Option<Box<dyn Any>> {
+        // Quotes are optional when set from the console
+        let input = input
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(input);
+        Some(Box::new(input.to_owned()))
+    }
+
+    fn default_value(&self) -> Box<dyn Any> {
+        Box::new((self.default)())
     }
 
     fn description(&self) -> &'static str {
@@ -144,13 +181,19 @@ impl Var for CVar<String> {
     fn can_serialize(&self) -> bool {
         self.serializable
     }
+
+    fn is_mutable(&self) -> bool {
+        self.mutable
+    }
 }
 
 pub trait Var {
     fn serialize(&self, val: &Box<dyn Any>) -> String;
-    fn deserialize(&self, input: &str) -> Box<dyn Any>;
+    fn deserialize(&self, input: &str) -> Option<Box<dyn Any>>;
+    fn default_value(&self) -> Box<dyn Any>;
     fn description(&self) -> &'static str;
     fn can_serialize(&self) -> bool;
+    fn is_mutable(&self) -> bool;
 }
 
 #[derive(Default)]
@@ -210,15 +253,69 @@ impl Vars {
                 let (name, arg) = (&parts[0], &parts[1]);
                 if let Some(var_name) = self.names.get(name) {
                     let var = self.vars.get(var_name).unwrap();
-                    let val = var.deserialize(arg);
-                    if var.can_serialize() {
-                        self.var_values.insert(var_name, RefCell::new(val));
+                    if !var.can_serialize() {
+                        continue;
+                    }
+                    match var.deserialize(arg) {
+                        Some(val) => {
+                            self.var_values.insert(var_name, RefCell::new(val));
+                        }
+                        None => log::warn!("Invalid value for {} in conf.cfg: {}", name, arg),
                     }
                 }
             }
         }
     }
 
+    /// Returns the names of all registered vars in sorted order.
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names = self.vars.keys().copied().collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    pub fn description(&self, name: &str) -> Option<&'static str> {
+        self.vars.get(name).map(|v| v.description())
+    }
+
+    /// Returns the value of the named var in its config file format.
+    pub fn get_string(&self, name: &str) -> Option<String> {
+        let var = self.vars.get(name)?;
+        let val = self.var_values.get(name)?.borrow();
+        Some(var.serialize(&val))
+    }
+
+    /// Parses and sets the value of the named var, used by the
+    /// console where the var isn't known at compile time.
+    pub fn set_string(&self, name: &str, input: &str) -> Result<(), String> {
+        let var = self
+            .vars
+            .get(name)
+            .ok_or_else(|| format!("Unknown cvar: {}", name))?;
+        if !var.is_mutable() {
+            return Err(format!("{} cannot be changed at runtime", name));
+        }
+        let val = var
+            .deserialize(input)
+            .ok_or_else(|| format!("Invalid value for {}: {}", name, input))?;
+        *self.var_values.get(name).unwrap().borrow_mut() = val;
+        self.save_config();
+        Ok(())
+    }
+
+    pub fn reset(&self, name: &str) -> Result<(), String> {
+        let var = self
+            .vars
+            .get(name)
+            .ok_or_else(|| format!("Unknown cvar: {}", name))?;
+        if !var.is_mutable() {
+            return Err(format!("{} cannot be changed at runtime", name));
+        }
+        *self.var_values.get(name).unwrap().borrow_mut() = var.default_value();
+        self.save_config();
+        Ok(())
+    }
+
     pub fn save_config(&self) {
         let mut file = BufWriter::new(fs::File::create("conf.cfg").unwrap());
         for (name, var) in &self.vars {
@@ -246,13 +343,23 @@ pub struct Console {
     log_level_term: log::Level,
     log_level_file: log::Level,
 
+    input: String,
+    input_history: Vec<String>,
+    input_history_pos: Option<usize>,
+    submitted: Vec<String>,
+    cursor_tick: f64,
+
     elements: Option<ConsoleElements>,
     active: bool,
+    // Set when the console is toggled, as the toggle key also arrives as
+    // a typed character which shouldn't be entered anywhere
+    toggle_char_pending: bool,
     position: f64,
 }
 
 struct ConsoleElements {
     background: ui::ImageRef,
+    input: ui::TextRef,
     lines: Vec<ui::FormattedRef>,
 }
 
@@ -271,8 +378,15 @@ impl Console {
             log_level_term: log::Level::Info,
             log_level_file: log::Level::Trace,
 
+            input: String::new(),
+            input_history: vec![],
+            input_history_pos: None,
+            submitted: vec![],
+            cursor_tick: 0.0,
+
             elements: None,
             active: false,
+            toggle_char_pending: false,
             position: -220.0,
         }
     }
@@ -288,12 +402,99 @@ impl Console {
 
     pub fn toggle(&mut self) {
         self.active = !self.active;
+        self.toggle_char_pending = true;
+    }
+
+    /// Returns whether a typed character came from the key that toggled
+    /// the console, only the first one after toggling does.
+    pub fn take_toggle_char(&mut self) -> bool {
+        std::mem::take(&mut self.toggle_char_pending)
     }
 
     pub fn activate(&mut self) {
         self.active = true;
     }
 
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    pub fn key_type(&mut self, c: char) {
+        if c == '\x7f' || c == '\x08' {
+            // Backspace
+            self.input.pop();
+            return;
+        }
+        if c < ' ' {
+            return;
+        }
+        self.input.push(c);
+    }
+
+    /// Queues the current input line to be executed by the game.
+    pub fn submit(&mut self) {
+        let line = std::mem::take(&mut self.input);
+        let line = line.trim();
+        self.input_history_pos = None;
+        if line.is_empty() {
+            return;
+        }
+        self.print(Component::Text(TextComponent::new(&format!("> {}", line))));
+        if self.input_history.last().map_or(true, |v| v != line) {
+            self.input_history.push(line.to_owned());
+        }
+        self.submitted.push(line.to_owned());
+    }
+
+    pub fn take_submitted(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.submitted)
+    }
+
+    /// Replaces the input line with a previously submitted line.
+    pub fn recall(&mut self, older: bool) {
+        if self.input_history.is_empty() {
+            return;
+        }
+        self.input_history_pos = match (self.input_history_pos, older) {
+            (None, true) => Some(self.input_history.len() - 1),
+            (None, false) => None,
+            (Some(pos), true) => Some(pos.saturating_sub(1)),
+            (Some(pos), false) if pos + 1 < self.input_history.len() => Some(pos + 1),
+            (Some(_), false) => None,
+        };
+        self.input = self
+            .input_history_pos
+            .map_or_else(String::new, |pos| self.input_history[pos].clone());
+    }
+
+    /// Completes the command or cvar name being typed. When there are
+    /// multiple candidates the common prefix is filled in and the
+    /// candidates are listed.
+    pub fn complete(&mut self, commands: &commands::Commands, vars: &Vars) {
+        let (prefix, candidates) = commands.complete(vars, &self.input);
+        match candidates.len() {
+            0 => {}
+            1 => self.input = format!("{}{} ", prefix, candidates[0]),
+            _ => {
+                let mut common = candidates[0].clone();
+                for candidate in &candidates[1..] {
+                    while !candidate.starts_with(&common) {
+                        common.pop();
+                    }
+                }
+                self.input = format!("{}{}", prefix, common);
+                self.print(Component::Text(TextComponent::new(&candidates.join("  "))));
+            }
+        }
+    }
+
+    /// Adds a line to the console without logging it.
+    pub fn print(&mut self, msg: Component) {
+        self.history.remove(0);
+        self.history.push(msg);
+        self.dirty = true;
+    }
+
     pub fn tick(
         &mut self,
         ui_container: &mut ui::Container,
@@ -329,8 +530,14 @@ impl Console {
                 .colour((0, 0, 0, 180))
                 .draw_index(500)
                 .create(ui_container);
+            let input = ui::TextBuilder::new()
+                .text("")
+                .position(5.0, 5.0)
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+                .create(&mut *background.borrow_mut());
             self.elements = Some(ConsoleElements {
                 background,
+                input,
                 lines: vec![],
             });
             self.dirty = true;
@@ -340,11 +547,22 @@ impl Console {
         background.y = self.position;
         background.width = w;
 
+        self.cursor_tick += delta;
+        if self.cursor_tick > 3000.0 {
+            self.cursor_tick -= 3000.0;
+        }
+        let cursor = if self.active && ((self.cursor_tick / 30.0) as i32) % 2 == 0 {
+            "|"
+        } else {
+            ""
+        };
+        elements.input.borrow_mut().text = format!("> {}{}", self.input, cursor);
+
         if self.dirty {
             self.dirty = false;
             elements.lines.clear();
 
-            let mut offset = 0.0;
+            let mut offset = INPUT_HEIGHT;
             for line in self.history.iter().rev() {
                 if offset >= 210.0 {
                     break;
@@ -392,7 +610,6 @@ impl Console {
             println_level(record.level(), line);
         }
 
-        self.history.remove(0);
         let mut msg = TextComponent::new("");
         msg.modifier.extra = Some(vec![
             Component::Text(TextComponent::new("[")),
@@ -423,8 +640,7 @@ impl Console {
             Component::Text(TextComponent::new("] ")),
             Component::Text(TextComponent::new(&format!("{}", record.args()))),
         ]);
-        self.history.push(Component::Text(msg));
-        self.dirty = true;
+        self.print(Component::Text(msg));
     }
 }
 
@@ -454,3 +670,66 @@ impl log::Log for ConsoleProxy {
 
 unsafe impl Send for ConsoleProxy {}
 unsafe impl Sync for ConsoleProxy {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_FOV: CVar<i64> = CVar {
+        ty: PhantomData,
+        name: "r_fov",
+        description: "Field of view",
+        mutable: true,
+        serializable: false,
+        default: &|| 90,
+    };
+
+    const TEST_LOCKED: CVar<bool> = CVar {
+        ty: PhantomData,
+        name: "r_locked",
+        description: "",
+        mutable: false,
+        serializable: false,
+        default: &|| true,
+    };
+
+    fn vars() -> Vars {
+        let mut vars = Vars::new();
+        vars.register(TEST_FOV);
+        vars.register(TEST_LOCKED);
+        vars
+    }
+
+    #[test]
+    fn var_strings() {
+        let vars = vars();
+        assert_eq!(vars.get_string("r_fov").as_deref(), Some("90"));
+        assert_eq!(vars.get_string("r_locked").as_deref(), Some("true"));
+        assert_eq!(vars.get_string("r_unknown"), None);
+        assert_eq!(vars.description("r_fov"), Some("Field of view"));
+        assert_eq!(vars.names(), vec!["r_fov", "r_locked"]);
+    }
+
+    #[test]
+    fn rejected_changes() {
+        let vars = vars();
+        assert_eq!(
+            vars.set_string("r_unknown", "1"),
+            Err("Unknown cvar: r_unknown".to_owned())
+        );
+        assert_eq!(
+            vars.set_string("r_fov", "wide"),
+            Err("Invalid value for r_fov: wide".to_owned())
+        );
+        assert_eq!(
+            vars.set_string("r_locked", "false"),
+            Err("r_locked cannot be changed at runtime".to_owned())
+        );
+        assert_eq!(
+            vars.reset("r_locked"),
+            Err("r_locked cannot be changed at runtime".to_owned())
+        );
+        assert_eq!(*vars.get(TEST_FOV), 90);
+        assert!(*vars.get(TEST_LOCKED));
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ecs;
+use crate::render;
+use crate::world;
+
+pub fn add_systems(m: &mut ecs::Manager) {
+    let sys = TickEffects::new(m);
+    m.add_system(sys);
+}
+
+/// A status effect from a potion, beacon or the server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EffectKind {
+    Speed,
+    Slowness,
+    Haste,
+    MiningFatigue,
+    Strength,
+    InstantHealth,
+    InstantDamage,
+    JumpBoost,
+    Nausea,
+    Regeneration,
+    Resistance,
+    FireResistance,
+    WaterBreathing,
+    Invisibility,
+    Blindness,
+    NightVision,
+    Hunger,
+    Weakness,
+    Poison,
+    Wither,
+    HealthBoost,
+    Absorption,
+    Saturation,
+    Glowing,
+    Levitation,
+    Luck,
+    Unluck,
+    SlowFalling,
+    ConduitPower,
+    DolphinsGrace,
+    BadOmen,
+    HeroOfTheVillage,
+}
+
+// In id order, starting from 1
+const KINDS: [EffectKind; 32] = {
+    use self::EffectKind::*;
+    [
+        Speed,
+        Slowness,
+        Haste,
+        MiningFatigue,
+        Strength,
+        InstantHealth,
+        InstantDamage,
+        JumpBoost,
+        Nausea,
+        Regeneration,
+        Resistance,
+        FireResistance,
+        WaterBreathing,
+        Invisibility,
+        Blindness,
+        NightVision,
+        Hunger,
+        Weakness,
+        Poison,
+        Wither,
+        HealthBoost,
+        Absorption,
+        Saturation,
+        Glowing,
+        Levitation,
+        Luck,
+        Unluck,
+        SlowFalling,
+        ConduitPower,
+        DolphinsGrace,
+        BadOmen,
+        HeroOfTheVillage,
+    ]
+};
+
+impl EffectKind {
+    /// Returns the effect with the given id, new effects have only ever
+    /// been added to the end so the ids are the same in every version.
+    pub fn from_id(id: i32) -> Option<EffectKind> {
+        usize::try_from(id - 1)
+            .ok()
+            .and_then(|id| KINDS.get(id))
+            .copied()
+    }
+
+    pub fn name(self) -> &'static str {
+        use self::EffectKind::*;
+        match self {
+            Speed => "Speed",
+            Slowness => "Slowness",
+            Haste => "Haste",
+            MiningFatigue => "Mining Fatigue",
+            Strength => "Strength",
+            InstantHealth => "Instant Health",
+            InstantDamage => "Instant Damage",
+            JumpBoost => "Jump Boost",
+            Nausea => "Nausea",
+            Regeneration => "Regeneration",
+            Resistance => "Resistance",
+            FireResistance => "Fire Resistance",
+            WaterBreathing => "Water Breathing",
+            Invisibility => "Invisibility",
+            Blindness => "Blindness",
+            NightVision => "Night Vision",
+            Hunger => "Hunger",
+            Weakness => "Weakness",
+            Poison => "Poison",
+            Wither => "Wither",
+            HealthBoost => "Health Boost",
+            Absorption => "Absorption",
+            Saturation => "Saturation",
+            Glowing => "Glowing",
+            Levitation => "Levitation",
+            Luck => "Luck",
+            Unluck => "Bad Luck",
+            SlowFalling => "Slow Falling",
+            ConduitPower => "Conduit Power",
+            DolphinsGrace => "Dolphin's Grace",
+            BadOmen => "Bad Omen",
+            HeroOfTheVillage => "Hero of the Village",
+        }
+    }
+
+    /// Whether the effect helps whoever has it, these are listed before
+    /// the others.
+    pub fn is_beneficial(self) -> bool {
+        use self::EffectKind::*;
+        matches!(
+            self,
+            Speed
+                | Haste
+                | Strength
+                | InstantHealth
+                | JumpBoost
+                | Regeneration
+                | Resistance
+                | FireResistance
+                | WaterBreathing
+                | Invisibility
+                | NightVision
+                | HealthBoost
+                | Absorption
+                | Saturation
+                | Luck
+                | SlowFalling
+                | ConduitPower
+                | DolphinsGrace
+                | HeroOfTheVillage
+        )
+    }
+
+    /// Returns the column and row of the effect's icon in the grid of
+    /// 18 pixel icons at the bottom of gui/container/inventory. Instant
+    /// effects have none and those added after 1.12 aren't in the
+    /// client's resources.
+    pub fn icon(self) -> Option<(i32, i32)> {
+        use self::EffectKind::*;
+        Some(match self {
+            Speed => (0, 0),
+            Slowness => (1, 0),
+            Haste => (2, 0),
+            MiningFatigue => (3, 0),
+            Strength => (4, 0),
+            Weakness => (5, 0),
+            Poison => (6, 0),
+            Regeneration => (7, 0),
+            Invisibility => (0, 1),
+            Hunger => (1, 1),
+            JumpBoost => (2, 1),
+            Nausea => (3, 1),
+            NightVision => (4, 1),
+            Blindness => (5, 1),
+            Resistance => (6, 1),
+            FireResistance => (7, 1),
+            WaterBreathing => (0, 2),
+            Wither => (1, 2),
+            Absorption => (2, 2),
+            Levitation => (3, 2),
+            Glowing => (4, 2),
+            Luck => (5, 2),
+            Unluck => (6, 2),
+            HealthBoost => (7, 2),
+            _ => return None,
+        })
+    }
+}
+
+/// An effect currently on an entity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Effect {
+    pub kind: EffectKind,
+    pub amplifier: u8,
+    /// Ticks until the effect wears off
+    pub duration: i32,
+}
+
+impl Effect {
+    /// The effect's strength, starting from 1.
+    pub fn level(&self) -> i32 {
+        i32::from(self.amplifier) + 1
+    }
+
+    /// Effects from beacons and commands last long enough that they are
+    /// shown as never ending.
+    pub fn is_endless(&self) -> bool {
+        self.duration >= 32767
+    }
+}
+
+/// The effects on an entity in the order they were given.
+#[derive(Default)]
+pub struct Effects {
+    effects: Vec<Effect>,
+}
+
+impl Effects {
+    pub fn new() -> Effects {
+        Default::default()
+    }
+
+    pub fn get(&self, kind: EffectKind) -> Option<&Effect> {
+        self.effects.iter().find(|v| v.kind == kind)
+    }
+
+    /// Returns the level of the effect or 0 if the entity doesn't have
+    /// it.
+    pub fn level(&self, kind: EffectKind) -> i32 {
+        self.get(kind).map_or(0, Effect::level)
+    }
+
+    /// Adds an effect, replacing any of the same kind.
+    pub fn add(&mut self, effect: Effect) {
+        if let Some(current) = self.effects.iter_mut().find(|v| v.kind == effect.kind) {
+            *current = effect;
+        } else {
+            self.effects.push(effect);
+        }
+    }
+
+    pub fn remove(&mut self, kind: EffectKind) {
+        self.effects.retain(|v| v.kind != kind);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Effect> {
+        self.effects.iter()
+    }
+}
+
+/// Counts down the time left on effects. They are only removed once the
+/// server says they have run out.
+struct TickEffects {
+    filter: ecs::Filter,
+    effects: ecs::Key<Effects>,
+}
+
+impl TickEffects {
+    fn new(m: &mut ecs::Manager) -> TickEffects {
+        let effects = m.get_key();
+        TickEffects {
+            filter: ecs::Filter::new().with(effects),
+            effects,
+        }
+    }
+}
+
+impl ecs::System for TickEffects {
+    fn filter(&self) -> &ecs::Filter {
+        &self.filter
+    }
+
+    fn update(&mut self, m: &mut ecs::Manager, _: &mut world::World, _: &mut render::Renderer) {
+        for e in m.find(&self.filter) {
+            let effects = m.get_component_mut(e, self.effects).unwrap();
+            for effect in &mut effects.effects {
+                if !effect.is_endless() && effect.duration > 0 {
+                    effect.duration -= 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids() {
+        assert_eq!(EffectKind::from_id(0), None);
+        assert_eq!(EffectKind::from_id(1), Some(EffectKind::Speed));
+        assert_eq!(EffectKind::from_id(16), Some(EffectKind::NightVision));
+        assert_eq!(EffectKind::from_id(32), Some(EffectKind::HeroOfTheVillage));
+        assert_eq!(EffectKind::from_id(33), None);
+    }
+
+    #[test]
+    fn adding_replaces() {
+        let mut effects = Effects::new();
+        let speed = |amplifier| Effect {
+            kind: EffectKind::Speed,
+            amplifier,
+            duration: 100,
+        };
+        effects.add(speed(0));
+        effects.add(speed(1));
+        assert_eq!(effects.iter().count(), 1);
+        assert_eq!(effects.level(EffectKind::Speed), 2);
+        effects.remove(EffectKind::Speed);
+        assert_eq!(effects.level(EffectKind::Speed), 0);
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::item;
+
+/// A slot other players can see an entity's items in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EquipmentSlot {
+    MainHand,
+    OffHand,
+    Feet,
+    Legs,
+    Chest,
+    Head,
+}
+
+impl EquipmentSlot {
+    /// Returns the slot with the id the server uses for it.
+    pub fn from_id(protocol_version: i32, id: i32) -> Option<EquipmentSlot> {
+        use self::EquipmentSlot::*;
+        // The off hand came in 15w31a, before the armor followed the
+        // held item
+        let slots: &[EquipmentSlot] = if protocol_version >= 49 {
+            &[MainHand, OffHand, Feet, Legs, Chest, Head]
+        } else {
+            &[MainHand, Feet, Legs, Chest, Head]
+        };
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| slots.get(id))
+            .copied()
+    }
+}
+
+/// The items an entity is holding and wearing.
+#[derive(Default)]
+pub struct Equipment {
+    slots: [Option<item::Stack>; 6],
+}
+
+impl Equipment {
+    pub fn new() -> Equipment {
+        Default::default()
+    }
+
+    pub fn get(&self, slot: EquipmentSlot) -> Option<&item::Stack> {
+        self.slots[slot as usize].as_ref()
+    }
+
+    pub fn set(&mut self, slot: EquipmentSlot, stack: Option<item::Stack>) {
+        self.slots[slot as usize] = stack;
+    }
+}
@@ -0,0 +1,1052 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A type of entity along with the size of its bounding box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntityKind {
+    /// Name of the entity type in the registry, using the current
+    /// name where it has been renamed.
+    pub name: &'static str,
+    pub width: f64,
+    pub height: f64,
+    /// Texture drawn on the entity's placeholder model, if the
+    /// client's resources have one.
+    pub texture: Option<&'static str>,
+}
+
+const fn kind(
+    name: &'static str,
+    width: f64,
+    height: f64,
+    texture: Option<&'static str>,
+) -> EntityKind {
+    EntityKind {
+        name,
+        width,
+        height,
+        texture,
+    }
+}
+
+impl EntityKind {
+    pub fn by_name(name: &str) -> Option<EntityKind> {
+        KINDS.iter().find(|v| v.name == name).copied()
+    }
+
+    /// Returns the kind of entity spawned by the spawn mob packet.
+    pub fn from_mob_id(protocol_version: i32, id: i32) -> Option<EntityKind> {
+        if protocol_version >= 404 {
+            EntityKind::from_registry_id(protocol_version, id)
+        } else {
+            EntityKind::by_name(legacy_mob_name(id)?)
+        }
+    }
+
+    /// Returns the kind of entity spawned by the spawn object packet,
+    /// before 1.14 some objects used the data field to pick a variant.
+    pub fn from_object_id(protocol_version: i32, id: i32, data: i32) -> Option<EntityKind> {
+        if protocol_version >= 477 {
+            EntityKind::from_registry_id(protocol_version, id)
+        } else {
+            EntityKind::by_name(legacy_object_name(id, data)?)
+        }
+    }
+
+    fn from_registry_id(protocol_version: i32, id: i32) -> Option<EntityKind> {
+        let types = if protocol_version >= 755 {
+            TYPES_1_17
+        } else if protocol_version >= 751 {
+            TYPES_1_16_2
+        } else if protocol_version >= 735 {
+            TYPES_1_16
+        } else if protocol_version >= 573 {
+            TYPES_1_15
+        } else if protocol_version >= 477 {
+            TYPES_1_14
+        } else {
+            // The 1.14 snapshots are treated as 1.13
+            TYPES_1_13
+        };
+        let name = types.get(usize::try_from(id).ok()?)?;
+        EntityKind::by_name(name)
+    }
+}
+
+/// Mob ids used before 1.13, the variants split out into their own
+/// ids in 1.11 were told apart by metadata before.
+fn legacy_mob_name(id: i32) -> Option<&'static str> {
+    Some(match id {
+        4 => "elder_guardian",
+        5 => "wither_skeleton",
+        6 => "stray",
+        23 => "husk",
+        27 => "zombie_villager",
+        28 => "skeleton_horse",
+        29 => "zombie_horse",
+        30 => "armor_stand",
+        31 => "donkey",
+        32 => "mule",
+        34 => "evoker",
+        35 => "vex",
+        36 => "vindicator",
+        37 => "illusioner",
+        50 => "creeper",
+        51 => "skeleton",
+        52 => "spider",
+        53 => "giant",
+        54 => "zombie",
+        55 => "slime",
+        56 => "ghast",
+        57 => "zombified_piglin",
+        58 => "enderman",
+        59 => "cave_spider",
+        60 => "silverfish",
+        61 => "blaze",
+        62 => "magma_cube",
+        63 => "ender_dragon",
+        64 => "wither",
+        65 => "bat",
+        66 => "witch",
+        67 => "endermite",
+        68 => "guardian",
+        69 => "shulker",
+        90 => "pig",
+        91 => "sheep",
+        92 => "cow",
+        93 => "chicken",
+        94 => "squid",
+        95 => "wolf",
+        96 => "mooshroom",
+        97 => "snow_golem",
+        98 => "ocelot",
+        99 => "iron_golem",
+        100 => "horse",
+        101 => "rabbit",
+        102 => "polar_bear",
+        103 => "llama",
+        105 => "parrot",
+        120 => "villager",
+        _ => return None,
+    })
+}
+
+/// Object ids used before 1.14.
+fn legacy_object_name(id: i32, data: i32) -> Option<&'static str> {
+    Some(match id {
+        1 => "boat",
+        2 => "item",
+        3 => "area_effect_cloud",
+        10 => match data {
+            1 => "chest_minecart",
+            2 => "furnace_minecart",
+            3 => "tnt_minecart",
+            4 => "spawner_minecart",
+            5 => "hopper_minecart",
+            6 => "command_block_minecart",
+            _ => "minecart",
+        },
+        50 => "tnt",
+        51 => "end_crystal",
+        60 => "arrow",
+        61 => "snowball",
+        62 => "egg",
+        63 => "fireball",
+        64 => "small_fireball",
+        65 => "ender_pearl",
+        66 => "wither_skull",
+        67 => "shulker_bullet",
+        68 => "llama_spit",
+        70 => "falling_block",
+        71 => "item_frame",
+        72 => "eye_of_ender",
+        73 => "potion",
+        75 => "experience_bottle",
+        76 => "firework_rocket",
+        77 => "leash_knot",
+        78 => "armor_stand",
+        79 => "evoker_fangs",
+        90 => "fishing_bobber",
+        91 => "spectral_arrow",
+        93 => "dragon_fireball",
+        94 => "trident",
+        _ => return None,
+    })
+}
+
+const KINDS: &[EntityKind] = &[
+    kind("area_effect_cloud", 1.0, 0.5, None),
+    kind("armor_stand", 0.5, 1.975, Some("entity/armorstand/wood")),
+    kind("arrow", 0.5, 0.5, Some("entity/projectiles/arrow")),
+    kind("axolotl", 0.75, 0.42, None),
+    kind("bat", 0.5, 0.9, Some("entity/bat")),
+    kind("bee", 0.7, 0.6, None),
+    kind("blaze", 0.6, 1.8, Some("entity/blaze")),
+    kind("boat", 1.375, 0.5625, Some("entity/boat/boat_oak")),
+    kind("cat", 0.6, 0.7, Some("entity/cat/ocelot")),
+    kind("cave_spider", 0.7, 0.5, Some("entity/spider/cave_spider")),
+    kind("chest_minecart", 0.98, 0.7, Some("entity/minecart")),
+    kind("chicken", 0.4, 0.7, Some("entity/chicken")),
+    kind("cod", 0.5, 0.3, None),
+    kind("command_block_minecart", 0.98, 0.7, Some("entity/minecart")),
+    kind("cow", 0.9, 1.4, Some("entity/cow/cow")),
+    kind("creeper", 0.6, 1.7, Some("entity/creeper/creeper")),
+    kind("dolphin", 0.9, 0.6, None),
+    kind("donkey", 1.3965, 1.5, Some("entity/horse/donkey")),
+    kind(
+        "dragon_fireball",
+        1.0,
+        1.0,
+        Some("entity/enderdragon/dragon_fireball"),
+    ),
+    kind("drowned", 0.6, 1.95, None),
+    kind("egg", 0.25, 0.25, Some("items/egg")),
+    kind(
+        "elder_guardian",
+        1.9975,
+        1.9975,
+        Some("entity/guardian_elder"),
+    ),
+    kind(
+        "end_crystal",
+        2.0,
+        2.0,
+        Some("entity/endercrystal/endercrystal"),
+    ),
+    kind("ender_dragon", 16.0, 8.0, Some("entity/enderdragon/dragon")),
+    kind("ender_pearl", 0.25, 0.25, Some("items/ender_pearl")),
+    kind("enderman", 0.6, 2.9, Some("entity/enderman/enderman")),
+    kind("endermite", 0.4, 0.3, Some("entity/endermite")),
+    kind("evoker", 0.6, 1.95, Some("entity/illager/evoker")),
+    kind("evoker_fangs", 0.5, 0.8, Some("entity/illager/fangs")),
+    kind(
+        "experience_bottle",
+        0.25,
+        0.25,
+        Some("items/experience_bottle"),
+    ),
+    kind("experience_orb", 0.5, 0.5, Some("entity/experience_orb")),
+    kind("eye_of_ender", 0.25, 0.25, Some("items/ender_eye")),
+    kind("falling_block", 0.98, 0.98, Some("blocks/sand")),
+    kind("fireball", 1.0, 1.0, Some("items/fireball")),
+    kind("firework_rocket", 0.25, 0.25, Some("items/fireworks")),
+    kind("fishing_bobber", 0.25, 0.25, Some("entity/fishing_hook")),
+    kind("fox", 0.6, 0.7, None),
+    kind("furnace_minecart", 0.98, 0.7, Some("entity/minecart")),
+    kind("ghast", 4.0, 4.0, Some("entity/ghast/ghast")),
+    kind("giant", 3.6, 12.0, Some("entity/zombie/zombie")),
+    kind("glow_item_frame", 0.5, 0.5, None),
+    kind("glow_squid", 0.8, 0.8, None),
+    kind("goat", 0.9, 1.3, None),
+    kind("guardian", 0.85, 0.85, Some("entity/guardian")),
+    kind("hoglin", 1.3965, 1.4, None),
+    kind("hopper_minecart", 0.98, 0.7, Some("entity/minecart")),
+    kind("horse", 1.3965, 1.6, Some("entity/horse/horse_brown")),
+    kind("husk", 0.6, 1.95, Some("entity/zombie/husk")),
+    kind("illusioner", 0.6, 1.95, Some("entity/illager/illusionist")),
+    kind("iron_golem", 1.4, 2.7, Some("entity/iron_golem")),
+    kind("item", 0.25, 0.25, None),
+    kind("item_frame", 0.5, 0.5, Some("blocks/itemframe_background")),
+    kind("leash_knot", 0.375, 0.5, Some("entity/lead_knot")),
+    kind("lightning_bolt", 0.0, 0.0, None),
+    kind("llama", 0.9, 1.87, Some("entity/llama/llama_creamy")),
+    kind("llama_spit", 0.25, 0.25, Some("entity/llama/spit")),
+    kind("magma_cube", 1.02, 1.02, Some("entity/slime/magmacube")),
+    kind("marker", 0.0, 0.0, None),
+    kind("minecart", 0.98, 0.7, Some("entity/minecart")),
+    kind("mooshroom", 0.9, 1.4, Some("entity/cow/mooshroom")),
+    kind("mule", 1.3965, 1.6, Some("entity/horse/mule")),
+    kind("ocelot", 0.6, 0.7, Some("entity/cat/ocelot")),
+    kind(
+        "painting",
+        1.0,
+        1.0,
+        Some("painting/paintings_kristoffer_zetterstrand"),
+    ),
+    kind("panda", 1.3, 1.25, None),
+    kind("parrot", 0.5, 0.9, Some("entity/parrot/parrot_red_blue")),
+    kind("phantom", 0.9, 0.5, None),
+    kind("pig", 0.9, 0.9, Some("entity/pig/pig")),
+    kind("piglin", 0.6, 1.95, None),
+    kind("piglin_brute", 0.6, 1.95, None),
+    kind("pillager", 0.6, 1.95, None),
+    kind("player", 0.6, 1.8, Some("entity/steve")),
+    kind("polar_bear", 1.4, 1.4, Some("entity/bear/polarbear")),
+    kind("potion", 0.25, 0.25, Some("items/potion_bottle_splash")),
+    kind("pufferfish", 0.7, 0.7, None),
+    kind("rabbit", 0.4, 0.5, Some("entity/rabbit/brown")),
+    kind("ravager", 1.95, 2.2, None),
+    kind("salmon", 0.7, 0.4, None),
+    kind("sheep", 0.9, 1.3, Some("entity/sheep/sheep")),
+    kind("shulker", 1.0, 1.0, Some("entity/shulker/shulker_purple")),
+    kind(
+        "shulker_bullet",
+        0.3125,
+        0.3125,
+        Some("entity/shulker/spark"),
+    ),
+    kind("silverfish", 0.4, 0.3, Some("entity/silverfish")),
+    kind("skeleton", 0.6, 1.99, Some("entity/skeleton/skeleton")),
+    kind(
+        "skeleton_horse",
+        1.3965,
+        1.6,
+        Some("entity/horse/horse_skeleton"),
+    ),
+    kind("slime", 1.02, 1.02, Some("entity/slime/slime")),
+    kind("small_fireball", 0.3125, 0.3125, Some("items/fireball")),
+    kind("snow_golem", 0.7, 1.9, Some("entity/snowman")),
+    kind("snowball", 0.25, 0.25, Some("items/snowball")),
+    kind("spawner_minecart", 0.98, 0.7, Some("entity/minecart")),
+    kind(
+        "spectral_arrow",
+        0.5,
+        0.5,
+        Some("entity/projectiles/spectral_arrow"),
+    ),
+    kind("spider", 1.4, 0.9, Some("entity/spider/spider")),
+    kind("squid", 0.8, 0.8, Some("entity/squid")),
+    kind("stray", 0.6, 1.99, Some("entity/skeleton/stray")),
+    kind("strider", 0.9, 1.7, None),
+    kind("tnt", 0.98, 0.98, Some("blocks/tnt_side")),
+    kind("tnt_minecart", 0.98, 0.7, Some("entity/minecart")),
+    kind("trader_llama", 0.9, 1.87, Some("entity/llama/llama_creamy")),
+    kind("trident", 0.5, 0.5, None),
+    kind("tropical_fish", 0.5, 0.4, None),
+    kind("turtle", 1.2, 0.4, None),
+    kind("vex", 0.4, 0.8, Some("entity/illager/vex")),
+    kind("villager", 0.6, 1.95, Some("entity/villager/villager")),
+    kind("vindicator", 0.6, 1.95, Some("entity/illager/vindicator")),
+    kind("wandering_trader", 0.6, 1.95, None),
+    kind("witch", 0.6, 1.95, Some("entity/witch")),
+    kind("wither", 0.9, 3.5, Some("entity/wither/wither")),
+    kind(
+        "wither_skeleton",
+        0.7,
+        2.4,
+        Some("entity/skeleton/wither_skeleton"),
+    ),
+    kind("wither_skull", 0.3125, 0.3125, Some("entity/wither/wither")),
+    kind("wolf", 0.6, 0.85, Some("entity/wolf/wolf")),
+    kind("zoglin", 1.3965, 1.4, None),
+    kind("zombie", 0.6, 1.95, Some("entity/zombie/zombie")),
+    kind(
+        "zombie_horse",
+        1.3965,
+        1.6,
+        Some("entity/horse/horse_zombie"),
+    ),
+    kind(
+        "zombie_villager",
+        0.6,
+        1.95,
+        Some("entity/zombie_villager/zombie_villager"),
+    ),
+    kind("zombified_piglin", 0.6, 1.95, Some("entity/zombie_pigman")),
+];
+
+const TYPES_1_13: &[&str] = &[
+    "area_effect_cloud",
+    "armor_stand",
+    "arrow",
+    "bat",
+    "blaze",
+    "boat",
+    "cave_spider",
+    "chicken",
+    "cod",
+    "cow",
+    "creeper",
+    "donkey",
+    "dolphin",
+    "dragon_fireball",
+    "drowned",
+    "elder_guardian",
+    "end_crystal",
+    "ender_dragon",
+    "enderman",
+    "endermite",
+    "evoker_fangs",
+    "evoker",
+    "experience_orb",
+    "eye_of_ender",
+    "falling_block",
+    "firework_rocket",
+    "ghast",
+    "giant",
+    "guardian",
+    "horse",
+    "husk",
+    "illusioner",
+    "item",
+    "item_frame",
+    "fireball",
+    "leash_knot",
+    "llama",
+    "llama_spit",
+    "magma_cube",
+    "minecart",
+    "chest_minecart",
+    "command_block_minecart",
+    "furnace_minecart",
+    "hopper_minecart",
+    "spawner_minecart",
+    "tnt_minecart",
+    "mule",
+    "mooshroom",
+    "ocelot",
+    "painting",
+    "parrot",
+    "pig",
+    "pufferfish",
+    "zombified_piglin",
+    "polar_bear",
+    "tnt",
+    "rabbit",
+    "salmon",
+    "sheep",
+    "shulker",
+    "shulker_bullet",
+    "silverfish",
+    "skeleton",
+    "skeleton_horse",
+    "slime",
+    "small_fireball",
+    "snow_golem",
+    "snowball",
+    "spectral_arrow",
+    "spider",
+    "squid",
+    "stray",
+    "tropical_fish",
+    "turtle",
+    "egg",
+    "ender_pearl",
+    "experience_bottle",
+    "potion",
+    "vex",
+    "villager",
+    "iron_golem",
+    "vindicator",
+    "witch",
+    "wither",
+    "wither_skeleton",
+    "wither_skull",
+    "wolf",
+    "zombie",
+    "zombie_horse",
+    "zombie_villager",
+    "phantom",
+    "lightning_bolt",
+    "player",
+    "fishing_bobber",
+    "trident",
+];
+
+const TYPES_1_14: &[&str] = &[
+    "area_effect_cloud",
+    "armor_stand",
+    "arrow",
+    "bat",
+    "blaze",
+    "boat",
+    "cat",
+    "cave_spider",
+    "chicken",
+    "cod",
+    "cow",
+    "creeper",
+    "donkey",
+    "dolphin",
+    "dragon_fireball",
+    "drowned",
+    "elder_guardian",
+    "end_crystal",
+    "ender_dragon",
+    "enderman",
+    "endermite",
+    "evoker_fangs",
+    "evoker",
+    "experience_orb",
+    "eye_of_ender",
+    "falling_block",
+    "firework_rocket",
+    "fox",
+    "ghast",
+    "giant",
+    "guardian",
+    "horse",
+    "husk",
+    "illusioner",
+    "item",
+    "item_frame",
+    "fireball",
+    "leash_knot",
+    "llama",
+    "llama_spit",
+    "magma_cube",
+    "minecart",
+    "chest_minecart",
+    "command_block_minecart",
+    "furnace_minecart",
+    "hopper_minecart",
+    "spawner_minecart",
+    "tnt_minecart",
+    "mule",
+    "mooshroom",
+    "ocelot",
+    "painting",
+    "panda",
+    "parrot",
+    "pig",
+    "pufferfish",
+    "zombified_piglin",
+    "polar_bear",
+    "tnt",
+    "rabbit",
+    "salmon",
+    "sheep",
+    "shulker",
+    "shulker_bullet",
+    "silverfish",
+    "skeleton",
+    "skeleton_horse",
+    "slime",
+    "small_fireball",
+    "snow_golem",
+    "snowball",
+    "spectral_arrow",
+    "spider",
+    "squid",
+    "stray",
+    "trader_llama",
+    "tropical_fish",
+    "turtle",
+    "egg",
+    "ender_pearl",
+    "experience_bottle",
+    "potion",
+    "vex",
+    "villager",
+    "iron_golem",
+    "vindicator",
+    "pillager",
+    "wandering_trader",
+    "witch",
+    "wither",
+    "wither_skeleton",
+    "wither_skull",
+    "wolf",
+    "zombie",
+    "zombie_horse",
+    "zombie_villager",
+    "phantom",
+    "ravager",
+    "lightning_bolt",
+    "player",
+    "fishing_bobber",
+    "trident",
+];
+
+const TYPES_1_15: &[&str] = &[
+    "area_effect_cloud",
+    "armor_stand",
+    "arrow",
+    "bat",
+    "bee",
+    "blaze",
+    "boat",
+    "cat",
+    "cave_spider",
+    "chicken",
+    "cod",
+    "cow",
+    "creeper",
+    "donkey",
+    "dolphin",
+    "dragon_fireball",
+    "drowned",
+    "elder_guardian",
+    "end_crystal",
+    "ender_dragon",
+    "enderman",
+    "endermite",
+    "evoker_fangs",
+    "evoker",
+    "experience_orb",
+    "eye_of_ender",
+    "falling_block",
+    "firework_rocket",
+    "fox",
+    "ghast",
+    "giant",
+    "guardian",
+    "horse",
+    "husk",
+    "illusioner",
+    "item",
+    "item_frame",
+    "fireball",
+    "leash_knot",
+    "llama",
+    "llama_spit",
+    "magma_cube",
+    "minecart",
+    "chest_minecart",
+    "command_block_minecart",
+    "furnace_minecart",
+    "hopper_minecart",
+    "spawner_minecart",
+    "tnt_minecart",
+    "mule",
+    "mooshroom",
+    "ocelot",
+    "painting",
+    "panda",
+    "parrot",
+    "pig",
+    "pufferfish",
+    "zombified_piglin",
+    "polar_bear",
+    "tnt",
+    "rabbit",
+    "salmon",
+    "sheep",
+    "shulker",
+    "shulker_bullet",
+    "silverfish",
+    "skeleton",
+    "skeleton_horse",
+    "slime",
+    "small_fireball",
+    "snow_golem",
+    "snowball",
+    "spectral_arrow",
+    "spider",
+    "squid",
+    "stray",
+    "trader_llama",
+    "tropical_fish",
+    "turtle",
+    "egg",
+    "ender_pearl",
+    "experience_bottle",
+    "potion",
+    "vex",
+    "villager",
+    "iron_golem",
+    "vindicator",
+    "pillager",
+    "wandering_trader",
+    "witch",
+    "wither",
+    "wither_skeleton",
+    "wither_skull",
+    "wolf",
+    "zombie",
+    "zombie_horse",
+    "zombie_villager",
+    "phantom",
+    "ravager",
+    "lightning_bolt",
+    "player",
+    "fishing_bobber",
+    "trident",
+];
+
+const TYPES_1_16: &[&str] = &[
+    "area_effect_cloud",
+    "armor_stand",
+    "arrow",
+    "bat",
+    "bee",
+    "blaze",
+    "boat",
+    "cat",
+    "cave_spider",
+    "chicken",
+    "cod",
+    "cow",
+    "creeper",
+    "dolphin",
+    "donkey",
+    "dragon_fireball",
+    "drowned",
+    "elder_guardian",
+    "end_crystal",
+    "ender_dragon",
+    "enderman",
+    "endermite",
+    "evoker",
+    "evoker_fangs",
+    "experience_orb",
+    "eye_of_ender",
+    "falling_block",
+    "firework_rocket",
+    "fox",
+    "ghast",
+    "giant",
+    "guardian",
+    "hoglin",
+    "horse",
+    "husk",
+    "illusioner",
+    "iron_golem",
+    "item",
+    "item_frame",
+    "fireball",
+    "leash_knot",
+    "lightning_bolt",
+    "llama",
+    "llama_spit",
+    "magma_cube",
+    "minecart",
+    "chest_minecart",
+    "command_block_minecart",
+    "furnace_minecart",
+    "hopper_minecart",
+    "spawner_minecart",
+    "tnt_minecart",
+    "mule",
+    "mooshroom",
+    "ocelot",
+    "painting",
+    "panda",
+    "parrot",
+    "phantom",
+    "pig",
+    "piglin",
+    "pillager",
+    "polar_bear",
+    "tnt",
+    "pufferfish",
+    "rabbit",
+    "ravager",
+    "salmon",
+    "sheep",
+    "shulker",
+    "shulker_bullet",
+    "silverfish",
+    "skeleton",
+    "skeleton_horse",
+    "slime",
+    "small_fireball",
+    "snow_golem",
+    "snowball",
+    "spectral_arrow",
+    "spider",
+    "squid",
+    "stray",
+    "strider",
+    "egg",
+    "ender_pearl",
+    "experience_bottle",
+    "potion",
+    "trident",
+    "trader_llama",
+    "tropical_fish",
+    "turtle",
+    "vex",
+    "villager",
+    "vindicator",
+    "wandering_trader",
+    "witch",
+    "wither",
+    "wither_skeleton",
+    "wither_skull",
+    "wolf",
+    "zoglin",
+    "zombie",
+    "zombie_horse",
+    "zombie_villager",
+    "zombified_piglin",
+    "player",
+    "fishing_bobber",
+];
+
+const TYPES_1_16_2: &[&str] = &[
+    "area_effect_cloud",
+    "armor_stand",
+    "arrow",
+    "bat",
+    "bee",
+    "blaze",
+    "boat",
+    "cat",
+    "cave_spider",
+    "chicken",
+    "cod",
+    "cow",
+    "creeper",
+    "dolphin",
+    "donkey",
+    "dragon_fireball",
+    "drowned",
+    "elder_guardian",
+    "end_crystal",
+    "ender_dragon",
+    "enderman",
+    "endermite",
+    "evoker",
+    "evoker_fangs",
+    "experience_orb",
+    "eye_of_ender",
+    "falling_block",
+    "firework_rocket",
+    "fox",
+    "ghast",
+    "giant",
+    "guardian",
+    "hoglin",
+    "horse",
+    "husk",
+    "illusioner",
+    "iron_golem",
+    "item",
+    "item_frame",
+    "fireball",
+    "leash_knot",
+    "lightning_bolt",
+    "llama",
+    "llama_spit",
+    "magma_cube",
+    "minecart",
+    "chest_minecart",
+    "command_block_minecart",
+    "furnace_minecart",
+    "hopper_minecart",
+    "spawner_minecart",
+    "tnt_minecart",
+    "mule",
+    "mooshroom",
+    "ocelot",
+    "painting",
+    "panda",
+    "parrot",
+    "phantom",
+    "pig",
+    "piglin",
+    "piglin_brute",
+    "pillager",
+    "polar_bear",
+    "tnt",
+    "pufferfish",
+    "rabbit",
+    "ravager",
+    "salmon",
+    "sheep",
+    "shulker",
+    "shulker_bullet",
+    "silverfish",
+    "skeleton",
+    "skeleton_horse",
+    "slime",
+    "small_fireball",
+    "snow_golem",
+    "snowball",
+    "spectral_arrow",
+    "spider",
+    "squid",
+    "stray",
+    "strider",
+    "egg",
+    "ender_pearl",
+    "experience_bottle",
+    "potion",
+    "trident",
+    "trader_llama",
+    "tropical_fish",
+    "turtle",
+    "vex",
+    "villager",
+    "vindicator",
+    "wandering_trader",
+    "witch",
+    "wither",
+    "wither_skeleton",
+    "wither_skull",
+    "wolf",
+    "zoglin",
+    "zombie",
+    "zombie_horse",
+    "zombie_villager",
+    "zombified_piglin",
+    "player",
+    "fishing_bobber",
+];
+
+const TYPES_1_17: &[&str] = &[
+    "area_effect_cloud",
+    "armor_stand",
+    "arrow",
+    "axolotl",
+    "bat",
+    "bee",
+    "blaze",
+    "boat",
+    "cat",
+    "cave_spider",
+    "chicken",
+    "cod",
+    "cow",
+    "creeper",
+    "dolphin",
+    "donkey",
+    "dragon_fireball",
+    "drowned",
+    "elder_guardian",
+    "end_crystal",
+    "ender_dragon",
+    "enderman",
+    "endermite",
+    "evoker",
+    "evoker_fangs",
+    "experience_orb",
+    "eye_of_ender",
+    "falling_block",
+    "firework_rocket",
+    "fox",
+    "ghast",
+    "giant",
+    "glow_item_frame",
+    "glow_squid",
+    "goat",
+    "guardian",
+    "hoglin",
+    "horse",
+    "husk",
+    "illusioner",
+    "iron_golem",
+    "item",
+    "item_frame",
+    "fireball",
+    "leash_knot",
+    "lightning_bolt",
+    "llama",
+    "llama_spit",
+    "magma_cube",
+    "marker",
+    "minecart",
+    "chest_minecart",
+    "command_block_minecart",
+    "furnace_minecart",
+    "hopper_minecart",
+    "spawner_minecart",
+    "tnt_minecart",
+    "mule",
+    "mooshroom",
+    "ocelot",
+    "painting",
+    "panda",
+    "parrot",
+    "phantom",
+    "pig",
+    "piglin",
+    "piglin_brute",
+    "pillager",
+    "polar_bear",
+    "tnt",
+    "pufferfish",
+    "rabbit",
+    "ravager",
+    "salmon",
+    "sheep",
+    "shulker",
+    "shulker_bullet",
+    "silverfish",
+    "skeleton",
+    "skeleton_horse",
+    "slime",
+    "small_fireball",
+    "snow_golem",
+    "snowball",
+    "spectral_arrow",
+    "spider",
+    "squid",
+    "stray",
+    "strider",
+    "egg",
+    "ender_pearl",
+    "experience_bottle",
+    "potion",
+    "trident",
+    "trader_llama",
+    "tropical_fish",
+    "turtle",
+    "vex",
+    "villager",
+    "vindicator",
+    "wandering_trader",
+    "witch",
+    "wither",
+    "wither_skeleton",
+    "wither_skull",
+    "wolf",
+    "zoglin",
+    "zombie",
+    "zombie_horse",
+    "zombie_villager",
+    "zombified_piglin",
+    "player",
+    "fishing_bobber",
+];
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(kind: Option<EntityKind>) -> Option<&'static str> {
+        kind.map(|v| v.name)
+    }
+
+    #[test]
+    fn every_type_has_a_kind() {
+        for types in [
+            TYPES_1_13,
+            TYPES_1_14,
+            TYPES_1_15,
+            TYPES_1_16,
+            TYPES_1_16_2,
+            TYPES_1_17,
+        ] {
+            for name in types {
+                assert!(EntityKind::by_name(name).is_some(), "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn ids_per_version() {
+        assert_eq!(name(EntityKind::from_mob_id(47, 90)), Some("pig"));
+        assert_eq!(
+            name(EntityKind::from_mob_id(340, 5)),
+            Some("wither_skeleton")
+        );
+        assert_eq!(name(EntityKind::from_mob_id(404, 87)), Some("zombie"));
+        assert_eq!(name(EntityKind::from_mob_id(578, 4)), Some("bee"));
+        assert_eq!(
+            name(EntityKind::from_mob_id(736, 104)),
+            Some("zombified_piglin")
+        );
+        assert_eq!(name(EntityKind::from_mob_id(758, 111)), Some("player"));
+        assert_eq!(name(EntityKind::from_mob_id(758, 113)), None);
+
+        assert_eq!(
+            name(EntityKind::from_object_id(340, 10, 1)),
+            Some("chest_minecart")
+        );
+        assert_eq!(name(EntityKind::from_object_id(404, 2, 0)), Some("item"));
+        assert_eq!(name(EntityKind::from_object_id(498, 34, 0)), Some("item"));
+        assert_eq!(name(EntityKind::from_object_id(754, 37, 0)), Some("item"));
+        assert_eq!(name(EntityKind::from_object_id(756, 41, 0)), Some("item"));
+    }
+}
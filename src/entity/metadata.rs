@@ -0,0 +1,354 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::kind::EntityKind;
+use super::player::PlayerModel;
+use crate::ecs;
+use crate::format;
+use crate::item;
+use crate::types::{Metadata, PoseData, Value};
+use std::any::Any;
+
+/// The flags every entity keeps in its first metadata field.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EntityFlags {
+    pub on_fire: bool,
+    pub sneaking: bool,
+    pub sprinting: bool,
+    pub invisible: bool,
+    pub glowing: bool,
+}
+
+/// The name given to an entity with a name tag or by the server.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CustomName {
+    pub name: Option<format::Component>,
+    /// Whether the name is drawn above the entity at all times
+    pub visible: bool,
+}
+
+/// The stack shown by an item lying on the ground.
+#[derive(Debug)]
+pub struct DroppedItem {
+    pub stack: Option<item::Stack>,
+}
+
+/// Copies the fields of an entity's metadata that the client reacts to
+/// into its components. Updates only carry the fields that changed so
+/// anything missing is left as it was.
+pub fn apply(m: &mut ecs::Manager, e: ecs::Entity, protocol_version: i32, metadata: &Metadata) {
+    if let Some(Value::Byte(flags)) = metadata.get_raw(0) {
+        let flags = *flags as u8;
+        let entity_flags = EntityFlags {
+            on_fire: flags & 0x01 != 0,
+            sneaking: flags & 0x02 != 0,
+            sprinting: flags & 0x08 != 0,
+            invisible: flags & 0x20 != 0,
+            glowing: protocol_version >= 107 && flags & 0x40 != 0,
+        };
+        // Poses were only sent on their own from 1.14, before that they
+        // follow the flags
+        if protocol_version < 477 {
+            let pose = if entity_flags.sneaking {
+                PoseData::Sneaking
+            } else if protocol_version >= 107 && flags & 0x80 != 0 {
+                PoseData::FallFlying
+            } else {
+                PoseData::Standing
+            };
+            set(m, e, pose);
+        }
+        set(m, e, entity_flags);
+    }
+
+    let (name_index, visible_index) = custom_name_indices(protocol_version);
+    let name = metadata.get_raw(name_index);
+    let visible = metadata.get_raw(visible_index);
+    if name.is_some() || visible.is_some() {
+        let mut custom_name = m
+            .get_component_direct::<CustomName>(e)
+            .cloned()
+            .unwrap_or_default();
+        match name {
+            // Names were plain strings with legacy formatting before 1.13
+            Some(Value::String(name)) => {
+                custom_name.name = if name.is_empty() {
+                    None
+                } else {
+                    let mut name = format::Component::Text(format::TextComponent::new(name));
+                    format::convert_legacy(&mut name);
+                    Some(name)
+                };
+            }
+            Some(Value::OptionalFormatComponent(name)) => {
+                custom_name.name = name.data.first().cloned();
+            }
+            _ => {}
+        }
+        match visible {
+            Some(Value::Byte(visible)) => custom_name.visible = *visible != 0,
+            Some(Value::Bool(visible)) => custom_name.visible = *visible,
+            _ => {}
+        }
+        set(m, e, custom_name);
+    }
+
+    if protocol_version >= 477 {
+        if let Some(Value::Pose(pose)) = metadata.get_raw(6) {
+            set(m, e, pose.clone());
+        }
+    }
+
+    let is_item = m
+        .get_component_direct::<EntityKind>(e)
+        .map_or(false, |kind| kind.name == "item");
+    if is_item {
+        if let Some(Value::OptionalItemStack(stack)) =
+            metadata.get_raw(item_index(protocol_version))
+        {
+            set(
+                m,
+                e,
+                DroppedItem {
+                    stack: stack.clone(),
+                },
+            );
+        }
+    }
+
+    if let Some(player_model) = m.get_component_mut_direct::<PlayerModel>(e) {
+        if let Some(Value::Byte(parts)) = metadata.get_raw(skin_parts_index(protocol_version)) {
+            player_model.set_skin_parts(*parts as u8);
+        }
+    }
+}
+
+fn set<T: Any>(m: &mut ecs::Manager, e: ecs::Entity, val: T) {
+    if let Some(component) = m.get_component_mut_direct::<T>(e) {
+        *component = val;
+    } else {
+        m.add_component_direct(e, val);
+    }
+}
+
+/// Indices of the custom name and whether it is always shown. Before
+/// 1.8 only living entities had a name, after their own fields.
+fn custom_name_indices(protocol_version: i32) -> (i32, i32) {
+    if protocol_version >= 47 {
+        (2, 3)
+    } else {
+        (10, 11)
+    }
+}
+
+// Both indices move as fields are added to the entity classes above
+// them: no gravity in 1.10, pose and the living entity's bed in 1.14,
+// its bee stingers in 1.15 and frozen ticks in 1.17.
+
+fn item_index(protocol_version: i32) -> i32 {
+    match protocol_version {
+        v if v >= 755 => 8,
+        v if v >= 477 => 7,
+        v if v >= 210 => 6,
+        v if v >= 107 => 5,
+        _ => 10,
+    }
+}
+
+fn skin_parts_index(protocol_version: i32) -> i32 {
+    match protocol_version {
+        v if v >= 755 => 17,
+        v if v >= 573 => 16,
+        v if v >= 477 => 15,
+        v if v >= 210 => 13,
+        v if v >= 107 => 12,
+        _ => 10,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::LenPrefixed;
+
+    fn spawn(m: &mut ecs::Manager, kind: &str) -> ecs::Entity {
+        let e = m.create_entity();
+        m.add_component_direct(e, EntityKind::by_name(kind).unwrap());
+        e
+    }
+
+    fn text(text: &str) -> format::Component {
+        format::Component::Text(format::TextComponent::new(text))
+    }
+
+    #[test]
+    fn flags() {
+        let mut m = ecs::Manager::new();
+        let e = spawn(&mut m, "zombie");
+        let mut metadata = Metadata::new();
+        metadata.put_raw(0, 0x02i8 | 0x40);
+
+        apply(&mut m, e, 340, &metadata);
+        assert_eq!(
+            m.get_component_direct::<EntityFlags>(e),
+            Some(&EntityFlags {
+                sneaking: true,
+                glowing: true,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            m.get_component_direct::<PoseData>(e),
+            Some(&PoseData::Sneaking)
+        );
+
+        // Entities only glow from 1.9
+        apply(&mut m, e, 47, &metadata);
+        assert!(!m.get_component_direct::<EntityFlags>(e).unwrap().glowing);
+    }
+
+    #[test]
+    fn pose_is_its_own_field_from_1_14() {
+        let mut m = ecs::Manager::new();
+        let e = spawn(&mut m, "zombie");
+        let mut metadata = Metadata::new();
+        metadata.put_raw(0, 0x02i8);
+        metadata.put_raw(6, PoseData::Swimming);
+
+        apply(&mut m, e, 477, &metadata);
+        assert_eq!(
+            m.get_component_direct::<PoseData>(e),
+            Some(&PoseData::Swimming)
+        );
+    }
+
+    #[test]
+    fn custom_names() {
+        let mut m = ecs::Manager::new();
+        let e = spawn(&mut m, "zombie");
+        let mut metadata = Metadata::new();
+        metadata.put_raw(2, LenPrefixed::<bool, _>::new(vec![text("Steve")]));
+        metadata.put_raw(3, true);
+        apply(&mut m, e, 404, &metadata);
+        assert_eq!(
+            m.get_component_direct::<CustomName>(e),
+            Some(&CustomName {
+                name: Some(text("Steve")),
+                visible: true,
+            })
+        );
+
+        // Updates leave out the fields that didn't change
+        let mut metadata = Metadata::new();
+        metadata.put_raw(3, false);
+        apply(&mut m, e, 404, &metadata);
+        assert_eq!(
+            m.get_component_direct::<CustomName>(e),
+            Some(&CustomName {
+                name: Some(text("Steve")),
+                visible: false,
+            })
+        );
+
+        let mut metadata = Metadata::new();
+        metadata.put_raw(2, LenPrefixed::<bool, format::Component>::new(vec![]));
+        apply(&mut m, e, 404, &metadata);
+        assert_eq!(m.get_component_direct::<CustomName>(e).unwrap().name, None);
+    }
+
+    #[test]
+    fn legacy_custom_names() {
+        let mut m = ecs::Manager::new();
+        let e = spawn(&mut m, "zombie");
+        let mut metadata = Metadata::new();
+        metadata.put_raw(2, "\u{a7}cSteve".to_owned());
+        metadata.put_raw(3, 1i8);
+        apply(&mut m, e, 47, &metadata);
+        let name = m.get_component_direct::<CustomName>(e).unwrap();
+        assert_eq!(
+            name.name.as_ref().map(|v| v.to_string()),
+            Some("Steve".to_owned())
+        );
+        assert!(name.visible);
+
+        let mut metadata = Metadata::new();
+        metadata.put_raw(2, String::new());
+        apply(&mut m, e, 47, &metadata);
+        assert_eq!(m.get_component_direct::<CustomName>(e).unwrap().name, None);
+    }
+
+    #[test]
+    fn custom_names_1_7() {
+        let mut m = ecs::Manager::new();
+        let e = spawn(&mut m, "zombie");
+        // Fields 2 and 3 aren't the name before 1.8
+        let mut metadata = Metadata::new();
+        metadata.put_raw(2, "Alex".to_owned());
+        metadata.put_raw(3, 1i8);
+        apply(&mut m, e, 5, &metadata);
+        assert_eq!(m.get_component_direct::<CustomName>(e), None);
+
+        let mut metadata = Metadata::new();
+        metadata.put_raw(10, "Steve".to_owned());
+        metadata.put_raw(11, 1i8);
+        apply(&mut m, e, 5, &metadata);
+        let name = m.get_component_direct::<CustomName>(e).unwrap();
+        assert_eq!(
+            name.name.as_ref().map(|v| v.to_string()),
+            Some("Steve".to_owned())
+        );
+        assert!(name.visible);
+    }
+
+    #[test]
+    fn dropped_items() {
+        let stack = Some(item::Stack {
+            id: 1,
+            count: 3,
+            ..Default::default()
+        });
+        for &(protocol_version, index) in &[(758, 8), (578, 7), (340, 6), (107, 5), (47, 10)] {
+            let mut m = ecs::Manager::new();
+            let e = spawn(&mut m, "item");
+            let mut metadata = Metadata::new();
+            metadata.put_raw(index, stack.clone());
+            apply(&mut m, e, protocol_version, &metadata);
+            assert_eq!(
+                m.get_component_direct::<DroppedItem>(e)
+                    .map(|v| v.stack.clone()),
+                Some(stack.clone()),
+                "protocol {}",
+                protocol_version
+            );
+        }
+
+        // Only items show a stack
+        let mut m = ecs::Manager::new();
+        let e = spawn(&mut m, "zombie");
+        let mut metadata = Metadata::new();
+        metadata.put_raw(6, stack);
+        apply(&mut m, e, 340, &metadata);
+        assert!(m.get_component_direct::<DroppedItem>(e).is_none());
+    }
+
+    #[test]
+    fn skin_parts_indices() {
+        assert_eq!(skin_parts_index(758), 17);
+        assert_eq!(skin_parts_index(578), 16);
+        assert_eq!(skin_parts_index(498), 15);
+        assert_eq!(skin_parts_index(340), 13);
+        assert_eq!(skin_parts_index(109), 12);
+        assert_eq!(skin_parts_index(47), 10);
+    }
+}
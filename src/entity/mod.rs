@@ -1,7 +1,13 @@
 use steven_blocks as block;
 use steven_protocol::protocol::packet;
 pub mod block_entity;
+pub mod effects;
+pub mod equipment;
+pub mod kind;
+pub mod metadata;
+pub mod placeholder;
 pub mod player;
+pub mod vehicle;
 
 use crate::ecs;
 use cgmath::Vector3;
@@ -13,7 +19,9 @@ pub fn add_systems(m: &mut ecs::Manager) {
     let sys = systems::UpdateLastPosition::new(m);
     m.add_system(sys);
 
+    effects::add_systems(m);
     player::add_systems(m);
+    placeholder::add_systems(m);
 
     let sys = systems::ApplyVelocity::new(m);
     m.add_system(sys);
@@ -23,6 +31,7 @@ pub fn add_systems(m: &mut ecs::Manager) {
     m.add_render_system(sys);
     let sys = systems::LerpRotation::new(m);
     m.add_render_system(sys);
+    vehicle::add_systems(m);
     let sys = systems::LightEntity::new(m);
     m.add_render_system(sys);
     let sys = systems::ApplyDigging::new(m);
@@ -0,0 +1,241 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::equipment::Equipment;
+use super::kind::EntityKind;
+use super::metadata::{CustomName, DroppedItem, EntityFlags};
+use super::{Bounds, Light, Position, Rotation, TargetPosition, TargetRotation, Velocity};
+use crate::ecs;
+use crate::format;
+use crate::render;
+use crate::render::model;
+use crate::world;
+use cgmath::{Decomposed, Matrix4, Point3, Quaternion, Rad, Rotation3, Vector3};
+use collision::Aabb3;
+use std::sync::{Arc, RwLock};
+
+pub fn add_systems(m: &mut ecs::Manager) {
+    let sys = PlaceholderRenderer::new(m);
+    m.add_render_system(sys);
+}
+
+/// Creates an entity other than a player, drawn as a textured box the
+/// size of its bounding box until it has a model of its own.
+pub fn create(m: &mut ecs::Manager, kind: EntityKind) -> ecs::Entity {
+    let entity = m.create_entity();
+    m.add_component_direct(entity, Position::new(0.0, 0.0, 0.0));
+    m.add_component_direct(entity, TargetPosition::new(0.0, 0.0, 0.0));
+    m.add_component_direct(entity, Rotation::new(0.0, 0.0));
+    m.add_component_direct(entity, TargetRotation::new(0.0, 0.0));
+    m.add_component_direct(entity, Velocity::new(0.0, 0.0, 0.0));
+    let half_width = kind.width / 2.0;
+    m.add_component_direct(
+        entity,
+        Bounds::new(Aabb3::new(
+            Point3::new(-half_width, 0.0, -half_width),
+            Point3::new(half_width, kind.height, half_width),
+        )),
+    );
+    m.add_component_direct(entity, kind);
+    m.add_component_direct(entity, Equipment::new());
+    m.add_component_direct(entity, PlaceholderModel::new());
+    m.add_component_direct(entity, Light::new());
+    entity
+}
+
+#[derive(Default)]
+pub struct PlaceholderModel {
+    model: Option<model::ModelKey>,
+    // What the model was last built with, changes rebuild it
+    texture: String,
+    name_tag: Option<format::Component>,
+}
+
+impl PlaceholderModel {
+    pub fn new() -> PlaceholderModel {
+        Default::default()
+    }
+}
+
+enum PlaceholderModelPart {
+    Body = 0,
+    NameTag = 1,
+}
+
+struct PlaceholderRenderer {
+    filter: ecs::Filter,
+    placeholder_model: ecs::Key<PlaceholderModel>,
+    kind: ecs::Key<EntityKind>,
+    position: ecs::Key<Position>,
+    rotation: ecs::Key<Rotation>,
+    light: ecs::Key<Light>,
+    flags: ecs::Key<EntityFlags>,
+    custom_name: ecs::Key<CustomName>,
+    dropped_item: ecs::Key<DroppedItem>,
+    models: ecs::Key<Arc<RwLock<crate::model::Factory>>>,
+}
+
+impl PlaceholderRenderer {
+    fn new(m: &mut ecs::Manager) -> PlaceholderRenderer {
+        let placeholder_model = m.get_key();
+        let kind = m.get_key();
+        let position = m.get_key();
+        let rotation = m.get_key();
+        let light = m.get_key();
+        PlaceholderRenderer {
+            filter: ecs::Filter::new()
+                .with(placeholder_model)
+                .with(kind)
+                .with(position)
+                .with(rotation)
+                .with(light),
+            placeholder_model,
+            kind,
+            position,
+            rotation,
+            light,
+            flags: m.get_key(),
+            custom_name: m.get_key(),
+            dropped_item: m.get_key(),
+            models: m.get_key(),
+        }
+    }
+}
+
+impl ecs::System for PlaceholderRenderer {
+    fn filter(&self) -> &ecs::Filter {
+        &self.filter
+    }
+
+    fn update(
+        &mut self,
+        m: &mut ecs::Manager,
+        world: &mut world::World,
+        renderer: &mut render::Renderer,
+    ) {
+        use std::f32::consts::PI;
+        let world_entity = m.get_world();
+        let models = m.get_component(world_entity, self.models);
+        for e in m.find(&self.filter) {
+            let placeholder_model = m.get_component_mut(e, self.placeholder_model).unwrap();
+            let kind = m.get_component(e, self.kind).unwrap();
+            let position = m.get_component(e, self.position).unwrap();
+            let rotation = m.get_component(e, self.rotation).unwrap();
+            let light = m.get_component(e, self.light).unwrap();
+            let flags = m.get_component(e, self.flags).copied().unwrap_or_default();
+
+            // Dropped items show the stack they hold
+            let texture = match (m.get_component(e, self.dropped_item), models) {
+                (Some(DroppedItem { stack: Some(stack) }), Some(models)) => {
+                    crate::model::Factory::get_item_icon(models, stack, world.protocol_version())
+                }
+                _ => kind.texture.unwrap_or("steven:solid").to_owned(),
+            };
+            // Without a way to look at entities yet names are only shown
+            // when they always should be
+            let name_tag = m
+                .get_component(e, self.custom_name)
+                .filter(|custom_name| custom_name.visible)
+                .and_then(|custom_name| custom_name.name.clone());
+            if placeholder_model.model.is_none()
+                || placeholder_model.texture != texture
+                || placeholder_model.name_tag != name_tag
+            {
+                if let Some(model) = placeholder_model.model.take() {
+                    renderer.model.remove_model(model);
+                }
+                placeholder_model.model =
+                    Some(build_model(renderer, kind, &texture, name_tag.as_ref()));
+                placeholder_model.texture = texture;
+                placeholder_model.name_tag = name_tag;
+            }
+
+            let mdl = renderer
+                .model
+                .get_model(placeholder_model.model.unwrap())
+                .unwrap();
+            mdl.block_light = light.block_light;
+            mdl.sky_light = light.sky_light;
+            let offset = Vector3::new(
+                position.position.x as f32,
+                -position.position.y as f32,
+                position.position.z as f32,
+            );
+            // Invisible entities keep their names, it's how servers
+            // float text in the air
+            mdl.matrix[PlaceholderModelPart::Body as usize] = if flags.invisible {
+                Matrix4::from_scale(0.0)
+            } else {
+                Matrix4::from(Decomposed {
+                    scale: 1.0,
+                    rot: Quaternion::from_angle_y(Rad(PI + rotation.yaw as f32)),
+                    disp: offset,
+                })
+            };
+            mdl.matrix[PlaceholderModelPart::NameTag as usize] = Matrix4::from(Decomposed {
+                scale: 1.0,
+                rot: Quaternion::from_angle_y(Rad(renderer.camera.yaw as f32)),
+                disp: offset + Vector3::new(0.0, -(kind.height as f32 + 0.3), 0.0),
+            });
+        }
+    }
+
+    fn entity_removed(
+        &mut self,
+        m: &mut ecs::Manager,
+        e: ecs::Entity,
+        _: &mut world::World,
+        renderer: &mut render::Renderer,
+    ) {
+        let placeholder_model = m.get_component_mut(e, self.placeholder_model).unwrap();
+        if let Some(model) = placeholder_model.model.take() {
+            renderer.model.remove_model(model);
+        }
+    }
+}
+
+fn build_model(
+    renderer: &mut render::Renderer,
+    kind: &EntityKind,
+    texture: &str,
+    name_tag: Option<&format::Component>,
+) -> model::ModelKey {
+    let tex = render::Renderer::get_texture(renderer.get_textures_ref(), texture);
+    let (width, height) = (kind.width as f32, kind.height as f32);
+    let mut verts = vec![];
+    model::append_box(
+        &mut verts,
+        -width / 2.0,
+        0.0,
+        -width / 2.0,
+        width,
+        height,
+        width,
+        [
+            Some(tex.clone()),
+            Some(tex.clone()),
+            Some(tex.clone()),
+            Some(tex.clone()),
+            Some(tex.clone()),
+            Some(tex),
+        ],
+    );
+    let mut name_verts = vec![];
+    if let Some(name_tag) = name_tag {
+        model::append_name_tag(&mut name_verts, renderer, name_tag);
+    }
+    renderer
+        .model
+        .create_model(model::DEFAULT, vec![verts, name_verts])
+}
@@ -1,21 +1,29 @@
+use super::effects::{EffectKind, Effects};
+use super::equipment::{Equipment, EquipmentSlot};
+use super::kind::EntityKind;
+use super::metadata::EntityFlags;
+use super::vehicle::{self, Riding, Steering};
 use super::{
     Bounds, Digging, GameInfo, Gravity, Light, MouseButtons, Position, Rotation, TargetPosition,
     TargetRotation, Velocity,
 };
 use crate::ecs;
 use crate::format;
+use crate::item;
 use crate::render;
-use crate::render::model::{self, FormatState};
+use crate::render::model;
 use crate::settings::Stevenkey;
 use crate::shared::Position as BPosition;
 use crate::types::hash::FNVHash;
-use crate::types::Gamemode;
+use crate::types::{Gamemode, PoseData};
 use crate::world;
+use crate::world::block::Block;
 use cgmath::{self, Decomposed, Matrix4, Point3, Quaternion, Rad, Rotation3, Vector3};
 use collision::{Aabb, Aabb3};
 use instant::Instant;
 use std::collections::HashMap;
 use std::hash::BuildHasherDefault;
+use std::sync::{Arc, RwLock};
 
 pub fn add_systems(m: &mut ecs::Manager) {
     let sys = MovementHandler::new(m);
@@ -64,15 +72,156 @@ pub fn create_remote(m: &mut ecs::Manager, name: &str) -> ecs::Entity {
         )),
     );
     m.add_component_direct(entity, PlayerModel::new(name, true, true, false));
+    m.add_component_direct(entity, Equipment::new());
     m.add_component_direct(entity, Light::new());
     entity
 }
 
+// Skin parts bits for the outer layers, the cape isn't drawn yet
+const ALL_SKIN_PARTS: u8 = 0x7f;
+const JACKET: u8 = 0x02;
+const LEFT_SLEEVE: u8 = 0x04;
+const RIGHT_SLEEVE: u8 = 0x08;
+const LEFT_PANTS: u8 = 0x10;
+const RIGHT_PANTS: u8 = 0x20;
+const HAT: u8 = 0x40;
+
+/// Returns how far above its feet a player's name tag is drawn, just
+/// over the top of its bounds in the pose it's in.
+fn name_tag_height(pose: Option<&PoseData>) -> f32 {
+    let height = match pose {
+        Some(PoseData::Sneaking) => 1.5,
+        Some(PoseData::FallFlying) | Some(PoseData::Swimming) | Some(PoseData::SpinAttack) => 0.6,
+        Some(PoseData::Sleeping) => 0.2,
+        _ => 1.8,
+    };
+    height + 0.3
+}
+
+/// The textures a player's equipment is drawn with, the model is
+/// rebuilt when they change.
+#[derive(Clone, Default, PartialEq)]
+struct EquipmentTextures {
+    // Feet, legs, chest and head
+    armor: [Option<ArmorTexture>; 4],
+    main_hand: Option<String>,
+    off_hand: Option<String>,
+}
+
+#[derive(Clone, PartialEq)]
+struct ArmorTexture {
+    material: &'static str,
+    tint: (u8, u8, u8),
+}
+
+impl EquipmentTextures {
+    fn new(
+        equipment: &Equipment,
+        models: Option<&Arc<RwLock<crate::model::Factory>>>,
+        protocol_version: i32,
+    ) -> EquipmentTextures {
+        use self::EquipmentSlot::*;
+        let armor = |slot| {
+            let stack = equipment.get(slot)?;
+            let material = crate::model::Factory::armor_material(stack, protocol_version)?;
+            let tint = if material == "leather" {
+                leather_color(stack)
+            } else {
+                (255, 255, 255)
+            };
+            Some(ArmorTexture { material, tint })
+        };
+        let icon = |slot| {
+            let stack = equipment.get(slot)?;
+            Some(crate::model::Factory::get_item_icon(
+                models?,
+                stack,
+                protocol_version,
+            ))
+        };
+        EquipmentTextures {
+            armor: [armor(Feet), armor(Legs), armor(Chest), armor(Head)],
+            main_hand: icon(MainHand),
+            off_hand: icon(OffHand),
+        }
+    }
+}
+
+/// Returns the colour leather armor has been dyed, or its natural brown.
+fn leather_color(stack: &item::Stack) -> (u8, u8, u8) {
+    let color = stack
+        .tag
+        .as_ref()
+        .and_then(|tag| tag.1.get("display"))
+        .and_then(|display| display.get("color"))
+        .and_then(|color| color.as_int())
+        .unwrap_or(0xa0_6540);
+    ((color >> 16) as u8, (color >> 8) as u8, color as u8)
+}
+
+/// Appends a box given in pixels, grown on every side by `inflate` as
+/// armor is, textured from the 64x32 layout armor shares with old skins.
+fn append_armor_box(
+    verts: &mut Vec<model::Vertex>,
+    texture: &render::Texture,
+    tint: (u8, u8, u8),
+    pos: [f32; 3],
+    size: [f32; 3],
+    inflate: f32,
+    (u, v): (f32, f32),
+) {
+    let [w, h, d] = size;
+    let face = |x: f32, y: f32, w: f32, h: f32| {
+        Some(texture.relative(x / 64.0, y / 32.0, w / 64.0, h / 32.0))
+    };
+    let start = verts.len();
+    model::append_box(
+        verts,
+        (pos[0] - inflate) / 16.0,
+        (pos[1] - inflate) / 16.0,
+        (pos[2] - inflate) / 16.0,
+        (w + inflate * 2.0) / 16.0,
+        (h + inflate * 2.0) / 16.0,
+        (d + inflate * 2.0) / 16.0,
+        [
+            face(u + d + w, v, w, d),         // Down
+            face(u + d, v, w, d),             // Up
+            face(u + d, v + d, w, h),         // North
+            face(u + d + w + d, v + d, w, h), // South
+            face(u + d + w, v + d, d, h),     // West
+            face(u, v + d, d, h),             // East
+        ],
+    );
+    for vert in &mut verts[start..] {
+        vert.r = (vert.r as u32 * tint.0 as u32 / 255) as u8;
+        vert.g = (vert.g as u32 * tint.1 as u32 / 255) as u8;
+        vert.b = (vert.b as u32 * tint.2 as u32 / 255) as u8;
+    }
+}
+
+/// Appends an item's icon held upright in the hand at the end of an arm,
+/// pointing forwards.
+fn append_held_item(verts: &mut Vec<model::Vertex>, texture: render::Texture) {
+    model::append_box(
+        verts,
+        -0.5 / 16.0,
+        -15.0 / 16.0,
+        -9.0 / 16.0,
+        1.0 / 16.0,
+        8.0 / 16.0,
+        8.0 / 16.0,
+        [None, None, None, None, Some(texture.clone()), Some(texture)],
+    );
+}
+
 pub struct PlayerModel {
     model: Option<model::ModelKey>,
     skin_url: Option<String>,
     dirty: bool,
     name: String,
+    name_tag: format::Component,
+    skin_parts: u8,
+    equipment: EquipmentTextures,
 
     has_head: bool,
     has_name_tag: bool,
@@ -92,6 +241,13 @@ impl PlayerModel {
             skin_url: None,
             dirty: false,
             name: name.to_owned(),
+            name_tag: {
+                let mut name = format::Component::Text(format::TextComponent::new(name));
+                format::convert_legacy(&mut name);
+                name
+            },
+            skin_parts: ALL_SKIN_PARTS,
+            equipment: Default::default(),
 
             has_head,
             has_name_tag,
@@ -109,6 +265,28 @@ impl PlayerModel {
         self.skin_url = skin;
         self.dirty = true;
     }
+
+    /// Sets which of the skin's outer layers are drawn, using the bits
+    /// of the skin parts metadata field.
+    pub fn set_skin_parts(&mut self, skin_parts: u8) {
+        if self.skin_parts != skin_parts {
+            self.skin_parts = skin_parts;
+            self.dirty = true;
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Replaces the text drawn above the player, an empty component
+    /// hides it.
+    pub fn set_name_tag(&mut self, name_tag: format::Component) {
+        if self.name_tag != name_tag {
+            self.name_tag = name_tag;
+            self.dirty = true;
+        }
+    }
 }
 
 struct PlayerRenderer {
@@ -118,6 +296,10 @@ struct PlayerRenderer {
     rotation: ecs::Key<Rotation>,
     game_info: ecs::Key<GameInfo>,
     light: ecs::Key<Light>,
+    flags: ecs::Key<EntityFlags>,
+    pose: ecs::Key<PoseData>,
+    equipment: ecs::Key<Equipment>,
+    models: ecs::Key<Arc<RwLock<crate::model::Factory>>>,
 }
 
 impl PlayerRenderer {
@@ -137,6 +319,10 @@ impl PlayerRenderer {
             rotation,
             game_info: m.get_key(),
             light,
+            flags: m.get_key(),
+            pose: m.get_key(),
+            equipment: m.get_key(),
+            models: m.get_key(),
         }
     }
 }
@@ -171,11 +357,22 @@ impl ecs::System for PlayerRenderer {
             .get_component_mut(world_entity, self.game_info)
             .unwrap()
             .delta;
+        let models = m.get_component(world_entity, self.models);
         for e in m.find(&self.filter) {
             let player_model = m.get_component_mut(e, self.player_model).unwrap();
             let position = m.get_component_mut(e, self.position).unwrap();
             let rotation = m.get_component_mut(e, self.rotation).unwrap();
             let light = m.get_component(e, self.light).unwrap();
+            let flags = m.get_component(e, self.flags).copied().unwrap_or_default();
+            let pose = m.get_component(e, self.pose);
+
+            if let Some(equipment) = m.get_component(e, self.equipment) {
+                let textures = EquipmentTextures::new(equipment, models, world.protocol_version());
+                if player_model.equipment != textures {
+                    player_model.equipment = textures;
+                    player_model.dirty = true;
+                }
+            }
 
             if player_model.dirty {
                 self.entity_removed(m, e, world, renderer);
@@ -214,7 +411,7 @@ impl ecs::System for PlayerRenderer {
                     mdl.matrix[PlayerModelPart::NameTag as usize] = Matrix4::from(Decomposed {
                         scale: 1.0,
                         rot: Quaternion::from_angle_y(Rad(renderer.camera.yaw as f32)),
-                        disp: offset + Vector3::new(0.0, (-24.0 / 16.0) - 0.6, 0.0),
+                        disp: offset + Vector3::new(0.0, -name_tag_height(pose), 0.0),
                     });
                 }
 
@@ -291,6 +488,14 @@ impl ecs::System for PlayerRenderer {
                     )))
                     * Matrix4::from(Quaternion::from_angle_x(Rad(-(i_time.sin() * 0.06) as f32)));
 
+                // Other players can't see invisible players or their
+                // name tags, collapsing every part hides them
+                if flags.invisible && !player_model.first_person {
+                    for matrix in &mut mdl.matrix {
+                        *matrix = Matrix4::from_scale(0.0);
+                    }
+                }
+
                 let mut update = true;
                 if position.moved {
                     player_model.still_time = 0.0;
@@ -305,7 +510,8 @@ impl ecs::System for PlayerRenderer {
                 }
 
                 if update {
-                    time += delta * 1.5 * (dir as f64);
+                    let speed = if flags.sprinting { 1.95 } else { 1.5 };
+                    time += delta * speed * (dir as f64);
                     if time > 30.0 {
                         time = 30.0;
                         dir = -1;
@@ -362,23 +568,25 @@ impl ecs::System for PlayerRenderer {
                     srel!(0.0, 8.0, 8.0, 8.0),  // East
                 ],
             );
-            model::append_box(
-                &mut head_verts,
-                -4.2 / 16.0,
-                -0.2 / 16.0,
-                -4.2 / 16.0,
-                8.4 / 16.0,
-                8.4 / 16.0,
-                8.4 / 16.0,
-                [
-                    srel!((16.0 + 32.0), 0.0, 8.0, 8.0), // Down
-                    srel!((8.0 + 32.0), 0.0, 8.0, 8.0),  // Up
-                    srel!((8.0 + 32.0), 8.0, 8.0, 8.0),  // North
-                    srel!((24.0 + 32.0), 8.0, 8.0, 8.0), // South
-                    srel!((16.0 + 32.0), 8.0, 8.0, 8.0), // West
-                    srel!((0.0 + 32.0), 8.0, 8.0, 8.0),  // East
-                ],
-            );
+            if player_model.skin_parts & HAT != 0 {
+                model::append_box(
+                    &mut head_verts,
+                    -4.2 / 16.0,
+                    -0.2 / 16.0,
+                    -4.2 / 16.0,
+                    8.4 / 16.0,
+                    8.4 / 16.0,
+                    8.4 / 16.0,
+                    [
+                        srel!((16.0 + 32.0), 0.0, 8.0, 8.0), // Down
+                        srel!((8.0 + 32.0), 0.0, 8.0, 8.0),  // Up
+                        srel!((8.0 + 32.0), 8.0, 8.0, 8.0),  // North
+                        srel!((24.0 + 32.0), 8.0, 8.0, 8.0), // South
+                        srel!((16.0 + 32.0), 8.0, 8.0, 8.0), // West
+                        srel!((0.0 + 32.0), 8.0, 8.0, 8.0),  // East
+                    ],
+                );
+            }
         }
 
         // TODO: Cape
@@ -400,25 +608,28 @@ impl ecs::System for PlayerRenderer {
                 srel!(28.0, 20.0, 4.0, 12.0), // East
             ],
         );
-        model::append_box(
-            &mut body_verts,
-            -4.2 / 16.0,
-            -6.2 / 16.0,
-            -2.2 / 16.0,
-            8.4 / 16.0,
-            12.4 / 16.0,
-            4.4 / 16.0,
-            [
-                srel!(28.0, 16.0 + 16.0, 8.0, 4.0),  // Down
-                srel!(20.0, 16.0 + 16.0, 8.0, 4.0),  // Up
-                srel!(20.0, 20.0 + 16.0, 8.0, 12.0), // North
-                srel!(32.0, 20.0 + 16.0, 8.0, 12.0), // South
-                srel!(16.0, 20.0 + 16.0, 4.0, 12.0), // West
-                srel!(28.0, 20.0 + 16.0, 4.0, 12.0), // East
-            ],
-        );
+        if player_model.skin_parts & JACKET != 0 {
+            model::append_box(
+                &mut body_verts,
+                -4.2 / 16.0,
+                -6.2 / 16.0,
+                -2.2 / 16.0,
+                8.4 / 16.0,
+                12.4 / 16.0,
+                4.4 / 16.0,
+                [
+                    srel!(28.0, 16.0 + 16.0, 8.0, 4.0),  // Down
+                    srel!(20.0, 16.0 + 16.0, 8.0, 4.0),  // Up
+                    srel!(20.0, 20.0 + 16.0, 8.0, 12.0), // North
+                    srel!(32.0, 20.0 + 16.0, 8.0, 12.0), // South
+                    srel!(16.0, 20.0 + 16.0, 4.0, 12.0), // West
+                    srel!(28.0, 20.0 + 16.0, 4.0, 12.0), // East
+                ],
+            );
+        }
 
         let mut part_verts = vec![vec![]; 4];
+        let outer_layers = [LEFT_PANTS, RIGHT_PANTS, LEFT_SLEEVE, RIGHT_SLEEVE];
 
         for (i, offsets) in [
             [16.0, 48.0, 0.0, 48.0],  // Left left
@@ -447,6 +658,9 @@ impl ecs::System for PlayerRenderer {
                     srel!(ox + 0.0, oy + 4.0, 4.0, 12.0),  // East
                 ],
             );
+            if player_model.skin_parts & outer_layers[i] == 0 {
+                continue;
+            }
             let (ox, oy) = (offsets[2], offsets[3]);
             model::append_box(
                 &mut part_verts[i],
@@ -467,37 +681,99 @@ impl ecs::System for PlayerRenderer {
             );
         }
 
-        let mut name_verts = vec![];
-        if player_model.has_name_tag {
-            let mut state = FormatState {
-                width: 0.0,
-                offset: 0.0,
-                text: Vec::new(),
-                renderer,
-                y_scale: 0.16,
-                x_scale: 0.01,
-            };
-            let mut name = format::Component::Text(format::TextComponent::new(&player_model.name));
-            format::convert_legacy(&mut name);
-            state.build(&name, format::Color::Black);
-            let width = state.width;
-            // Center align text
-            for vert in &mut state.text {
-                vert.x += width * 0.5;
-                vert.r = 64;
-                vert.g = 64;
-                vert.b = 64;
+        let textures = renderer.get_textures_ref();
+        let armor_layer = |armor: &ArmorTexture, layer: i32| {
+            render::Renderer::get_texture(
+                textures,
+                &format!("models/armor/{}_layer_{}", armor.material, layer),
+            )
+        };
+        let [feet, legs, chest, head] = &player_model.equipment.armor;
+        if let (true, Some(head)) = (player_model.has_head, head) {
+            let texture = armor_layer(head, 1);
+            append_armor_box(
+                &mut head_verts,
+                &texture,
+                head.tint,
+                [-4.0, 0.0, -4.0],
+                [8.0, 8.0, 8.0],
+                1.0,
+                (0.0, 0.0),
+            );
+        }
+        if let Some(chest) = chest {
+            let texture = armor_layer(chest, 1);
+            append_armor_box(
+                &mut body_verts,
+                &texture,
+                chest.tint,
+                [-4.0, -6.0, -2.0],
+                [8.0, 12.0, 4.0],
+                1.0,
+                (16.0, 16.0),
+            );
+            for verts in &mut part_verts[2..4] {
+                append_armor_box(
+                    verts,
+                    &texture,
+                    chest.tint,
+                    [-2.0, -12.0, -2.0],
+                    [4.0, 12.0, 4.0],
+                    1.0,
+                    (40.0, 16.0),
+                );
             }
-            name_verts.extend_from_slice(&state.text);
-            for vert in &mut state.text {
-                vert.x -= 0.01;
-                vert.y -= 0.01;
-                vert.z -= 0.05;
-                vert.r = 255;
-                vert.g = 255;
-                vert.b = 255;
+        }
+        // Leggings sit under the boots and chestplate on the second layer
+        if let Some(legs) = legs {
+            let texture = armor_layer(legs, 2);
+            append_armor_box(
+                &mut body_verts,
+                &texture,
+                legs.tint,
+                [-4.0, -6.0, -2.0],
+                [8.0, 12.0, 4.0],
+                0.5,
+                (16.0, 16.0),
+            );
+            for verts in &mut part_verts[0..2] {
+                append_armor_box(
+                    verts,
+                    &texture,
+                    legs.tint,
+                    [-2.0, -12.0, -2.0],
+                    [4.0, 12.0, 4.0],
+                    0.5,
+                    (0.0, 16.0),
+                );
             }
-            name_verts.extend_from_slice(&state.text);
+        }
+        if let Some(feet) = feet {
+            let texture = armor_layer(feet, 1);
+            for verts in &mut part_verts[0..2] {
+                append_armor_box(
+                    verts,
+                    &texture,
+                    feet.tint,
+                    [-2.0, -12.0, -2.0],
+                    [4.0, 12.0, 4.0],
+                    1.0,
+                    (0.0, 16.0),
+                );
+            }
+        }
+        if let Some(icon) = &player_model.equipment.main_hand {
+            let texture = render::Renderer::get_texture(textures, icon);
+            append_held_item(&mut part_verts[3], texture);
+        }
+        if let Some(icon) = &player_model.equipment.off_hand {
+            let texture = render::Renderer::get_texture(textures, icon);
+            append_held_item(&mut part_verts[2], texture);
+        }
+
+        let mut name_verts = vec![];
+        if player_model.has_name_tag {
+            model::append_name_tag(&mut name_verts, renderer, &player_model.name_tag);
         }
 
         player_model.model = Some(renderer.model.create_model(
@@ -535,16 +811,42 @@ impl ecs::System for PlayerRenderer {
     }
 }
 
-#[derive(Default)]
 pub struct PlayerMovement {
     pub flying: bool,
     pub want_to_fly: bool,
+    /// Whether the server lets the player start flying
+    pub can_fly: bool,
+    /// Set when the player starts or stops flying themselves, so the
+    /// server can be told
+    pub toggled_flight: bool,
+    pub flying_speed: f64,
+    /// The player's movement speed attribute, without sprinting
+    pub walking_speed: f64,
+    pub sprinting: bool,
     pub when_last_jump_pressed: Option<Instant>,
     pub when_last_jump_released: Option<Instant>,
     pub did_touch_ground: bool,
     pub pressed_keys: HashMap<Stevenkey, bool, BuildHasherDefault<FNVHash>>,
 }
 
+impl Default for PlayerMovement {
+    fn default() -> PlayerMovement {
+        PlayerMovement {
+            flying: false,
+            want_to_fly: false,
+            can_fly: false,
+            toggled_flight: false,
+            flying_speed: 0.05,
+            walking_speed: 0.1,
+            sprinting: false,
+            when_last_jump_pressed: None,
+            when_last_jump_released: None,
+            did_touch_ground: false,
+            pressed_keys: Default::default(),
+        }
+    }
+}
+
 impl PlayerMovement {
     pub fn new() -> PlayerMovement {
         Default::default()
@@ -579,7 +881,7 @@ impl PlayerMovement {
         (forward, yaw)
     }
 
-    fn is_key_pressed(&self, key: Stevenkey) -> bool {
+    pub fn is_key_pressed(&self, key: Stevenkey) -> bool {
         self.pressed_keys.get(&key).map_or(false, |v| *v)
     }
 }
@@ -593,6 +895,11 @@ struct MovementHandler {
     velocity: ecs::Key<Velocity>,
     bounds: ecs::Key<Bounds>,
     rotation: ecs::Key<Rotation>,
+    riding: ecs::Key<Riding>,
+    steering: ecs::Key<Steering>,
+    kind: ecs::Key<EntityKind>,
+    target_rotation: ecs::Key<TargetRotation>,
+    effects: ecs::Key<Effects>,
 }
 
 impl MovementHandler {
@@ -616,6 +923,104 @@ impl MovementHandler {
             velocity,
             bounds,
             rotation,
+            riding: m.get_key(),
+            steering: m.get_key(),
+            kind: m.get_key(),
+            target_rotation: m.get_key(),
+            effects: m.get_key(),
+        }
+    }
+
+    fn steer_vehicle(
+        &self,
+        m: &mut ecs::Manager,
+        world: &world::World,
+        e: ecs::Entity,
+        vehicle: ecs::Entity,
+    ) {
+        use std::f64::consts::PI;
+        let kind = *m.get_component(vehicle, self.kind).unwrap();
+        if m.get_component(vehicle, self.steering).is_none() {
+            m.add_component(vehicle, self.steering, Steering::new());
+        }
+
+        let movement = m.get_component(e, self.movement).unwrap();
+        let player_yaw = m.get_component(e, self.rotation).unwrap().yaw;
+        let steering = m.get_component_mut(vehicle, self.steering).unwrap();
+        let (position, rotation, bounds) = match (
+            m.get_component_mut(vehicle, self.position),
+            m.get_component_mut(vehicle, self.target_rotation),
+            m.get_component(vehicle, self.bounds),
+        ) {
+            (Some(position), Some(rotation), Some(bounds)) => (position, rotation, bounds.bounds),
+            _ => return,
+        };
+        if !world.is_chunk_loaded(
+            (position.position.x as i32) >> 4,
+            (position.position.z as i32) >> 4,
+        ) {
+            return;
+        }
+
+        if vehicle::is_boat(&kind) {
+            let left = movement.is_key_pressed(Stevenkey::Left);
+            let right = movement.is_key_pressed(Stevenkey::Right);
+            let forward = movement.is_key_pressed(Stevenkey::Forward);
+            let backward = movement.is_key_pressed(Stevenkey::Backward);
+            let mut thrust = 0.0;
+            if left {
+                steering.turn += PI / 180.0;
+            }
+            if right {
+                steering.turn -= PI / 180.0;
+            }
+            if left != right && !forward && !backward {
+                thrust += 0.005;
+            }
+            if forward {
+                thrust += 0.04;
+            }
+            if backward {
+                thrust -= 0.005;
+            }
+            rotation.yaw += steering.turn;
+            steering.velocity.x += rotation.yaw.sin() * thrust;
+            steering.velocity.z += rotation.yaw.cos() * thrust;
+
+            let in_water = fluid_height(world.get_block(block_at(position.position, -0.1)))
+                .map_or(false, |(fluid, _)| fluid == Fluid::Water);
+            // Boats float on water and slide to a stop on land
+            let momentum = if in_water {
+                steering.velocity.y = 0.0;
+                0.9
+            } else {
+                steering.velocity.y -= 0.04;
+                0.5
+            };
+            steering.velocity.x *= momentum;
+            steering.velocity.z *= momentum;
+            steering.turn *= momentum;
+            move_vehicle(world, position, steering, bounds, 0.0);
+        } else {
+            // Horses face wherever their rider looks
+            rotation.yaw = player_yaw;
+            let (forward, yaw) = movement.calculate_movement(player_yaw);
+            let speed = if movement.is_key_pressed(Stevenkey::Backward) {
+                0.075
+            } else {
+                0.3
+            };
+            steering.velocity.x = forward * yaw.cos() * speed;
+            steering.velocity.z = -forward * yaw.sin() * speed;
+            if steering.on_ground {
+                if movement.is_key_pressed(Stevenkey::Jump) {
+                    steering.velocity.y = 0.7;
+                }
+            } else {
+                steering.velocity.y -= 0.08;
+            }
+            steering.velocity.y *= 0.98;
+            move_vehicle(world, position, steering, bounds, 1.0);
         }
     }
 }
@@ -627,6 +1032,15 @@ impl ecs::System for MovementHandler {
 
     fn update(&mut self, m: &mut ecs::Manager, world: &mut world::World, _: &mut render::Renderer) {
         for e in m.find(&self.filter) {
+            // The vehicle carries the player, at most steering is left to do
+            if m.get_component(e, self.riding).is_some() {
+                m.get_component_mut(e, self.movement).unwrap().sprinting = false;
+                if let Some(vehicle) = vehicle::steered_vehicle(m, e, world.protocol_version()) {
+                    self.steer_vehicle(m, world, e, vehicle);
+                }
+                continue;
+            }
+
             let movement = m.get_component_mut(e, self.movement).unwrap();
             if movement.flying && m.get_component(e, self.gravity).is_some() {
                 m.remove_component(e, self.gravity);
@@ -649,8 +1063,9 @@ impl ecs::System for MovementHandler {
                             movement.want_to_fly = !movement.want_to_fly;
                             //info!("double jump! dt={:?} toggle want_to_fly = {}", dt, movement.want_to_fly);
 
-                            if gamemode.can_fly() && !gamemode.always_fly() {
+                            if movement.can_fly && !gamemode.always_fly() {
                                 movement.flying = movement.want_to_fly;
+                                movement.toggled_flight = true;
                             }
                         }
                     }
@@ -666,6 +1081,8 @@ impl ecs::System for MovementHandler {
             let gravity = m.get_component_mut(e, self.gravity);
 
             let player_bounds = m.get_component(e, self.bounds).unwrap().bounds;
+            let effects = m.get_component(e, self.effects);
+            let effect_level = |kind| f64::from(effects.map_or(0, |v: &Effects| v.level(kind)));
 
             let mut last_position = position.position;
 
@@ -674,91 +1091,224 @@ impl ecs::System for MovementHandler {
                 (position.position.z as i32) >> 4,
             ) {
                 let (forward, yaw) = movement.calculate_movement(rotation.yaw);
-                let mut speed = if movement.is_key_pressed(Stevenkey::Sprint) {
-                    0.2806
-                } else {
-                    0.21585
-                };
-                if movement.flying {
-                    speed *= 2.5;
+                let on_ground = gravity.as_ref().map_or(false, |v| v.on_ground);
+                let jumping = movement.is_key_pressed(Stevenkey::Jump);
+                let sneaking = movement.is_key_pressed(Stevenkey::Sneak);
+                // Blind players can't see where they are sprinting to
+                let sprinting = movement.is_key_pressed(Stevenkey::Sprint)
+                    && movement.is_key_pressed(Stevenkey::Forward)
+                    && !sneaking
+                    && effect_level(EffectKind::Blindness) == 0.0;
+                movement.sprinting = sprinting;
+                let feet = world.get_block(block_at(position.position, 0.0));
+                // The block that decides how the ground feels is the one
+                // just under the player's feet
+                let below = world.get_block(block_at(position.position, -0.5));
+                let fluid = fluid_in(world, player_bounds.add_v(position.position));
+                let climbing = is_climbable(feet);
+                let stuck = stuck_multiplier(feet).or_else(|| {
+                    stuck_multiplier(world.get_block(block_at(position.position, 1.0)))
+                });
 
-                    if movement.is_key_pressed(Stevenkey::Jump) {
-                        position.position.y += speed;
-                    }
-                    if movement.is_key_pressed(Stevenkey::Sneak) {
-                        position.position.y -= speed;
+                let motion = if movement.flying {
+                    let speed = if movement.is_key_pressed(Stevenkey::Sprint) {
+                        0.2806 * 2.5
+                    } else {
+                        0.21585 * 2.5
+                    };
+                    // Servers can change how fast the player flies, 0.05
+                    // being the normal speed
+                    let speed = speed * movement.flying_speed / 0.05;
+                    let mut motion = Vector3::new(
+                        forward * yaw.cos() * speed,
+                        0.0,
+                        -forward * yaw.sin() * speed,
+                    );
+                    if jumping {
+                        motion.y += speed;
                     }
-                } else if gravity.as_ref().map_or(false, |v| v.on_ground) {
-                    if movement.is_key_pressed(Stevenkey::Jump) && velocity.velocity.y.abs() < 0.001
-                    {
-                        velocity.velocity.y = 0.42;
+                    if sneaking {
+                        motion.y -= speed;
                     }
+                    // Flying doesn't carry momentum back to the ground
+                    velocity.velocity.x = 0.0;
+                    velocity.velocity.z = 0.0;
+                    velocity.velocity.y *= 0.98;
+                    motion.y += velocity.velocity.y;
+                    motion
                 } else {
-                    velocity.velocity.y -= 0.08;
-                    if velocity.velocity.y < -3.92 {
-                        velocity.velocity.y = -3.92;
+                    let mut input = forward * 0.98;
+                    if sneaking {
+                        input *= 0.3;
+                    }
+                    let accel = match fluid {
+                        Some(fluid) => {
+                            if jumping {
+                                velocity.velocity.y += 0.04;
+                            } else if sneaking
+                                && fluid == Fluid::Water
+                                && world.protocol_version() >= 393
+                            {
+                                velocity.velocity.y -= 0.04;
+                            }
+                            0.02
+                        }
+                        None if on_ground => {
+                            if jumping {
+                                velocity.velocity.y =
+                                    0.42 + 0.1 * effect_level(EffectKind::JumpBoost);
+                                // Sprint jumping gives a boost forwards
+                                if sprinting {
+                                    velocity.velocity.x += rotation.yaw.sin() * 0.2;
+                                    velocity.velocity.z += rotation.yaw.cos() * 0.2;
+                                }
+                            }
+                            // The speed and slowness effects are already
+                            // part of the movement speed attribute
+                            let speed = if sprinting {
+                                movement.walking_speed * 1.3
+                            } else {
+                                movement.walking_speed
+                            };
+                            speed * 0.216 / slipperiness(below).powi(3)
+                        }
+                        None if sprinting => 0.026,
+                        None => 0.02,
+                    };
+                    velocity.velocity.x += input * yaw.cos() * accel;
+                    velocity.velocity.z -= input * yaw.sin() * accel;
+
+                    if climbing && fluid.is_none() {
+                        velocity.velocity.x = velocity.velocity.x.clamp(-0.15, 0.15);
+                        velocity.velocity.z = velocity.velocity.z.clamp(-0.15, 0.15);
+                        velocity.velocity.y = velocity.velocity.y.max(-0.15);
+                        // Sneaking holds on to the ladder
+                        if sneaking && velocity.velocity.y < 0.0 {
+                            velocity.velocity.y = 0.0;
+                        }
                     }
-                }
-                velocity.velocity.y *= 0.98;
-                position.position.x += forward * yaw.cos() * speed;
-                position.position.z -= forward * yaw.sin() * speed;
-                position.position.y += velocity.velocity.y;
+
+                    match stuck {
+                        Some(stuck) => Vector3::new(
+                            velocity.velocity.x * stuck.x,
+                            velocity.velocity.y * stuck.y,
+                            velocity.velocity.z * stuck.z,
+                        ),
+                        None => velocity.velocity,
+                    }
+                };
+                position.position += motion;
 
                 if !gamemode.noclip() {
-                    let mut target = position.position;
-                    position.position.y = last_position.y;
-                    position.position.z = last_position.z;
-
-                    // We handle each axis separately to allow for a sliding
-                    // effect when pushing up against walls.
-
-                    let (bounds, xhit) =
-                        check_collisions(world, position, &last_position, player_bounds);
-                    position.position.x = bounds.min.x + 0.3;
-                    last_position.x = position.position.x;
-
-                    position.position.z = target.z;
-                    let (bounds, zhit) =
-                        check_collisions(world, position, &last_position, player_bounds);
-                    position.position.z = bounds.min.z + 0.3;
-                    last_position.z = position.position.z;
-
-                    // Half block jumps
-                    // Minecraft lets you 'jump' up 0.5 blocks
-                    // for slabs and stairs (or smaller blocks).
-                    // Currently we implement this as a teleport to the
-                    // top of the block if we could move there
-                    // but this isn't smooth.
-                    if (xhit || zhit) && gravity.as_ref().map_or(false, |v| v.on_ground) {
-                        let mut ox = position.position.x;
-                        let mut oz = position.position.z;
-                        position.position.x = target.x;
-                        position.position.z = target.z;
-                        for offset in 1..9 {
-                            let mini = player_bounds.add_v(cgmath::Vector3::new(
-                                0.0,
-                                offset as f64 / 16.0,
-                                0.0,
-                            ));
-                            let (_, hit) = check_collisions(world, position, &last_position, mini);
-                            if !hit {
-                                target.y += offset as f64 / 16.0;
-                                ox = target.x;
-                                oz = target.z;
-                                break;
+                    let target = position.position;
+                    let (x_blocked, z_blocked, yhit) = move_with_collisions(
+                        world,
+                        position,
+                        &mut last_position,
+                        target,
+                        player_bounds,
+                        0.5,
+                        on_ground,
+                    );
+
+                    if movement.flying {
+                        if yhit {
+                            velocity.velocity.y = 0.0;
+                        }
+                    } else {
+                        if x_blocked {
+                            velocity.velocity.x = 0.0;
+                        }
+                        if z_blocked {
+                            velocity.velocity.z = 0.0;
+                        }
+                        if yhit {
+                            let landed_on = world.get_block(block_at(position.position, -0.2));
+                            // Slime blocks bounce back anyone not sneaking
+                            if motion.y < 0.0
+                                && !sneaking
+                                && matches!(landed_on, Block::Slime { .. })
+                            {
+                                velocity.velocity.y = -velocity.velocity.y;
+                            } else {
+                                velocity.velocity.y = 0.0;
+                            }
+                        }
+                        if stuck.is_some() {
+                            velocity.velocity = Vector3::new(0.0, 0.0, 0.0);
+                        }
+                        let factor = speed_factor(below);
+                        velocity.velocity.x *= factor;
+                        velocity.velocity.z *= factor;
+                        if (x_blocked || z_blocked) && climbing {
+                            velocity.velocity.y = 0.2;
+                        }
+
+                        // Slow falling only kicks in on the way down
+                        let fall = if velocity.velocity.y <= 0.0
+                            && effect_level(EffectKind::SlowFalling) > 0.0
+                        {
+                            0.01
+                        } else {
+                            0.08
+                        };
+                        match fluid {
+                            Some(Fluid::Water) => {
+                                let drag = if sprinting { 0.9 } else { 0.8 };
+                                velocity.velocity.x *= drag;
+                                velocity.velocity.y *= 0.8;
+                                velocity.velocity.z *= drag;
+                                // Water pulled much harder before 1.13
+                                velocity.velocity.y -= if world.protocol_version() >= 393 {
+                                    fall / 16.0
+                                } else {
+                                    0.02
+                                };
+                            }
+                            Some(Fluid::Lava) => {
+                                velocity.velocity *= 0.5;
+                                velocity.velocity.y -= fall / 4.0;
+                            }
+                            None => {
+                                let levitation = effect_level(EffectKind::Levitation);
+                                if levitation > 0.0 {
+                                    // Levitation floats towards a steady
+                                    // climb instead of falling
+                                    velocity.velocity.y +=
+                                        (0.05 * levitation - velocity.velocity.y) * 0.2;
+                                } else {
+                                    velocity.velocity.y -= fall;
+                                }
+                                velocity.velocity.y *= 0.98;
+                                let friction = if on_ground {
+                                    slipperiness(below) * 0.91
+                                } else {
+                                    0.91
+                                };
+                                velocity.velocity.x *= friction;
+                                velocity.velocity.z *= friction;
+                                if on_ground
+                                    && !sneaking
+                                    && matches!(below, Block::Slime { .. })
+                                    && velocity.velocity.y.abs() < 0.1
+                                {
+                                    let slow = 0.4 + velocity.velocity.y.abs() * 0.2;
+                                    velocity.velocity.x *= slow;
+                                    velocity.velocity.z *= slow;
+                                }
                             }
                         }
-                        position.position.x = ox;
-                        position.position.z = oz;
-                    }
 
-                    position.position.y = target.y;
-                    let (bounds, yhit) =
-                        check_collisions(world, position, &last_position, player_bounds);
-                    position.position.y = bounds.min.y;
-                    last_position.y = position.position.y;
-                    if yhit {
-                        velocity.velocity.y = 0.0;
+                        // Swimming into a ledge hops out of the fluid
+                        if fluid.is_some() && (x_blocked || z_blocked) {
+                            let raised = player_bounds
+                                .add_v(velocity.velocity + Vector3::new(0.0, 0.6, 0.0));
+                            let (_, hit) =
+                                check_collisions(world, position, &last_position, raised);
+                            if !hit && fluid_in(world, raised.add_v(position.position)).is_none() {
+                                velocity.velocity.y = 0.3;
+                            }
+                        }
                     }
 
                     if let Some(gravity) = gravity {
@@ -777,6 +1327,197 @@ impl ecs::System for MovementHandler {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Fluid {
+    Water,
+    Lava,
+}
+
+fn block_at(position: Vector3<f64>, y_offset: f64) -> BPosition {
+    BPosition::new(
+        position.x.floor() as i32,
+        (position.y + y_offset).floor() as i32,
+        position.z.floor() as i32,
+    )
+}
+
+/// Returns the fluid touching the bounds, water winning over lava.
+fn fluid_in(world: &world::World, bounds: Aabb3<f64>) -> Option<Fluid> {
+    let mut found = None;
+    for y in (bounds.min.y + 0.001).floor() as i32..=(bounds.max.y - 0.001).floor() as i32 {
+        for z in (bounds.min.z + 0.001).floor() as i32..=(bounds.max.z - 0.001).floor() as i32 {
+            for x in (bounds.min.x + 0.001).floor() as i32..=(bounds.max.x - 0.001).floor() as i32 {
+                let (fluid, height) = match fluid_height(world.get_block(BPosition::new(x, y, z))) {
+                    Some(val) => val,
+                    None => continue,
+                };
+                if y as f64 + height < bounds.min.y {
+                    continue;
+                }
+                if fluid == Fluid::Water {
+                    return Some(fluid);
+                }
+                found = Some(fluid);
+            }
+        }
+    }
+    found
+}
+
+/// Returns the fluid in a block and how far up the block it reaches.
+fn fluid_height(block: Block) -> Option<(Fluid, f64)> {
+    // Falling fluids fill the whole block
+    let height = |level: u8| {
+        if level >= 8 {
+            1.0
+        } else {
+            f64::from(8 - level) / 9.0
+        }
+    };
+    match block {
+        Block::Water { level } | Block::FlowingWater { level } => {
+            Some((Fluid::Water, height(level)))
+        }
+        Block::Lava { level } | Block::FlowingLava { level } => Some((Fluid::Lava, height(level))),
+        Block::Seagrass { .. }
+        | Block::TallSeagrass { .. }
+        | Block::Kelp { .. }
+        | Block::KelpPlant { .. }
+        | Block::BubbleColumn { .. } => Some((Fluid::Water, 1.0)),
+        _ => None,
+    }
+}
+
+// The block registry stops at the 1.13 blocks, so honey, sweet berry
+// bushes, scaffolding, powder snow and the nether vines arrive as missing
+// blocks and can't get their own movement here until they are added.
+
+/// Fraction of its speed kept each tick sliding over the block.
+fn slipperiness(block: Block) -> f64 {
+    match block {
+        Block::Ice { .. } | Block::PackedIce { .. } | Block::FrostedIce { .. } => 0.98,
+        Block::BlueIce { .. } => 0.989,
+        Block::Slime { .. } => 0.8,
+        _ => 0.6,
+    }
+}
+
+/// Multiplier on speed walking over the block.
+fn speed_factor(block: Block) -> f64 {
+    match block {
+        Block::SoulSand { .. } => 0.4,
+        _ => 1.0,
+    }
+}
+
+fn is_climbable(block: Block) -> bool {
+    matches!(block, Block::Ladder { .. } | Block::Vine { .. })
+}
+
+/// Multiplier on movement while inside a block that slows everything
+/// down, velocity is lost each tick spent in it.
+fn stuck_multiplier(block: Block) -> Option<Vector3<f64>> {
+    match block {
+        Block::Web { .. } => Some(Vector3::new(0.25, 0.05, 0.25)),
+        _ => None,
+    }
+}
+
+/// Moves a vehicle the player is steering by its velocity, stepping up
+/// blocks no taller than `step`.
+fn move_vehicle(
+    world: &world::World,
+    position: &mut TargetPosition,
+    steering: &mut Steering,
+    bounds: Aabb3<f64>,
+    step: f64,
+) {
+    let mut last_position = position.position;
+    let target = position.position + steering.velocity;
+    let (x_blocked, z_blocked, yhit) = move_with_collisions(
+        world,
+        position,
+        &mut last_position,
+        target,
+        bounds,
+        step,
+        steering.on_ground,
+    );
+    if x_blocked {
+        steering.velocity.x = 0.0;
+    }
+    if z_blocked {
+        steering.velocity.z = 0.0;
+    }
+    if yhit {
+        steering.velocity.y = 0.0;
+    }
+
+    let ground = Aabb3::new(
+        Point3::new(bounds.min.x, -0.005, bounds.min.z),
+        Point3::new(bounds.max.x, 0.0, bounds.max.z),
+    );
+    let (_, hit) = check_collisions(world, position, &last_position, ground);
+    steering.on_ground = hit;
+}
+
+/// Moves from `last_position` towards `target`, returning whether the x
+/// and z movement was blocked and whether anything was hit vertically.
+///
+/// Each axis is handled separately to allow for a sliding effect when
+/// pushing up against walls. Walking into a block no taller than `step`
+/// while on the ground teleports on top of it if there is room, which
+/// isn't as smooth as vanilla.
+fn move_with_collisions(
+    world: &world::World,
+    position: &mut TargetPosition,
+    last_position: &mut Vector3<f64>,
+    mut target: Vector3<f64>,
+    bounds: Aabb3<f64>,
+    step: f64,
+    on_ground: bool,
+) -> (bool, bool, bool) {
+    position.position = *last_position;
+
+    position.position.x = target.x;
+    let (hit_bounds, xhit) = check_collisions(world, position, last_position, bounds);
+    position.position.x = hit_bounds.min.x - bounds.min.x;
+    last_position.x = position.position.x;
+
+    position.position.z = target.z;
+    let (hit_bounds, zhit) = check_collisions(world, position, last_position, bounds);
+    position.position.z = hit_bounds.min.z - bounds.min.z;
+    last_position.z = position.position.z;
+
+    if (xhit || zhit) && on_ground {
+        let mut ox = position.position.x;
+        let mut oz = position.position.z;
+        position.position.x = target.x;
+        position.position.z = target.z;
+        for offset in 1..=(step * 16.0) as i32 {
+            let raised = bounds.add_v(Vector3::new(0.0, offset as f64 / 16.0, 0.0));
+            let (_, hit) = check_collisions(world, position, last_position, raised);
+            if !hit {
+                target.y += offset as f64 / 16.0;
+                ox = target.x;
+                oz = target.z;
+                break;
+            }
+        }
+        position.position.x = ox;
+        position.position.z = oz;
+    }
+    let x_blocked = xhit && position.position.x != target.x;
+    let z_blocked = zhit && position.position.z != target.z;
+
+    position.position.y = target.y;
+    let (hit_bounds, yhit) = check_collisions(world, position, last_position, bounds);
+    position.position.y = hit_bounds.min.y - bounds.min.y;
+    last_position.y = position.position.y;
+
+    (x_blocked, z_blocked, yhit)
+}
+
 fn check_collisions(
     world: &world::World,
     position: &mut TargetPosition,
@@ -867,3 +1608,172 @@ impl Collidable<Aabb3<f64>> for Aabb3<f64> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::Direction;
+    use crate::world::block::{BlockHalf, StoneSlabVariant};
+
+    fn player_bounds() -> Aabb3<f64> {
+        Aabb3::new(Point3::new(-0.3, 0.0, -0.3), Point3::new(0.3, 1.8, 0.3))
+    }
+
+    /// A bedrock floor with `blocks` placed on top of it.
+    fn world_with(blocks: &[(i32, i32, i32, Block)]) -> world::World {
+        let mut world = world::World::new(340);
+        for x in -2..4 {
+            for z in -2..3 {
+                world.set_block(BPosition::new(x, 63, z), Block::Bedrock {});
+            }
+        }
+        for &(x, y, z, block) in blocks {
+            world.set_block(BPosition::new(x, y, z), block);
+        }
+        world
+    }
+
+    fn walk(
+        world: &world::World,
+        target: Vector3<f64>,
+        step: f64,
+    ) -> (Vector3<f64>, (bool, bool, bool)) {
+        let mut position = TargetPosition::new(0.5, 64.0, 0.5);
+        let mut last_position = position.position;
+        let hits = move_with_collisions(
+            world,
+            &mut position,
+            &mut last_position,
+            target,
+            player_bounds(),
+            step,
+            true,
+        );
+        (position.position, hits)
+    }
+
+    #[test]
+    fn fluid_heights() {
+        assert_eq!(
+            fluid_height(Block::Water { level: 0 }),
+            Some((Fluid::Water, 8.0 / 9.0))
+        );
+        assert_eq!(
+            fluid_height(Block::FlowingWater { level: 7 }),
+            Some((Fluid::Water, 1.0 / 9.0))
+        );
+        // Falling fluids fill the whole block
+        assert_eq!(
+            fluid_height(Block::FlowingWater { level: 8 }),
+            Some((Fluid::Water, 1.0))
+        );
+        assert_eq!(
+            fluid_height(Block::Lava { level: 4 }),
+            Some((Fluid::Lava, 4.0 / 9.0))
+        );
+        assert_eq!(
+            fluid_height(Block::Kelp { age: 0 }),
+            Some((Fluid::Water, 1.0))
+        );
+        assert_eq!(fluid_height(Block::Air {}), None);
+        assert_eq!(fluid_height(Block::Bedrock {}), None);
+    }
+
+    #[test]
+    fn block_slipperiness() {
+        assert_eq!(slipperiness(Block::Ice {}), 0.98);
+        assert_eq!(slipperiness(Block::FrostedIce { age: 2 }), 0.98);
+        assert_eq!(slipperiness(Block::BlueIce {}), 0.989);
+        assert_eq!(slipperiness(Block::Slime {}), 0.8);
+        assert_eq!(slipperiness(Block::Bedrock {}), 0.6);
+        assert_eq!(speed_factor(Block::SoulSand {}), 0.4);
+        assert_eq!(speed_factor(Block::Ice {}), 1.0);
+    }
+
+    #[test]
+    fn climbing_and_cobwebs() {
+        assert!(is_climbable(Block::Ladder {
+            facing: Direction::North,
+            waterlogged: false,
+        }));
+        assert!(!is_climbable(Block::Bedrock {}));
+        assert_eq!(
+            stuck_multiplier(Block::Web {}),
+            Some(Vector3::new(0.25, 0.05, 0.25))
+        );
+        assert_eq!(stuck_multiplier(Block::Air {}), None);
+    }
+
+    #[test]
+    fn walls_block_movement() {
+        let world = world_with(&[(1, 64, 0, Block::Bedrock {}), (1, 65, 0, Block::Bedrock {})]);
+        let (position, (x_blocked, z_blocked, _)) = walk(&world, Vector3::new(1.5, 64.0, 0.5), 0.5);
+        assert!(x_blocked);
+        assert!(!z_blocked);
+        assert!(position.x < 0.7);
+        assert_eq!(position.y, 64.0);
+    }
+
+    #[test]
+    fn slides_along_walls() {
+        let world = world_with(&[(1, 64, 0, Block::Bedrock {}), (1, 65, 0, Block::Bedrock {})]);
+        let (position, (x_blocked, z_blocked, _)) = walk(&world, Vector3::new(0.9, 64.0, 0.6), 0.5);
+        assert!(x_blocked);
+        assert!(!z_blocked);
+        assert!((position.z - 0.6).abs() < 0.001);
+    }
+
+    #[test]
+    fn steps_up_slabs() {
+        let slab = Block::StoneSlab {
+            half: BlockHalf::Bottom,
+            variant: StoneSlabVariant::Stone,
+        };
+        let world = world_with(&[(1, 64, 0, slab)]);
+        let (position, (x_blocked, _, _)) = walk(&world, Vector3::new(1.5, 64.0, 0.5), 0.5);
+        assert!(!x_blocked);
+        assert_eq!(position.x, 1.5);
+        assert_eq!(position.y, 64.5);
+    }
+
+    #[test]
+    fn step_height() {
+        let world = world_with(&[(1, 64, 0, Block::Bedrock {})]);
+        let (position, (x_blocked, _, _)) = walk(&world, Vector3::new(1.5, 64.0, 0.5), 0.5);
+        assert!(x_blocked);
+        assert_eq!(position.y, 64.0);
+
+        // Horses can step up whole blocks
+        let (position, (x_blocked, _, _)) = walk(&world, Vector3::new(1.5, 64.0, 0.5), 1.0);
+        assert!(!x_blocked);
+        assert_eq!(position.y, 65.0);
+
+        // Boats can't step up at all
+        let slab = Block::StoneSlab {
+            half: BlockHalf::Bottom,
+            variant: StoneSlabVariant::Stone,
+        };
+        let world = world_with(&[(1, 64, 0, slab)]);
+        let (_, (x_blocked, _, _)) = walk(&world, Vector3::new(1.5, 64.0, 0.5), 0.0);
+        assert!(x_blocked);
+    }
+
+    #[test]
+    fn lands_on_the_floor() {
+        let world = world_with(&[]);
+        let mut position = TargetPosition::new(0.5, 64.5, 0.5);
+        let mut last_position = position.position;
+        let (_, _, yhit) = move_with_collisions(
+            &world,
+            &mut position,
+            &mut last_position,
+            Vector3::new(0.5, 63.8, 0.5),
+            player_bounds(),
+            0.5,
+            false,
+        );
+        assert!(yhit);
+        assert!((position.position.y - 64.0).abs() < 0.001);
+        assert_eq!(last_position, position.position);
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 use std::sync::RwLock;
 
+use super::metadata::EntityFlags;
 use super::*;
 use crate::ecs;
 use crate::render;
@@ -226,6 +227,7 @@ pub struct LightEntity {
     position: ecs::Key<Position>,
     bounds: ecs::Key<Bounds>,
     light: ecs::Key<Light>,
+    flags: ecs::Key<EntityFlags>,
 }
 
 impl LightEntity {
@@ -238,6 +240,7 @@ impl LightEntity {
             position,
             bounds,
             light,
+            flags: m.get_key(),
         }
     }
 }
@@ -288,6 +291,13 @@ impl ecs::System for LightEntity {
                 light.block_light = block_light / count;
                 light.sky_light = sky_light / count;
             }
+            // Burning entities light themselves up, glowing ones stand
+            // out the same way in place of an outline
+            if let Some(flags) = m.get_component(e, self.flags) {
+                if flags.on_fire || flags.glowing {
+                    light.block_light = 15.0;
+                }
+            }
         }
     }
 }
@@ -0,0 +1,215 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::kind::EntityKind;
+use super::player::PlayerModel;
+use super::{Position, TargetPosition};
+use crate::ecs;
+use crate::render;
+use crate::world;
+use cgmath::Vector3;
+
+pub fn add_systems(m: &mut ecs::Manager) {
+    let sys = FollowVehicle::new(m);
+    m.add_render_system(sys);
+}
+
+/// The entity this one is sitting on.
+pub struct Riding {
+    pub vehicle: ecs::Entity,
+}
+
+/// The entities sitting on a vehicle, the first one steers it.
+#[derive(Default)]
+pub struct Passengers {
+    pub passengers: Vec<ecs::Entity>,
+}
+
+/// Motion of a vehicle the local player is moving themselves.
+pub struct Steering {
+    pub velocity: Vector3<f64>,
+    /// Change in yaw each tick, boats keep turning for a while
+    pub turn: f64,
+    pub on_ground: bool,
+}
+
+impl Steering {
+    pub fn new() -> Steering {
+        Steering {
+            velocity: Vector3::new(0.0, 0.0, 0.0),
+            turn: 0.0,
+            on_ground: false,
+        }
+    }
+}
+
+/// Returns the vehicle the player is moving themselves, if any. Only
+/// the first passenger steers and until 1.9 the server moved every
+/// vehicle from its rider's input.
+pub fn steered_vehicle(
+    m: &ecs::Manager,
+    player: ecs::Entity,
+    protocol_version: i32,
+) -> Option<ecs::Entity> {
+    let vehicle = m.get_component_direct::<Riding>(player)?.vehicle;
+    let kind = m.get_component_direct::<EntityKind>(vehicle)?;
+    let controlling = m
+        .get_component_direct::<Passengers>(vehicle)
+        .map_or(false, |v| v.passengers.first() == Some(&player));
+    if controlling && protocol_version >= 107 && (is_boat(kind) || is_horse(kind)) {
+        Some(vehicle)
+    } else {
+        None
+    }
+}
+
+pub fn is_boat(kind: &EntityKind) -> bool {
+    kind.name == "boat"
+}
+
+fn is_horse(kind: &EntityKind) -> bool {
+    matches!(
+        kind.name,
+        "horse" | "donkey" | "mule" | "skeleton_horse" | "zombie_horse"
+    )
+}
+
+/// Replaces the passengers of a vehicle, dismounting anyone no
+/// longer on it.
+pub fn set_passengers(m: &mut ecs::Manager, vehicle: ecs::Entity, passengers: Vec<ecs::Entity>) {
+    let old = m
+        .get_component_mut_direct::<Passengers>(vehicle)
+        .map(|v| std::mem::take(&mut v.passengers))
+        .unwrap_or_default();
+    for e in old {
+        if !passengers.contains(&e) {
+            m.remove_component_direct::<Riding>(e);
+        }
+    }
+    for &e in &passengers {
+        if m.get_component_direct::<Riding>(e)
+            .map_or(false, |v| v.vehicle != vehicle)
+        {
+            dismount(m, e);
+        }
+        set_riding(m, e, vehicle);
+    }
+    if let Some(current) = m.get_component_mut_direct::<Passengers>(vehicle) {
+        current.passengers = passengers;
+    } else if !passengers.is_empty() {
+        m.add_component_direct(vehicle, Passengers { passengers });
+    }
+}
+
+/// Puts an entity on a vehicle behind any passengers already on it.
+pub fn mount(m: &mut ecs::Manager, e: ecs::Entity, vehicle: ecs::Entity) {
+    let mut passengers = m
+        .get_component_direct::<Passengers>(vehicle)
+        .map_or_else(Vec::new, |v| v.passengers.clone());
+    if !passengers.contains(&e) {
+        passengers.push(e);
+    }
+    set_passengers(m, vehicle, passengers);
+}
+
+/// Takes an entity off the vehicle it is riding, if any.
+pub fn dismount(m: &mut ecs::Manager, e: ecs::Entity) {
+    let vehicle = match m.get_component_direct::<Riding>(e) {
+        Some(riding) => riding.vehicle,
+        None => return,
+    };
+    m.remove_component_direct::<Riding>(e);
+    if let Some(passengers) = m.get_component_mut_direct::<Passengers>(vehicle) {
+        passengers.passengers.retain(|v| *v != e);
+    }
+}
+
+/// Unlinks an entity that is about to be removed from its vehicle and
+/// its passengers.
+pub fn remove(m: &mut ecs::Manager, e: ecs::Entity) {
+    dismount(m, e);
+    set_passengers(m, e, Vec::new());
+}
+
+fn set_riding(m: &mut ecs::Manager, e: ecs::Entity, vehicle: ecs::Entity) {
+    if let Some(riding) = m.get_component_mut_direct::<Riding>(e) {
+        riding.vehicle = vehicle;
+    } else {
+        m.add_component_direct(e, Riding { vehicle });
+    }
+}
+
+/// Height above a vehicle's feet that its passengers' feet are placed.
+fn seat_height(kind: Option<&EntityKind>) -> f64 {
+    match kind {
+        Some(kind) if is_boat(kind) => -0.1,
+        Some(kind) if kind.name.contains("minecart") => 0.0,
+        Some(kind) => kind.height * 0.75,
+        None => 0.0,
+    }
+}
+
+struct FollowVehicle {
+    filter: ecs::Filter,
+    riding: ecs::Key<Riding>,
+    position: ecs::Key<Position>,
+    target_position: ecs::Key<TargetPosition>,
+    kind: ecs::Key<EntityKind>,
+    player_model: ecs::Key<PlayerModel>,
+}
+
+impl FollowVehicle {
+    fn new(m: &mut ecs::Manager) -> FollowVehicle {
+        let riding = m.get_key();
+        let position = m.get_key();
+        let target_position = m.get_key();
+        FollowVehicle {
+            filter: ecs::Filter::new()
+                .with(riding)
+                .with(position)
+                .with(target_position),
+            riding,
+            position,
+            target_position,
+            kind: m.get_key(),
+            player_model: m.get_key(),
+        }
+    }
+}
+
+impl ecs::System for FollowVehicle {
+    fn filter(&self) -> &ecs::Filter {
+        &self.filter
+    }
+
+    fn update(&mut self, m: &mut ecs::Manager, _: &mut world::World, _: &mut render::Renderer) {
+        for e in m.find(&self.filter) {
+            let vehicle = m.get_component(e, self.riding).unwrap().vehicle;
+            let vehicle_position = match m.get_component(vehicle, self.position) {
+                Some(position) => position.position,
+                None => continue,
+            };
+            let mut offset = seat_height(m.get_component(vehicle, self.kind));
+            // Players sit down into the seat
+            if m.get_component(e, self.player_model).is_some() {
+                offset -= 0.35;
+            }
+            let position = vehicle_position + Vector3::new(0.0, offset, 0.0);
+            m.get_component_mut(e, self.position).unwrap().position = position;
+            m.get_component_mut(e, self.target_position)
+                .unwrap()
+                .position = position;
+        }
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::server;
+use crate::ui;
+
+// Vanilla gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const WIDTH: f64 = 182.0;
+const HEIGHT: f64 = 5.0;
+const TOP: f64 = 12.0 * SCALE;
+const SPACING: f64 = 19.0 * SCALE;
+const TITLE_OFFSET: f64 = 9.0 * SCALE;
+// The notch overlays follow the seven colours in gui/bars
+const NOTCHES_V: f64 = 80.0;
+// Bars stop being added once they reach a third of the way down the
+// scaled screen
+const MAX_Y: f64 = 480.0 / 3.0;
+
+struct Elements {
+    _bars: Vec<ui::ImageRef>,
+    _fills: Vec<ui::ImageRef>,
+    _titles: Vec<ui::FormattedRef>,
+}
+
+/// The boss bars stacked at the top of the screen.
+#[derive(Default)]
+pub struct BossBars {
+    shown: Option<Vec<server::BossBar>>,
+    elements: Option<Elements>,
+}
+
+impl BossBars {
+    pub fn new() -> BossBars {
+        Default::default()
+    }
+
+    pub fn tick(&mut self, ui_container: &mut ui::Container, server: &server::Server) {
+        if !server.is_connected() || server.boss_bars.is_empty() {
+            self.shown = None;
+            self.elements = None;
+            return;
+        }
+        if self.shown.as_ref() == Some(&server.boss_bars) {
+            return;
+        }
+        self.elements = Some(BossBars::build(ui_container, &server.boss_bars));
+        self.shown = Some(server.boss_bars.clone());
+    }
+
+    fn build(ui_container: &mut ui::Container, boss_bars: &[server::BossBar]) -> Elements {
+        let mut bars = vec![];
+        let mut fills = vec![];
+        let mut titles = vec![];
+
+        for (i, boss_bar) in boss_bars.iter().enumerate() {
+            let y = TOP + i as f64 * SPACING;
+            if y > MAX_Y {
+                break;
+            }
+            let v = boss_bar.color as f64 * HEIGHT * 2.0;
+            let bar = ui::ImageBuilder::new()
+                .texture("gui/bars")
+                .position(0.0, y)
+                .size(WIDTH * SCALE, HEIGHT * SCALE)
+                .texture_coords((0.0, v / 256.0, WIDTH / 256.0, HEIGHT / 256.0))
+                .alignment(ui::VAttach::Top, ui::HAttach::Center)
+                .create(ui_container);
+
+            let fill_width = fill_width(boss_bar.health);
+            if fill_width > 0.0 {
+                fills.push(
+                    ui::ImageBuilder::new()
+                        .texture("gui/bars")
+                        .size(fill_width * SCALE, HEIGHT * SCALE)
+                        .texture_coords((
+                            0.0,
+                            (v + HEIGHT) / 256.0,
+                            fill_width / 256.0,
+                            HEIGHT / 256.0,
+                        ))
+                        .create(&mut *bar.borrow_mut()),
+                );
+            }
+            if let Some(v) = notches_v(boss_bar.style) {
+                fills.push(
+                    ui::ImageBuilder::new()
+                        .texture("gui/bars")
+                        .size(WIDTH * SCALE, HEIGHT * SCALE)
+                        .texture_coords((0.0, v / 256.0, WIDTH / 256.0, HEIGHT / 256.0))
+                        .create(&mut *bar.borrow_mut()),
+                );
+                if fill_width > 0.0 {
+                    fills.push(
+                        ui::ImageBuilder::new()
+                            .texture("gui/bars")
+                            .size(fill_width * SCALE, HEIGHT * SCALE)
+                            .texture_coords((
+                                0.0,
+                                (v + HEIGHT) / 256.0,
+                                fill_width / 256.0,
+                                HEIGHT / 256.0,
+                            ))
+                            .create(&mut *bar.borrow_mut()),
+                    );
+                }
+            }
+
+            titles.push(
+                ui::FormattedBuilder::new()
+                    .text(boss_bar.title.clone())
+                    .position(0.0, y - TITLE_OFFSET)
+                    .alignment(ui::VAttach::Top, ui::HAttach::Center)
+                    .create(ui_container),
+            );
+            bars.push(bar);
+        }
+
+        Elements {
+            _bars: bars,
+            _fills: fills,
+            _titles: titles,
+        }
+    }
+}
+
+/// Returns how much of a bar's width is filled at the given health.
+/// Vanilla scales the bar one pixel past its width, so it only reads
+/// as full once the health is.
+fn fill_width(health: f32) -> f64 {
+    (health.clamp(0.0, 1.0) as f64 * (WIDTH + 1.0)).min(WIDTH)
+}
+
+/// Returns where the notch overlay for a style starts in gui/bars,
+/// if the style has notches at all.
+fn notches_v(style: i32) -> Option<f64> {
+    if style > 0 {
+        Some(NOTCHES_V + (style - 1) as f64 * HEIGHT * 2.0)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill() {
+        assert_eq!(fill_width(0.0), 0.0);
+        assert_eq!(fill_width(0.5), 91.5);
+        assert_eq!(fill_width(1.0), WIDTH);
+        assert_eq!(fill_width(-1.0), 0.0);
+        assert_eq!(fill_width(2.0), WIDTH);
+    }
+
+    #[test]
+    fn notches() {
+        assert_eq!(notches_v(0), None);
+        assert_eq!(notches_v(1), Some(NOTCHES_V));
+        assert_eq!(notches_v(4), Some(NOTCHES_V + 30.0));
+    }
+}
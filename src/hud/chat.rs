@@ -0,0 +1,621 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::format::{self, ClickEvent, Component, HoverEvent, TextComponent};
+use crate::render;
+use crate::server;
+use crate::ui;
+use instant::Instant;
+
+const MAX_MESSAGES: usize = 100;
+const MAX_SENT_HISTORY: usize = 100;
+// Lines shown while the chat is closed, these fade out over time
+const CLOSED_LINES: usize = 10;
+const OPEN_LINES: usize = 20;
+// Seconds after which a message starts/finishes fading out
+const FADE_START: f64 = 9.0;
+const FADE_END: f64 = 10.0;
+
+const WIDTH: f64 = 320.0;
+const INPUT_HEIGHT: f64 = 20.0;
+const LINES_OFFSET: f64 = 40.0;
+const TOOLTIP_OFFSET: f64 = 12.0;
+const TOOLTIP_PADDING: f64 = 4.0;
+// Offset of the text inside the input line
+const INPUT_TEXT_OFFSET: f64 = 7.0;
+const MAX_SUGGESTIONS: usize = 10;
+const SUGGESTION_HEIGHT: f64 = 18.0;
+const SUGGESTION_PADDING: f64 = 2.0;
+
+struct Message {
+    text: Component,
+    received_at: Instant,
+}
+
+struct Line {
+    background: ui::ImageRef,
+    text: ui::FormattedRef,
+}
+
+struct Tooltip {
+    event: HoverEvent,
+    background: ui::ImageRef,
+    _text: ui::FormattedRef,
+}
+
+/// Ways the word being typed could be completed, cycled through
+/// with tab.
+struct Suggestions {
+    /// The input the suggestions were made for.
+    text: String,
+    start: usize,
+    matches: Vec<String>,
+    selected: Option<usize>,
+}
+
+#[derive(Default)]
+pub struct Chat {
+    messages: Vec<Message>,
+    sent: Vec<String>,
+    sent_pos: Option<usize>,
+    dirty: bool,
+
+    lines: Vec<Line>,
+    input: Option<ui::TextBoxRef>,
+    tooltip: Option<Tooltip>,
+
+    last_input: String,
+    suggestions: Option<Suggestions>,
+    // Set when tab was pressed while waiting for the server's
+    // completions, so the first is used once they arrive
+    complete_on_reply: bool,
+    suggestion_lines: Vec<Line>,
+    suggestions_dirty: bool,
+}
+
+impl Chat {
+    pub fn new() -> Chat {
+        Default::default()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.input.is_some()
+    }
+
+    /// Opens the chat input line, pre-filled with the passed text.
+    pub fn open(&mut self, ui_container: &mut ui::Container, text: &str) {
+        if self.input.is_some() {
+            return;
+        }
+        let input = ui::TextBoxBuilder::new()
+            .input(text)
+            .position(2.0, 2.0)
+            .size(WIDTH, INPUT_HEIGHT)
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+            .create(ui_container);
+        ui::TextBox::make_focusable(&input, ui_container);
+        input.borrow_mut().add_submit_func(|txt, game| {
+            let message = std::mem::take(&mut txt.input);
+            game.hud.chat.close();
+            game.focused = true;
+            let message = message.trim();
+            if !message.is_empty() {
+                game.hud.chat.add_sent(message);
+                game.server.send_chat(message);
+            }
+        });
+        self.input = Some(input);
+        self.sent_pos = None;
+        self.dirty = true;
+    }
+
+    pub fn close(&mut self) {
+        if self.input.take().is_some() {
+            self.dirty = true;
+        }
+    }
+
+    /// Replaces the contents of the input line, if it is open.
+    fn set_input(&mut self, text: &str) {
+        if let Some(input) = &self.input {
+            input.borrow_mut().input = text.to_owned();
+        }
+    }
+
+    fn insert_input(&mut self, text: &str) {
+        if let Some(input) = &self.input {
+            input.borrow_mut().input.push_str(text);
+        }
+    }
+
+    /// Removes all received messages, keeping the sent history.
+    pub fn clear(&mut self) {
+        self.messages.clear();
+        self.dirty = true;
+    }
+
+    pub fn add_message(&mut self, text: Component) {
+        if self.messages.len() >= MAX_MESSAGES {
+            self.messages.remove(0);
+        }
+        self.messages.push(Message {
+            text,
+            received_at: Instant::now(),
+        });
+        self.dirty = true;
+    }
+
+    fn add_sent(&mut self, message: &str) {
+        if self.sent.last().map_or(false, |v| v == message) {
+            return;
+        }
+        if self.sent.len() >= MAX_SENT_HISTORY {
+            self.sent.remove(0);
+        }
+        self.sent.push(message.to_owned());
+    }
+
+    /// Replaces the contents of the input line with a previously
+    /// sent message, moving backwards in the history if `older`
+    /// is set and forwards otherwise.
+    pub fn recall(&mut self, older: bool) {
+        let input = match self.input {
+            Some(ref input) => input,
+            None => return,
+        };
+        if self.sent.is_empty() {
+            return;
+        }
+        self.sent_pos = recall_pos(self.sent_pos, older, self.sent.len());
+        input.borrow_mut().input = self
+            .sent_pos
+            .map_or_else(String::new, |pos| self.sent[pos].clone());
+    }
+
+    /// Completes the word being typed, cycling through the
+    /// suggestions each time it is called.
+    pub fn complete(&mut self, server: &mut server::Server) {
+        let text = match &self.input {
+            Some(input) => input.borrow().input.clone(),
+            None => return,
+        };
+        if self.suggestions.is_none() {
+            if text.starts_with('/') {
+                // Commands are completed by servers that don't send
+                // their commands up front
+                if server.commands.is_none() {
+                    server.request_completions(&text);
+                    self.complete_on_reply = true;
+                }
+                return;
+            }
+            self.suggestions = player_suggestions(&text, server);
+            self.suggestions_dirty = true;
+        }
+        let suggestions = match &mut self.suggestions {
+            Some(suggestions) => suggestions,
+            None => return,
+        };
+        let selected = suggestions
+            .selected
+            .map_or(0, |v| (v + 1) % suggestions.matches.len());
+        suggestions.selected = Some(selected);
+        let completed = format!(
+            "{}{}",
+            &suggestions.text[..suggestions.start],
+            suggestions.matches[selected]
+        );
+        self.suggestions_dirty = true;
+        // Remembered so the suggestions aren't replaced by ones for the
+        // completed text
+        self.last_input = completed.clone();
+        if let Some(input) = &self.input {
+            let mut input = input.borrow_mut();
+            input.input = completed;
+            let highlight = server
+                .commands
+                .as_ref()
+                .and_then(|v| v.parse(&input.input))
+                .map(|v| v.highlight);
+            input.set_highlight(highlight);
+        }
+    }
+
+    pub fn tick(
+        &mut self,
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        server: &mut server::Server,
+    ) {
+        if !server.is_connected() {
+            self.input = None;
+            self.lines.clear();
+            self.tooltip = None;
+            self.tick_suggestions(ui_container, renderer, server);
+            return;
+        }
+
+        let now = Instant::now();
+        let open = self.is_open();
+        let visible = self
+            .messages
+            .iter()
+            .rev()
+            .take(if open { OPEN_LINES } else { CLOSED_LINES })
+            .take_while(|v| open || age(now, v) < FADE_END)
+            .count();
+
+        if self.dirty || visible != self.lines.len() {
+            self.dirty = false;
+            self.lines.clear();
+
+            let mut offset = 0.0;
+            for msg in self.messages.iter().rev().take(visible) {
+                let (_, height) = ui::Formatted::compute_size(renderer, &msg.text, WIDTH - 4.0);
+                let background = ui::ImageBuilder::new()
+                    .texture("steven:solid")
+                    .position(2.0, LINES_OFFSET + offset)
+                    .size(WIDTH, height)
+                    .colour((0, 0, 0, 100))
+                    .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+                    .create(ui_container);
+                let text = ui::FormattedBuilder::new()
+                    .text(msg.text.clone())
+                    .position(2.0, 0.0)
+                    .max_width(WIDTH - 4.0)
+                    .create(&mut *background.borrow_mut());
+                text.borrow_mut().add_click_func(click_text);
+                self.lines.push(Line { background, text });
+                offset += height;
+            }
+        }
+
+        for (line, msg) in self.lines.iter().zip(self.messages.iter().rev()) {
+            let visibility = if open { 1.0 } else { fade(age(now, msg)) };
+            line.background.borrow_mut().colour.3 = (100.0 * visibility) as u8;
+            line.text.borrow_mut().alpha = (255.0 * visibility) as u8;
+        }
+
+        self.tick_tooltip(ui_container, renderer);
+        self.tick_suggestions(ui_container, renderer, server);
+    }
+
+    fn tick_suggestions(
+        &mut self,
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        server: &mut server::Server,
+    ) {
+        let text = match &self.input {
+            Some(input) => input.borrow().input.clone(),
+            None => {
+                self.last_input.clear();
+                self.suggestions = None;
+                self.complete_on_reply = false;
+                self.suggestion_lines.clear();
+                return;
+            }
+        };
+
+        if text != self.last_input {
+            self.last_input = text.clone();
+            self.complete_on_reply = false;
+            let parsed = server.commands.as_ref().and_then(|v| v.parse(&text));
+            if let Some(input) = &self.input {
+                input
+                    .borrow_mut()
+                    .set_highlight(parsed.as_ref().map(|v| v.highlight.clone()));
+            }
+            self.suggestions = None;
+            self.suggestions_dirty = true;
+            if let Some(parsed) = parsed {
+                if parsed.ask_server {
+                    server.request_completions(&text);
+                } else if !parsed.suggestions.is_empty() {
+                    self.suggestions = Some(Suggestions {
+                        text: text.clone(),
+                        start: parsed.start,
+                        matches: parsed.suggestions,
+                        selected: None,
+                    });
+                }
+            }
+        }
+
+        if let Some(completions) = server.completions.take() {
+            if completions.text == text && !completions.matches.is_empty() {
+                self.suggestions = Some(Suggestions {
+                    text: completions.text,
+                    start: completions.start,
+                    matches: completions.matches,
+                    selected: None,
+                });
+                self.suggestions_dirty = true;
+                if self.complete_on_reply {
+                    self.complete_on_reply = false;
+                    self.complete(server);
+                }
+            }
+        }
+
+        if !self.suggestions_dirty {
+            return;
+        }
+        self.suggestions_dirty = false;
+        self.suggestion_lines.clear();
+        let suggestions = match &self.suggestions {
+            Some(suggestions) => suggestions,
+            None => return,
+        };
+
+        // Only a window of the matches around the selected one is shown
+        let selected = suggestions.selected.unwrap_or(0);
+        let first = (selected + 1).saturating_sub(MAX_SUGGESTIONS);
+        let shown = suggestions.matches.iter().enumerate().skip(first);
+        let shown: Vec<_> = shown.take(MAX_SUGGESTIONS).collect();
+        let x = 2.0
+            + INPUT_TEXT_OFFSET
+            + renderer
+                .ui
+                .size_of_string(&suggestions.text[..suggestions.start])
+                .max(0.0);
+        let width = shown
+            .iter()
+            .map(|(_, v)| renderer.ui.size_of_string(v))
+            .fold(0.0, f64::max)
+            + SUGGESTION_PADDING * 2.0;
+        for (row, (i, text)) in shown.iter().enumerate() {
+            let background = ui::ImageBuilder::new()
+                .texture("steven:solid")
+                .position(x, 2.0 + INPUT_HEIGHT + row as f64 * SUGGESTION_HEIGHT)
+                .size(width, SUGGESTION_HEIGHT)
+                .colour((0, 0, 0, 200))
+                .draw_index(1)
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+                .create(ui_container);
+            let mut text = TextComponent::new(text);
+            text.modifier.color = Some(if Some(*i) == suggestions.selected {
+                format::Color::Yellow
+            } else {
+                format::Color::Gray
+            });
+            let text = ui::FormattedBuilder::new()
+                .text(Component::Text(text))
+                .position(SUGGESTION_PADDING, 0.0)
+                .create(&mut *background.borrow_mut());
+            self.suggestion_lines.push(Line { background, text });
+        }
+    }
+
+    fn tick_tooltip(&mut self, ui_container: &mut ui::Container, renderer: &render::Renderer) {
+        // Hover events only apply while the chat is open and the mouse
+        // is free to move over it
+        let hovered = if self.is_open() {
+            self.lines.iter().find_map(|line| {
+                let text = line.text.borrow();
+                let event = text.hovered_events()?.hover.clone()?;
+                Some((event, text.mouse_position()))
+            })
+        } else {
+            None
+        };
+        let (event, (x, y)) = match hovered {
+            Some(hovered) => hovered,
+            None => {
+                self.tooltip = None;
+                return;
+            }
+        };
+
+        if self.tooltip.as_ref().map_or(true, |v| v.event != event) {
+            let text = tooltip_text(&event);
+            let (width, height) = ui::Formatted::compute_size(renderer, &text, -1.0);
+            let background = ui::ImageBuilder::new()
+                .texture("steven:solid")
+                .size(
+                    width + TOOLTIP_PADDING * 2.0,
+                    height + TOOLTIP_PADDING * 2.0,
+                )
+                .colour((16, 0, 16, 240))
+                .draw_index(1)
+                .create(ui_container);
+            let text = ui::FormattedBuilder::new()
+                .text(text)
+                .position(TOOLTIP_PADDING, TOOLTIP_PADDING)
+                .create(&mut *background.borrow_mut());
+            self.tooltip = Some(Tooltip {
+                event,
+                background,
+                _text: text,
+            });
+        }
+        // Shown above the mouse as the chat is at the bottom of the
+        // screen
+        let mut background = self.tooltip.as_ref().unwrap().background.borrow_mut();
+        background.x = x + TOOLTIP_OFFSET;
+        background.y = y - TOOLTIP_OFFSET - background.height;
+    }
+}
+
+fn click_text(text: &mut ui::Formatted, game: &mut crate::Game) -> bool {
+    let events = match text.hovered_events() {
+        Some(events) => events.clone(),
+        None => return false,
+    };
+    if game.is_shift_pressed {
+        if let Some(insertion) = events.insertion {
+            game.hud.chat.insert_input(&insertion);
+            return true;
+        }
+    }
+    match events.click {
+        Some(ClickEvent::RunCommand(command)) => game.server.send_chat(&command),
+        Some(ClickEvent::SuggestCommand(command)) => game.hud.chat.set_input(&command),
+        Some(ClickEvent::CopyToClipboard(text)) => copy_to_clipboard(&text),
+        // Links are copied rather than opened so nothing is opened
+        // without the player choosing to
+        Some(ClickEvent::OpenUrl(url)) => {
+            copy_to_clipboard(&url);
+            let mut msg = TextComponent::new(&format!("Copied link to clipboard: {}", url));
+            msg.modifier.color = Some(format::Color::Gray);
+            game.hud.chat.add_message(Component::Text(msg));
+        }
+        // Pages only exist in books
+        Some(ClickEvent::ChangePage(_)) | None => return false,
+    }
+    true
+}
+
+/// Suggests the names of players on the server that start with the
+/// last word of a chat message.
+fn player_suggestions(text: &str, server: &server::Server) -> Option<Suggestions> {
+    let start = text.rfind(' ').map_or(0, |v| v + 1);
+    let word = text[start..].to_lowercase();
+    let mut matches: Vec<String> = server
+        .players
+        .values()
+        .filter(|v| v.name.to_lowercase().starts_with(&word))
+        .map(|v| v.name.clone())
+        .collect();
+    if matches.is_empty() {
+        return None;
+    }
+    matches.sort_by_key(|v| v.to_lowercase());
+    Some(Suggestions {
+        text: text.to_owned(),
+        start,
+        matches,
+        selected: None,
+    })
+}
+
+fn tooltip_text(event: &HoverEvent) -> Component {
+    match event {
+        HoverEvent::ShowText(text) => (**text).clone(),
+        HoverEvent::ShowItem { id, count } => {
+            let text = if *count > 1 {
+                format!("{} x{}", id, count)
+            } else {
+                id.clone()
+            };
+            Component::Text(TextComponent::new(&text))
+        }
+        HoverEvent::ShowEntity { name, kind, id } => {
+            let mut details = TextComponent::new(&format!("{}\n{}", kind, id));
+            details.modifier.color = Some(format::Color::DarkGray);
+            let mut text = TextComponent::new("");
+            text.modifier.extra = Some(match name {
+                Some(name) => vec![
+                    (**name).clone(),
+                    Component::Text(TextComponent::new("\n")),
+                    Component::Text(details),
+                ],
+                None => vec![Component::Text(details)],
+            });
+            Component::Text(text)
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn copy_to_clipboard(text: &str) {
+    use clipboard::{ClipboardContext, ClipboardProvider};
+    let result: Result<(), _> = ClipboardProvider::new()
+        .and_then(|mut clipboard: ClipboardContext| clipboard.set_contents(text.to_owned()));
+    if let Err(err) = result {
+        log::warn!("Failed to copy to clipboard: {}", err);
+    }
+}
+
+// TODO: wasm clipboard, Clipboard API: https://www.w3.org/TR/clipboard-apis/
+#[cfg(target_arch = "wasm32")]
+fn copy_to_clipboard(_text: &str) {}
+
+fn age(now: Instant, msg: &Message) -> f64 {
+    now.duration_since(msg.received_at).as_secs_f64()
+}
+
+/// Returns how visible a message is in the closed chat after the given
+/// number of seconds.
+fn fade(age: f64) -> f64 {
+    (1.0 - (age - FADE_START) / (FADE_END - FADE_START)).clamp(0.0, 1.0)
+}
+
+/// Returns the position in the sent history `recall` moves to, `None`
+/// being the empty line after the newest message.
+fn recall_pos(pos: Option<usize>, older: bool, len: usize) -> Option<usize> {
+    match (pos, older) {
+        (None, true) => Some(len - 1),
+        (None, false) => None,
+        (Some(pos), true) => Some(pos.saturating_sub(1)),
+        (Some(pos), false) if pos + 1 < len => Some(pos + 1),
+        (Some(_), false) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fades_out() {
+        assert_eq!(fade(0.0), 1.0);
+        assert_eq!(fade(FADE_START), 1.0);
+        assert_eq!(fade((FADE_START + FADE_END) / 2.0), 0.5);
+        assert_eq!(fade(FADE_END), 0.0);
+        assert_eq!(fade(FADE_END + 60.0), 0.0);
+    }
+
+    #[test]
+    fn recall_history() {
+        assert_eq!(recall_pos(None, true, 3), Some(2));
+        assert_eq!(recall_pos(Some(2), true, 3), Some(1));
+        // Stops at the oldest message
+        assert_eq!(recall_pos(Some(0), true, 3), Some(0));
+        assert_eq!(recall_pos(Some(1), false, 3), Some(2));
+        // Going past the newest message empties the line again
+        assert_eq!(recall_pos(Some(2), false, 3), None);
+        assert_eq!(recall_pos(None, false, 3), None);
+    }
+
+    #[test]
+    fn sent_history() {
+        let mut chat = Chat::new();
+        chat.add_sent("hello");
+        chat.add_sent("hello");
+        chat.add_sent("/help");
+        chat.add_sent("hello");
+        assert_eq!(chat.sent, vec!["hello", "/help", "hello"]);
+
+        for i in 0..MAX_SENT_HISTORY {
+            chat.add_sent(&i.to_string());
+        }
+        assert_eq!(chat.sent.len(), MAX_SENT_HISTORY);
+        assert_eq!(chat.sent[0], "0");
+    }
+
+    #[test]
+    fn message_log() {
+        let mut chat = Chat::new();
+        for i in 0..MAX_MESSAGES + 5 {
+            chat.add_message(Component::Text(TextComponent::new(&i.to_string())));
+        }
+        assert_eq!(chat.messages.len(), MAX_MESSAGES);
+        assert_eq!(chat.messages[0].text.to_string(), "5");
+        assert!(chat.dirty);
+
+        chat.clear();
+        assert!(chat.messages.is_empty());
+    }
+}
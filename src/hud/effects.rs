@@ -0,0 +1,231 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::entity::effects::{Effect, EffectKind};
+use crate::inventory;
+use crate::server;
+use crate::ui;
+
+// Vanilla gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const TEXTURE: &str = "gui/container/inventory";
+const ICON_SIZE: f64 = 18.0;
+// Space left for each icon in the corner, including its duration
+const ICON_WIDTH: f64 = 25.0;
+const ICON_HEIGHT: f64 = 34.0;
+// Size of an entry in the list beside the inventory
+const ENTRY_WIDTH: f64 = 120.0;
+const ENTRY_HEIGHT: f64 = 32.0;
+
+const DURATION_COLOUR: (u8, u8, u8, u8) = (127, 127, 127, 255);
+
+/// An effect as drawn, with its duration rounded to the second.
+#[derive(Clone, PartialEq)]
+struct ShownEffect {
+    kind: EffectKind,
+    amplifier: u8,
+    duration: String,
+}
+
+#[derive(Clone, PartialEq)]
+struct Shown {
+    effects: Vec<ShownEffect>,
+    in_inventory: bool,
+}
+
+struct Elements {
+    _images: Vec<ui::ImageRef>,
+    _texts: Vec<ui::TextRef>,
+}
+
+/// The player's status effects, as icons in the top right corner of the
+/// screen or listed beside the inventory while it is open.
+#[derive(Default)]
+pub struct Effects {
+    shown: Option<Shown>,
+    elements: Option<Elements>,
+}
+
+impl Effects {
+    pub fn tick(&mut self, ui_container: &mut ui::Container, server: &server::Server) {
+        let effects = match server.player_effects() {
+            Some(effects) if server.is_connected() => effects,
+            _ => {
+                self.shown = None;
+                self.elements = None;
+                return;
+            }
+        };
+
+        // Beneficial effects come first
+        let mut sorted: Vec<&Effect> = effects.iter().collect();
+        sorted.sort_by_key(|v| !v.kind.is_beneficial());
+        let shown = Shown {
+            effects: sorted
+                .into_iter()
+                .map(|v| ShownEffect {
+                    kind: v.kind,
+                    amplifier: v.amplifier,
+                    duration: format_duration(v),
+                })
+                .collect(),
+            in_inventory: server.inventory.read().unwrap().open_window()
+                == Some(inventory::PLAYER_WINDOW),
+        };
+        if self.shown.as_ref() == Some(&shown) {
+            return;
+        }
+        self.elements = Some(if shown.in_inventory {
+            Effects::build_list(ui_container, &shown.effects)
+        } else {
+            Effects::build_icons(ui_container, &shown.effects)
+        });
+        self.shown = Some(shown);
+    }
+
+    fn build_icons(ui_container: &mut ui::Container, effects: &[ShownEffect]) -> Elements {
+        let mut images = vec![];
+        let mut texts = vec![];
+        let (mut beneficial, mut harmful) = (0, 0);
+        for effect in effects {
+            let (u, v) = match effect.kind.icon() {
+                Some(icon) => icon,
+                None => continue,
+            };
+            let (column, row) = if effect.kind.is_beneficial() {
+                beneficial += 1;
+                (beneficial, 0)
+            } else {
+                harmful += 1;
+                (harmful, 1)
+            };
+            let container = ui::ImageBuilder::new()
+                .texture("steven:solid")
+                .position(
+                    (column as f64 * ICON_WIDTH - 24.0) * SCALE,
+                    (1.0 + row as f64 * ICON_HEIGHT) * SCALE,
+                )
+                .size(24.0 * SCALE, ICON_HEIGHT * SCALE)
+                .colour((0, 0, 0, 0))
+                .alignment(ui::VAttach::Top, ui::HAttach::Right)
+                .create(ui_container);
+            let background = ui::ImageBuilder::new()
+                .texture(TEXTURE)
+                .size(24.0 * SCALE, 24.0 * SCALE)
+                .texture_coords((141.0 / 256.0, 166.0 / 256.0, 24.0 / 256.0, 24.0 / 256.0))
+                .alignment(ui::VAttach::Top, ui::HAttach::Center)
+                .create(&mut *container.borrow_mut());
+            images.push(icon(&background, u, v, 3.0, 3.0));
+            texts.push(
+                ui::TextBuilder::new()
+                    .text(effect.duration.clone())
+                    .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+                    .create(&mut *container.borrow_mut()),
+            );
+            images.push(background);
+            images.push(container);
+        }
+        Elements {
+            _images: images,
+            _texts: texts,
+        }
+    }
+
+    fn build_list(ui_container: &mut ui::Container, effects: &[ShownEffect]) -> Elements {
+        let mut images = vec![];
+        let mut texts = vec![];
+        // Entries squeeze together when there are too many to fit beside
+        // the inventory
+        let spacing = if effects.len() > 5 {
+            132.0 / (effects.len() - 1) as f64
+        } else {
+            33.0
+        };
+        for (i, effect) in effects.iter().enumerate() {
+            let background = ui::ImageBuilder::new()
+                .texture(TEXTURE)
+                .position(-152.0 * SCALE, (-67.0 + i as f64 * spacing) * SCALE)
+                .size(ENTRY_WIDTH * SCALE, ENTRY_HEIGHT * SCALE)
+                .texture_coords((
+                    0.0,
+                    166.0 / 256.0,
+                    ENTRY_WIDTH / 256.0,
+                    ENTRY_HEIGHT / 256.0,
+                ))
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .create(ui_container);
+            if let Some((u, v)) = effect.kind.icon() {
+                images.push(icon(&background, u, v, 6.0, 7.0));
+            }
+            let name = match effect.amplifier {
+                0 => effect.kind.name().to_owned(),
+                amplifier => format!("{} {}", effect.kind.name(), level_numeral(amplifier)),
+            };
+            texts.push(
+                ui::TextBuilder::new()
+                    .text(name)
+                    .position(28.0 * SCALE, 6.0 * SCALE)
+                    .create(&mut *background.borrow_mut()),
+            );
+            texts.push(
+                ui::TextBuilder::new()
+                    .text(effect.duration.clone())
+                    .position(28.0 * SCALE, 16.0 * SCALE)
+                    .colour(DURATION_COLOUR)
+                    .create(&mut *background.borrow_mut()),
+            );
+            images.push(background);
+        }
+        Elements {
+            _images: images,
+            _texts: texts,
+        }
+    }
+}
+
+/// Draws an effect's icon at the given position within its background.
+fn icon(background: &ui::ImageRef, u: i32, v: i32, x: f64, y: f64) -> ui::ImageRef {
+    ui::ImageBuilder::new()
+        .texture(TEXTURE)
+        .position(x * SCALE, y * SCALE)
+        .size(ICON_SIZE * SCALE, ICON_SIZE * SCALE)
+        .texture_coords((
+            (u as f64 * ICON_SIZE) / 256.0,
+            (198.0 + v as f64 * ICON_SIZE) / 256.0,
+            ICON_SIZE / 256.0,
+            ICON_SIZE / 256.0,
+        ))
+        .create(&mut *background.borrow_mut())
+}
+
+/// Formats the time left on an effect as minutes and seconds.
+fn format_duration(effect: &Effect) -> String {
+    if effect.is_endless() {
+        return "**:**".to_owned();
+    }
+    let seconds = effect.duration.max(0) / 20;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Vanilla only names the levels it has translations for and falls back
+/// to the plain number after that.
+fn level_numeral(amplifier: u8) -> String {
+    match amplifier {
+        1 => "II".to_owned(),
+        2 => "III".to_owned(),
+        3 => "IV".to_owned(),
+        4 => "V".to_owned(),
+        amplifier => format!("{}", u32::from(amplifier) + 1),
+    }
+}
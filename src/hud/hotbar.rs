@@ -0,0 +1,111 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::inventory;
+use crate::model;
+use crate::screen::inventory::{create_item, ItemElements};
+use crate::server;
+use crate::types::Gamemode;
+use crate::ui;
+use std::sync::{Arc, RwLock};
+
+// Vanilla gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const WIDTH: f64 = 182.0;
+const HEIGHT: f64 = 22.0;
+
+struct Elements {
+    _background: ui::ImageRef,
+    _selection: ui::ImageRef,
+    _slots: Vec<ui::ImageRef>,
+    _items: Vec<ItemElements>,
+}
+
+/// The nine hotbar slots at the bottom of the screen with the selected
+/// one highlighted.
+#[derive(Default)]
+pub struct Hotbar {
+    last_version: Option<usize>,
+    elements: Option<Elements>,
+}
+
+impl Hotbar {
+    pub fn new() -> Hotbar {
+        Default::default()
+    }
+
+    pub fn tick(
+        &mut self,
+        ui_container: &mut ui::Container,
+        server: &server::Server,
+        models: &Arc<RwLock<model::Factory>>,
+    ) {
+        if !server.is_connected() || server.gamemode() == Gamemode::Spectator {
+            self.last_version = None;
+            self.elements = None;
+            return;
+        }
+        let inventory = server.inventory.read().unwrap();
+        if self.last_version == Some(inventory.version()) {
+            return;
+        }
+        self.last_version = Some(inventory.version());
+
+        let background = ui::ImageBuilder::new()
+            .texture("gui/widgets")
+            .size(WIDTH * SCALE, HEIGHT * SCALE)
+            .texture_coords((0.0, 0.0, WIDTH / 256.0, HEIGHT / 256.0))
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+            .create(ui_container);
+        let selection = ui::ImageBuilder::new()
+            .texture("gui/widgets")
+            .position(
+                (inventory.selected_slot() as f64 * 20.0 - 1.0) * SCALE,
+                -1.0 * SCALE,
+            )
+            .size(24.0 * SCALE, 22.0 * SCALE)
+            .texture_coords((0.0, 22.0 / 256.0, 24.0 / 256.0, 22.0 / 256.0))
+            .create(&mut *background.borrow_mut());
+
+        let mut slots = vec![];
+        let mut items = vec![];
+        if let Some(window) = inventory.window(inventory::PLAYER_WINDOW) {
+            for i in 0..9 {
+                let slot = ui::ImageBuilder::new()
+                    .texture("steven:solid")
+                    .position((3.0 + i as f64 * 20.0) * SCALE, 3.0 * SCALE)
+                    .size(16.0 * SCALE, 16.0 * SCALE)
+                    .colour((0, 0, 0, 0))
+                    .create(&mut *background.borrow_mut());
+                if let Some(stack) = window.get(window.hotbar_slot(i)) {
+                    items.push(create_item(
+                        &slot,
+                        models,
+                        inventory.protocol_version(),
+                        stack,
+                        0.0,
+                    ));
+                }
+                slots.push(slot);
+            }
+        }
+
+        self.elements = Some(Elements {
+            _background: background,
+            _selection: selection,
+            _slots: slots,
+            _items: items,
+        });
+    }
+}
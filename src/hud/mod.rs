@@ -0,0 +1,70 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod boss_bar;
+pub mod chat;
+pub mod effects;
+pub mod hotbar;
+pub mod player_list;
+pub mod sidebar;
+pub mod stats;
+pub mod title;
+
+use crate::model;
+use crate::render;
+use crate::server;
+use crate::ui;
+use std::sync::{Arc, RwLock};
+
+/// The in-game overlay drawn on top of the world while playing.
+#[derive(Default)]
+pub struct Hud {
+    pub boss_bars: boss_bar::BossBars,
+    pub chat: chat::Chat,
+    pub effects: effects::Effects,
+    pub hotbar: hotbar::Hotbar,
+    pub player_list: player_list::PlayerList,
+    pub sidebar: sidebar::Sidebar,
+    pub stats: stats::Stats,
+    pub titles: title::Titles,
+}
+
+impl Hud {
+    pub fn new() -> Hud {
+        Default::default()
+    }
+
+    pub fn tick(
+        &mut self,
+        server: &mut server::Server,
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        models: &Arc<RwLock<model::Factory>>,
+    ) {
+        for message in server.received_chat.drain(..) {
+            self.chat.add_message(message);
+        }
+        for action in server.received_titles.drain(..) {
+            self.titles.apply(action);
+        }
+        self.chat.tick(ui_container, renderer, server);
+        self.titles.tick(ui_container, server.is_connected());
+        self.hotbar.tick(ui_container, server, models);
+        self.stats.tick(ui_container, server);
+        self.effects.tick(ui_container, server);
+        self.boss_bars.tick(ui_container, server);
+        self.sidebar.tick(ui_container, renderer, server);
+        self.player_list.tick(ui_container, renderer, server);
+    }
+}
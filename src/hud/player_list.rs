@@ -0,0 +1,305 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::format::{self, Component, TextComponent};
+use crate::render;
+use crate::server;
+use crate::types::Gamemode;
+use crate::ui;
+
+// Vanilla gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const TOP: f64 = 10.0 * SCALE;
+const PADDING: f64 = 1.0 * SCALE;
+const ROW_HEIGHT: f64 = 9.0 * SCALE;
+const HEAD_SIZE: f64 = 8.0 * SCALE;
+const PING_WIDTH: f64 = 10.0 * SCALE;
+const MAX_ROWS: usize = 20;
+
+/// A player as drawn in the list.
+#[derive(Clone, PartialEq)]
+struct Entry {
+    name: Component,
+    skin_url: Option<String>,
+    // Row of the connection icon in gui/icons
+    ping_icon: u8,
+}
+
+#[derive(Clone, PartialEq)]
+struct Shown {
+    header: Option<Component>,
+    footer: Option<Component>,
+    entries: Vec<Entry>,
+}
+
+struct Elements {
+    _background: ui::ImageRef,
+    _images: Vec<ui::ImageRef>,
+    _text: Vec<ui::FormattedRef>,
+    skins: Vec<String>,
+}
+
+/// The list of online players shown while the player list key is
+/// held down.
+#[derive(Default)]
+pub struct PlayerList {
+    visible: bool,
+    shown: Option<Shown>,
+    elements: Option<Elements>,
+}
+
+impl PlayerList {
+    pub fn new() -> PlayerList {
+        Default::default()
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    pub fn tick(
+        &mut self,
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        server: &server::Server,
+    ) {
+        if !self.visible || !server.is_connected() {
+            self.shown = None;
+            self.remove(renderer);
+            return;
+        }
+
+        let shown = Shown::new(server);
+        if self.shown.as_ref() == Some(&shown) {
+            return;
+        }
+        self.remove(renderer);
+        self.elements = Some(PlayerList::build(ui_container, renderer, &shown));
+        self.shown = Some(shown);
+    }
+
+    fn remove(&mut self, renderer: &render::Renderer) {
+        if let Some(elements) = self.elements.take() {
+            let textures = renderer.get_textures_ref().read().unwrap();
+            for url in &elements.skins {
+                textures.release_skin(url);
+            }
+        }
+    }
+
+    fn build(
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        shown: &Shown,
+    ) -> Elements {
+        let (columns, rows) = columns_and_rows(shown.entries.len());
+        let name_width = shown
+            .entries
+            .iter()
+            .map(|entry| ui::Formatted::compute_size(renderer, &entry.name, -1.0).0)
+            .fold(0.0, f64::max);
+        let column_width = HEAD_SIZE + PADDING + name_width + PADDING * 2.0 + PING_WIDTH;
+        let list_width = columns as f64 * column_width + (columns - 1) as f64 * PADDING;
+
+        let size = |text: &Option<Component>| {
+            text.as_ref().map_or((0.0, 0.0), |text| {
+                ui::Formatted::compute_size(renderer, text, -1.0)
+            })
+        };
+        let (header_width, header_height) = size(&shown.header);
+        let (footer_width, footer_height) = size(&shown.footer);
+        let width = list_width.max(header_width).max(footer_width) + PADDING * 2.0;
+        let list_top = PADDING + header_height;
+        let list_height = rows as f64 * ROW_HEIGHT;
+        let height = list_top + list_height + footer_height + PADDING;
+
+        let background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(0.0, TOP)
+            .size(width, height)
+            .colour((0, 0, 0, 128))
+            .alignment(ui::VAttach::Top, ui::HAttach::Center)
+            .create(ui_container);
+        let mut images = vec![];
+        let mut text = vec![];
+        let mut skins = vec![];
+
+        for (component, y) in [
+            (&shown.header, PADDING),
+            (&shown.footer, list_top + list_height),
+        ] {
+            if let Some(component) = component {
+                text.push(
+                    ui::FormattedBuilder::new()
+                        .text(component.clone())
+                        .position(0.0, y)
+                        .alignment(ui::VAttach::Top, ui::HAttach::Center)
+                        .create(&mut *background.borrow_mut()),
+                );
+            }
+        }
+
+        let left = (width - list_width) / 2.0;
+        for (i, entry) in shown.entries.iter().enumerate() {
+            let row = ui::ImageBuilder::new()
+                .texture("steven:solid")
+                .position(
+                    left + (i / rows) as f64 * (column_width + PADDING),
+                    list_top + (i % rows) as f64 * ROW_HEIGHT,
+                )
+                .size(column_width, ROW_HEIGHT - PADDING)
+                .colour((255, 255, 255, 32))
+                .create(&mut *background.borrow_mut());
+
+            let skin = match entry.skin_url.as_ref() {
+                Some(url) => {
+                    skins.push(url.clone());
+                    renderer.get_skin(renderer.get_textures_ref(), url).name
+                }
+                None => "entity/steve".to_owned(),
+            };
+            // The face, then the hat layer over it
+            for u in [8.0, 40.0] {
+                images.push(
+                    ui::ImageBuilder::new()
+                        .texture(skin.clone())
+                        .size(HEAD_SIZE, HEAD_SIZE)
+                        .texture_coords((u / 64.0, 8.0 / 64.0, 8.0 / 64.0, 8.0 / 64.0))
+                        .create(&mut *row.borrow_mut()),
+                );
+            }
+            text.push(
+                ui::FormattedBuilder::new()
+                    .text(entry.name.clone())
+                    .position(HEAD_SIZE + PADDING, 0.0)
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Left)
+                    .create(&mut *row.borrow_mut()),
+            );
+            images.push(
+                ui::ImageBuilder::new()
+                    .texture("gui/icons")
+                    .size(PING_WIDTH, 8.0 * SCALE)
+                    .texture_coords((
+                        0.0,
+                        (176.0 + entry.ping_icon as f64 * 8.0) / 256.0,
+                        10.0 / 256.0,
+                        8.0 / 256.0,
+                    ))
+                    .alignment(ui::VAttach::Top, ui::HAttach::Right)
+                    .create(&mut *row.borrow_mut()),
+            );
+            images.push(row);
+        }
+
+        Elements {
+            _background: background,
+            _images: images,
+            _text: text,
+            skins,
+        }
+    }
+}
+
+impl Shown {
+    fn new(server: &server::Server) -> Shown {
+        let scoreboard = &server.scoreboard;
+        let mut players: Vec<_> = server.players.values().collect();
+        // Spectators are listed after everyone else, then players are
+        // grouped by team
+        players.sort_by_cached_key(|info| {
+            (
+                info.gamemode == Gamemode::Spectator,
+                scoreboard.team_name(&info.name).map(|name| name.to_owned()),
+                info.name.to_lowercase(),
+            )
+        });
+        let entries = players
+            .into_iter()
+            .map(|info| {
+                let mut name = info
+                    .display_name
+                    .clone()
+                    .unwrap_or_else(|| scoreboard.format_name(&info.name));
+                if info.gamemode == Gamemode::Spectator {
+                    let mut greyed = TextComponent::new("");
+                    greyed.modifier.color = Some(format::Color::Gray);
+                    greyed.modifier.italic = Some(true);
+                    greyed.modifier.extra = Some(vec![name]);
+                    name = Component::Text(greyed);
+                }
+                Entry {
+                    name,
+                    skin_url: info.skin_url.clone(),
+                    ping_icon: ping_icon(info.ping),
+                }
+            })
+            .collect();
+        Shown {
+            header: server.player_list_header.clone(),
+            footer: server.player_list_footer.clone(),
+            entries,
+        }
+    }
+}
+
+/// Splits the players into as few columns as possible while keeping
+/// each column at most MAX_ROWS long.
+fn columns_and_rows(count: usize) -> (usize, usize) {
+    let mut columns = 1;
+    let mut rows = count;
+    while rows > MAX_ROWS {
+        columns += 1;
+        rows = (count + columns - 1) / columns;
+    }
+    (columns, rows)
+}
+
+/// Returns how many bars to take away from the connection icon, the
+/// last row being the crossed out icon for unknown latency.
+fn ping_icon(ping: i32) -> u8 {
+    match ping {
+        p if p < 0 => 5,
+        p if p < 150 => 0,
+        p if p < 300 => 1,
+        p if p < 600 => 2,
+        p if p < 1000 => 3,
+        _ => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns() {
+        assert_eq!(columns_and_rows(0), (1, 0));
+        assert_eq!(columns_and_rows(20), (1, 20));
+        assert_eq!(columns_and_rows(21), (2, 11));
+        assert_eq!(columns_and_rows(40), (2, 20));
+        assert_eq!(columns_and_rows(41), (3, 14));
+        assert_eq!(columns_and_rows(100), (5, 20));
+    }
+
+    #[test]
+    fn ping_bars() {
+        assert_eq!(ping_icon(-1), 5);
+        assert_eq!(ping_icon(0), 0);
+        assert_eq!(ping_icon(149), 0);
+        assert_eq!(ping_icon(150), 1);
+        assert_eq!(ping_icon(450), 2);
+        assert_eq!(ping_icon(999), 3);
+        assert_eq!(ping_icon(1000), 4);
+    }
+}
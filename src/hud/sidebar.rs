@@ -0,0 +1,156 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::format::Component;
+use crate::render;
+use crate::server;
+use crate::ui;
+
+const LINE_HEIGHT: f64 = 18.0;
+const PADDING: f64 = 2.0;
+const SCORE_COLOUR: (u8, u8, u8, u8) = (255, 85, 85, 255);
+
+#[derive(Clone, PartialEq)]
+struct Shown {
+    title: Component,
+    scores: Vec<(Component, i32)>,
+}
+
+struct Elements {
+    _container: ui::ImageRef,
+    _rows: Vec<ui::ImageRef>,
+    _text: Vec<ui::FormattedRef>,
+    _scores: Vec<ui::TextRef>,
+}
+
+/// The scoreboard objective displayed on the right side of the screen.
+#[derive(Default)]
+pub struct Sidebar {
+    shown: Option<Shown>,
+    elements: Option<Elements>,
+}
+
+impl Sidebar {
+    pub fn new() -> Sidebar {
+        Default::default()
+    }
+
+    pub fn tick(
+        &mut self,
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        server: &server::Server,
+    ) {
+        let scoreboard = &server.scoreboard;
+        let objective = server
+            .player_name()
+            .and_then(|name| scoreboard.sidebar(name))
+            .filter(|_| server.is_connected());
+        let objective = match objective {
+            Some(objective) => objective,
+            None => {
+                self.shown = None;
+                self.elements = None;
+                return;
+            }
+        };
+
+        let shown = Shown {
+            title: objective.display_name.clone(),
+            scores: objective
+                .sidebar_scores()
+                .into_iter()
+                .map(|(entry, score)| (scoreboard.format_name(entry), score))
+                .collect(),
+        };
+        if self.shown.as_ref() == Some(&shown) {
+            return;
+        }
+        self.elements = Some(Sidebar::build(ui_container, renderer, &shown));
+        self.shown = Some(shown);
+    }
+
+    fn build(
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        shown: &Shown,
+    ) -> Elements {
+        let separator = renderer.ui.size_of_string(": ");
+        let mut width = ui::Formatted::compute_size(renderer, &shown.title, -1.0).0;
+        for (name, score) in &shown.scores {
+            let name = ui::Formatted::compute_size(renderer, name, -1.0).0;
+            let score = renderer.ui.size_of_string(&format!("{}", score));
+            width = width.max(name + separator + score);
+        }
+        width += PADDING * 2.0;
+        let height = (shown.scores.len() + 1) as f64 * LINE_HEIGHT;
+
+        // Centered a little above the middle of the screen, as vanilla does
+        let container = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(PADDING, -height / 6.0)
+            .size(width, height)
+            .colour((0, 0, 0, 0))
+            .alignment(ui::VAttach::Middle, ui::HAttach::Right)
+            .create(ui_container);
+        let mut rows = vec![];
+        let mut text = vec![];
+        let mut scores = vec![];
+
+        let title = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .size(width, LINE_HEIGHT)
+            .colour((0, 0, 0, 102))
+            .create(&mut *container.borrow_mut());
+        text.push(
+            ui::FormattedBuilder::new()
+                .text(shown.title.clone())
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .create(&mut *title.borrow_mut()),
+        );
+        rows.push(title);
+
+        for (i, (name, score)) in shown.scores.iter().enumerate() {
+            let row = ui::ImageBuilder::new()
+                .texture("steven:solid")
+                .position(0.0, (i + 1) as f64 * LINE_HEIGHT)
+                .size(width, LINE_HEIGHT)
+                .colour((0, 0, 0, 80))
+                .create(&mut *container.borrow_mut());
+            text.push(
+                ui::FormattedBuilder::new()
+                    .text(name.clone())
+                    .position(PADDING, 0.0)
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Left)
+                    .create(&mut *row.borrow_mut()),
+            );
+            scores.push(
+                ui::TextBuilder::new()
+                    .text(format!("{}", score))
+                    .position(PADDING, 0.0)
+                    .colour(SCORE_COLOUR)
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Right)
+                    .create(&mut *row.borrow_mut()),
+            );
+            rows.push(row);
+        }
+
+        Elements {
+            _container: container,
+            _rows: rows,
+            _text: text,
+            _scores: scores,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::server;
+use crate::types::Gamemode;
+use crate::ui;
+
+// Vanilla gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const WIDTH: f64 = 182.0;
+const HEIGHT: f64 = 100.0;
+const ICON_SIZE: f64 = 9.0;
+// Distances from the bottom of the screen, leaving room for the hotbar
+const XP_BAR_BOTTOM: f64 = 24.0;
+const LEVEL_BOTTOM: f64 = 27.0;
+const BARS_BOTTOM: f64 = 30.0;
+
+const LEVEL_COLOUR: (u8, u8, u8, u8) = (128, 255, 32, 255);
+
+/// The values currently drawn, rounded to what the icons can show so
+/// the elements are only rebuilt when they would look different.
+#[derive(Clone, PartialEq)]
+struct Shown {
+    health: i32,
+    max_health: i32,
+    absorption: i32,
+    food: i32,
+    armor: i32,
+    experience: i32,
+    level: i32,
+}
+
+struct Elements {
+    _container: ui::ImageRef,
+    _icons: Vec<ui::ImageRef>,
+    _level: Vec<ui::TextRef>,
+}
+
+/// Health, food, armor and experience shown above the hotbar while
+/// playing in survival or adventure mode.
+#[derive(Default)]
+pub struct Stats {
+    shown: Option<Shown>,
+    elements: Option<Elements>,
+}
+
+impl Stats {
+    pub fn new() -> Stats {
+        Default::default()
+    }
+
+    pub fn tick(&mut self, ui_container: &mut ui::Container, server: &server::Server) {
+        let visible = server.is_connected()
+            && server.player.is_some()
+            && matches!(server.gamemode(), Gamemode::Survival | Gamemode::Adventure);
+        if !visible {
+            self.shown = None;
+            self.elements = None;
+            return;
+        }
+
+        let status = &server.status;
+        let shown = Shown {
+            health: status.health.max(0.0).ceil() as i32,
+            max_health: status.max_health.max(1.0).ceil() as i32,
+            absorption: status.absorption.max(0.0).ceil() as i32,
+            food: status.food,
+            armor: server.armor_points(),
+            experience: (status.experience_bar.clamp(0.0, 1.0) * (WIDTH + 1.0)) as i32,
+            level: status.level,
+        };
+        if self.shown.as_ref() == Some(&shown) {
+            return;
+        }
+        self.elements = Some(Stats::build(ui_container, &shown));
+        self.shown = Some(shown);
+    }
+
+    fn build(ui_container: &mut ui::Container, shown: &Shown) -> Elements {
+        let container = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .size(WIDTH * SCALE, HEIGHT * SCALE)
+            .colour((0, 0, 0, 0))
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+            .create(ui_container);
+        let mut icons = vec![];
+        let mut icon = |u: f64, v: f64, width: f64, x: f64, bottom: f64| {
+            let image = ui::ImageBuilder::new()
+                .texture("gui/icons")
+                .position(x * SCALE, bottom * SCALE)
+                .size(width * SCALE, ICON_SIZE * SCALE)
+                .texture_coords((u / 256.0, v / 256.0, width / 256.0, ICON_SIZE / 256.0))
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+                .create(&mut *container.borrow_mut());
+            icons.push(image);
+        };
+
+        // Hearts fill rows of ten from the bottom, with extra hearts from
+        // absorption after the normal ones. Rows overlap when there are
+        // many of them.
+        let health_hearts = (shown.max_health + 1) / 2;
+        let hearts = health_hearts + (shown.absorption + 1) / 2;
+        let rows = (hearts + 9) / 10;
+        let row_height = (10 - (rows - 2)).clamp(3, 10) as f64;
+        for i in 0..hearts {
+            let x = (i % 10) as f64 * 8.0;
+            let bottom = BARS_BOTTOM + (i / 10) as f64 * row_height;
+            icon(16.0, 0.0, ICON_SIZE, x, bottom);
+            let u = if i < health_hearts {
+                match shown.health - i * 2 {
+                    v if v >= 2 => Some(52.0),
+                    1 => Some(61.0),
+                    _ => None,
+                }
+            } else {
+                match shown.absorption - (i - health_hearts) * 2 {
+                    v if v >= 2 => Some(160.0),
+                    1 => Some(169.0),
+                    _ => None,
+                }
+            };
+            if let Some(u) = u {
+                icon(u, 0.0, ICON_SIZE, x, bottom);
+            }
+        }
+
+        if shown.armor > 0 {
+            let bottom = BARS_BOTTOM + (rows - 1) as f64 * row_height + 10.0;
+            for i in 0..10 {
+                let u = match shown.armor - i * 2 {
+                    v if v >= 2 => 34.0,
+                    1 => 25.0,
+                    _ => 16.0,
+                };
+                icon(u, 9.0, ICON_SIZE, i as f64 * 8.0, bottom);
+            }
+        }
+
+        // Food is drawn right to left
+        for i in 0..10 {
+            let x = WIDTH - i as f64 * 8.0 - ICON_SIZE;
+            icon(16.0, 27.0, ICON_SIZE, x, BARS_BOTTOM);
+            match shown.food - i * 2 {
+                v if v >= 2 => icon(52.0, 27.0, ICON_SIZE, x, BARS_BOTTOM),
+                1 => icon(61.0, 27.0, ICON_SIZE, x, BARS_BOTTOM),
+                _ => {}
+            }
+        }
+
+        for (v, width) in [(64.0, WIDTH), (69.0, shown.experience as f64)] {
+            let image = ui::ImageBuilder::new()
+                .texture("gui/icons")
+                .position(0.0, XP_BAR_BOTTOM * SCALE)
+                .size(width * SCALE, 5.0 * SCALE)
+                .texture_coords((0.0, v / 256.0, width / 256.0, 5.0 / 256.0))
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+                .create(&mut *container.borrow_mut());
+            icons.push(image);
+        }
+
+        // The level number is outlined by drawing it in black offset in
+        // each direction first
+        let mut level = vec![];
+        if shown.level > 0 {
+            let text = format!("{}", shown.level);
+            for &(x, y, colour) in &[
+                (1.0, 0.0, (0, 0, 0, 255)),
+                (-1.0, 0.0, (0, 0, 0, 255)),
+                (0.0, 1.0, (0, 0, 0, 255)),
+                (0.0, -1.0, (0, 0, 0, 255)),
+                (0.0, 0.0, LEVEL_COLOUR),
+            ] {
+                level.push(
+                    ui::TextBuilder::new()
+                        .text(text.clone())
+                        .position(x * SCALE, (LEVEL_BOTTOM + y) * SCALE)
+                        .colour(colour)
+                        .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+                        .create(&mut *container.borrow_mut()),
+                );
+            }
+        }
+
+        Elements {
+            _container: container,
+            _icons: icons,
+            _level: level,
+        }
+    }
+}
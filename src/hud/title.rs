@@ -0,0 +1,263 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::format::Component;
+use crate::server::TitleAction;
+use crate::ui;
+use instant::Instant;
+
+// Vanilla gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const TITLE_SCALE: f64 = 4.0;
+const SUBTITLE_SCALE: f64 = 2.0;
+// Offsets from the middle of the screen
+const TITLE_Y: f64 = -20.0 * SCALE;
+const SUBTITLE_Y: f64 = 10.0 * SCALE;
+// Offset from the bottom of the screen, just above the hotbar
+const ACTION_BAR_Y: f64 = 63.0 * SCALE;
+
+const TICK: f64 = 1.0 / 20.0;
+// Fade in, stay and fade out times in ticks used until the server
+// sends its own
+const DEFAULT_TIMES: (i32, i32, i32) = (10, 70, 20);
+// Seconds the action bar is shown for, the last of which it fades out
+const ACTION_BAR_TIME: f64 = 3.0;
+const ACTION_BAR_FADE: f64 = 1.0;
+
+struct Elements {
+    title: ui::FormattedRef,
+    subtitle: Option<ui::FormattedRef>,
+}
+
+/// The title and subtitle shown in the middle of the screen and the
+/// action bar message above the hotbar.
+pub struct Titles {
+    title: Option<Component>,
+    subtitle: Option<Component>,
+    times: (i32, i32, i32),
+    shown_at: Option<Instant>,
+    action_bar: Option<(Component, Instant)>,
+    dirty: bool,
+
+    elements: Option<Elements>,
+    action_bar_text: Option<ui::FormattedRef>,
+}
+
+impl Default for Titles {
+    fn default() -> Titles {
+        Titles {
+            title: None,
+            subtitle: None,
+            times: DEFAULT_TIMES,
+            shown_at: None,
+            action_bar: None,
+            dirty: false,
+            elements: None,
+            action_bar_text: None,
+        }
+    }
+}
+
+impl Titles {
+    pub fn new() -> Titles {
+        Default::default()
+    }
+
+    pub fn apply(&mut self, action: TitleAction) {
+        match action {
+            TitleAction::Title(title) => {
+                // Only a new title starts the fade, subtitles wait for it
+                self.title = Some(title);
+                self.shown_at = Some(Instant::now());
+                self.dirty = true;
+            }
+            TitleAction::Subtitle(subtitle) => {
+                self.subtitle = Some(subtitle);
+                self.dirty = true;
+            }
+            TitleAction::ActionBar(text) => {
+                self.action_bar = Some((text, Instant::now()));
+                self.action_bar_text = None;
+            }
+            TitleAction::Times(fade_in, stay, fade_out) => {
+                self.times = (fade_in, stay, fade_out);
+            }
+            TitleAction::Clear => {
+                self.shown_at = None;
+            }
+            TitleAction::Reset => {
+                *self = Titles {
+                    action_bar: self.action_bar.take(),
+                    action_bar_text: self.action_bar_text.take(),
+                    ..Default::default()
+                };
+            }
+        }
+    }
+
+    pub fn tick(&mut self, ui_container: &mut ui::Container, connected: bool) {
+        if !connected {
+            *self = Default::default();
+            return;
+        }
+        let now = Instant::now();
+        self.tick_title(ui_container, now);
+        self.tick_action_bar(ui_container, now);
+    }
+
+    fn tick_title(&mut self, ui_container: &mut ui::Container, now: Instant) {
+        let alpha = match self.shown_at {
+            Some(shown_at) => {
+                let age = now.duration_since(shown_at).as_secs_f64() / TICK;
+                title_alpha(age, self.times)
+            }
+            None => None,
+        };
+        let alpha = match alpha {
+            Some(alpha) => alpha,
+            None => {
+                self.shown_at = None;
+                self.elements = None;
+                return;
+            }
+        };
+
+        if self.dirty || self.elements.is_none() {
+            self.dirty = false;
+            let title = ui::FormattedBuilder::new()
+                .text(self.title.clone().unwrap_or_default())
+                .position(0.0, TITLE_Y)
+                .scale_x(TITLE_SCALE)
+                .scale_y(TITLE_SCALE)
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .create(ui_container);
+            let subtitle = self.subtitle.as_ref().map(|subtitle| {
+                ui::FormattedBuilder::new()
+                    .text(subtitle.clone())
+                    .position(0.0, SUBTITLE_Y)
+                    .scale_x(SUBTITLE_SCALE)
+                    .scale_y(SUBTITLE_SCALE)
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                    .create(ui_container)
+            });
+            self.elements = Some(Elements { title, subtitle });
+        }
+
+        let elements = self.elements.as_ref().unwrap();
+        let alpha = (255.0 * alpha) as u8;
+        elements.title.borrow_mut().alpha = alpha;
+        if let Some(subtitle) = &elements.subtitle {
+            subtitle.borrow_mut().alpha = alpha;
+        }
+    }
+
+    fn tick_action_bar(&mut self, ui_container: &mut ui::Container, now: Instant) {
+        let (text, received_at) = match &self.action_bar {
+            Some(action_bar) => action_bar,
+            None => return,
+        };
+        let age = now.duration_since(*received_at).as_secs_f64();
+        if age >= ACTION_BAR_TIME {
+            self.action_bar = None;
+            self.action_bar_text = None;
+            return;
+        }
+        let text = self.action_bar_text.get_or_insert_with(|| {
+            ui::FormattedBuilder::new()
+                .text(text.clone())
+                .position(0.0, ACTION_BAR_Y)
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+                .create(ui_container)
+        });
+        let fade = ((ACTION_BAR_TIME - age) / ACTION_BAR_FADE).min(1.0);
+        text.borrow_mut().alpha = (255.0 * fade) as u8;
+    }
+}
+
+/// Returns how opaque the title is the given number of ticks after it
+/// was shown, or `None` once it has faded out.
+fn title_alpha(age: f64, (fade_in, stay, fade_out): (i32, i32, i32)) -> Option<f64> {
+    let (fade_in, stay, fade_out) = (fade_in as f64, stay as f64, fade_out as f64);
+    if age < fade_in {
+        Some(age / fade_in)
+    } else if age < fade_in + stay {
+        Some(1.0)
+    } else if age < fade_in + stay + fade_out {
+        Some(1.0 - (age - fade_in - stay) / fade_out)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::TextComponent;
+
+    fn text(text: &str) -> Component {
+        Component::Text(TextComponent::new(text))
+    }
+
+    #[test]
+    fn fades() {
+        let times = (10, 70, 20);
+        assert_eq!(title_alpha(0.0, times), Some(0.0));
+        assert_eq!(title_alpha(5.0, times), Some(0.5));
+        assert_eq!(title_alpha(10.0, times), Some(1.0));
+        assert_eq!(title_alpha(79.0, times), Some(1.0));
+        assert_eq!(title_alpha(85.0, times), Some(0.75));
+        assert_eq!(title_alpha(100.0, times), None);
+    }
+
+    #[test]
+    fn no_fade() {
+        let times = (0, 20, 0);
+        assert_eq!(title_alpha(0.0, times), Some(1.0));
+        assert_eq!(title_alpha(19.5, times), Some(1.0));
+        assert_eq!(title_alpha(20.0, times), None);
+    }
+
+    #[test]
+    fn subtitles_wait_for_title() {
+        let mut titles = Titles::new();
+        titles.apply(TitleAction::Subtitle(text("subtitle")));
+        assert!(titles.shown_at.is_none());
+        titles.apply(TitleAction::Title(text("title")));
+        assert!(titles.shown_at.is_some());
+        assert_eq!(titles.subtitle, Some(text("subtitle")));
+    }
+
+    #[test]
+    fn clear_and_reset() {
+        let mut titles = Titles::new();
+        titles.apply(TitleAction::Times(5, 10, 5));
+        titles.apply(TitleAction::Title(text("title")));
+        titles.apply(TitleAction::ActionBar(text("action bar")));
+
+        // Clearing hides the title but keeps it and its times
+        titles.apply(TitleAction::Clear);
+        assert!(titles.shown_at.is_none());
+        assert_eq!(titles.title, Some(text("title")));
+        assert_eq!(titles.times, (5, 10, 5));
+
+        // Resetting also forgets them, but not the action bar
+        titles.apply(TitleAction::Reset);
+        assert_eq!(titles.title, None);
+        assert_eq!(titles.times, DEFAULT_TIMES);
+        assert_eq!(
+            titles.action_bar.map(|(message, _)| message),
+            Some(text("action bar"))
+        );
+    }
+}
@@ -0,0 +1,998 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::format::Component;
+use crate::item::Stack;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// The id of the player's own inventory window, always open server side.
+pub const PLAYER_WINDOW: u8 = 0;
+/// The slot number used for clicks outside of the window.
+pub const OUTSIDE_SLOT: i16 = -999;
+
+// WindowSetSlot uses window -1 slot -1 to set the item held by the cursor
+// and (1.9+) window -2 to set a slot of the player's inventory. Window -2
+// numbers its slots differently to the player's window, see
+// player_inventory_slot.
+const CURSOR_WINDOW: u8 = 0xFF;
+const PLAYER_INVENTORY_WINDOW: u8 = 0xFE;
+
+// The client doesn't know the max stack size of every item, if this
+// guesses wrong the server will correct it when it rejects the click.
+const MAX_STACK_SIZE: isize = 64;
+
+// Number of slots of the player's inventory shown at the bottom of every
+// window, main inventory followed by the hotbar.
+const PLAYER_SLOTS: usize = 36;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClickMode {
+    Pickup,
+    QuickMove,
+    Swap,
+    Drag,
+}
+
+impl ClickMode {
+    pub fn id(self) -> u8 {
+        match self {
+            ClickMode::Pickup => 0,
+            ClickMode::QuickMove => 1,
+            ClickMode::Swap => 2,
+            ClickMode::Drag => 5,
+        }
+    }
+}
+
+/// A click predicted on the client, ready to be sent to the server.
+#[derive(Debug)]
+pub struct Click {
+    pub window_id: u8,
+    pub slot: i16,
+    pub button: u8,
+    pub mode: ClickMode,
+    pub action_number: i16,
+    pub state_id: i32,
+    /// The contents of the slot before the click, used by pre-1.17 servers
+    /// to check the prediction.
+    pub clicked_item: Option<Stack>,
+    /// Slots changed by the click and the item left on the cursor, used
+    /// by 1.17+ servers to check the prediction.
+    pub changed: Vec<(i16, Option<Stack>)>,
+    pub carried: Option<Stack>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowKind {
+    Player,
+    /// A grid of slots nine wide with the given number of rows
+    Chest(u8),
+    CraftingTable,
+    Furnace,
+    Dispenser,
+    Hopper,
+    BrewingStand,
+    /// Windows without their own layout, shown as a grid of their slots
+    Other,
+}
+
+impl WindowKind {
+    /// Parses the window type sent by 1.8 to 1.13.2 servers, returning it
+    /// with the number of slots of the container.
+    pub fn from_name(name: &str, slot_count: usize) -> (WindowKind, usize) {
+        let name = name.trim_start_matches("minecraft:");
+        match name {
+            "chest" | "container" | "shulker_box" if slot_count > 0 && slot_count % 9 == 0 => {
+                (WindowKind::Chest((slot_count / 9) as u8), slot_count)
+            }
+            // Windows without storage of their own are sent without slots
+            "crafting_table" => (WindowKind::CraftingTable, 10),
+            "furnace" => (WindowKind::Furnace, 3),
+            "dispenser" | "dropper" => (WindowKind::Dispenser, 9),
+            "hopper" => (WindowKind::Hopper, 5),
+            // Before 1.9 brewing stands had no fuel slot
+            "brewing_stand" => (WindowKind::BrewingStand, slot_count.max(4)),
+            "enchanting_table" => (WindowKind::Other, 2),
+            "anvil" | "villager" => (WindowKind::Other, 3),
+            _ => (WindowKind::Other, slot_count),
+        }
+    }
+
+    /// Parses the window type sent by 1.7 servers.
+    pub fn from_legacy_id(id: u8, slot_count: usize) -> (WindowKind, usize) {
+        match id {
+            0 if slot_count > 0 && slot_count % 9 == 0 => {
+                (WindowKind::Chest((slot_count / 9) as u8), slot_count)
+            }
+            1 => (WindowKind::CraftingTable, 10),
+            2 => (WindowKind::Furnace, 3),
+            3 | 10 => (WindowKind::Dispenser, 9),
+            5 => (WindowKind::BrewingStand, 4),
+            9 => (WindowKind::Hopper, 5),
+            _ => (WindowKind::Other, slot_count),
+        }
+    }
+
+    /// Parses the window type registry id sent by 1.14+ servers, which
+    /// no longer send the number of slots.
+    pub fn from_registry_id(id: i32, protocol_version: i32) -> (WindowKind, usize) {
+        // 1.16 inserted the smithing table before the smoker
+        let id = if protocol_version < 735 && id >= 20 {
+            id + 1
+        } else {
+            id
+        };
+        match id {
+            0..=5 => (WindowKind::Chest(id as u8 + 1), (id as usize + 1) * 9),
+            6 => (WindowKind::Dispenser, 9),
+            // Blast furnace and smoker share the furnace's layout
+            9 | 13 | 21 => (WindowKind::Furnace, 3),
+            10 => (WindowKind::BrewingStand, 5),
+            11 => (WindowKind::CraftingTable, 10),
+            15 => (WindowKind::Hopper, 5),
+            19 => (WindowKind::Chest(3), 27),
+            8 | 16 => (WindowKind::Other, 1),
+            12 | 23 => (WindowKind::Other, 2),
+            17 => (WindowKind::Other, 4),
+            _ => (WindowKind::Other, 3),
+        }
+    }
+
+    fn result_slot(self) -> Option<usize> {
+        match self {
+            WindowKind::Player | WindowKind::CraftingTable => Some(0),
+            WindowKind::Furnace => Some(2),
+            _ => None,
+        }
+    }
+}
+
+pub struct Window {
+    pub id: u8,
+    pub kind: WindowKind,
+    pub title: Option<Component>,
+    pub slots: Vec<Option<Stack>>,
+    properties: HashMap<i16, i16>,
+}
+
+impl Window {
+    /// Creates a window with the given number of container slots
+    /// followed by the player's inventory.
+    pub fn new(id: u8, kind: WindowKind, container_slots: usize) -> Window {
+        Window {
+            id,
+            kind,
+            title: None,
+            slots: vec![None; container_slots + PLAYER_SLOTS],
+            properties: HashMap::new(),
+        }
+    }
+
+    pub fn player(protocol_version: i32) -> Window {
+        // 1.9 added the off hand slot at the end
+        let size = if protocol_version >= 107 { 46 } else { 45 };
+        Window {
+            id: PLAYER_WINDOW,
+            kind: WindowKind::Player,
+            title: None,
+            slots: vec![None; size],
+            properties: HashMap::new(),
+        }
+    }
+
+    /// Returns the value of a property of the window such as the
+    /// progress of a furnace, zero if the server hasn't sent it.
+    pub fn property(&self, property: i16) -> i16 {
+        self.properties.get(&property).cloned().unwrap_or(0)
+    }
+
+    pub fn get(&self, slot: i16) -> Option<&Stack> {
+        if slot < 0 {
+            return None;
+        }
+        self.slots.get(slot as usize).and_then(|v| v.as_ref())
+    }
+
+    fn set(&mut self, slot: i16, stack: Option<Stack>) {
+        if slot < 0 {
+            return;
+        }
+        if let Some(v) = self.slots.get_mut(slot as usize) {
+            *v = stack.filter(|v| v.count > 0);
+        }
+    }
+
+    /// Returns the index of the first slot of the player's inventory
+    /// section of the window.
+    pub fn player_start(&self) -> usize {
+        if self.kind == WindowKind::Player {
+            9
+        } else {
+            self.slots.len().saturating_sub(PLAYER_SLOTS)
+        }
+    }
+
+    pub fn hotbar_slot(&self, index: u8) -> i16 {
+        (self.player_start() + 27 + index as usize) as i16
+    }
+
+    fn is_result(&self, slot: i16) -> bool {
+        self.kind.result_slot().map_or(false, |v| v as i16 == slot)
+    }
+
+    /// Returns the slots a shift-clicked stack is moved into, in the
+    /// order they are tried.
+    fn quick_move_targets(&self, slot: usize) -> Range<usize> {
+        let start = self.player_start();
+        let hotbar = start + 27;
+        let end = (start + PLAYER_SLOTS).min(self.slots.len());
+        if slot < start {
+            start..end
+        } else if self.kind != WindowKind::Player {
+            0..start
+        } else if slot < hotbar {
+            hotbar..end
+        } else if slot < end {
+            start..hotbar
+        } else {
+            // Off hand
+            start..end
+        }
+    }
+}
+
+struct Drag {
+    button: u8,
+    slots: Vec<i16>,
+}
+
+pub struct Inventory {
+    protocol_version: i32,
+    windows: HashMap<u8, Window>,
+    pub cursor: Option<Stack>,
+    open: Option<u8>,
+    opened: Option<(u8, bool)>,
+    closed: Option<u8>,
+    closed_by_server: Option<u8>,
+    action_number: i16,
+    state_id: i32,
+
+    hovered: Option<i16>,
+    over_window: bool,
+    drag: Option<Drag>,
+    selected_slot: u8,
+    version: usize,
+}
+
+impl Inventory {
+    pub fn new(protocol_version: i32) -> Inventory {
+        let mut windows = HashMap::new();
+        windows.insert(PLAYER_WINDOW, Window::player(protocol_version));
+        Inventory {
+            protocol_version,
+            windows,
+            cursor: None,
+            open: None,
+            opened: None,
+            closed: None,
+            closed_by_server: None,
+            action_number: 0,
+            state_id: 0,
+            hovered: None,
+            over_window: false,
+            drag: None,
+            selected_slot: 0,
+            version: 0,
+        }
+    }
+
+    /// Incremented on every change, used by the ui to know when to redraw.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// The protocol version item ids in this inventory are from.
+    pub fn protocol_version(&self) -> i32 {
+        self.protocol_version
+    }
+
+    /// Returns the index of the selected hotbar slot.
+    pub fn selected_slot(&self) -> u8 {
+        self.selected_slot
+    }
+
+    /// Selects a hotbar slot, returning whether the selection changed.
+    pub fn select_slot(&mut self, index: u8) -> bool {
+        if index >= 9 || index == self.selected_slot {
+            return false;
+        }
+        self.selected_slot = index;
+        self.version += 1;
+        true
+    }
+
+    /// Returns the item in the selected hotbar slot.
+    pub fn held_item(&self) -> Option<&Stack> {
+        let window = self.windows.get(&PLAYER_WINDOW)?;
+        window.get(window.hotbar_slot(self.selected_slot))
+    }
+
+    pub fn window(&self, id: u8) -> Option<&Window> {
+        self.windows.get(&id)
+    }
+
+    pub fn open_window(&self) -> Option<u8> {
+        self.open
+    }
+
+    pub fn open(&mut self, id: u8) {
+        self.open = Some(id);
+        self.hovered = None;
+        self.over_window = false;
+        self.drag = None;
+        self.version += 1;
+    }
+
+    /// Closes the window if it is still open, the server is told about
+    /// it on the next tick.
+    pub fn close(&mut self, id: u8) {
+        if self.open == Some(id) {
+            self.remove_open();
+            self.closed = Some(id);
+        }
+    }
+
+    fn remove_open(&mut self) {
+        if let Some(id) = self.open.take() {
+            if id != PLAYER_WINDOW {
+                self.windows.remove(&id);
+            }
+            // The server drops the held item when the window closes
+            self.cursor = None;
+            self.drag = None;
+            self.version += 1;
+        }
+    }
+
+    pub fn take_closed(&mut self) -> Option<u8> {
+        self.closed.take()
+    }
+
+    /// Opens a container window requested by the server, replacing any
+    /// window already open.
+    pub fn open_container(
+        &mut self,
+        id: u8,
+        kind: WindowKind,
+        container_slots: usize,
+        title: Option<Component>,
+    ) {
+        let replaced = self.open.is_some();
+        self.remove_open();
+        let mut window = Window::new(id, kind, container_slots);
+        window.title = title;
+        self.windows.insert(id, window);
+        self.open(id);
+        self.opened = Some((id, replaced));
+    }
+
+    /// Returns the window the server opened since the last call and
+    /// whether it replaced a window that was already open.
+    pub fn take_opened(&mut self) -> Option<(u8, bool)> {
+        self.opened.take()
+    }
+
+    /// Closes the window without telling the server, used when the
+    /// server closes it itself.
+    pub fn close_from_server(&mut self, id: u8) {
+        if self.open == Some(id) {
+            self.remove_open();
+            self.closed_by_server = Some(id);
+        }
+    }
+
+    /// Returns the window the server closed since the last call.
+    pub fn take_closed_by_server(&mut self) -> Option<u8> {
+        self.closed_by_server.take()
+    }
+
+    pub fn set_property(&mut self, id: u8, property: i16, value: i16) {
+        if let Some(window) = self.windows.get_mut(&id) {
+            window.properties.insert(property, value);
+            self.version += 1;
+        }
+    }
+
+    /// Returns how much fuel is left and how far the current item has
+    /// cooked in a furnace window, both between 0 and 1.
+    pub fn furnace_progress(&self, id: u8) -> (f64, f64) {
+        let window = match self.windows.get(&id) {
+            Some(window) => window,
+            None => return (0.0, 0.0),
+        };
+        let ratio = |value: i16, max: i16| {
+            if max <= 0 {
+                0.0
+            } else {
+                (value as f64 / max as f64).clamp(0.0, 1.0)
+            }
+        };
+        if self.protocol_version >= 47 {
+            (
+                ratio(window.property(0), window.property(1)),
+                ratio(window.property(2), window.property(3)),
+            )
+        } else {
+            // 1.7 only sends the values, scaled to 200
+            (
+                ratio(window.property(1), 200),
+                ratio(window.property(0), 200),
+            )
+        }
+    }
+
+    /// Returns the armor points given by the armor the player is
+    /// wearing, worked out from pre-1.13 item ids. Later versions
+    /// send this as the player's armor attribute instead.
+    pub fn legacy_armor_points(&self) -> i32 {
+        let window = match self.windows.get(&PLAYER_WINDOW) {
+            Some(window) => window,
+            None => return 0,
+        };
+        // Helmet, chestplate, leggings and boots of leather, chain,
+        // iron, diamond and gold in item id order
+        const POINTS: [i32; 20] = [1, 3, 2, 1, 2, 5, 4, 1, 2, 6, 5, 2, 3, 8, 6, 3, 2, 5, 3, 1];
+        (5..9)
+            .filter_map(|slot| window.get(slot))
+            .filter_map(|stack| POINTS.get((stack.id - 298) as usize))
+            .sum()
+    }
+
+    pub fn set_items(
+        &mut self,
+        id: u8,
+        items: Vec<Option<Stack>>,
+        state_id: Option<i32>,
+        carried: Option<Option<Stack>>,
+    ) {
+        if let Some(state_id) = state_id {
+            self.state_id = state_id;
+        }
+        if let Some(carried) = carried {
+            self.cursor = carried;
+        }
+        if let Some(window) = self.windows.get_mut(&id) {
+            for (slot, item) in items.into_iter().enumerate() {
+                window.set(slot as i16, item);
+            }
+        }
+        self.version += 1;
+    }
+
+    pub fn set_slot(&mut self, id: u8, slot: i16, item: Option<Stack>, state_id: Option<i32>) {
+        if let Some(state_id) = state_id {
+            self.state_id = state_id;
+        }
+        let (id, slot) = match id {
+            CURSOR_WINDOW if slot == -1 => {
+                self.cursor = item;
+                self.version += 1;
+                return;
+            }
+            PLAYER_INVENTORY_WINDOW => match player_inventory_slot(slot) {
+                Some(slot) => (PLAYER_WINDOW, slot),
+                None => return,
+            },
+            id => (id, slot),
+        };
+        if let Some(window) = self.windows.get_mut(&id) {
+            window.set(slot, item);
+        }
+        self.version += 1;
+    }
+
+    pub fn hovered_slot(&self) -> Option<i16> {
+        self.hovered
+    }
+
+    pub fn is_over_window(&self) -> bool {
+        self.over_window
+    }
+
+    pub fn set_over_window(&mut self, over: bool) {
+        self.over_window = over;
+    }
+
+    /// Updates the slot under the mouse, extending a drag in progress.
+    pub fn hover_slot(&mut self, slot: i16, state: bool) {
+        if state {
+            self.hovered = Some(slot);
+            if self.can_drag_to(slot) {
+                self.drag.as_mut().unwrap().slots.push(slot);
+            }
+        } else if self.hovered == Some(slot) {
+            self.hovered = None;
+        }
+        self.version += 1;
+    }
+
+    fn can_drag_to(&self, slot: i16) -> bool {
+        let (drag, cursor, window) = match (&self.drag, &self.cursor, self.open_window_ref()) {
+            (Some(drag), Some(cursor), Some(window)) => (drag, cursor, window),
+            _ => return false,
+        };
+        if drag.slots.contains(&slot) || drag.slots.len() as isize >= cursor.count {
+            return false;
+        }
+        !window.is_result(slot)
+            && match window.get(slot) {
+                Some(item) => same_item(item, cursor) && item.count < MAX_STACK_SIZE,
+                None => true,
+            }
+    }
+
+    fn open_window_ref(&self) -> Option<&Window> {
+        self.open.and_then(|id| self.windows.get(&id))
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Starts spreading the held stack over the slots the mouse passes
+    /// over while the button is held, `button` is 0 for left and 1 for right.
+    pub fn begin_drag(&mut self, slot: i16, button: u8) {
+        if self.cursor.is_none() {
+            return;
+        }
+        self.drag = Some(Drag {
+            button,
+            slots: vec![],
+        });
+        if self.can_drag_to(slot) {
+            self.drag.as_mut().unwrap().slots.push(slot);
+        }
+    }
+
+    /// Ends the drag in progress, returning the clicks to send. A drag
+    /// over a single slot is treated as a normal click on it.
+    pub fn end_drag(&mut self) -> Vec<Click> {
+        let drag = match self.drag.take() {
+            Some(drag) => drag,
+            None => return vec![],
+        };
+        let id = match self.open {
+            Some(id) => id,
+            None => return vec![],
+        };
+        match drag.slots.len() {
+            0 => vec![],
+            1 => self
+                .click(id, drag.slots[0], drag.button, ClickMode::Pickup)
+                .into_iter()
+                .collect(),
+            _ => self.drag_clicks(id, drag.button, &drag.slots),
+        }
+    }
+
+    /// Applies a click to the window, predicting the result the server
+    /// will send back.
+    pub fn click(&mut self, id: u8, slot: i16, button: u8, mode: ClickMode) -> Option<Click> {
+        let window = self.windows.get_mut(&id)?;
+        let before = window.slots.clone();
+        let clicked_item = window.get(slot).cloned();
+        match mode {
+            ClickMode::Pickup => {
+                if slot == OUTSIDE_SLOT {
+                    drop_cursor(&mut self.cursor, button);
+                } else {
+                    pickup(window, &mut self.cursor, slot, button);
+                }
+            }
+            ClickMode::QuickMove => quick_move(window, slot),
+            ClickMode::Swap => {
+                let hotbar = window.hotbar_slot(button);
+                if hotbar != slot && !window.is_result(slot) {
+                    let item = window.get(slot).cloned();
+                    let other = window.get(hotbar).cloned();
+                    window.set(slot, other);
+                    window.set(hotbar, item);
+                }
+            }
+            ClickMode::Drag => unreachable!("drags are sent with drag_clicks"),
+        }
+        let changed = changed_slots(&before, &window.slots);
+        self.version += 1;
+        Some(self.make_click(id, slot, button, mode, clicked_item, changed))
+    }
+
+    fn drag_clicks(&mut self, id: u8, button: u8, slots: &[i16]) -> Vec<Click> {
+        // Start, add slot and end buttons for left and right drags
+        let (start, add, end) = if button == 0 { (0, 1, 2) } else { (4, 5, 6) };
+        let window = match self.windows.get_mut(&id) {
+            Some(window) => window,
+            None => return vec![],
+        };
+        let before = window.slots.clone();
+        if let Some(cursor) = self.cursor.as_mut() {
+            let per_slot = if button == 0 {
+                cursor.count / slots.len() as isize
+            } else {
+                1
+            };
+            for &slot in slots {
+                let existing = window.get(slot).map_or(0, |v| v.count);
+                let count = per_slot.min(MAX_STACK_SIZE - existing).min(cursor.count);
+                if count <= 0 {
+                    continue;
+                }
+                let mut item = cursor.clone();
+                item.count = existing + count;
+                cursor.count -= count;
+                window.set(slot, Some(item));
+            }
+        }
+        if self.cursor.as_ref().map_or(false, |v| v.count <= 0) {
+            self.cursor = None;
+        }
+        let changed = changed_slots(&before, &window.slots);
+        self.version += 1;
+
+        let mut clicks =
+            vec![self.make_click(id, OUTSIDE_SLOT, start, ClickMode::Drag, None, vec![])];
+        for &slot in slots {
+            clicks.push(self.make_click(id, slot, add, ClickMode::Drag, None, vec![]));
+        }
+        clicks.push(self.make_click(id, OUTSIDE_SLOT, end, ClickMode::Drag, None, changed));
+        clicks
+    }
+
+    fn make_click(
+        &mut self,
+        window_id: u8,
+        slot: i16,
+        button: u8,
+        mode: ClickMode,
+        clicked_item: Option<Stack>,
+        changed: Vec<(i16, Option<Stack>)>,
+    ) -> Click {
+        self.action_number = self.action_number.wrapping_add(1);
+        Click {
+            window_id,
+            slot,
+            button,
+            mode,
+            action_number: self.action_number,
+            state_id: self.state_id,
+            clicked_item,
+            changed,
+            carried: self.cursor.clone(),
+        }
+    }
+}
+
+fn same_item(a: &Stack, b: &Stack) -> bool {
+    a.id == b.id && a.damage == b.damage && a.tag == b.tag
+}
+
+fn changed_slots(before: &[Option<Stack>], after: &[Option<Stack>]) -> Vec<(i16, Option<Stack>)> {
+    before
+        .iter()
+        .zip(after)
+        .enumerate()
+        .filter(|(_, (a, b))| a != b)
+        .map(|(slot, (_, b))| (slot as i16, b.clone()))
+        .collect()
+}
+
+fn drop_cursor(cursor: &mut Option<Stack>, button: u8) {
+    if button == 0 {
+        *cursor = None;
+    } else if let Some(item) = cursor.as_mut() {
+        item.count -= 1;
+        if item.count <= 0 {
+            *cursor = None;
+        }
+    }
+}
+
+fn pickup(window: &mut Window, cursor: &mut Option<Stack>, slot: i16, button: u8) {
+    let right = button == 1;
+    let item = window.get(slot).cloned();
+    if window.is_result(slot) {
+        // Results can only be taken, and only added to a matching stack
+        if let Some(item) = item {
+            match cursor {
+                None => {
+                    *cursor = Some(item);
+                    window.set(slot, None);
+                }
+                Some(held)
+                    if same_item(&item, held) && held.count + item.count <= MAX_STACK_SIZE =>
+                {
+                    held.count += item.count;
+                    window.set(slot, None);
+                }
+                _ => {}
+            }
+        }
+        return;
+    }
+    match (item, cursor.take()) {
+        (None, None) => {}
+        (Some(mut item), None) => {
+            if right {
+                let taken = (item.count + 1) / 2;
+                let mut held = item.clone();
+                held.count = taken;
+                item.count -= taken;
+                *cursor = Some(held);
+                window.set(slot, Some(item));
+            } else {
+                *cursor = Some(item);
+                window.set(slot, None);
+            }
+        }
+        (None, Some(mut held)) => {
+            if right {
+                let mut item = held.clone();
+                item.count = 1;
+                held.count -= 1;
+                window.set(slot, Some(item));
+                *cursor = Some(held).filter(|v| v.count > 0);
+            } else {
+                window.set(slot, Some(held));
+            }
+        }
+        (Some(mut item), Some(mut held)) => {
+            if same_item(&item, &held) {
+                let wanted = if right { 1 } else { held.count };
+                let moved = wanted.min(MAX_STACK_SIZE - item.count).max(0);
+                item.count += moved;
+                held.count -= moved;
+                window.set(slot, Some(item));
+                *cursor = Some(held).filter(|v| v.count > 0);
+            } else {
+                window.set(slot, Some(held));
+                *cursor = Some(item);
+            }
+        }
+    }
+}
+
+fn quick_move(window: &mut Window, slot: i16) {
+    let mut item = match window.get(slot).cloned() {
+        Some(item) => item,
+        None => return,
+    };
+    let targets = window
+        .quick_move_targets(slot as usize)
+        .map(|v| v as i16)
+        .filter(|&v| v != slot && !window.is_result(v))
+        .collect::<Vec<_>>();
+    // Top up matching stacks first then use the first empty slot
+    for &target in &targets {
+        if item.count <= 0 {
+            break;
+        }
+        if let Some(mut other) = window.get(target).cloned() {
+            if same_item(&other, &item) {
+                let moved = item.count.min(MAX_STACK_SIZE - other.count).max(0);
+                other.count += moved;
+                item.count -= moved;
+                window.set(target, Some(other));
+            }
+        }
+    }
+    if item.count > 0 {
+        if let Some(&target) = targets.iter().find(|&&v| window.get(v).is_none()) {
+            window.set(target, Some(item.clone()));
+            item.count = 0;
+        }
+    }
+    window.set(slot, Some(item));
+}
+
+/// Converts a slot of the player's inventory, numbered hotbar first then
+/// the main inventory, armor and off hand, to the matching slot of the
+/// player's window.
+fn player_inventory_slot(slot: i16) -> Option<i16> {
+    match slot {
+        0..=8 => Some(slot + 36),
+        9..=35 => Some(slot),
+        // Armor is listed from the feet up
+        36..=39 => Some(44 - slot),
+        40 => Some(45),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack(id: isize, count: isize) -> Option<Stack> {
+        Some(Stack {
+            id,
+            count,
+            damage: Some(0),
+            tag: None,
+        })
+    }
+
+    #[test]
+    fn pickup_and_place() {
+        let mut inv = Inventory::new(340);
+        inv.set_slot(PLAYER_WINDOW, 10, stack(1, 5), None);
+
+        let click = inv.click(PLAYER_WINDOW, 10, 0, ClickMode::Pickup).unwrap();
+        assert_eq!(click.clicked_item, stack(1, 5));
+        assert_eq!(inv.cursor, stack(1, 5));
+        assert_eq!(inv.window(PLAYER_WINDOW).unwrap().get(10), None);
+
+        inv.click(PLAYER_WINDOW, 11, 1, ClickMode::Pickup);
+        assert_eq!(inv.cursor, stack(1, 4));
+        assert_eq!(inv.window(PLAYER_WINDOW).unwrap().slots[11], stack(1, 1));
+    }
+
+    #[test]
+    fn right_click_takes_half() {
+        let mut inv = Inventory::new(340);
+        inv.set_slot(PLAYER_WINDOW, 10, stack(1, 5), None);
+        inv.click(PLAYER_WINDOW, 10, 1, ClickMode::Pickup);
+        assert_eq!(inv.cursor, stack(1, 3));
+        assert_eq!(inv.window(PLAYER_WINDOW).unwrap().slots[10], stack(1, 2));
+    }
+
+    #[test]
+    fn player_inventory_slots_map_to_window() {
+        let mut inv = Inventory::new(340);
+        inv.set_slot(PLAYER_INVENTORY_WINDOW, 0, stack(1, 1), None);
+        inv.set_slot(PLAYER_INVENTORY_WINDOW, 9, stack(2, 1), None);
+        inv.set_slot(PLAYER_INVENTORY_WINDOW, 36, stack(3, 1), None);
+        inv.set_slot(PLAYER_INVENTORY_WINDOW, 39, stack(4, 1), None);
+        inv.set_slot(PLAYER_INVENTORY_WINDOW, 40, stack(5, 1), None);
+        let window = inv.window(PLAYER_WINDOW).unwrap();
+        assert_eq!(window.slots[0], None);
+        assert_eq!(window.slots[36], stack(1, 1));
+        assert_eq!(window.slots[9], stack(2, 1));
+        assert_eq!(window.slots[8], stack(3, 1));
+        assert_eq!(window.slots[5], stack(4, 1));
+        assert_eq!(window.slots[45], stack(5, 1));
+    }
+
+    #[test]
+    fn armor_points_from_worn_items() {
+        let mut inv = Inventory::new(47);
+        // Diamond helmet, iron chestplate and a pumpkin
+        inv.set_slot(PLAYER_WINDOW, 5, stack(310, 1), None);
+        inv.set_slot(PLAYER_WINDOW, 6, stack(307, 1), None);
+        inv.set_slot(PLAYER_WINDOW, 8, stack(86, 1), None);
+        assert_eq!(inv.legacy_armor_points(), 9);
+    }
+
+    #[test]
+    fn held_item_follows_selection() {
+        let mut inv = Inventory::new(340);
+        inv.set_slot(PLAYER_WINDOW, 38, stack(1, 3), None);
+        assert_eq!(inv.held_item(), None);
+        assert!(inv.select_slot(2));
+        assert!(!inv.select_slot(2));
+        assert!(!inv.select_slot(9));
+        assert_eq!(inv.held_item(), stack(1, 3).as_ref());
+    }
+
+    #[test]
+    fn shift_click_merges_into_hotbar() {
+        let mut inv = Inventory::new(340);
+        inv.set_slot(PLAYER_WINDOW, 10, stack(1, 10), None);
+        inv.set_slot(PLAYER_WINDOW, 37, stack(1, 60), None);
+        let click = inv
+            .click(PLAYER_WINDOW, 10, 0, ClickMode::QuickMove)
+            .unwrap();
+        let window = inv.window(PLAYER_WINDOW).unwrap();
+        assert_eq!(window.slots[37], stack(1, 64));
+        assert_eq!(window.slots[36], stack(1, 6));
+        assert_eq!(window.slots[10], None);
+        assert_eq!(click.changed.len(), 3);
+    }
+
+    #[test]
+    fn number_key_swaps_with_hotbar() {
+        let mut inv = Inventory::new(340);
+        inv.set_slot(PLAYER_WINDOW, 10, stack(1, 1), None);
+        inv.set_slot(PLAYER_WINDOW, 38, stack(2, 1), None);
+        inv.click(PLAYER_WINDOW, 10, 2, ClickMode::Swap);
+        let window = inv.window(PLAYER_WINDOW).unwrap();
+        assert_eq!(window.slots[10], stack(2, 1));
+        assert_eq!(window.slots[38], stack(1, 1));
+    }
+
+    #[test]
+    fn drag_splits_evenly() {
+        let mut inv = Inventory::new(340);
+        inv.open(PLAYER_WINDOW);
+        inv.cursor = stack(1, 7);
+        inv.begin_drag(10, 0);
+        inv.hover_slot(11, true);
+        inv.hover_slot(12, true);
+        let clicks = inv.end_drag();
+        assert_eq!(clicks.len(), 5);
+        assert_eq!(clicks[0].button, 0);
+        assert_eq!(clicks[4].button, 2);
+        assert_eq!(clicks[4].changed.len(), 3);
+        assert_eq!(inv.cursor, stack(1, 1));
+        assert_eq!(inv.window(PLAYER_WINDOW).unwrap().slots[12], stack(1, 2));
+    }
+
+    #[test]
+    fn window_kinds_across_versions() {
+        assert_eq!(
+            WindowKind::from_registry_id(2, 578),
+            (WindowKind::Chest(3), 27)
+        );
+        assert_eq!(
+            WindowKind::from_registry_id(20, 578),
+            (WindowKind::Furnace, 3)
+        );
+        assert_eq!(
+            WindowKind::from_registry_id(21, 754),
+            (WindowKind::Furnace, 3)
+        );
+        assert_eq!(
+            WindowKind::from_name("minecraft:crafting_table", 0),
+            (WindowKind::CraftingTable, 10)
+        );
+        assert_eq!(
+            WindowKind::from_legacy_id(0, 54),
+            (WindowKind::Chest(6), 54)
+        );
+    }
+
+    #[test]
+    fn chest_shift_click_moves_to_player() {
+        let mut inv = Inventory::new(340);
+        inv.open_container(1, WindowKind::Chest(3), 27, None);
+        assert_eq!(inv.take_opened(), Some((1, false)));
+        inv.set_slot(1, 0, stack(1, 5), None);
+        inv.click(1, 0, 0, ClickMode::QuickMove);
+        let window = inv.window(1).unwrap();
+        assert_eq!(window.slots[0], None);
+        assert_eq!(window.slots[27], stack(1, 5));
+
+        inv.close(1);
+        assert_eq!(inv.take_closed(), Some(1));
+        assert!(inv.window(1).is_none());
+    }
+
+    #[test]
+    fn server_closes_only_the_open_window() {
+        let mut inv = Inventory::new(340);
+        inv.open_container(1, WindowKind::Chest(3), 27, None);
+        inv.open_container(2, WindowKind::Chest(3), 27, None);
+        assert_eq!(inv.take_opened(), Some((2, true)));
+
+        inv.close_from_server(1);
+        assert_eq!(inv.take_closed_by_server(), None);
+        assert_eq!(inv.open_window(), Some(2));
+
+        inv.close_from_server(2);
+        assert_eq!(inv.take_closed_by_server(), Some(2));
+        assert_eq!(inv.take_closed_by_server(), None);
+        // The server already knows it closed the window
+        assert_eq!(inv.take_closed(), None);
+        assert_eq!(inv.open_window(), None);
+    }
+}
@@ -28,6 +28,7 @@ extern crate steven_protocol;
 
 pub mod ecs;
 use steven_protocol::format;
+use steven_protocol::item;
 use steven_protocol::nbt;
 use steven_protocol::protocol;
 pub mod gl;
@@ -36,6 +37,8 @@ pub mod auth;
 pub mod chunk_builder;
 pub mod console;
 pub mod entity;
+pub mod hud;
+pub mod inventory;
 pub mod model;
 pub mod render;
 pub mod resources;
@@ -70,10 +73,14 @@ pub struct Game {
     resource_manager: Arc<RwLock<resources::Manager>>,
     console: Arc<Mutex<console::Console>>,
     vars: Rc<console::Vars>,
+    commands: Rc<console::Commands>,
     should_close: bool,
 
     server: server::Server,
+    hud: hud::Hud,
     focused: bool,
+    /// Whether the death screen is being shown
+    dead: bool,
     chunk_builder: chunk_builder::ChunkBuilder,
 
     connect_reply: Option<mpsc::Receiver<Result<server::Server, protocol::Error>>>,
@@ -85,6 +92,7 @@ pub struct Game {
     last_mouse_yrel: f64,
     is_ctrl_pressed: bool,
     is_logo_pressed: bool,
+    is_shift_pressed: bool,
     is_fullscreen: bool,
     default_protocol_version: i32,
 }
@@ -138,6 +146,19 @@ impl Game {
     }
 
     pub fn tick(&mut self, delta: f64) {
+        let submitted = self.console.lock().unwrap().take_submitted();
+        let commands = self.commands.clone();
+        for line in submitted {
+            if let Err(err) = commands.execute(self, &line) {
+                let mut msg = format::TextComponent::new(&err);
+                msg.modifier.color = Some(format::Color::Red);
+                self.console
+                    .lock()
+                    .unwrap()
+                    .print(format::Component::Text(msg));
+            }
+        }
+
         if !self.server.is_connected() {
             self.renderer.camera.yaw += 0.005 * delta;
             if self.renderer.camera.yaw > ::std::f64::consts::PI * 2.0 {
@@ -153,6 +174,46 @@ impl Game {
             self.focused = false;
         }
 
+        // Windows opened and closed by the server, handled in that order
+        // as both can happen within the same tick
+        let (opened, closed) = {
+            let mut inventory = self.server.inventory.write().unwrap();
+            (inventory.take_opened(), inventory.take_closed_by_server())
+        };
+        if let Some((id, replaced)) = opened {
+            let window = Box::new(screen::inventory::InventoryWindow::new(
+                self.server.inventory.clone(),
+                self.chunk_builder.models(),
+                id,
+            ));
+            if replaced {
+                self.screen_sys
+                    .remove_screens(|screen| screen.window_id().is_some());
+            }
+            self.screen_sys.add_screen(window);
+            self.focused = false;
+        }
+        if let Some(id) = closed {
+            self.screen_sys
+                .remove_screens(|screen| screen.window_id() == Some(id));
+            // Other screens, like the death screen, stay open
+            self.focused = self.screen_sys.is_empty();
+        }
+
+        let dead = self.server.is_connected() && self.server.is_dead();
+        if dead && !self.dead {
+            self.screen_sys
+                .add_screen(Box::new(screen::death::Death::new(
+                    self.server.death_message(),
+                )));
+            self.focused = false;
+        } else if !dead && self.dead && self.server.is_connected() {
+            self.screen_sys
+                .remove_screens(|screen| screen.is_death_screen());
+            self.focused = self.screen_sys.is_empty();
+        }
+        self.dead = dead;
+
         let mut clear_reply = false;
         if let Some(ref recv) = self.connect_reply {
             if let Ok(server) = recv.try_recv() {
@@ -163,6 +224,7 @@ impl Game {
                         self.focused = true;
                         self.server.remove(&mut self.renderer);
                         self.server = val;
+                        self.hud.chat.clear();
                     }
                     Err(err) => {
                         let msg = match err {
@@ -265,6 +327,11 @@ fn main2() {
         let vsync = *vars.get(settings::R_VSYNC);
         (Rc::new(vars), vsync)
     };
+    let commands = {
+        let mut commands = console::Commands::new();
+        console::register_commands(&mut commands);
+        Rc::new(commands)
+    };
 
     let (res, mut resui) = resources::Manager::new();
     let resource_manager = Arc::new(RwLock::new(res));
@@ -373,12 +440,15 @@ fn main2() {
     );
     let mut game = Game {
         server: server::Server::dummy_server(resource_manager.clone()),
+        hud: hud::Hud::new(),
         focused: false,
+        dead: false,
         renderer,
         screen_sys,
         resource_manager: resource_manager.clone(),
         console: con,
         vars,
+        commands,
         should_close: false,
         chunk_builder: chunk_builder::ChunkBuilder::new(resource_manager, textures),
         connect_reply: None,
@@ -389,6 +459,7 @@ fn main2() {
         last_mouse_yrel: 0.0,
         is_ctrl_pressed: false,
         is_logo_pressed: false,
+        is_shift_pressed: false,
         is_fullscreen: false,
         default_protocol_version,
     };
@@ -577,7 +648,8 @@ fn tick_all(
     let fps_cap = *game.vars.get(settings::R_MAX_FPS);
 
     game.tick(delta);
-    game.server.tick(&mut game.renderer, delta);
+    game.server
+        .tick(&mut game.renderer, &game.chunk_builder.models(), delta);
 
     // Check if window is valid, it might be minimized
     if physical_width == 0 || physical_height == 0 {
@@ -585,20 +657,29 @@ fn tick_all(
     }
 
     game.renderer.update_camera(physical_width, physical_height);
+    game.renderer
+        .ui
+        .set_locale(game.vars.get(settings::CL_LOCALE));
+    game.server.set_client_settings(server::ClientSettings {
+        locale: game.vars.get(settings::CL_LOCALE).clone(),
+        view_distance: (*game.vars.get(settings::R_RENDER_DISTANCE)).clamp(2, 32) as u8,
+        chat_mode: (*game.vars.get(settings::CL_CHAT_MODE)).clamp(0, 2) as u8,
+        chat_colors: *game.vars.get(settings::CL_CHAT_COLORS),
+        displayed_skin_parts: *game.vars.get(settings::CL_SKIN_PARTS) as u8 & 0x7f,
+        main_hand: (*game.vars.get(settings::CL_MAIN_HAND)).clamp(0, 1) as u8,
+    });
     game.server.world.compute_render_list(&mut game.renderer);
     game.chunk_builder
         .tick(&mut game.server.world, &mut game.renderer, version);
 
     game.screen_sys
         .tick(delta, &mut game.renderer, ui_container);
-    /* TODO: open console for chat messages
-    if let Some(received_chat_at) = game.server.received_chat_at {
-        if Instant::now().duration_since(received_chat_at).as_secs() < 5 {
-            game.console.lock().unwrap().activate()
-            // TODO: automatically deactivate the console after inactivity
-        }
-    }
-    */
+    game.hud.tick(
+        &mut game.server,
+        ui_container,
+        &game.renderer,
+        &game.chunk_builder.models(),
+    );
     game.console
         .lock()
         .unwrap()
@@ -622,6 +703,22 @@ fn tick_all(
     }
 }
 
+fn hotbar_index(key: winit::event::VirtualKeyCode) -> Option<u8> {
+    use winit::event::VirtualKeyCode::*;
+    match key {
+        Key1 => Some(0),
+        Key2 => Some(1),
+        Key3 => Some(2),
+        Key4 => Some(3),
+        Key5 => Some(4),
+        Key6 => Some(5),
+        Key7 => Some(6),
+        Key8 => Some(7),
+        Key9 => Some(8),
+        _ => None,
+    }
+}
+
 fn handle_window_event<T>(
     window: &winit::window::Window,
     game: &mut Game,
@@ -693,6 +790,7 @@ fn handle_window_event<T>(
                 WindowEvent::ModifiersChanged(modifiers_state) => {
                     game.is_ctrl_pressed = modifiers_state.ctrl();
                     game.is_logo_pressed = modifiers_state.logo();
+                    game.is_shift_pressed = modifiers_state.shift();
                 }
                 WindowEvent::CloseRequested => game.should_close = true,
                 WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
@@ -700,7 +798,13 @@ fn handle_window_event<T>(
                 }
 
                 WindowEvent::ReceivedCharacter(codepoint) => {
-                    if !game.focused && !game.is_ctrl_pressed && !game.is_logo_pressed {
+                    if game.console.lock().unwrap().take_toggle_char() {
+                        // Typed by the key that toggled the console
+                    } else if game.console.lock().unwrap().is_active() {
+                        if !game.is_ctrl_pressed && !game.is_logo_pressed {
+                            game.console.lock().unwrap().key_type(codepoint);
+                        }
+                    } else if !game.focused && !game.is_ctrl_pressed && !game.is_logo_pressed {
                         ui_container.key_type(game, codepoint);
                     }
 
@@ -710,6 +814,20 @@ fn handle_window_event<T>(
                     }
                 }
 
+                WindowEvent::MouseInput { state, button, .. }
+                    if !game.focused && game.server.is_inventory_open() =>
+                {
+                    let button = match button {
+                        MouseButton::Left => 0,
+                        MouseButton::Right => 1,
+                        _ => return false,
+                    };
+                    game.server.on_inventory_mouse(
+                        button,
+                        state == ElementState::Pressed,
+                        game.is_shift_pressed,
+                    );
+                }
                 WindowEvent::MouseInput { state, button, .. } => match (state, button) {
                     (ElementState::Released, MouseButton::Left) => {
                         let physical_size = window.inner_size();
@@ -719,6 +837,8 @@ fn handle_window_event<T>(
                         if game.server.is_connected()
                             && !game.focused
                             && !game.screen_sys.is_current_closable()
+                            && !game.dead
+                            && !game.hud.chat.is_open()
                         {
                             game.focused = true;
                             window.set_cursor_grab(cursor_grab_mode).unwrap();
@@ -774,6 +894,16 @@ fn handle_window_event<T>(
                         ui_container.hover_at(game, x, y, width, height);
                     }
                 }
+                WindowEvent::MouseWheel { delta, .. } if game.focused => {
+                    // Scrolling down moves the selection right
+                    let y = match delta {
+                        MouseScrollDelta::LineDelta(_, y) => y as f64,
+                        MouseScrollDelta::PixelDelta(position) => position.y,
+                    };
+                    if y != 0.0 {
+                        game.server.scroll_hotbar(if y > 0.0 { -1 } else { 1 });
+                    }
+                }
                 WindowEvent::MouseWheel { delta, .. } => {
                     // TODO: line vs pixel delta? does pixel scrolling (e.g. touchpad) need scaling?
                     match delta {
@@ -787,7 +917,29 @@ fn handle_window_event<T>(
                     }
                 }
                 WindowEvent::KeyboardInput { input, .. } => {
+                    let console_active = game.console.lock().unwrap().is_active();
                     match (input.state, input.virtual_keycode) {
+                        (ElementState::Released, Some(VirtualKeyCode::Escape))
+                            if console_active =>
+                        {
+                            game.console.lock().unwrap().deactivate();
+                        }
+                        (ElementState::Pressed, Some(key))
+                            if console_active
+                                && key != VirtualKeyCode::Grave
+                                && key != VirtualKeyCode::F11 =>
+                        {
+                            let mut console = game.console.lock().unwrap();
+                            match key {
+                                VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                                    console.submit()
+                                }
+                                VirtualKeyCode::Tab => console.complete(&game.commands, &game.vars),
+                                VirtualKeyCode::Up => console.recall(true),
+                                VirtualKeyCode::Down => console.recall(false),
+                                _ => {}
+                            }
+                        }
                         (ElementState::Released, Some(VirtualKeyCode::Escape)) => {
                             if game.focused {
                                 window
@@ -798,6 +950,11 @@ fn handle_window_event<T>(
                                 game.screen_sys.replace_screen(Box::new(
                                     screen::SettingsMenu::new(game.vars.clone(), true),
                                 ));
+                            } else if game.hud.chat.is_open() {
+                                window.set_cursor_grab(cursor_grab_mode).unwrap();
+                                window.set_cursor_visible(false);
+                                game.focused = true;
+                                game.hud.chat.close();
                             } else if game.screen_sys.is_current_closable() {
                                 window.set_cursor_grab(cursor_grab_mode).unwrap();
                                 window.set_cursor_visible(false);
@@ -826,20 +983,76 @@ fn handle_window_event<T>(
                                 if let Some(steven_key) =
                                     settings::Stevenkey::get_by_keycode(key, &game.vars)
                                 {
+                                    if steven_key == settings::Stevenkey::PlayerList {
+                                        game.hud.player_list.set_visible(true);
+                                    }
                                     game.server.key_press(true, steven_key);
+                                } else if let Some(index) = hotbar_index(key) {
+                                    game.server.select_hotbar_slot(index);
                                 }
+                            } else if game.hud.chat.is_open()
+                                && (key == VirtualKeyCode::Up || key == VirtualKeyCode::Down)
+                            {
+                                game.hud.chat.recall(key == VirtualKeyCode::Up);
+                            } else if game.hud.chat.is_open() && key == VirtualKeyCode::Tab {
+                                game.hud.chat.complete(&mut game.server);
+                            } else if let Some(index) =
+                                hotbar_index(key).filter(|_| game.server.is_inventory_open())
+                            {
+                                game.server.on_inventory_hotbar_key(index);
                             } else {
                                 let ctrl_pressed = game.is_ctrl_pressed || game.is_logo_pressed;
                                 ui_container.key_press(game, key, true, ctrl_pressed);
                             }
                         }
                         (ElementState::Released, Some(key)) => {
+                            // Hidden even if focus was lost while it was held
+                            if settings::Stevenkey::get_by_keycode(key, &game.vars)
+                                == Some(settings::Stevenkey::PlayerList)
+                            {
+                                game.hud.player_list.set_visible(false);
+                            }
                             if game.focused {
                                 if let Some(steven_key) =
                                     settings::Stevenkey::get_by_keycode(key, &game.vars)
                                 {
+                                    // Opened on release so the key isn't typed into the input
+                                    if steven_key == settings::Stevenkey::Chat
+                                        && game.server.is_connected()
+                                    {
+                                        window
+                                            .set_cursor_grab(winit::window::CursorGrabMode::None)
+                                            .unwrap();
+                                        window.set_cursor_visible(true);
+                                        game.focused = false;
+                                        game.hud.chat.open(ui_container, "");
+                                    }
+                                    if steven_key == settings::Stevenkey::OpenInv
+                                        && game.server.is_connected()
+                                    {
+                                        window
+                                            .set_cursor_grab(winit::window::CursorGrabMode::None)
+                                            .unwrap();
+                                        window.set_cursor_visible(true);
+                                        game.focused = false;
+                                        game.screen_sys.add_screen(Box::new(
+                                            screen::inventory::InventoryWindow::new(
+                                                game.server.inventory.clone(),
+                                                game.chunk_builder.models(),
+                                                inventory::PLAYER_WINDOW,
+                                            ),
+                                        ));
+                                    }
                                     game.server.key_press(false, steven_key);
                                 }
+                            } else if game.server.is_inventory_open()
+                                && settings::Stevenkey::get_by_keycode(key, &game.vars)
+                                    == Some(settings::Stevenkey::OpenInv)
+                            {
+                                window.set_cursor_grab(cursor_grab_mode).unwrap();
+                                window.set_cursor_visible(false);
+                                game.focused = true;
+                                game.screen_sys.pop_screen();
                             } else {
                                 let ctrl_pressed = game.is_ctrl_pressed;
                                 ui_container.key_press(game, key, false, ctrl_pressed);
@@ -0,0 +1,765 @@
+use super::item_registry;
+use super::{BuiltinType, Factory, ModelElement, RawModel};
+use crate::item::Stack;
+use crate::shared::Direction;
+use crate::world::block::{self, Block, TintType};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use image::{GenericImageView, Rgba, RgbaImage};
+use log::error;
+
+/// Size in pixels of icons rendered from block models.
+const BLOCK_ICON_SIZE: u32 = 32;
+const MISSING_ICON: &str = "steven:missing_texture";
+/// Version whose block ids are used for items mapped back to their ids
+/// from before the flattening, 1.12.2.
+const LEGACY_PROTOCOL_VERSION: i32 = 340;
+
+/// The model an item stack is drawn with.
+enum ItemModel {
+    Block(Block),
+    Item(&'static str),
+}
+
+impl Factory {
+    /// Returns the name of a texture showing the stack as it looks in
+    /// an inventory, rendering it into the texture atlas the first time
+    /// it's needed.
+    pub fn get_item_icon(
+        models: &Arc<RwLock<Factory>>,
+        stack: &Stack,
+        protocol_version: i32,
+    ) -> String {
+        let key = (protocol_version, stack.id, stack.damage.unwrap_or(0));
+        if let Some(name) = models.read().unwrap().item_icons.get(&key) {
+            return name.clone();
+        }
+        let mut m = models.write().unwrap();
+        let icon = m
+            .item_model(stack, protocol_version)
+            .and_then(|model| m.render_item_icon(&model));
+        let name = match icon {
+            Some((name, img)) => {
+                if m.rendered_icons.insert(name.clone()) {
+                    m.textures
+                        .write()
+                        .unwrap()
+                        .put_dynamic(&name, image::DynamicImage::ImageRgba8(img));
+                }
+                format!("steven-dynamic:{}", name)
+            }
+            None => MISSING_ICON.to_owned(),
+        };
+        m.item_icons.insert(key, name.clone());
+        name
+    }
+
+    /// Returns the material an armor stack's worn texture is named
+    /// after, such as `iron` for `models/armor/iron_layer_1`.
+    pub fn armor_material(stack: &Stack, protocol_version: i32) -> Option<&'static str> {
+        if protocol_version >= 404 {
+            return item_registry::item_name(protocol_version, stack.id).and_then(armor_material);
+        }
+        legacy_item_name(stack.id, stack.damage.unwrap_or(0)).and_then(armor_material)
+    }
+
+    /// Frees the icons rendered so far, they are drawn again from the
+    /// new resources when next needed.
+    pub(super) fn clear_item_icons(&mut self) {
+        let mut textures = self.textures.write().unwrap();
+        for name in self.rendered_icons.drain() {
+            textures.remove_dynamic(&name);
+        }
+        self.item_icons.clear();
+    }
+
+    fn item_model(&mut self, stack: &Stack, protocol_version: i32) -> Option<ItemModel> {
+        // Newer items are drawn with the models they had before the
+        // flattening, as those are the ones in the resources
+        let (id, damage, protocol_version) = if protocol_version >= 404 {
+            let name = item_registry::item_name(protocol_version, stack.id)?;
+            let (id, damage) = item_registry::legacy_item(name)?;
+            (id, damage, LEGACY_PROTOCOL_VERSION)
+        } else {
+            (stack.id, stack.damage.unwrap_or(0), protocol_version)
+        };
+        if id < 256 {
+            if self
+                .item_id_map
+                .as_ref()
+                .map_or(true, |(version, _)| *version != protocol_version)
+            {
+                self.item_id_map =
+                    Some((protocol_version, block::VanillaIDMap::new(protocol_version)));
+            }
+            let id_map = &self.item_id_map.as_ref().unwrap().1;
+            let id = ((id as usize) << 4) | (damage as usize & 0xF);
+            return match id_map.by_vanilla_id(id, &HashMap::new()) {
+                Block::Missing {} | Block::Air {} => None,
+                block => Some(ItemModel::Block(block)),
+            };
+        }
+        legacy_item_name(id, damage).map(ItemModel::Item)
+    }
+
+    fn render_item_icon(&self, model: &ItemModel) -> Option<(String, RgbaImage)> {
+        match *model {
+            ItemModel::Item(name) => {
+                let raw = self.load_item_model(name)?;
+                Some((format!("item-{}", name), self.render_item_model(&raw)?))
+            }
+            ItemModel::Block(block) => {
+                let (plugin, name) = block.get_model();
+                let variant = block.get_model_variant();
+                // Blocks with a flat item model of the same name, such as
+                // doors and plants, use that instead of the block model
+                if let Some(raw) = self.load_item_model(&name) {
+                    if let BuiltinType::Generated = raw.builtin {
+                        if let Some(img) = self.render_item_model(&raw) {
+                            return Some((format!("item-{}", name), img));
+                        }
+                    }
+                }
+                let raws = self.load_block_item_models(&plugin, &name, block)?;
+                let tint = match block.get_tint() {
+                    TintType::Default => None,
+                    TintType::Color { r, g, b } => Some((r, g, b)),
+                    // Items use the colour of a temperate biome
+                    TintType::Grass => Some(biome_color(&self.grass_colors)),
+                    TintType::Foliage => Some(biome_color(&self.foliage_colors)),
+                };
+                let mut img = RgbaImage::new(BLOCK_ICON_SIZE, BLOCK_ICON_SIZE);
+                let mut depth = vec![f32::INFINITY; (BLOCK_ICON_SIZE * BLOCK_ICON_SIZE) as usize];
+                for raw in &raws {
+                    self.render_block_model(raw, tint, &mut img, &mut depth);
+                }
+                Some((format!("item-{}:{}-{}", plugin, name, variant), img))
+            }
+        }
+    }
+
+    fn load_item_model(&self, name: &str) -> Option<RawModel> {
+        let file = self
+            .resources
+            .read()
+            .unwrap()
+            .open("minecraft", &format!("models/item/{}.json", name))?;
+        let item_model: serde_json::Value = match serde_json::from_reader(file) {
+            Ok(val) => val,
+            Err(err) => {
+                error!("Error loading item model {}: {:?}", name, err);
+                return None;
+            }
+        };
+        self.parse_model("minecraft", &item_model)
+    }
+
+    /// Loads the models making up the block state, following the same
+    /// rules as when the block is placed in the world.
+    fn load_block_item_models(
+        &self,
+        plugin: &str,
+        name: &str,
+        block: Block,
+    ) -> Option<Vec<RawModel>> {
+        let file = self
+            .resources
+            .read()
+            .unwrap()
+            .open(plugin, &format!("blockstates/{}.json", name))?;
+        let state: serde_json::Value = match serde_json::from_reader(file) {
+            Ok(val) => val,
+            Err(err) => {
+                error!("Error loading block state {}:{}: {:?}", plugin, name, err);
+                return None;
+            }
+        };
+        let first = |v: &serde_json::Value| match v.as_array() {
+            Some(list) => list.first().cloned(),
+            None => Some(v.clone()),
+        };
+
+        let mut raws = vec![];
+        if let Some(variant) = state
+            .get("variants")
+            .and_then(|v| v.get(block.get_model_variant()))
+            .and_then(first)
+        {
+            raws.extend(self.parse_block_state_variant(plugin, &variant));
+        }
+        if let Some(multipart) = state.get("multipart").and_then(|v| v.as_array()) {
+            for part in multipart {
+                let mut rules = vec![];
+                if let Some(when) = part.get("when").and_then(|v| v.as_object()) {
+                    Self::parse_rules(when, &mut rules);
+                }
+                if !Self::eval_rules(block, &rules) {
+                    continue;
+                }
+                if let Some(apply) = part.get("apply").and_then(first) {
+                    raws.extend(self.parse_block_state_variant(plugin, &apply));
+                }
+            }
+        }
+        if raws.is_empty() {
+            None
+        } else {
+            Some(raws)
+        }
+    }
+
+    /// Loads a texture's image from the resources, only keeping the
+    /// first frame of animated textures.
+    fn load_image(&self, name: &str) -> Option<RgbaImage> {
+        let (plugin, name) = match name.find(':') {
+            Some(pos) => (&name[..pos], &name[pos + 1..]),
+            None => ("minecraft", name),
+        };
+        let mut file = self
+            .resources
+            .read()
+            .unwrap()
+            .open(plugin, &format!("textures/{}.png", name))?;
+        let mut data = Vec::new();
+        file.read_to_end(&mut data).ok()?;
+        let img = image::load_from_memory(&data).ok()?;
+        let (width, height) = img.dimensions();
+        Some(img.crop_imm(0, 0, width, height.min(width)).to_rgba8())
+    }
+
+    /// Draws a flat item by layering its textures over each other.
+    fn render_item_model(&self, raw: &RawModel) -> Option<RgbaImage> {
+        let mut out: Option<RgbaImage> = None;
+        for layer in 0.. {
+            let name = match raw.texture_vars.get(&format!("layer{}", layer)) {
+                Some(name) => raw.lookup_texture(name),
+                None => break,
+            };
+            let img = match self.load_image(&name) {
+                Some(img) => img,
+                None => continue,
+            };
+            let out = out.get_or_insert_with(|| RgbaImage::new(img.width(), img.height()));
+            for (x, y, pixel) in img.enumerate_pixels() {
+                if x < out.width() && y < out.height() {
+                    blend(out.get_pixel_mut(x, y), *pixel);
+                }
+            }
+        }
+        out
+    }
+
+    /// Draws a block model from above at an angle, like the vanilla
+    /// inventory does. Faces are filled by projecting points sampled
+    /// across them, keeping the nearest one for each pixel.
+    fn render_block_model(
+        &self,
+        raw: &RawModel,
+        tint: Option<(u8, u8, u8)>,
+        img: &mut RgbaImage,
+        depth: &mut [f32],
+    ) {
+        let mut images: HashMap<String, Option<RgbaImage>> = HashMap::new();
+        let size = BLOCK_ICON_SIZE as f32;
+        let scale = size / 1.6;
+
+        for el in &raw.elements {
+            for dir in Direction::all() {
+                let face = match el.faces[dir.index()].as_ref() {
+                    Some(face) => face,
+                    None => continue,
+                };
+                let (nx, ny, nz) = dir.get_offset();
+                let normal = transform(raw, el, [nx as f32, ny as f32, nz as f32], true);
+                if dot(normal, VIEW) >= 0.0 {
+                    continue;
+                }
+                let texture_name = raw.lookup_texture(&face.texture);
+                let texture = match images
+                    .entry(texture_name.clone())
+                    .or_insert_with(|| self.load_image(&texture_name))
+                {
+                    Some(texture) => texture,
+                    None => continue,
+                };
+                let shade = if normal[1].abs() > 0.5 {
+                    if normal[1] > 0.0 {
+                        1.0
+                    } else {
+                        0.5
+                    }
+                } else if normal[2].abs() > normal[0].abs() {
+                    0.8
+                } else {
+                    0.6
+                };
+                let tint = if face.tint_index >= 0 { tint } else { None };
+
+                let from = el.from;
+                let to = el.to;
+                let samples = 64;
+                for i in 0..samples {
+                    for j in 0..samples {
+                        let s = (i as f64 + 0.5) / samples as f64;
+                        let t = (j as f64 + 0.5) / samples as f64;
+                        let lerp = |a: usize, v: f64| from[a] + (to[a] - from[a]) * v;
+                        let p = match dir {
+                            Direction::North => [lerp(0, 1.0 - s), lerp(1, 1.0 - t), from[2]],
+                            Direction::South => [lerp(0, s), lerp(1, 1.0 - t), to[2]],
+                            Direction::West => [from[0], lerp(1, 1.0 - t), lerp(2, s)],
+                            Direction::East => [to[0], lerp(1, 1.0 - t), lerp(2, 1.0 - s)],
+                            Direction::Up => [lerp(0, s), to[1], lerp(2, t)],
+                            Direction::Down => [lerp(0, s), from[1], lerp(2, 1.0 - t)],
+                            _ => unreachable!(),
+                        };
+                        let p = transform(
+                            raw,
+                            el,
+                            [
+                                (p[0] / 16.0) as f32,
+                                (p[1] / 16.0) as f32,
+                                (p[2] / 16.0) as f32,
+                            ],
+                            false,
+                        );
+                        let p = [p[0] - 0.5, p[1] - 0.5, p[2] - 0.5];
+                        let x = (dot(p, RIGHT) * scale + size / 2.0) as i32;
+                        let y = (size / 2.0 - dot(p, UP) * scale) as i32;
+                        if x < 0 || y < 0 || x >= size as i32 || y >= size as i32 {
+                            continue;
+                        }
+                        let d = dot(p, VIEW);
+                        let index = (y as u32 * BLOCK_ICON_SIZE + x as u32) as usize;
+                        if d >= depth[index] {
+                            continue;
+                        }
+
+                        let (s, t) = match face.rotation {
+                            90 => (t, 1.0 - s),
+                            180 => (1.0 - s, 1.0 - t),
+                            270 => (1.0 - t, s),
+                            _ => (s, t),
+                        };
+                        let u = (face.uv[0] + (face.uv[2] - face.uv[0]) * s) / 16.0;
+                        let v = (face.uv[1] + (face.uv[3] - face.uv[1]) * t) / 16.0;
+                        let tx = ((u * texture.width() as f64) as u32).min(texture.width() - 1);
+                        let ty = ((v * texture.height() as f64) as u32).min(texture.height() - 1);
+                        let pixel = *texture.get_pixel(tx, ty);
+                        if pixel[3] == 0 {
+                            continue;
+                        }
+                        depth[index] = d;
+                        img.put_pixel(
+                            x as u32,
+                            y as u32,
+                            tinted(pixel, tint.unwrap_or((255, 255, 255)), shade),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+// The view used for block icons, looking down at 30 degrees towards the
+// south east so the top, north and west faces are visible.
+const VIEW: [f32; 3] = [0.612_372_4, -0.5, 0.612_372_4];
+const RIGHT: [f32; 3] = [-0.707_106_8, 0.0, 0.707_106_8];
+const UP: [f32; 3] = [0.353_553_4, 0.866_025_4, 0.353_553_4];
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Applies the element's and block state's rotations to a point, or to
+/// a direction if `direction` is set.
+fn transform(raw: &RawModel, el: &ModelElement, p: [f32; 3], direction: bool) -> [f32; 3] {
+    let [mut x, mut y, mut z] = p;
+    let rotate = |a: f32, b: f32, angle: f32| {
+        let (s, c) = angle.sin_cos();
+        (a * c - b * s, b * c + a * s)
+    };
+    if let Some(r) = el.rotation.as_ref() {
+        let angle = r.angle.to_radians() as f32;
+        let angle = if r.axis == "z" { angle } else { -angle };
+        let origin = if direction {
+            [0.0; 3]
+        } else {
+            [
+                (r.origin[0] / 16.0) as f32,
+                (r.origin[1] / 16.0) as f32,
+                (r.origin[2] / 16.0) as f32,
+            ]
+        };
+        x -= origin[0];
+        y -= origin[1];
+        z -= origin[2];
+        match &*r.axis {
+            "y" => (x, z) = rotate(x, z, angle),
+            "x" => (z, y) = rotate(z, y, angle),
+            "z" => (x, y) = rotate(x, y, angle),
+            _ => {}
+        }
+        x += origin[0];
+        y += origin[1];
+        z += origin[2];
+    }
+    let center = if direction { 0.0 } else { 0.5 };
+    if raw.x > 0.0 {
+        let (nz, ny) = rotate(z - center, y - center, raw.x.to_radians() as f32);
+        z = nz + center;
+        y = ny + center;
+    }
+    if raw.y > 0.0 {
+        let (nx, nz) = rotate(x - center, z - center, raw.y.to_radians() as f32);
+        x = nx + center;
+        z = nz + center;
+    }
+    [x, y, z]
+}
+
+fn biome_color(img: &image::DynamicImage) -> (u8, u8, u8) {
+    let col = img.get_pixel(127, 127);
+    (col[0], col[1], col[2])
+}
+
+fn tinted(pixel: Rgba<u8>, tint: (u8, u8, u8), shade: f32) -> Rgba<u8> {
+    let apply = |v: u8, t: u8| ((v as f32) * (t as f32 / 255.0) * shade) as u8;
+    Rgba([
+        apply(pixel[0], tint.0),
+        apply(pixel[1], tint.1),
+        apply(pixel[2], tint.2),
+        pixel[3],
+    ])
+}
+
+fn blend(dst: &mut Rgba<u8>, src: Rgba<u8>) {
+    let a = src[3] as f32 / 255.0;
+    for i in 0..3 {
+        dst[i] = (src[i] as f32 * a + dst[i] as f32 * (1.0 - a)) as u8;
+    }
+    dst[3] = dst[3].max(src[3]);
+}
+
+/// Returns the name of the item model of a pre-1.13 item id.
+fn legacy_item_name(id: isize, damage: isize) -> Option<&'static str> {
+    const DYES: [&str; 16] = [
+        "dye_black",
+        "dye_red",
+        "dye_green",
+        "dye_brown",
+        "dye_blue",
+        "dye_purple",
+        "dye_cyan",
+        "dye_silver",
+        "dye_gray",
+        "dye_pink",
+        "dye_lime",
+        "dye_yellow",
+        "dye_light_blue",
+        "dye_magenta",
+        "dye_orange",
+        "dye_white",
+    ];
+    const FISH: [&str; 4] = [
+        "fish_cod_raw",
+        "fish_salmon_raw",
+        "fish_clownfish_raw",
+        "fish_pufferfish_raw",
+    ];
+    const ITEMS: [&str; 198] = [
+        "iron_shovel",
+        "iron_pickaxe",
+        "iron_axe",
+        "flint_and_steel",
+        "apple",
+        "bow",
+        "arrow",
+        "coal",
+        "diamond",
+        "iron_ingot",
+        "gold_ingot",
+        "iron_sword",
+        "wooden_sword",
+        "wooden_shovel",
+        "wooden_pickaxe",
+        "wooden_axe",
+        "stone_sword",
+        "stone_shovel",
+        "stone_pickaxe",
+        "stone_axe",
+        "diamond_sword",
+        "diamond_shovel",
+        "diamond_pickaxe",
+        "diamond_axe",
+        "stick",
+        "bowl",
+        "mushroom_stew",
+        "golden_sword",
+        "golden_shovel",
+        "golden_pickaxe",
+        "golden_axe",
+        "string",
+        "feather",
+        "gunpowder",
+        "wooden_hoe",
+        "stone_hoe",
+        "iron_hoe",
+        "diamond_hoe",
+        "golden_hoe",
+        "wheat_seeds",
+        "wheat",
+        "bread",
+        "leather_helmet",
+        "leather_chestplate",
+        "leather_leggings",
+        "leather_boots",
+        "chainmail_helmet",
+        "chainmail_chestplate",
+        "chainmail_leggings",
+        "chainmail_boots",
+        "iron_helmet",
+        "iron_chestplate",
+        "iron_leggings",
+        "iron_boots",
+        "diamond_helmet",
+        "diamond_chestplate",
+        "diamond_leggings",
+        "diamond_boots",
+        "golden_helmet",
+        "golden_chestplate",
+        "golden_leggings",
+        "golden_boots",
+        "flint",
+        "porkchop_raw",
+        "porkchop_cooked",
+        "painting",
+        "golden_apple",
+        "sign",
+        "wooden_door",
+        "bucket",
+        "water_bucket",
+        "lava_bucket",
+        "minecart",
+        "saddle",
+        "iron_door",
+        "redstone",
+        "snowball",
+        "oak_boat",
+        "leather",
+        "milk_bucket",
+        "brick",
+        "clay_ball",
+        "reeds",
+        "paper",
+        "book",
+        "slime_ball",
+        "chest_minecart",
+        "furnace_minecart",
+        "egg",
+        "compass",
+        "fishing_rod",
+        "clock",
+        "glowstone_dust",
+        "fish_cod_raw",
+        "fish_cod_cooked",
+        "dye",
+        "bone",
+        "sugar",
+        "cake",
+        "bed",
+        "repeater",
+        "cookie",
+        "filled_map",
+        "shears",
+        "melon",
+        "pumpkin_seeds",
+        "melon_seeds",
+        "beef_raw",
+        "beef_cooked",
+        "chicken_raw",
+        "chicken_cooked",
+        "rotten_flesh",
+        "ender_pearl",
+        "blaze_rod",
+        "ghast_tear",
+        "gold_nugget",
+        "nether_wart",
+        "bottle_drinkable",
+        "glass_bottle",
+        "spider_eye",
+        "fermented_spider_eye",
+        "blaze_powder",
+        "magma_cream",
+        "brewing_stand",
+        "cauldron",
+        "ender_eye",
+        "speckled_melon",
+        "spawn_egg",
+        "experience_bottle",
+        "fire_charge",
+        "writable_book",
+        "written_book",
+        "emerald",
+        "item_frame",
+        "flower_pot",
+        "carrot",
+        "potato",
+        "baked_potato",
+        "poisonous_potato",
+        "map",
+        "golden_carrot",
+        "skull",
+        "carrot_on_a_stick",
+        "nether_star",
+        "pumpkin_pie",
+        "fireworks",
+        "firework_charge",
+        "enchanted_book",
+        "comparator",
+        "netherbrick",
+        "quartz",
+        "tnt_minecart",
+        "hopper_minecart",
+        "prismarine_shard",
+        "prismarine_crystals",
+        "rabbit_raw",
+        "rabbit_cooked",
+        "rabbit_stew",
+        "rabbit_foot",
+        "rabbit_hide",
+        "armor_stand",
+        "iron_horse_armor",
+        "golden_horse_armor",
+        "diamond_horse_armor",
+        "lead",
+        "name_tag",
+        "command_block_minecart",
+        "mutton_raw",
+        "mutton_cooked",
+        "banner",
+        "end_crystal",
+        "spruce_door",
+        "birch_door",
+        "jungle_door",
+        "acacia_door",
+        "dark_oak_door",
+        "chorus_fruit",
+        "chorus_fruit_popped",
+        "beetroot",
+        "beetroot_seeds",
+        "beetroot_soup",
+        "dragon_breath",
+        "bottle_splash",
+        "spectral_arrow",
+        "tipped_arrow",
+        "bottle_lingering",
+        "shield",
+        "elytra",
+        "spruce_boat",
+        "birch_boat",
+        "jungle_boat",
+        "acacia_boat",
+        "dark_oak_boat",
+        "totem",
+        "shulker_shell",
+        "",
+        "iron_nugget",
+        "knowledge_book",
+    ];
+    const RECORDS: [&str; 12] = [
+        "record_13",
+        "record_cat",
+        "record_blocks",
+        "record_chirp",
+        "record_far",
+        "record_mall",
+        "record_mellohi",
+        "record_stal",
+        "record_strad",
+        "record_ward",
+        "record_11",
+        "record_wait",
+    ];
+    match id {
+        263 if damage == 1 => Some("charcoal"),
+        349 => FISH.get(damage as usize).copied(),
+        350 if damage == 1 => Some("fish_salmon_cooked"),
+        351 => DYES.get(damage as usize).copied(),
+        256..=453 => Some(ITEMS[(id - 256) as usize]).filter(|v| !v.is_empty()),
+        2256..=2267 => Some(RECORDS[(id - 2256) as usize]),
+        _ => None,
+    }
+}
+
+fn armor_material(name: &str) -> Option<&'static str> {
+    const PIECES: [&str; 4] = ["_helmet", "_chestplate", "_leggings", "_boots"];
+    if !PIECES.iter().any(|piece| name.ends_with(piece)) {
+        return None;
+    }
+    // Gold armor's textures kept their name when the items became golden
+    const MATERIALS: [(&str, &str); 5] = [
+        ("leather", "leather"),
+        ("chainmail", "chainmail"),
+        ("iron", "iron"),
+        ("diamond", "diamond"),
+        ("golden", "gold"),
+    ];
+    MATERIALS
+        .iter()
+        .find(|(prefix, _)| name.starts_with(prefix))
+        .map(|&(_, material)| material)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_item_names() {
+        assert_eq!(legacy_item_name(256, 0), Some("iron_shovel"));
+        assert_eq!(legacy_item_name(276, 0), Some("diamond_sword"));
+        assert_eq!(legacy_item_name(263, 1), Some("charcoal"));
+        assert_eq!(legacy_item_name(351, 4), Some("dye_blue"));
+        assert_eq!(legacy_item_name(319, 0), Some("porkchop_raw"));
+        assert_eq!(legacy_item_name(320, 0), Some("porkchop_cooked"));
+        assert_eq!(legacy_item_name(363, 0), Some("beef_raw"));
+        assert_eq!(legacy_item_name(364, 0), Some("beef_cooked"));
+        assert_eq!(legacy_item_name(365, 0), Some("chicken_raw"));
+        assert_eq!(legacy_item_name(366, 0), Some("chicken_cooked"));
+        assert_eq!(legacy_item_name(349, 0), Some("fish_cod_raw"));
+        assert_eq!(legacy_item_name(349, 2), Some("fish_clownfish_raw"));
+        assert_eq!(legacy_item_name(350, 0), Some("fish_cod_cooked"));
+        assert_eq!(legacy_item_name(350, 1), Some("fish_salmon_cooked"));
+        assert_eq!(legacy_item_name(411, 0), Some("rabbit_raw"));
+        assert_eq!(legacy_item_name(412, 0), Some("rabbit_cooked"));
+        assert_eq!(legacy_item_name(423, 0), Some("mutton_raw"));
+        assert_eq!(legacy_item_name(424, 0), Some("mutton_cooked"));
+        assert_eq!(legacy_item_name(451, 0), None);
+        assert_eq!(legacy_item_name(453, 0), Some("knowledge_book"));
+        assert_eq!(legacy_item_name(2267, 0), Some("record_wait"));
+        assert_eq!(legacy_item_name(454, 0), None);
+    }
+
+    #[test]
+    fn flattened_items_use_legacy_models() {
+        let model = |protocol_version, id| {
+            let name = item_registry::item_name(protocol_version, id)?;
+            let (id, damage) = item_registry::legacy_item(name)?;
+            legacy_item_name(id, damage)
+        };
+        assert_eq!(model(404, 571), Some("fish_cod_raw"));
+        assert_eq!(model(758, 747), Some("iron_chestplate"));
+        assert_eq!(model(758, 1027), None);
+    }
+
+    #[test]
+    fn armor_materials() {
+        assert_eq!(armor_material("iron_helmet"), Some("iron"));
+        assert_eq!(armor_material("golden_boots"), Some("gold"));
+        assert_eq!(armor_material("leather_leggings"), Some("leather"));
+        assert_eq!(armor_material("diamond_sword"), None);
+        assert_eq!(armor_material("golden_apple"), None);
+    }
+}
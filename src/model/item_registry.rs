@@ -0,0 +1,7509 @@
+//! Item ids since the flattening, which were renumbered by every
+//! version that added items, and the ids the same items had before.
+
+/// Returns the registry name of an item id.
+pub fn item_name(protocol_version: i32, id: isize) -> Option<&'static str> {
+    let items = if protocol_version >= 757 {
+        ITEMS_1_18
+    } else if protocol_version >= 755 {
+        ITEMS_1_17
+    } else if protocol_version >= 751 {
+        ITEMS_1_16_2
+    } else if protocol_version >= 735 {
+        ITEMS_1_16
+    } else if protocol_version >= 573 {
+        ITEMS_1_15
+    } else if protocol_version >= 477 {
+        ITEMS_1_14
+    } else {
+        // The 1.14 snapshots are treated as 1.13
+        ITEMS_1_13
+    };
+    items.get(usize::try_from(id).ok()?).copied()
+}
+
+/// Returns the pre-1.13 id and damage of an item, which the bundled
+/// resources name its models after. Items added since have none.
+pub fn legacy_item(name: &str) -> Option<(isize, isize)> {
+    LEGACY_ITEMS
+        .iter()
+        .find(|&&(legacy, _, _)| legacy == name)
+        .map(|&(_, id, damage)| (id, damage))
+}
+
+// Names that were reused by later versions, such as `stone_slab`, keep
+// the item they had in 1.13.
+const LEGACY_ITEMS: &[(&str, isize, isize)] = &[
+    ("stone", 1, 0),
+    ("granite", 1, 1),
+    ("polished_granite", 1, 2),
+    ("diorite", 1, 3),
+    ("polished_diorite", 1, 4),
+    ("andesite", 1, 5),
+    ("polished_andesite", 1, 6),
+    ("grass_block", 2, 0),
+    ("dirt", 3, 0),
+    ("coarse_dirt", 3, 1),
+    ("podzol", 3, 2),
+    ("cobblestone", 4, 0),
+    ("oak_planks", 5, 0),
+    ("spruce_planks", 5, 1),
+    ("birch_planks", 5, 2),
+    ("jungle_planks", 5, 3),
+    ("acacia_planks", 5, 4),
+    ("dark_oak_planks", 5, 5),
+    ("oak_sapling", 6, 0),
+    ("spruce_sapling", 6, 1),
+    ("birch_sapling", 6, 2),
+    ("jungle_sapling", 6, 3),
+    ("acacia_sapling", 6, 4),
+    ("dark_oak_sapling", 6, 5),
+    ("bedrock", 7, 0),
+    ("sand", 12, 0),
+    ("red_sand", 12, 1),
+    ("gravel", 13, 0),
+    ("gold_ore", 14, 0),
+    ("iron_ore", 15, 0),
+    ("coal_ore", 16, 0),
+    ("oak_log", 17, 0),
+    ("spruce_log", 17, 1),
+    ("birch_log", 17, 2),
+    ("jungle_log", 17, 3),
+    ("oak_wood", 17, 12),
+    ("spruce_wood", 17, 13),
+    ("birch_wood", 17, 14),
+    ("jungle_wood", 17, 15),
+    ("oak_leaves", 18, 0),
+    ("spruce_leaves", 18, 1),
+    ("birch_leaves", 18, 2),
+    ("jungle_leaves", 18, 3),
+    ("sponge", 19, 0),
+    ("wet_sponge", 19, 1),
+    ("glass", 20, 0),
+    ("lapis_ore", 21, 0),
+    ("lapis_block", 22, 0),
+    ("dispenser", 23, 0),
+    ("sandstone", 24, 0),
+    ("chiseled_sandstone", 24, 1),
+    ("cut_sandstone", 24, 2),
+    ("note_block", 25, 0),
+    ("powered_rail", 27, 0),
+    ("detector_rail", 28, 0),
+    ("sticky_piston", 29, 0),
+    ("cobweb", 30, 0),
+    ("grass", 31, 1),
+    ("fern", 31, 2),
+    ("dead_bush", 32, 0),
+    ("piston", 33, 0),
+    ("white_wool", 35, 0),
+    ("orange_wool", 35, 1),
+    ("magenta_wool", 35, 2),
+    ("light_blue_wool", 35, 3),
+    ("yellow_wool", 35, 4),
+    ("lime_wool", 35, 5),
+    ("pink_wool", 35, 6),
+    ("gray_wool", 35, 7),
+    ("light_gray_wool", 35, 8),
+    ("cyan_wool", 35, 9),
+    ("purple_wool", 35, 10),
+    ("blue_wool", 35, 11),
+    ("brown_wool", 35, 12),
+    ("green_wool", 35, 13),
+    ("red_wool", 35, 14),
+    ("black_wool", 35, 15),
+    ("dandelion", 37, 0),
+    ("poppy", 38, 0),
+    ("blue_orchid", 38, 1),
+    ("allium", 38, 2),
+    ("azure_bluet", 38, 3),
+    ("red_tulip", 38, 4),
+    ("orange_tulip", 38, 5),
+    ("white_tulip", 38, 6),
+    ("pink_tulip", 38, 7),
+    ("oxeye_daisy", 38, 8),
+    ("brown_mushroom", 39, 0),
+    ("red_mushroom", 40, 0),
+    ("gold_block", 41, 0),
+    ("iron_block", 42, 0),
+    ("smooth_stone", 43, 8),
+    ("smooth_sandstone", 43, 9),
+    ("smooth_quartz", 43, 15),
+    ("stone_slab", 44, 0),
+    ("smooth_stone_slab", 44, 0),
+    ("sandstone_slab", 44, 1),
+    ("petrified_oak_slab", 44, 2),
+    ("cobblestone_slab", 44, 3),
+    ("brick_slab", 44, 4),
+    ("stone_brick_slab", 44, 5),
+    ("nether_brick_slab", 44, 6),
+    ("quartz_slab", 44, 7),
+    ("bricks", 45, 0),
+    ("tnt", 46, 0),
+    ("bookshelf", 47, 0),
+    ("mossy_cobblestone", 48, 0),
+    ("obsidian", 49, 0),
+    ("torch", 50, 0),
+    ("spawner", 52, 0),
+    ("oak_stairs", 53, 0),
+    ("chest", 54, 0),
+    ("diamond_ore", 56, 0),
+    ("diamond_block", 57, 0),
+    ("crafting_table", 58, 0),
+    ("farmland", 60, 0),
+    ("furnace", 61, 0),
+    ("ladder", 65, 0),
+    ("rail", 66, 0),
+    ("cobblestone_stairs", 67, 0),
+    ("lever", 69, 0),
+    ("stone_pressure_plate", 70, 0),
+    ("oak_pressure_plate", 72, 0),
+    ("redstone_ore", 73, 0),
+    ("redstone_torch", 76, 0),
+    ("stone_button", 77, 0),
+    ("snow", 78, 0),
+    ("ice", 79, 0),
+    ("snow_block", 80, 0),
+    ("cactus", 81, 0),
+    ("clay", 82, 0),
+    ("jukebox", 84, 0),
+    ("oak_fence", 85, 0),
+    ("pumpkin", 86, 0),
+    ("carved_pumpkin", 86, 0),
+    ("netherrack", 87, 0),
+    ("soul_sand", 88, 0),
+    ("glowstone", 89, 0),
+    ("jack_o_lantern", 91, 0),
+    ("white_stained_glass", 95, 0),
+    ("orange_stained_glass", 95, 1),
+    ("magenta_stained_glass", 95, 2),
+    ("light_blue_stained_glass", 95, 3),
+    ("yellow_stained_glass", 95, 4),
+    ("lime_stained_glass", 95, 5),
+    ("pink_stained_glass", 95, 6),
+    ("gray_stained_glass", 95, 7),
+    ("light_gray_stained_glass", 95, 8),
+    ("cyan_stained_glass", 95, 9),
+    ("purple_stained_glass", 95, 10),
+    ("blue_stained_glass", 95, 11),
+    ("brown_stained_glass", 95, 12),
+    ("green_stained_glass", 95, 13),
+    ("red_stained_glass", 95, 14),
+    ("black_stained_glass", 95, 15),
+    ("oak_trapdoor", 96, 0),
+    ("infested_stone", 97, 0),
+    ("infested_cobblestone", 97, 1),
+    ("infested_stone_bricks", 97, 2),
+    ("infested_mossy_stone_bricks", 97, 3),
+    ("infested_cracked_stone_bricks", 97, 4),
+    ("infested_chiseled_stone_bricks", 97, 5),
+    ("stone_bricks", 98, 0),
+    ("mossy_stone_bricks", 98, 1),
+    ("cracked_stone_bricks", 98, 2),
+    ("chiseled_stone_bricks", 98, 3),
+    ("brown_mushroom_block", 99, 14),
+    ("mushroom_stem", 99, 15),
+    ("red_mushroom_block", 100, 14),
+    ("iron_bars", 101, 0),
+    ("glass_pane", 102, 0),
+    ("melon", 103, 0),
+    ("vine", 106, 0),
+    ("oak_fence_gate", 107, 0),
+    ("brick_stairs", 108, 0),
+    ("stone_brick_stairs", 109, 0),
+    ("mycelium", 110, 0),
+    ("lily_pad", 111, 0),
+    ("nether_bricks", 112, 0),
+    ("nether_brick_fence", 113, 0),
+    ("nether_brick_stairs", 114, 0),
+    ("enchanting_table", 116, 0),
+    ("end_portal_frame", 120, 0),
+    ("end_stone", 121, 0),
+    ("dragon_egg", 122, 0),
+    ("redstone_lamp", 123, 0),
+    ("oak_slab", 126, 0),
+    ("spruce_slab", 126, 1),
+    ("birch_slab", 126, 2),
+    ("jungle_slab", 126, 3),
+    ("acacia_slab", 126, 4),
+    ("dark_oak_slab", 126, 5),
+    ("sandstone_stairs", 128, 0),
+    ("emerald_ore", 129, 0),
+    ("ender_chest", 130, 0),
+    ("tripwire_hook", 131, 0),
+    ("emerald_block", 133, 0),
+    ("spruce_stairs", 134, 0),
+    ("birch_stairs", 135, 0),
+    ("jungle_stairs", 136, 0),
+    ("command_block", 137, 0),
+    ("beacon", 138, 0),
+    ("cobblestone_wall", 139, 0),
+    ("mossy_cobblestone_wall", 139, 1),
+    ("oak_button", 143, 0),
+    ("anvil", 145, 0),
+    ("chipped_anvil", 145, 4),
+    ("damaged_anvil", 145, 8),
+    ("trapped_chest", 146, 0),
+    ("light_weighted_pressure_plate", 147, 0),
+    ("heavy_weighted_pressure_plate", 148, 0),
+    ("daylight_detector", 151, 0),
+    ("redstone_block", 152, 0),
+    ("nether_quartz_ore", 153, 0),
+    ("hopper", 154, 0),
+    ("quartz_block", 155, 0),
+    ("chiseled_quartz_block", 155, 1),
+    ("quartz_pillar", 155, 2),
+    ("quartz_stairs", 156, 0),
+    ("activator_rail", 157, 0),
+    ("dropper", 158, 0),
+    ("white_terracotta", 159, 0),
+    ("orange_terracotta", 159, 1),
+    ("magenta_terracotta", 159, 2),
+    ("light_blue_terracotta", 159, 3),
+    ("yellow_terracotta", 159, 4),
+    ("lime_terracotta", 159, 5),
+    ("pink_terracotta", 159, 6),
+    ("gray_terracotta", 159, 7),
+    ("light_gray_terracotta", 159, 8),
+    ("cyan_terracotta", 159, 9),
+    ("purple_terracotta", 159, 10),
+    ("blue_terracotta", 159, 11),
+    ("brown_terracotta", 159, 12),
+    ("green_terracotta", 159, 13),
+    ("red_terracotta", 159, 14),
+    ("black_terracotta", 159, 15),
+    ("white_stained_glass_pane", 160, 0),
+    ("orange_stained_glass_pane", 160, 1),
+    ("magenta_stained_glass_pane", 160, 2),
+    ("light_blue_stained_glass_pane", 160, 3),
+    ("yellow_stained_glass_pane", 160, 4),
+    ("lime_stained_glass_pane", 160, 5),
+    ("pink_stained_glass_pane", 160, 6),
+    ("gray_stained_glass_pane", 160, 7),
+    ("light_gray_stained_glass_pane", 160, 8),
+    ("cyan_stained_glass_pane", 160, 9),
+    ("purple_stained_glass_pane", 160, 10),
+    ("blue_stained_glass_pane", 160, 11),
+    ("brown_stained_glass_pane", 160, 12),
+    ("green_stained_glass_pane", 160, 13),
+    ("red_stained_glass_pane", 160, 14),
+    ("black_stained_glass_pane", 160, 15),
+    ("acacia_leaves", 161, 0),
+    ("dark_oak_leaves", 161, 1),
+    ("acacia_log", 162, 0),
+    ("dark_oak_log", 162, 1),
+    ("acacia_wood", 162, 12),
+    ("dark_oak_wood", 162, 13),
+    ("acacia_stairs", 163, 0),
+    ("dark_oak_stairs", 164, 0),
+    ("slime_block", 165, 0),
+    ("barrier", 166, 0),
+    ("iron_trapdoor", 167, 0),
+    ("prismarine", 168, 0),
+    ("prismarine_bricks", 168, 1),
+    ("dark_prismarine", 168, 2),
+    ("sea_lantern", 169, 0),
+    ("hay_block", 170, 0),
+    ("white_carpet", 171, 0),
+    ("orange_carpet", 171, 1),
+    ("magenta_carpet", 171, 2),
+    ("light_blue_carpet", 171, 3),
+    ("yellow_carpet", 171, 4),
+    ("lime_carpet", 171, 5),
+    ("pink_carpet", 171, 6),
+    ("gray_carpet", 171, 7),
+    ("light_gray_carpet", 171, 8),
+    ("cyan_carpet", 171, 9),
+    ("purple_carpet", 171, 10),
+    ("blue_carpet", 171, 11),
+    ("brown_carpet", 171, 12),
+    ("green_carpet", 171, 13),
+    ("red_carpet", 171, 14),
+    ("black_carpet", 171, 15),
+    ("terracotta", 172, 0),
+    ("coal_block", 173, 0),
+    ("packed_ice", 174, 0),
+    ("sunflower", 175, 0),
+    ("lilac", 175, 1),
+    ("tall_grass", 175, 2),
+    ("large_fern", 175, 3),
+    ("rose_bush", 175, 4),
+    ("peony", 175, 5),
+    ("red_sandstone", 179, 0),
+    ("chiseled_red_sandstone", 179, 1),
+    ("cut_red_sandstone", 179, 2),
+    ("red_sandstone_stairs", 180, 0),
+    ("smooth_red_sandstone", 181, 8),
+    ("red_sandstone_slab", 182, 0),
+    ("spruce_fence_gate", 183, 0),
+    ("birch_fence_gate", 184, 0),
+    ("jungle_fence_gate", 185, 0),
+    ("dark_oak_fence_gate", 186, 0),
+    ("acacia_fence_gate", 187, 0),
+    ("spruce_fence", 188, 0),
+    ("birch_fence", 189, 0),
+    ("jungle_fence", 190, 0),
+    ("dark_oak_fence", 191, 0),
+    ("acacia_fence", 192, 0),
+    ("end_rod", 198, 0),
+    ("chorus_plant", 199, 0),
+    ("chorus_flower", 200, 0),
+    ("purpur_block", 201, 0),
+    ("purpur_pillar", 202, 0),
+    ("purpur_stairs", 203, 0),
+    ("purpur_slab", 205, 0),
+    ("end_stone_bricks", 206, 0),
+    ("grass_path", 208, 0),
+    ("dirt_path", 208, 0),
+    ("repeating_command_block", 210, 0),
+    ("chain_command_block", 211, 0),
+    ("magma_block", 213, 0),
+    ("nether_wart_block", 214, 0),
+    ("red_nether_bricks", 215, 0),
+    ("bone_block", 216, 0),
+    ("structure_void", 217, 0),
+    ("observer", 218, 0),
+    ("white_shulker_box", 219, 0),
+    ("orange_shulker_box", 220, 0),
+    ("magenta_shulker_box", 221, 0),
+    ("light_blue_shulker_box", 222, 0),
+    ("yellow_shulker_box", 223, 0),
+    ("lime_shulker_box", 224, 0),
+    ("pink_shulker_box", 225, 0),
+    ("gray_shulker_box", 226, 0),
+    ("light_gray_shulker_box", 227, 0),
+    ("cyan_shulker_box", 228, 0),
+    ("purple_shulker_box", 229, 0),
+    ("blue_shulker_box", 230, 0),
+    ("brown_shulker_box", 231, 0),
+    ("green_shulker_box", 232, 0),
+    ("red_shulker_box", 233, 0),
+    ("black_shulker_box", 234, 0),
+    ("white_glazed_terracotta", 235, 0),
+    ("orange_glazed_terracotta", 236, 0),
+    ("magenta_glazed_terracotta", 237, 0),
+    ("light_blue_glazed_terracotta", 238, 0),
+    ("yellow_glazed_terracotta", 239, 0),
+    ("lime_glazed_terracotta", 240, 0),
+    ("pink_glazed_terracotta", 241, 0),
+    ("gray_glazed_terracotta", 242, 0),
+    ("light_gray_glazed_terracotta", 243, 0),
+    ("cyan_glazed_terracotta", 244, 0),
+    ("purple_glazed_terracotta", 245, 0),
+    ("blue_glazed_terracotta", 246, 0),
+    ("brown_glazed_terracotta", 247, 0),
+    ("green_glazed_terracotta", 248, 0),
+    ("red_glazed_terracotta", 249, 0),
+    ("black_glazed_terracotta", 250, 0),
+    ("white_concrete", 251, 0),
+    ("orange_concrete", 251, 1),
+    ("magenta_concrete", 251, 2),
+    ("light_blue_concrete", 251, 3),
+    ("yellow_concrete", 251, 4),
+    ("lime_concrete", 251, 5),
+    ("pink_concrete", 251, 6),
+    ("gray_concrete", 251, 7),
+    ("light_gray_concrete", 251, 8),
+    ("cyan_concrete", 251, 9),
+    ("purple_concrete", 251, 10),
+    ("blue_concrete", 251, 11),
+    ("brown_concrete", 251, 12),
+    ("green_concrete", 251, 13),
+    ("red_concrete", 251, 14),
+    ("black_concrete", 251, 15),
+    ("white_concrete_powder", 252, 0),
+    ("orange_concrete_powder", 252, 1),
+    ("magenta_concrete_powder", 252, 2),
+    ("light_blue_concrete_powder", 252, 3),
+    ("yellow_concrete_powder", 252, 4),
+    ("lime_concrete_powder", 252, 5),
+    ("pink_concrete_powder", 252, 6),
+    ("gray_concrete_powder", 252, 7),
+    ("light_gray_concrete_powder", 252, 8),
+    ("cyan_concrete_powder", 252, 9),
+    ("purple_concrete_powder", 252, 10),
+    ("blue_concrete_powder", 252, 11),
+    ("brown_concrete_powder", 252, 12),
+    ("green_concrete_powder", 252, 13),
+    ("red_concrete_powder", 252, 14),
+    ("black_concrete_powder", 252, 15),
+    ("structure_block", 255, 0),
+    ("iron_shovel", 256, 0),
+    ("iron_pickaxe", 257, 0),
+    ("iron_axe", 258, 0),
+    ("flint_and_steel", 259, 0),
+    ("apple", 260, 0),
+    ("bow", 261, 0),
+    ("arrow", 262, 0),
+    ("coal", 263, 0),
+    ("charcoal", 263, 1),
+    ("diamond", 264, 0),
+    ("iron_ingot", 265, 0),
+    ("gold_ingot", 266, 0),
+    ("iron_sword", 267, 0),
+    ("wooden_sword", 268, 0),
+    ("wooden_shovel", 269, 0),
+    ("wooden_pickaxe", 270, 0),
+    ("wooden_axe", 271, 0),
+    ("stone_sword", 272, 0),
+    ("stone_shovel", 273, 0),
+    ("stone_pickaxe", 274, 0),
+    ("stone_axe", 275, 0),
+    ("diamond_sword", 276, 0),
+    ("diamond_shovel", 277, 0),
+    ("diamond_pickaxe", 278, 0),
+    ("diamond_axe", 279, 0),
+    ("stick", 280, 0),
+    ("bowl", 281, 0),
+    ("mushroom_stew", 282, 0),
+    ("golden_sword", 283, 0),
+    ("golden_shovel", 284, 0),
+    ("golden_pickaxe", 285, 0),
+    ("golden_axe", 286, 0),
+    ("string", 287, 0),
+    ("feather", 288, 0),
+    ("gunpowder", 289, 0),
+    ("wooden_hoe", 290, 0),
+    ("stone_hoe", 291, 0),
+    ("iron_hoe", 292, 0),
+    ("diamond_hoe", 293, 0),
+    ("golden_hoe", 294, 0),
+    ("wheat_seeds", 295, 0),
+    ("wheat", 296, 0),
+    ("bread", 297, 0),
+    ("leather_helmet", 298, 0),
+    ("leather_chestplate", 299, 0),
+    ("leather_leggings", 300, 0),
+    ("leather_boots", 301, 0),
+    ("chainmail_helmet", 302, 0),
+    ("chainmail_chestplate", 303, 0),
+    ("chainmail_leggings", 304, 0),
+    ("chainmail_boots", 305, 0),
+    ("iron_helmet", 306, 0),
+    ("iron_chestplate", 307, 0),
+    ("iron_leggings", 308, 0),
+    ("iron_boots", 309, 0),
+    ("diamond_helmet", 310, 0),
+    ("diamond_chestplate", 311, 0),
+    ("diamond_leggings", 312, 0),
+    ("diamond_boots", 313, 0),
+    ("golden_helmet", 314, 0),
+    ("golden_chestplate", 315, 0),
+    ("golden_leggings", 316, 0),
+    ("golden_boots", 317, 0),
+    ("flint", 318, 0),
+    ("porkchop", 319, 0),
+    ("cooked_porkchop", 320, 0),
+    ("painting", 321, 0),
+    ("golden_apple", 322, 0),
+    ("enchanted_golden_apple", 322, 1),
+    ("sign", 323, 0),
+    ("oak_sign", 323, 0),
+    ("oak_door", 324, 0),
+    ("bucket", 325, 0),
+    ("water_bucket", 326, 0),
+    ("lava_bucket", 327, 0),
+    ("minecart", 328, 0),
+    ("saddle", 329, 0),
+    ("iron_door", 330, 0),
+    ("redstone", 331, 0),
+    ("snowball", 332, 0),
+    ("oak_boat", 333, 0),
+    ("leather", 334, 0),
+    ("milk_bucket", 335, 0),
+    ("brick", 336, 0),
+    ("clay_ball", 337, 0),
+    ("sugar_cane", 338, 0),
+    ("paper", 339, 0),
+    ("book", 340, 0),
+    ("slime_ball", 341, 0),
+    ("chest_minecart", 342, 0),
+    ("furnace_minecart", 343, 0),
+    ("egg", 344, 0),
+    ("compass", 345, 0),
+    ("fishing_rod", 346, 0),
+    ("clock", 347, 0),
+    ("glowstone_dust", 348, 0),
+    ("cod", 349, 0),
+    ("salmon", 349, 1),
+    ("tropical_fish", 349, 2),
+    ("pufferfish", 349, 3),
+    ("cooked_cod", 350, 0),
+    ("cooked_salmon", 350, 1),
+    ("ink_sac", 351, 0),
+    ("rose_red", 351, 1),
+    ("red_dye", 351, 1),
+    ("cactus_green", 351, 2),
+    ("green_dye", 351, 2),
+    ("cocoa_beans", 351, 3),
+    ("lapis_lazuli", 351, 4),
+    ("purple_dye", 351, 5),
+    ("cyan_dye", 351, 6),
+    ("light_gray_dye", 351, 7),
+    ("gray_dye", 351, 8),
+    ("pink_dye", 351, 9),
+    ("lime_dye", 351, 10),
+    ("dandelion_yellow", 351, 11),
+    ("yellow_dye", 351, 11),
+    ("light_blue_dye", 351, 12),
+    ("magenta_dye", 351, 13),
+    ("orange_dye", 351, 14),
+    ("bone_meal", 351, 15),
+    ("bone", 352, 0),
+    ("sugar", 353, 0),
+    ("cake", 354, 0),
+    ("white_bed", 355, 0),
+    ("orange_bed", 355, 1),
+    ("magenta_bed", 355, 2),
+    ("light_blue_bed", 355, 3),
+    ("yellow_bed", 355, 4),
+    ("lime_bed", 355, 5),
+    ("pink_bed", 355, 6),
+    ("gray_bed", 355, 7),
+    ("light_gray_bed", 355, 8),
+    ("cyan_bed", 355, 9),
+    ("purple_bed", 355, 10),
+    ("blue_bed", 355, 11),
+    ("brown_bed", 355, 12),
+    ("green_bed", 355, 13),
+    ("red_bed", 355, 14),
+    ("black_bed", 355, 15),
+    ("repeater", 356, 0),
+    ("cookie", 357, 0),
+    ("filled_map", 358, 0),
+    ("shears", 359, 0),
+    ("melon_slice", 360, 0),
+    ("pumpkin_seeds", 361, 0),
+    ("melon_seeds", 362, 0),
+    ("beef", 363, 0),
+    ("cooked_beef", 364, 0),
+    ("chicken", 365, 0),
+    ("cooked_chicken", 366, 0),
+    ("rotten_flesh", 367, 0),
+    ("ender_pearl", 368, 0),
+    ("blaze_rod", 369, 0),
+    ("ghast_tear", 370, 0),
+    ("gold_nugget", 371, 0),
+    ("nether_wart", 372, 0),
+    ("potion", 373, 0),
+    ("glass_bottle", 374, 0),
+    ("spider_eye", 375, 0),
+    ("fermented_spider_eye", 376, 0),
+    ("blaze_powder", 377, 0),
+    ("magma_cream", 378, 0),
+    ("brewing_stand", 379, 0),
+    ("cauldron", 380, 0),
+    ("ender_eye", 381, 0),
+    ("glistering_melon_slice", 382, 0),
+    ("bat_spawn_egg", 383, 0),
+    ("blaze_spawn_egg", 383, 0),
+    ("cave_spider_spawn_egg", 383, 0),
+    ("chicken_spawn_egg", 383, 0),
+    ("cow_spawn_egg", 383, 0),
+    ("creeper_spawn_egg", 383, 0),
+    ("donkey_spawn_egg", 383, 0),
+    ("elder_guardian_spawn_egg", 383, 0),
+    ("enderman_spawn_egg", 383, 0),
+    ("endermite_spawn_egg", 383, 0),
+    ("evoker_spawn_egg", 383, 0),
+    ("ghast_spawn_egg", 383, 0),
+    ("guardian_spawn_egg", 383, 0),
+    ("horse_spawn_egg", 383, 0),
+    ("husk_spawn_egg", 383, 0),
+    ("llama_spawn_egg", 383, 0),
+    ("magma_cube_spawn_egg", 383, 0),
+    ("mooshroom_spawn_egg", 383, 0),
+    ("mule_spawn_egg", 383, 0),
+    ("ocelot_spawn_egg", 383, 0),
+    ("parrot_spawn_egg", 383, 0),
+    ("pig_spawn_egg", 383, 0),
+    ("polar_bear_spawn_egg", 383, 0),
+    ("rabbit_spawn_egg", 383, 0),
+    ("sheep_spawn_egg", 383, 0),
+    ("shulker_spawn_egg", 383, 0),
+    ("silverfish_spawn_egg", 383, 0),
+    ("skeleton_spawn_egg", 383, 0),
+    ("skeleton_horse_spawn_egg", 383, 0),
+    ("slime_spawn_egg", 383, 0),
+    ("spider_spawn_egg", 383, 0),
+    ("squid_spawn_egg", 383, 0),
+    ("stray_spawn_egg", 383, 0),
+    ("vex_spawn_egg", 383, 0),
+    ("villager_spawn_egg", 383, 0),
+    ("vindicator_spawn_egg", 383, 0),
+    ("witch_spawn_egg", 383, 0),
+    ("wither_skeleton_spawn_egg", 383, 0),
+    ("wolf_spawn_egg", 383, 0),
+    ("zombie_spawn_egg", 383, 0),
+    ("zombie_horse_spawn_egg", 383, 0),
+    ("zombie_pigman_spawn_egg", 383, 0),
+    ("zombie_villager_spawn_egg", 383, 0),
+    ("experience_bottle", 384, 0),
+    ("fire_charge", 385, 0),
+    ("writable_book", 386, 0),
+    ("written_book", 387, 0),
+    ("emerald", 388, 0),
+    ("item_frame", 389, 0),
+    ("flower_pot", 390, 0),
+    ("carrot", 391, 0),
+    ("potato", 392, 0),
+    ("baked_potato", 393, 0),
+    ("poisonous_potato", 394, 0),
+    ("map", 395, 0),
+    ("golden_carrot", 396, 0),
+    ("skeleton_skull", 397, 0),
+    ("wither_skeleton_skull", 397, 1),
+    ("zombie_head", 397, 2),
+    ("player_head", 397, 3),
+    ("creeper_head", 397, 4),
+    ("dragon_head", 397, 5),
+    ("carrot_on_a_stick", 398, 0),
+    ("nether_star", 399, 0),
+    ("pumpkin_pie", 400, 0),
+    ("firework_rocket", 401, 0),
+    ("firework_star", 402, 0),
+    ("enchanted_book", 403, 0),
+    ("comparator", 404, 0),
+    ("nether_brick", 405, 0),
+    ("quartz", 406, 0),
+    ("tnt_minecart", 407, 0),
+    ("hopper_minecart", 408, 0),
+    ("prismarine_shard", 409, 0),
+    ("prismarine_crystals", 410, 0),
+    ("rabbit", 411, 0),
+    ("cooked_rabbit", 412, 0),
+    ("rabbit_stew", 413, 0),
+    ("rabbit_foot", 414, 0),
+    ("rabbit_hide", 415, 0),
+    ("armor_stand", 416, 0),
+    ("iron_horse_armor", 417, 0),
+    ("golden_horse_armor", 418, 0),
+    ("diamond_horse_armor", 419, 0),
+    ("lead", 420, 0),
+    ("name_tag", 421, 0),
+    ("command_block_minecart", 422, 0),
+    ("mutton", 423, 0),
+    ("cooked_mutton", 424, 0),
+    ("black_banner", 425, 0),
+    ("red_banner", 425, 1),
+    ("green_banner", 425, 2),
+    ("brown_banner", 425, 3),
+    ("blue_banner", 425, 4),
+    ("purple_banner", 425, 5),
+    ("cyan_banner", 425, 6),
+    ("light_gray_banner", 425, 7),
+    ("gray_banner", 425, 8),
+    ("pink_banner", 425, 9),
+    ("lime_banner", 425, 10),
+    ("yellow_banner", 425, 11),
+    ("light_blue_banner", 425, 12),
+    ("magenta_banner", 425, 13),
+    ("orange_banner", 425, 14),
+    ("white_banner", 425, 15),
+    ("end_crystal", 426, 0),
+    ("spruce_door", 427, 0),
+    ("birch_door", 428, 0),
+    ("jungle_door", 429, 0),
+    ("acacia_door", 430, 0),
+    ("dark_oak_door", 431, 0),
+    ("chorus_fruit", 432, 0),
+    ("popped_chorus_fruit", 433, 0),
+    ("beetroot", 434, 0),
+    ("beetroot_seeds", 435, 0),
+    ("beetroot_soup", 436, 0),
+    ("dragon_breath", 437, 0),
+    ("splash_potion", 438, 0),
+    ("spectral_arrow", 439, 0),
+    ("tipped_arrow", 440, 0),
+    ("lingering_potion", 441, 0),
+    ("shield", 442, 0),
+    ("elytra", 443, 0),
+    ("spruce_boat", 444, 0),
+    ("birch_boat", 445, 0),
+    ("jungle_boat", 446, 0),
+    ("acacia_boat", 447, 0),
+    ("dark_oak_boat", 448, 0),
+    ("totem_of_undying", 449, 0),
+    ("shulker_shell", 450, 0),
+    ("iron_nugget", 452, 0),
+    ("knowledge_book", 453, 0),
+    ("music_disc_13", 2256, 0),
+    ("music_disc_cat", 2257, 0),
+    ("music_disc_blocks", 2258, 0),
+    ("music_disc_chirp", 2259, 0),
+    ("music_disc_far", 2260, 0),
+    ("music_disc_mall", 2261, 0),
+    ("music_disc_mellohi", 2262, 0),
+    ("music_disc_stal", 2263, 0),
+    ("music_disc_strad", 2264, 0),
+    ("music_disc_ward", 2265, 0),
+    ("music_disc_11", 2266, 0),
+    ("music_disc_wait", 2267, 0),
+];
+
+const ITEMS_1_13: &[&str] = &[
+    "air",
+    "stone",
+    "granite",
+    "polished_granite",
+    "diorite",
+    "polished_diorite",
+    "andesite",
+    "polished_andesite",
+    "grass_block",
+    "dirt",
+    "coarse_dirt",
+    "podzol",
+    "cobblestone",
+    "oak_planks",
+    "spruce_planks",
+    "birch_planks",
+    "jungle_planks",
+    "acacia_planks",
+    "dark_oak_planks",
+    "oak_sapling",
+    "spruce_sapling",
+    "birch_sapling",
+    "jungle_sapling",
+    "acacia_sapling",
+    "dark_oak_sapling",
+    "bedrock",
+    "sand",
+    "red_sand",
+    "gravel",
+    "gold_ore",
+    "iron_ore",
+    "coal_ore",
+    "oak_log",
+    "spruce_log",
+    "birch_log",
+    "jungle_log",
+    "acacia_log",
+    "dark_oak_log",
+    "stripped_oak_log",
+    "stripped_spruce_log",
+    "stripped_birch_log",
+    "stripped_jungle_log",
+    "stripped_acacia_log",
+    "stripped_dark_oak_log",
+    "stripped_oak_wood",
+    "stripped_spruce_wood",
+    "stripped_birch_wood",
+    "stripped_jungle_wood",
+    "stripped_acacia_wood",
+    "stripped_dark_oak_wood",
+    "oak_wood",
+    "spruce_wood",
+    "birch_wood",
+    "jungle_wood",
+    "acacia_wood",
+    "dark_oak_wood",
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "sponge",
+    "wet_sponge",
+    "glass",
+    "lapis_ore",
+    "lapis_block",
+    "dispenser",
+    "sandstone",
+    "chiseled_sandstone",
+    "cut_sandstone",
+    "note_block",
+    "powered_rail",
+    "detector_rail",
+    "sticky_piston",
+    "cobweb",
+    "grass",
+    "fern",
+    "dead_bush",
+    "seagrass",
+    "sea_pickle",
+    "piston",
+    "white_wool",
+    "orange_wool",
+    "magenta_wool",
+    "light_blue_wool",
+    "yellow_wool",
+    "lime_wool",
+    "pink_wool",
+    "gray_wool",
+    "light_gray_wool",
+    "cyan_wool",
+    "purple_wool",
+    "blue_wool",
+    "brown_wool",
+    "green_wool",
+    "red_wool",
+    "black_wool",
+    "dandelion",
+    "poppy",
+    "blue_orchid",
+    "allium",
+    "azure_bluet",
+    "red_tulip",
+    "orange_tulip",
+    "white_tulip",
+    "pink_tulip",
+    "oxeye_daisy",
+    "brown_mushroom",
+    "red_mushroom",
+    "gold_block",
+    "iron_block",
+    "oak_slab",
+    "spruce_slab",
+    "birch_slab",
+    "jungle_slab",
+    "acacia_slab",
+    "dark_oak_slab",
+    "stone_slab",
+    "sandstone_slab",
+    "petrified_oak_slab",
+    "cobblestone_slab",
+    "brick_slab",
+    "stone_brick_slab",
+    "nether_brick_slab",
+    "quartz_slab",
+    "red_sandstone_slab",
+    "purpur_slab",
+    "prismarine_slab",
+    "prismarine_brick_slab",
+    "dark_prismarine_slab",
+    "smooth_quartz",
+    "smooth_red_sandstone",
+    "smooth_sandstone",
+    "smooth_stone",
+    "bricks",
+    "tnt",
+    "bookshelf",
+    "mossy_cobblestone",
+    "obsidian",
+    "torch",
+    "end_rod",
+    "chorus_plant",
+    "chorus_flower",
+    "purpur_block",
+    "purpur_pillar",
+    "purpur_stairs",
+    "spawner",
+    "oak_stairs",
+    "chest",
+    "diamond_ore",
+    "diamond_block",
+    "crafting_table",
+    "farmland",
+    "furnace",
+    "ladder",
+    "rail",
+    "cobblestone_stairs",
+    "lever",
+    "stone_pressure_plate",
+    "oak_pressure_plate",
+    "spruce_pressure_plate",
+    "birch_pressure_plate",
+    "jungle_pressure_plate",
+    "acacia_pressure_plate",
+    "dark_oak_pressure_plate",
+    "redstone_ore",
+    "redstone_torch",
+    "stone_button",
+    "snow",
+    "ice",
+    "snow_block",
+    "cactus",
+    "clay",
+    "jukebox",
+    "oak_fence",
+    "spruce_fence",
+    "birch_fence",
+    "jungle_fence",
+    "acacia_fence",
+    "dark_oak_fence",
+    "pumpkin",
+    "carved_pumpkin",
+    "netherrack",
+    "soul_sand",
+    "glowstone",
+    "jack_o_lantern",
+    "oak_trapdoor",
+    "spruce_trapdoor",
+    "birch_trapdoor",
+    "jungle_trapdoor",
+    "acacia_trapdoor",
+    "dark_oak_trapdoor",
+    "infested_stone",
+    "infested_cobblestone",
+    "infested_stone_bricks",
+    "infested_mossy_stone_bricks",
+    "infested_cracked_stone_bricks",
+    "infested_chiseled_stone_bricks",
+    "stone_bricks",
+    "mossy_stone_bricks",
+    "cracked_stone_bricks",
+    "chiseled_stone_bricks",
+    "brown_mushroom_block",
+    "red_mushroom_block",
+    "mushroom_stem",
+    "iron_bars",
+    "glass_pane",
+    "melon",
+    "vine",
+    "oak_fence_gate",
+    "spruce_fence_gate",
+    "birch_fence_gate",
+    "jungle_fence_gate",
+    "acacia_fence_gate",
+    "dark_oak_fence_gate",
+    "brick_stairs",
+    "stone_brick_stairs",
+    "mycelium",
+    "lily_pad",
+    "nether_bricks",
+    "nether_brick_fence",
+    "nether_brick_stairs",
+    "enchanting_table",
+    "end_portal_frame",
+    "end_stone",
+    "end_stone_bricks",
+    "dragon_egg",
+    "redstone_lamp",
+    "sandstone_stairs",
+    "emerald_ore",
+    "ender_chest",
+    "tripwire_hook",
+    "emerald_block",
+    "spruce_stairs",
+    "birch_stairs",
+    "jungle_stairs",
+    "command_block",
+    "beacon",
+    "cobblestone_wall",
+    "mossy_cobblestone_wall",
+    "oak_button",
+    "spruce_button",
+    "birch_button",
+    "jungle_button",
+    "acacia_button",
+    "dark_oak_button",
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "trapped_chest",
+    "light_weighted_pressure_plate",
+    "heavy_weighted_pressure_plate",
+    "daylight_detector",
+    "redstone_block",
+    "nether_quartz_ore",
+    "hopper",
+    "chiseled_quartz_block",
+    "quartz_block",
+    "quartz_pillar",
+    "quartz_stairs",
+    "activator_rail",
+    "dropper",
+    "white_terracotta",
+    "orange_terracotta",
+    "magenta_terracotta",
+    "light_blue_terracotta",
+    "yellow_terracotta",
+    "lime_terracotta",
+    "pink_terracotta",
+    "gray_terracotta",
+    "light_gray_terracotta",
+    "cyan_terracotta",
+    "purple_terracotta",
+    "blue_terracotta",
+    "brown_terracotta",
+    "green_terracotta",
+    "red_terracotta",
+    "black_terracotta",
+    "barrier",
+    "iron_trapdoor",
+    "hay_block",
+    "white_carpet",
+    "orange_carpet",
+    "magenta_carpet",
+    "light_blue_carpet",
+    "yellow_carpet",
+    "lime_carpet",
+    "pink_carpet",
+    "gray_carpet",
+    "light_gray_carpet",
+    "cyan_carpet",
+    "purple_carpet",
+    "blue_carpet",
+    "brown_carpet",
+    "green_carpet",
+    "red_carpet",
+    "black_carpet",
+    "terracotta",
+    "coal_block",
+    "packed_ice",
+    "acacia_stairs",
+    "dark_oak_stairs",
+    "slime_block",
+    "grass_path",
+    "sunflower",
+    "lilac",
+    "rose_bush",
+    "peony",
+    "tall_grass",
+    "large_fern",
+    "white_stained_glass",
+    "orange_stained_glass",
+    "magenta_stained_glass",
+    "light_blue_stained_glass",
+    "yellow_stained_glass",
+    "lime_stained_glass",
+    "pink_stained_glass",
+    "gray_stained_glass",
+    "light_gray_stained_glass",
+    "cyan_stained_glass",
+    "purple_stained_glass",
+    "blue_stained_glass",
+    "brown_stained_glass",
+    "green_stained_glass",
+    "red_stained_glass",
+    "black_stained_glass",
+    "white_stained_glass_pane",
+    "orange_stained_glass_pane",
+    "magenta_stained_glass_pane",
+    "light_blue_stained_glass_pane",
+    "yellow_stained_glass_pane",
+    "lime_stained_glass_pane",
+    "pink_stained_glass_pane",
+    "gray_stained_glass_pane",
+    "light_gray_stained_glass_pane",
+    "cyan_stained_glass_pane",
+    "purple_stained_glass_pane",
+    "blue_stained_glass_pane",
+    "brown_stained_glass_pane",
+    "green_stained_glass_pane",
+    "red_stained_glass_pane",
+    "black_stained_glass_pane",
+    "prismarine",
+    "prismarine_bricks",
+    "dark_prismarine",
+    "prismarine_stairs",
+    "prismarine_brick_stairs",
+    "dark_prismarine_stairs",
+    "sea_lantern",
+    "red_sandstone",
+    "chiseled_red_sandstone",
+    "cut_red_sandstone",
+    "red_sandstone_stairs",
+    "repeating_command_block",
+    "chain_command_block",
+    "magma_block",
+    "nether_wart_block",
+    "red_nether_bricks",
+    "bone_block",
+    "structure_void",
+    "observer",
+    "shulker_box",
+    "white_shulker_box",
+    "orange_shulker_box",
+    "magenta_shulker_box",
+    "light_blue_shulker_box",
+    "yellow_shulker_box",
+    "lime_shulker_box",
+    "pink_shulker_box",
+    "gray_shulker_box",
+    "light_gray_shulker_box",
+    "cyan_shulker_box",
+    "purple_shulker_box",
+    "blue_shulker_box",
+    "brown_shulker_box",
+    "green_shulker_box",
+    "red_shulker_box",
+    "black_shulker_box",
+    "white_glazed_terracotta",
+    "orange_glazed_terracotta",
+    "magenta_glazed_terracotta",
+    "light_blue_glazed_terracotta",
+    "yellow_glazed_terracotta",
+    "lime_glazed_terracotta",
+    "pink_glazed_terracotta",
+    "gray_glazed_terracotta",
+    "light_gray_glazed_terracotta",
+    "cyan_glazed_terracotta",
+    "purple_glazed_terracotta",
+    "blue_glazed_terracotta",
+    "brown_glazed_terracotta",
+    "green_glazed_terracotta",
+    "red_glazed_terracotta",
+    "black_glazed_terracotta",
+    "white_concrete",
+    "orange_concrete",
+    "magenta_concrete",
+    "light_blue_concrete",
+    "yellow_concrete",
+    "lime_concrete",
+    "pink_concrete",
+    "gray_concrete",
+    "light_gray_concrete",
+    "cyan_concrete",
+    "purple_concrete",
+    "blue_concrete",
+    "brown_concrete",
+    "green_concrete",
+    "red_concrete",
+    "black_concrete",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+    "turtle_egg",
+    "dead_tube_coral_block",
+    "dead_brain_coral_block",
+    "dead_bubble_coral_block",
+    "dead_fire_coral_block",
+    "dead_horn_coral_block",
+    "tube_coral_block",
+    "brain_coral_block",
+    "bubble_coral_block",
+    "fire_coral_block",
+    "horn_coral_block",
+    "tube_coral",
+    "brain_coral",
+    "bubble_coral",
+    "fire_coral",
+    "horn_coral",
+    "dead_brain_coral",
+    "dead_bubble_coral",
+    "dead_fire_coral",
+    "dead_horn_coral",
+    "dead_tube_coral",
+    "tube_coral_fan",
+    "brain_coral_fan",
+    "bubble_coral_fan",
+    "fire_coral_fan",
+    "horn_coral_fan",
+    "dead_tube_coral_fan",
+    "dead_brain_coral_fan",
+    "dead_bubble_coral_fan",
+    "dead_fire_coral_fan",
+    "dead_horn_coral_fan",
+    "blue_ice",
+    "conduit",
+    "iron_door",
+    "oak_door",
+    "spruce_door",
+    "birch_door",
+    "jungle_door",
+    "acacia_door",
+    "dark_oak_door",
+    "repeater",
+    "comparator",
+    "structure_block",
+    "turtle_helmet",
+    "scute",
+    "iron_shovel",
+    "iron_pickaxe",
+    "iron_axe",
+    "flint_and_steel",
+    "apple",
+    "bow",
+    "arrow",
+    "coal",
+    "charcoal",
+    "diamond",
+    "iron_ingot",
+    "gold_ingot",
+    "iron_sword",
+    "wooden_sword",
+    "wooden_shovel",
+    "wooden_pickaxe",
+    "wooden_axe",
+    "stone_sword",
+    "stone_shovel",
+    "stone_pickaxe",
+    "stone_axe",
+    "diamond_sword",
+    "diamond_shovel",
+    "diamond_pickaxe",
+    "diamond_axe",
+    "stick",
+    "bowl",
+    "mushroom_stew",
+    "golden_sword",
+    "golden_shovel",
+    "golden_pickaxe",
+    "golden_axe",
+    "string",
+    "feather",
+    "gunpowder",
+    "wooden_hoe",
+    "stone_hoe",
+    "iron_hoe",
+    "diamond_hoe",
+    "golden_hoe",
+    "wheat_seeds",
+    "wheat",
+    "bread",
+    "leather_helmet",
+    "leather_chestplate",
+    "leather_leggings",
+    "leather_boots",
+    "chainmail_helmet",
+    "chainmail_chestplate",
+    "chainmail_leggings",
+    "chainmail_boots",
+    "iron_helmet",
+    "iron_chestplate",
+    "iron_leggings",
+    "iron_boots",
+    "diamond_helmet",
+    "diamond_chestplate",
+    "diamond_leggings",
+    "diamond_boots",
+    "golden_helmet",
+    "golden_chestplate",
+    "golden_leggings",
+    "golden_boots",
+    "flint",
+    "porkchop",
+    "cooked_porkchop",
+    "painting",
+    "golden_apple",
+    "enchanted_golden_apple",
+    "sign",
+    "bucket",
+    "water_bucket",
+    "lava_bucket",
+    "minecart",
+    "saddle",
+    "redstone",
+    "snowball",
+    "oak_boat",
+    "leather",
+    "milk_bucket",
+    "pufferfish_bucket",
+    "salmon_bucket",
+    "cod_bucket",
+    "tropical_fish_bucket",
+    "brick",
+    "clay_ball",
+    "sugar_cane",
+    "kelp",
+    "dried_kelp_block",
+    "paper",
+    "book",
+    "slime_ball",
+    "chest_minecart",
+    "furnace_minecart",
+    "egg",
+    "compass",
+    "fishing_rod",
+    "clock",
+    "glowstone_dust",
+    "cod",
+    "salmon",
+    "tropical_fish",
+    "pufferfish",
+    "cooked_cod",
+    "cooked_salmon",
+    "ink_sac",
+    "rose_red",
+    "cactus_green",
+    "cocoa_beans",
+    "lapis_lazuli",
+    "purple_dye",
+    "cyan_dye",
+    "light_gray_dye",
+    "gray_dye",
+    "pink_dye",
+    "lime_dye",
+    "dandelion_yellow",
+    "light_blue_dye",
+    "magenta_dye",
+    "orange_dye",
+    "bone_meal",
+    "bone",
+    "sugar",
+    "cake",
+    "white_bed",
+    "orange_bed",
+    "magenta_bed",
+    "light_blue_bed",
+    "yellow_bed",
+    "lime_bed",
+    "pink_bed",
+    "gray_bed",
+    "light_gray_bed",
+    "cyan_bed",
+    "purple_bed",
+    "blue_bed",
+    "brown_bed",
+    "green_bed",
+    "red_bed",
+    "black_bed",
+    "cookie",
+    "filled_map",
+    "shears",
+    "melon_slice",
+    "dried_kelp",
+    "pumpkin_seeds",
+    "melon_seeds",
+    "beef",
+    "cooked_beef",
+    "chicken",
+    "cooked_chicken",
+    "rotten_flesh",
+    "ender_pearl",
+    "blaze_rod",
+    "ghast_tear",
+    "gold_nugget",
+    "nether_wart",
+    "potion",
+    "glass_bottle",
+    "spider_eye",
+    "fermented_spider_eye",
+    "blaze_powder",
+    "magma_cream",
+    "brewing_stand",
+    "cauldron",
+    "ender_eye",
+    "glistering_melon_slice",
+    "bat_spawn_egg",
+    "blaze_spawn_egg",
+    "cave_spider_spawn_egg",
+    "chicken_spawn_egg",
+    "cod_spawn_egg",
+    "cow_spawn_egg",
+    "creeper_spawn_egg",
+    "dolphin_spawn_egg",
+    "donkey_spawn_egg",
+    "drowned_spawn_egg",
+    "elder_guardian_spawn_egg",
+    "enderman_spawn_egg",
+    "endermite_spawn_egg",
+    "evoker_spawn_egg",
+    "ghast_spawn_egg",
+    "guardian_spawn_egg",
+    "horse_spawn_egg",
+    "husk_spawn_egg",
+    "llama_spawn_egg",
+    "magma_cube_spawn_egg",
+    "mooshroom_spawn_egg",
+    "mule_spawn_egg",
+    "ocelot_spawn_egg",
+    "parrot_spawn_egg",
+    "phantom_spawn_egg",
+    "pig_spawn_egg",
+    "polar_bear_spawn_egg",
+    "pufferfish_spawn_egg",
+    "rabbit_spawn_egg",
+    "salmon_spawn_egg",
+    "sheep_spawn_egg",
+    "shulker_spawn_egg",
+    "silverfish_spawn_egg",
+    "skeleton_spawn_egg",
+    "skeleton_horse_spawn_egg",
+    "slime_spawn_egg",
+    "spider_spawn_egg",
+    "squid_spawn_egg",
+    "stray_spawn_egg",
+    "tropical_fish_spawn_egg",
+    "turtle_spawn_egg",
+    "vex_spawn_egg",
+    "villager_spawn_egg",
+    "vindicator_spawn_egg",
+    "witch_spawn_egg",
+    "wither_skeleton_spawn_egg",
+    "wolf_spawn_egg",
+    "zombie_spawn_egg",
+    "zombie_horse_spawn_egg",
+    "zombie_pigman_spawn_egg",
+    "zombie_villager_spawn_egg",
+    "experience_bottle",
+    "fire_charge",
+    "writable_book",
+    "written_book",
+    "emerald",
+    "item_frame",
+    "flower_pot",
+    "carrot",
+    "potato",
+    "baked_potato",
+    "poisonous_potato",
+    "map",
+    "golden_carrot",
+    "skeleton_skull",
+    "wither_skeleton_skull",
+    "player_head",
+    "zombie_head",
+    "creeper_head",
+    "dragon_head",
+    "carrot_on_a_stick",
+    "nether_star",
+    "pumpkin_pie",
+    "firework_rocket",
+    "firework_star",
+    "enchanted_book",
+    "nether_brick",
+    "quartz",
+    "tnt_minecart",
+    "hopper_minecart",
+    "prismarine_shard",
+    "prismarine_crystals",
+    "rabbit",
+    "cooked_rabbit",
+    "rabbit_stew",
+    "rabbit_foot",
+    "rabbit_hide",
+    "armor_stand",
+    "iron_horse_armor",
+    "golden_horse_armor",
+    "diamond_horse_armor",
+    "lead",
+    "name_tag",
+    "command_block_minecart",
+    "mutton",
+    "cooked_mutton",
+    "white_banner",
+    "orange_banner",
+    "magenta_banner",
+    "light_blue_banner",
+    "yellow_banner",
+    "lime_banner",
+    "pink_banner",
+    "gray_banner",
+    "light_gray_banner",
+    "cyan_banner",
+    "purple_banner",
+    "blue_banner",
+    "brown_banner",
+    "green_banner",
+    "red_banner",
+    "black_banner",
+    "end_crystal",
+    "chorus_fruit",
+    "popped_chorus_fruit",
+    "beetroot",
+    "beetroot_seeds",
+    "beetroot_soup",
+    "dragon_breath",
+    "splash_potion",
+    "spectral_arrow",
+    "tipped_arrow",
+    "lingering_potion",
+    "shield",
+    "elytra",
+    "spruce_boat",
+    "birch_boat",
+    "jungle_boat",
+    "acacia_boat",
+    "dark_oak_boat",
+    "totem_of_undying",
+    "shulker_shell",
+    "iron_nugget",
+    "knowledge_book",
+    "debug_stick",
+    "music_disc_13",
+    "music_disc_cat",
+    "music_disc_blocks",
+    "music_disc_chirp",
+    "music_disc_far",
+    "music_disc_mall",
+    "music_disc_mellohi",
+    "music_disc_stal",
+    "music_disc_strad",
+    "music_disc_ward",
+    "music_disc_11",
+    "music_disc_wait",
+    "trident",
+    "phantom_membrane",
+    "nautilus_shell",
+    "heart_of_the_sea",
+];
+
+const ITEMS_1_14: &[&str] = &[
+    "air",
+    "stone",
+    "granite",
+    "polished_granite",
+    "diorite",
+    "polished_diorite",
+    "andesite",
+    "polished_andesite",
+    "grass_block",
+    "dirt",
+    "coarse_dirt",
+    "podzol",
+    "cobblestone",
+    "oak_planks",
+    "spruce_planks",
+    "birch_planks",
+    "jungle_planks",
+    "acacia_planks",
+    "dark_oak_planks",
+    "oak_sapling",
+    "spruce_sapling",
+    "birch_sapling",
+    "jungle_sapling",
+    "acacia_sapling",
+    "dark_oak_sapling",
+    "bedrock",
+    "sand",
+    "red_sand",
+    "gravel",
+    "gold_ore",
+    "iron_ore",
+    "coal_ore",
+    "oak_log",
+    "spruce_log",
+    "birch_log",
+    "jungle_log",
+    "acacia_log",
+    "dark_oak_log",
+    "stripped_oak_log",
+    "stripped_spruce_log",
+    "stripped_birch_log",
+    "stripped_jungle_log",
+    "stripped_acacia_log",
+    "stripped_dark_oak_log",
+    "stripped_oak_wood",
+    "stripped_spruce_wood",
+    "stripped_birch_wood",
+    "stripped_jungle_wood",
+    "stripped_acacia_wood",
+    "stripped_dark_oak_wood",
+    "oak_wood",
+    "spruce_wood",
+    "birch_wood",
+    "jungle_wood",
+    "acacia_wood",
+    "dark_oak_wood",
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "sponge",
+    "wet_sponge",
+    "glass",
+    "lapis_ore",
+    "lapis_block",
+    "dispenser",
+    "sandstone",
+    "chiseled_sandstone",
+    "cut_sandstone",
+    "note_block",
+    "powered_rail",
+    "detector_rail",
+    "sticky_piston",
+    "cobweb",
+    "grass",
+    "fern",
+    "dead_bush",
+    "seagrass",
+    "sea_pickle",
+    "piston",
+    "white_wool",
+    "orange_wool",
+    "magenta_wool",
+    "light_blue_wool",
+    "yellow_wool",
+    "lime_wool",
+    "pink_wool",
+    "gray_wool",
+    "light_gray_wool",
+    "cyan_wool",
+    "purple_wool",
+    "blue_wool",
+    "brown_wool",
+    "green_wool",
+    "red_wool",
+    "black_wool",
+    "dandelion",
+    "poppy",
+    "blue_orchid",
+    "allium",
+    "azure_bluet",
+    "red_tulip",
+    "orange_tulip",
+    "white_tulip",
+    "pink_tulip",
+    "oxeye_daisy",
+    "cornflower",
+    "lily_of_the_valley",
+    "wither_rose",
+    "brown_mushroom",
+    "red_mushroom",
+    "gold_block",
+    "iron_block",
+    "oak_slab",
+    "spruce_slab",
+    "birch_slab",
+    "jungle_slab",
+    "acacia_slab",
+    "dark_oak_slab",
+    "stone_slab",
+    "smooth_stone_slab",
+    "sandstone_slab",
+    "cut_sandstone_slab",
+    "petrified_oak_slab",
+    "cobblestone_slab",
+    "brick_slab",
+    "stone_brick_slab",
+    "nether_brick_slab",
+    "quartz_slab",
+    "red_sandstone_slab",
+    "cut_red_sandstone_slab",
+    "purpur_slab",
+    "prismarine_slab",
+    "prismarine_brick_slab",
+    "dark_prismarine_slab",
+    "smooth_quartz",
+    "smooth_red_sandstone",
+    "smooth_sandstone",
+    "smooth_stone",
+    "bricks",
+    "tnt",
+    "bookshelf",
+    "mossy_cobblestone",
+    "obsidian",
+    "torch",
+    "end_rod",
+    "chorus_plant",
+    "chorus_flower",
+    "purpur_block",
+    "purpur_pillar",
+    "purpur_stairs",
+    "spawner",
+    "oak_stairs",
+    "chest",
+    "diamond_ore",
+    "diamond_block",
+    "crafting_table",
+    "farmland",
+    "furnace",
+    "ladder",
+    "rail",
+    "cobblestone_stairs",
+    "lever",
+    "stone_pressure_plate",
+    "oak_pressure_plate",
+    "spruce_pressure_plate",
+    "birch_pressure_plate",
+    "jungle_pressure_plate",
+    "acacia_pressure_plate",
+    "dark_oak_pressure_plate",
+    "redstone_ore",
+    "redstone_torch",
+    "snow",
+    "ice",
+    "snow_block",
+    "cactus",
+    "clay",
+    "jukebox",
+    "oak_fence",
+    "spruce_fence",
+    "birch_fence",
+    "jungle_fence",
+    "acacia_fence",
+    "dark_oak_fence",
+    "pumpkin",
+    "carved_pumpkin",
+    "netherrack",
+    "soul_sand",
+    "glowstone",
+    "jack_o_lantern",
+    "oak_trapdoor",
+    "spruce_trapdoor",
+    "birch_trapdoor",
+    "jungle_trapdoor",
+    "acacia_trapdoor",
+    "dark_oak_trapdoor",
+    "infested_stone",
+    "infested_cobblestone",
+    "infested_stone_bricks",
+    "infested_mossy_stone_bricks",
+    "infested_cracked_stone_bricks",
+    "infested_chiseled_stone_bricks",
+    "stone_bricks",
+    "mossy_stone_bricks",
+    "cracked_stone_bricks",
+    "chiseled_stone_bricks",
+    "brown_mushroom_block",
+    "red_mushroom_block",
+    "mushroom_stem",
+    "iron_bars",
+    "glass_pane",
+    "melon",
+    "vine",
+    "oak_fence_gate",
+    "spruce_fence_gate",
+    "birch_fence_gate",
+    "jungle_fence_gate",
+    "acacia_fence_gate",
+    "dark_oak_fence_gate",
+    "brick_stairs",
+    "stone_brick_stairs",
+    "mycelium",
+    "lily_pad",
+    "nether_bricks",
+    "nether_brick_fence",
+    "nether_brick_stairs",
+    "enchanting_table",
+    "end_portal_frame",
+    "end_stone",
+    "end_stone_bricks",
+    "dragon_egg",
+    "redstone_lamp",
+    "sandstone_stairs",
+    "emerald_ore",
+    "ender_chest",
+    "tripwire_hook",
+    "emerald_block",
+    "spruce_stairs",
+    "birch_stairs",
+    "jungle_stairs",
+    "command_block",
+    "beacon",
+    "cobblestone_wall",
+    "mossy_cobblestone_wall",
+    "brick_wall",
+    "prismarine_wall",
+    "red_sandstone_wall",
+    "mossy_stone_brick_wall",
+    "granite_wall",
+    "stone_brick_wall",
+    "nether_brick_wall",
+    "andesite_wall",
+    "red_nether_brick_wall",
+    "sandstone_wall",
+    "end_stone_brick_wall",
+    "diorite_wall",
+    "stone_button",
+    "oak_button",
+    "spruce_button",
+    "birch_button",
+    "jungle_button",
+    "acacia_button",
+    "dark_oak_button",
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "trapped_chest",
+    "light_weighted_pressure_plate",
+    "heavy_weighted_pressure_plate",
+    "daylight_detector",
+    "redstone_block",
+    "nether_quartz_ore",
+    "hopper",
+    "chiseled_quartz_block",
+    "quartz_block",
+    "quartz_pillar",
+    "quartz_stairs",
+    "activator_rail",
+    "dropper",
+    "white_terracotta",
+    "orange_terracotta",
+    "magenta_terracotta",
+    "light_blue_terracotta",
+    "yellow_terracotta",
+    "lime_terracotta",
+    "pink_terracotta",
+    "gray_terracotta",
+    "light_gray_terracotta",
+    "cyan_terracotta",
+    "purple_terracotta",
+    "blue_terracotta",
+    "brown_terracotta",
+    "green_terracotta",
+    "red_terracotta",
+    "black_terracotta",
+    "barrier",
+    "iron_trapdoor",
+    "hay_block",
+    "white_carpet",
+    "orange_carpet",
+    "magenta_carpet",
+    "light_blue_carpet",
+    "yellow_carpet",
+    "lime_carpet",
+    "pink_carpet",
+    "gray_carpet",
+    "light_gray_carpet",
+    "cyan_carpet",
+    "purple_carpet",
+    "blue_carpet",
+    "brown_carpet",
+    "green_carpet",
+    "red_carpet",
+    "black_carpet",
+    "terracotta",
+    "coal_block",
+    "packed_ice",
+    "acacia_stairs",
+    "dark_oak_stairs",
+    "slime_block",
+    "grass_path",
+    "sunflower",
+    "lilac",
+    "rose_bush",
+    "peony",
+    "tall_grass",
+    "large_fern",
+    "white_stained_glass",
+    "orange_stained_glass",
+    "magenta_stained_glass",
+    "light_blue_stained_glass",
+    "yellow_stained_glass",
+    "lime_stained_glass",
+    "pink_stained_glass",
+    "gray_stained_glass",
+    "light_gray_stained_glass",
+    "cyan_stained_glass",
+    "purple_stained_glass",
+    "blue_stained_glass",
+    "brown_stained_glass",
+    "green_stained_glass",
+    "red_stained_glass",
+    "black_stained_glass",
+    "white_stained_glass_pane",
+    "orange_stained_glass_pane",
+    "magenta_stained_glass_pane",
+    "light_blue_stained_glass_pane",
+    "yellow_stained_glass_pane",
+    "lime_stained_glass_pane",
+    "pink_stained_glass_pane",
+    "gray_stained_glass_pane",
+    "light_gray_stained_glass_pane",
+    "cyan_stained_glass_pane",
+    "purple_stained_glass_pane",
+    "blue_stained_glass_pane",
+    "brown_stained_glass_pane",
+    "green_stained_glass_pane",
+    "red_stained_glass_pane",
+    "black_stained_glass_pane",
+    "prismarine",
+    "prismarine_bricks",
+    "dark_prismarine",
+    "prismarine_stairs",
+    "prismarine_brick_stairs",
+    "dark_prismarine_stairs",
+    "sea_lantern",
+    "red_sandstone",
+    "chiseled_red_sandstone",
+    "cut_red_sandstone",
+    "red_sandstone_stairs",
+    "repeating_command_block",
+    "chain_command_block",
+    "magma_block",
+    "nether_wart_block",
+    "red_nether_bricks",
+    "bone_block",
+    "structure_void",
+    "observer",
+    "shulker_box",
+    "white_shulker_box",
+    "orange_shulker_box",
+    "magenta_shulker_box",
+    "light_blue_shulker_box",
+    "yellow_shulker_box",
+    "lime_shulker_box",
+    "pink_shulker_box",
+    "gray_shulker_box",
+    "light_gray_shulker_box",
+    "cyan_shulker_box",
+    "purple_shulker_box",
+    "blue_shulker_box",
+    "brown_shulker_box",
+    "green_shulker_box",
+    "red_shulker_box",
+    "black_shulker_box",
+    "white_glazed_terracotta",
+    "orange_glazed_terracotta",
+    "magenta_glazed_terracotta",
+    "light_blue_glazed_terracotta",
+    "yellow_glazed_terracotta",
+    "lime_glazed_terracotta",
+    "pink_glazed_terracotta",
+    "gray_glazed_terracotta",
+    "light_gray_glazed_terracotta",
+    "cyan_glazed_terracotta",
+    "purple_glazed_terracotta",
+    "blue_glazed_terracotta",
+    "brown_glazed_terracotta",
+    "green_glazed_terracotta",
+    "red_glazed_terracotta",
+    "black_glazed_terracotta",
+    "white_concrete",
+    "orange_concrete",
+    "magenta_concrete",
+    "light_blue_concrete",
+    "yellow_concrete",
+    "lime_concrete",
+    "pink_concrete",
+    "gray_concrete",
+    "light_gray_concrete",
+    "cyan_concrete",
+    "purple_concrete",
+    "blue_concrete",
+    "brown_concrete",
+    "green_concrete",
+    "red_concrete",
+    "black_concrete",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+    "turtle_egg",
+    "dead_tube_coral_block",
+    "dead_brain_coral_block",
+    "dead_bubble_coral_block",
+    "dead_fire_coral_block",
+    "dead_horn_coral_block",
+    "tube_coral_block",
+    "brain_coral_block",
+    "bubble_coral_block",
+    "fire_coral_block",
+    "horn_coral_block",
+    "tube_coral",
+    "brain_coral",
+    "bubble_coral",
+    "fire_coral",
+    "horn_coral",
+    "dead_brain_coral",
+    "dead_bubble_coral",
+    "dead_fire_coral",
+    "dead_horn_coral",
+    "dead_tube_coral",
+    "tube_coral_fan",
+    "brain_coral_fan",
+    "bubble_coral_fan",
+    "fire_coral_fan",
+    "horn_coral_fan",
+    "dead_tube_coral_fan",
+    "dead_brain_coral_fan",
+    "dead_bubble_coral_fan",
+    "dead_fire_coral_fan",
+    "dead_horn_coral_fan",
+    "blue_ice",
+    "conduit",
+    "polished_granite_stairs",
+    "smooth_red_sandstone_stairs",
+    "mossy_stone_brick_stairs",
+    "polished_diorite_stairs",
+    "mossy_cobblestone_stairs",
+    "end_stone_brick_stairs",
+    "stone_stairs",
+    "smooth_sandstone_stairs",
+    "smooth_quartz_stairs",
+    "granite_stairs",
+    "andesite_stairs",
+    "red_nether_brick_stairs",
+    "polished_andesite_stairs",
+    "diorite_stairs",
+    "polished_granite_slab",
+    "smooth_red_sandstone_slab",
+    "mossy_stone_brick_slab",
+    "polished_diorite_slab",
+    "mossy_cobblestone_slab",
+    "end_stone_brick_slab",
+    "smooth_sandstone_slab",
+    "smooth_quartz_slab",
+    "granite_slab",
+    "andesite_slab",
+    "red_nether_brick_slab",
+    "polished_andesite_slab",
+    "diorite_slab",
+    "scaffolding",
+    "iron_door",
+    "oak_door",
+    "spruce_door",
+    "birch_door",
+    "jungle_door",
+    "acacia_door",
+    "dark_oak_door",
+    "repeater",
+    "comparator",
+    "structure_block",
+    "jigsaw",
+    "composter",
+    "turtle_helmet",
+    "scute",
+    "iron_shovel",
+    "iron_pickaxe",
+    "iron_axe",
+    "flint_and_steel",
+    "apple",
+    "bow",
+    "arrow",
+    "coal",
+    "charcoal",
+    "diamond",
+    "iron_ingot",
+    "gold_ingot",
+    "iron_sword",
+    "wooden_sword",
+    "wooden_shovel",
+    "wooden_pickaxe",
+    "wooden_axe",
+    "stone_sword",
+    "stone_shovel",
+    "stone_pickaxe",
+    "stone_axe",
+    "diamond_sword",
+    "diamond_shovel",
+    "diamond_pickaxe",
+    "diamond_axe",
+    "stick",
+    "bowl",
+    "mushroom_stew",
+    "golden_sword",
+    "golden_shovel",
+    "golden_pickaxe",
+    "golden_axe",
+    "string",
+    "feather",
+    "gunpowder",
+    "wooden_hoe",
+    "stone_hoe",
+    "iron_hoe",
+    "diamond_hoe",
+    "golden_hoe",
+    "wheat_seeds",
+    "wheat",
+    "bread",
+    "leather_helmet",
+    "leather_chestplate",
+    "leather_leggings",
+    "leather_boots",
+    "chainmail_helmet",
+    "chainmail_chestplate",
+    "chainmail_leggings",
+    "chainmail_boots",
+    "iron_helmet",
+    "iron_chestplate",
+    "iron_leggings",
+    "iron_boots",
+    "diamond_helmet",
+    "diamond_chestplate",
+    "diamond_leggings",
+    "diamond_boots",
+    "golden_helmet",
+    "golden_chestplate",
+    "golden_leggings",
+    "golden_boots",
+    "flint",
+    "porkchop",
+    "cooked_porkchop",
+    "painting",
+    "golden_apple",
+    "enchanted_golden_apple",
+    "oak_sign",
+    "spruce_sign",
+    "birch_sign",
+    "jungle_sign",
+    "acacia_sign",
+    "dark_oak_sign",
+    "bucket",
+    "water_bucket",
+    "lava_bucket",
+    "minecart",
+    "saddle",
+    "redstone",
+    "snowball",
+    "oak_boat",
+    "leather",
+    "milk_bucket",
+    "pufferfish_bucket",
+    "salmon_bucket",
+    "cod_bucket",
+    "tropical_fish_bucket",
+    "brick",
+    "clay_ball",
+    "sugar_cane",
+    "kelp",
+    "dried_kelp_block",
+    "bamboo",
+    "paper",
+    "book",
+    "slime_ball",
+    "chest_minecart",
+    "furnace_minecart",
+    "egg",
+    "compass",
+    "fishing_rod",
+    "clock",
+    "glowstone_dust",
+    "cod",
+    "salmon",
+    "tropical_fish",
+    "pufferfish",
+    "cooked_cod",
+    "cooked_salmon",
+    "ink_sac",
+    "red_dye",
+    "green_dye",
+    "cocoa_beans",
+    "lapis_lazuli",
+    "purple_dye",
+    "cyan_dye",
+    "light_gray_dye",
+    "gray_dye",
+    "pink_dye",
+    "lime_dye",
+    "yellow_dye",
+    "light_blue_dye",
+    "magenta_dye",
+    "orange_dye",
+    "bone_meal",
+    "blue_dye",
+    "brown_dye",
+    "black_dye",
+    "white_dye",
+    "bone",
+    "sugar",
+    "cake",
+    "white_bed",
+    "orange_bed",
+    "magenta_bed",
+    "light_blue_bed",
+    "yellow_bed",
+    "lime_bed",
+    "pink_bed",
+    "gray_bed",
+    "light_gray_bed",
+    "cyan_bed",
+    "purple_bed",
+    "blue_bed",
+    "brown_bed",
+    "green_bed",
+    "red_bed",
+    "black_bed",
+    "cookie",
+    "filled_map",
+    "shears",
+    "melon_slice",
+    "dried_kelp",
+    "pumpkin_seeds",
+    "melon_seeds",
+    "beef",
+    "cooked_beef",
+    "chicken",
+    "cooked_chicken",
+    "rotten_flesh",
+    "ender_pearl",
+    "blaze_rod",
+    "ghast_tear",
+    "gold_nugget",
+    "nether_wart",
+    "potion",
+    "glass_bottle",
+    "spider_eye",
+    "fermented_spider_eye",
+    "blaze_powder",
+    "magma_cream",
+    "brewing_stand",
+    "cauldron",
+    "ender_eye",
+    "glistering_melon_slice",
+    "bat_spawn_egg",
+    "blaze_spawn_egg",
+    "cat_spawn_egg",
+    "cave_spider_spawn_egg",
+    "chicken_spawn_egg",
+    "cod_spawn_egg",
+    "cow_spawn_egg",
+    "creeper_spawn_egg",
+    "dolphin_spawn_egg",
+    "donkey_spawn_egg",
+    "drowned_spawn_egg",
+    "elder_guardian_spawn_egg",
+    "enderman_spawn_egg",
+    "endermite_spawn_egg",
+    "evoker_spawn_egg",
+    "fox_spawn_egg",
+    "ghast_spawn_egg",
+    "guardian_spawn_egg",
+    "horse_spawn_egg",
+    "husk_spawn_egg",
+    "llama_spawn_egg",
+    "magma_cube_spawn_egg",
+    "mooshroom_spawn_egg",
+    "mule_spawn_egg",
+    "ocelot_spawn_egg",
+    "panda_spawn_egg",
+    "parrot_spawn_egg",
+    "phantom_spawn_egg",
+    "pig_spawn_egg",
+    "pillager_spawn_egg",
+    "polar_bear_spawn_egg",
+    "pufferfish_spawn_egg",
+    "rabbit_spawn_egg",
+    "ravager_spawn_egg",
+    "salmon_spawn_egg",
+    "sheep_spawn_egg",
+    "shulker_spawn_egg",
+    "silverfish_spawn_egg",
+    "skeleton_spawn_egg",
+    "skeleton_horse_spawn_egg",
+    "slime_spawn_egg",
+    "spider_spawn_egg",
+    "squid_spawn_egg",
+    "stray_spawn_egg",
+    "trader_llama_spawn_egg",
+    "tropical_fish_spawn_egg",
+    "turtle_spawn_egg",
+    "vex_spawn_egg",
+    "villager_spawn_egg",
+    "vindicator_spawn_egg",
+    "wandering_trader_spawn_egg",
+    "witch_spawn_egg",
+    "wither_skeleton_spawn_egg",
+    "wolf_spawn_egg",
+    "zombie_spawn_egg",
+    "zombie_horse_spawn_egg",
+    "zombie_pigman_spawn_egg",
+    "zombie_villager_spawn_egg",
+    "experience_bottle",
+    "fire_charge",
+    "writable_book",
+    "written_book",
+    "emerald",
+    "item_frame",
+    "flower_pot",
+    "carrot",
+    "potato",
+    "baked_potato",
+    "poisonous_potato",
+    "map",
+    "golden_carrot",
+    "skeleton_skull",
+    "wither_skeleton_skull",
+    "player_head",
+    "zombie_head",
+    "creeper_head",
+    "dragon_head",
+    "carrot_on_a_stick",
+    "nether_star",
+    "pumpkin_pie",
+    "firework_rocket",
+    "firework_star",
+    "enchanted_book",
+    "nether_brick",
+    "quartz",
+    "tnt_minecart",
+    "hopper_minecart",
+    "prismarine_shard",
+    "prismarine_crystals",
+    "rabbit",
+    "cooked_rabbit",
+    "rabbit_stew",
+    "rabbit_foot",
+    "rabbit_hide",
+    "armor_stand",
+    "iron_horse_armor",
+    "golden_horse_armor",
+    "diamond_horse_armor",
+    "leather_horse_armor",
+    "lead",
+    "name_tag",
+    "command_block_minecart",
+    "mutton",
+    "cooked_mutton",
+    "white_banner",
+    "orange_banner",
+    "magenta_banner",
+    "light_blue_banner",
+    "yellow_banner",
+    "lime_banner",
+    "pink_banner",
+    "gray_banner",
+    "light_gray_banner",
+    "cyan_banner",
+    "purple_banner",
+    "blue_banner",
+    "brown_banner",
+    "green_banner",
+    "red_banner",
+    "black_banner",
+    "end_crystal",
+    "chorus_fruit",
+    "popped_chorus_fruit",
+    "beetroot",
+    "beetroot_seeds",
+    "beetroot_soup",
+    "dragon_breath",
+    "splash_potion",
+    "spectral_arrow",
+    "tipped_arrow",
+    "lingering_potion",
+    "shield",
+    "elytra",
+    "spruce_boat",
+    "birch_boat",
+    "jungle_boat",
+    "acacia_boat",
+    "dark_oak_boat",
+    "totem_of_undying",
+    "shulker_shell",
+    "iron_nugget",
+    "knowledge_book",
+    "debug_stick",
+    "music_disc_13",
+    "music_disc_cat",
+    "music_disc_blocks",
+    "music_disc_chirp",
+    "music_disc_far",
+    "music_disc_mall",
+    "music_disc_mellohi",
+    "music_disc_stal",
+    "music_disc_strad",
+    "music_disc_ward",
+    "music_disc_11",
+    "music_disc_wait",
+    "trident",
+    "phantom_membrane",
+    "nautilus_shell",
+    "heart_of_the_sea",
+    "crossbow",
+    "suspicious_stew",
+    "loom",
+    "flower_banner_pattern",
+    "creeper_banner_pattern",
+    "skull_banner_pattern",
+    "mojang_banner_pattern",
+    "globe_banner_pattern",
+    "barrel",
+    "smoker",
+    "blast_furnace",
+    "cartography_table",
+    "fletching_table",
+    "grindstone",
+    "lectern",
+    "smithing_table",
+    "stonecutter",
+    "bell",
+    "lantern",
+    "sweet_berries",
+    "campfire",
+];
+
+const ITEMS_1_15: &[&str] = &[
+    "air",
+    "stone",
+    "granite",
+    "polished_granite",
+    "diorite",
+    "polished_diorite",
+    "andesite",
+    "polished_andesite",
+    "grass_block",
+    "dirt",
+    "coarse_dirt",
+    "podzol",
+    "cobblestone",
+    "oak_planks",
+    "spruce_planks",
+    "birch_planks",
+    "jungle_planks",
+    "acacia_planks",
+    "dark_oak_planks",
+    "oak_sapling",
+    "spruce_sapling",
+    "birch_sapling",
+    "jungle_sapling",
+    "acacia_sapling",
+    "dark_oak_sapling",
+    "bedrock",
+    "sand",
+    "red_sand",
+    "gravel",
+    "gold_ore",
+    "iron_ore",
+    "coal_ore",
+    "oak_log",
+    "spruce_log",
+    "birch_log",
+    "jungle_log",
+    "acacia_log",
+    "dark_oak_log",
+    "stripped_oak_log",
+    "stripped_spruce_log",
+    "stripped_birch_log",
+    "stripped_jungle_log",
+    "stripped_acacia_log",
+    "stripped_dark_oak_log",
+    "stripped_oak_wood",
+    "stripped_spruce_wood",
+    "stripped_birch_wood",
+    "stripped_jungle_wood",
+    "stripped_acacia_wood",
+    "stripped_dark_oak_wood",
+    "oak_wood",
+    "spruce_wood",
+    "birch_wood",
+    "jungle_wood",
+    "acacia_wood",
+    "dark_oak_wood",
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "sponge",
+    "wet_sponge",
+    "glass",
+    "lapis_ore",
+    "lapis_block",
+    "dispenser",
+    "sandstone",
+    "chiseled_sandstone",
+    "cut_sandstone",
+    "note_block",
+    "powered_rail",
+    "detector_rail",
+    "sticky_piston",
+    "cobweb",
+    "grass",
+    "fern",
+    "dead_bush",
+    "seagrass",
+    "sea_pickle",
+    "piston",
+    "white_wool",
+    "orange_wool",
+    "magenta_wool",
+    "light_blue_wool",
+    "yellow_wool",
+    "lime_wool",
+    "pink_wool",
+    "gray_wool",
+    "light_gray_wool",
+    "cyan_wool",
+    "purple_wool",
+    "blue_wool",
+    "brown_wool",
+    "green_wool",
+    "red_wool",
+    "black_wool",
+    "dandelion",
+    "poppy",
+    "blue_orchid",
+    "allium",
+    "azure_bluet",
+    "red_tulip",
+    "orange_tulip",
+    "white_tulip",
+    "pink_tulip",
+    "oxeye_daisy",
+    "cornflower",
+    "lily_of_the_valley",
+    "wither_rose",
+    "brown_mushroom",
+    "red_mushroom",
+    "gold_block",
+    "iron_block",
+    "oak_slab",
+    "spruce_slab",
+    "birch_slab",
+    "jungle_slab",
+    "acacia_slab",
+    "dark_oak_slab",
+    "stone_slab",
+    "smooth_stone_slab",
+    "sandstone_slab",
+    "cut_sandstone_slab",
+    "petrified_oak_slab",
+    "cobblestone_slab",
+    "brick_slab",
+    "stone_brick_slab",
+    "nether_brick_slab",
+    "quartz_slab",
+    "red_sandstone_slab",
+    "cut_red_sandstone_slab",
+    "purpur_slab",
+    "prismarine_slab",
+    "prismarine_brick_slab",
+    "dark_prismarine_slab",
+    "smooth_quartz",
+    "smooth_red_sandstone",
+    "smooth_sandstone",
+    "smooth_stone",
+    "bricks",
+    "tnt",
+    "bookshelf",
+    "mossy_cobblestone",
+    "obsidian",
+    "torch",
+    "end_rod",
+    "chorus_plant",
+    "chorus_flower",
+    "purpur_block",
+    "purpur_pillar",
+    "purpur_stairs",
+    "spawner",
+    "oak_stairs",
+    "chest",
+    "diamond_ore",
+    "diamond_block",
+    "crafting_table",
+    "farmland",
+    "furnace",
+    "ladder",
+    "rail",
+    "cobblestone_stairs",
+    "lever",
+    "stone_pressure_plate",
+    "oak_pressure_plate",
+    "spruce_pressure_plate",
+    "birch_pressure_plate",
+    "jungle_pressure_plate",
+    "acacia_pressure_plate",
+    "dark_oak_pressure_plate",
+    "redstone_ore",
+    "redstone_torch",
+    "snow",
+    "ice",
+    "snow_block",
+    "cactus",
+    "clay",
+    "jukebox",
+    "oak_fence",
+    "spruce_fence",
+    "birch_fence",
+    "jungle_fence",
+    "acacia_fence",
+    "dark_oak_fence",
+    "pumpkin",
+    "carved_pumpkin",
+    "netherrack",
+    "soul_sand",
+    "glowstone",
+    "jack_o_lantern",
+    "oak_trapdoor",
+    "spruce_trapdoor",
+    "birch_trapdoor",
+    "jungle_trapdoor",
+    "acacia_trapdoor",
+    "dark_oak_trapdoor",
+    "infested_stone",
+    "infested_cobblestone",
+    "infested_stone_bricks",
+    "infested_mossy_stone_bricks",
+    "infested_cracked_stone_bricks",
+    "infested_chiseled_stone_bricks",
+    "stone_bricks",
+    "mossy_stone_bricks",
+    "cracked_stone_bricks",
+    "chiseled_stone_bricks",
+    "brown_mushroom_block",
+    "red_mushroom_block",
+    "mushroom_stem",
+    "iron_bars",
+    "glass_pane",
+    "melon",
+    "vine",
+    "oak_fence_gate",
+    "spruce_fence_gate",
+    "birch_fence_gate",
+    "jungle_fence_gate",
+    "acacia_fence_gate",
+    "dark_oak_fence_gate",
+    "brick_stairs",
+    "stone_brick_stairs",
+    "mycelium",
+    "lily_pad",
+    "nether_bricks",
+    "nether_brick_fence",
+    "nether_brick_stairs",
+    "enchanting_table",
+    "end_portal_frame",
+    "end_stone",
+    "end_stone_bricks",
+    "dragon_egg",
+    "redstone_lamp",
+    "sandstone_stairs",
+    "emerald_ore",
+    "ender_chest",
+    "tripwire_hook",
+    "emerald_block",
+    "spruce_stairs",
+    "birch_stairs",
+    "jungle_stairs",
+    "command_block",
+    "beacon",
+    "cobblestone_wall",
+    "mossy_cobblestone_wall",
+    "brick_wall",
+    "prismarine_wall",
+    "red_sandstone_wall",
+    "mossy_stone_brick_wall",
+    "granite_wall",
+    "stone_brick_wall",
+    "nether_brick_wall",
+    "andesite_wall",
+    "red_nether_brick_wall",
+    "sandstone_wall",
+    "end_stone_brick_wall",
+    "diorite_wall",
+    "stone_button",
+    "oak_button",
+    "spruce_button",
+    "birch_button",
+    "jungle_button",
+    "acacia_button",
+    "dark_oak_button",
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "trapped_chest",
+    "light_weighted_pressure_plate",
+    "heavy_weighted_pressure_plate",
+    "daylight_detector",
+    "redstone_block",
+    "nether_quartz_ore",
+    "hopper",
+    "chiseled_quartz_block",
+    "quartz_block",
+    "quartz_pillar",
+    "quartz_stairs",
+    "activator_rail",
+    "dropper",
+    "white_terracotta",
+    "orange_terracotta",
+    "magenta_terracotta",
+    "light_blue_terracotta",
+    "yellow_terracotta",
+    "lime_terracotta",
+    "pink_terracotta",
+    "gray_terracotta",
+    "light_gray_terracotta",
+    "cyan_terracotta",
+    "purple_terracotta",
+    "blue_terracotta",
+    "brown_terracotta",
+    "green_terracotta",
+    "red_terracotta",
+    "black_terracotta",
+    "barrier",
+    "iron_trapdoor",
+    "hay_block",
+    "white_carpet",
+    "orange_carpet",
+    "magenta_carpet",
+    "light_blue_carpet",
+    "yellow_carpet",
+    "lime_carpet",
+    "pink_carpet",
+    "gray_carpet",
+    "light_gray_carpet",
+    "cyan_carpet",
+    "purple_carpet",
+    "blue_carpet",
+    "brown_carpet",
+    "green_carpet",
+    "red_carpet",
+    "black_carpet",
+    "terracotta",
+    "coal_block",
+    "packed_ice",
+    "acacia_stairs",
+    "dark_oak_stairs",
+    "slime_block",
+    "grass_path",
+    "sunflower",
+    "lilac",
+    "rose_bush",
+    "peony",
+    "tall_grass",
+    "large_fern",
+    "white_stained_glass",
+    "orange_stained_glass",
+    "magenta_stained_glass",
+    "light_blue_stained_glass",
+    "yellow_stained_glass",
+    "lime_stained_glass",
+    "pink_stained_glass",
+    "gray_stained_glass",
+    "light_gray_stained_glass",
+    "cyan_stained_glass",
+    "purple_stained_glass",
+    "blue_stained_glass",
+    "brown_stained_glass",
+    "green_stained_glass",
+    "red_stained_glass",
+    "black_stained_glass",
+    "white_stained_glass_pane",
+    "orange_stained_glass_pane",
+    "magenta_stained_glass_pane",
+    "light_blue_stained_glass_pane",
+    "yellow_stained_glass_pane",
+    "lime_stained_glass_pane",
+    "pink_stained_glass_pane",
+    "gray_stained_glass_pane",
+    "light_gray_stained_glass_pane",
+    "cyan_stained_glass_pane",
+    "purple_stained_glass_pane",
+    "blue_stained_glass_pane",
+    "brown_stained_glass_pane",
+    "green_stained_glass_pane",
+    "red_stained_glass_pane",
+    "black_stained_glass_pane",
+    "prismarine",
+    "prismarine_bricks",
+    "dark_prismarine",
+    "prismarine_stairs",
+    "prismarine_brick_stairs",
+    "dark_prismarine_stairs",
+    "sea_lantern",
+    "red_sandstone",
+    "chiseled_red_sandstone",
+    "cut_red_sandstone",
+    "red_sandstone_stairs",
+    "repeating_command_block",
+    "chain_command_block",
+    "magma_block",
+    "nether_wart_block",
+    "red_nether_bricks",
+    "bone_block",
+    "structure_void",
+    "observer",
+    "shulker_box",
+    "white_shulker_box",
+    "orange_shulker_box",
+    "magenta_shulker_box",
+    "light_blue_shulker_box",
+    "yellow_shulker_box",
+    "lime_shulker_box",
+    "pink_shulker_box",
+    "gray_shulker_box",
+    "light_gray_shulker_box",
+    "cyan_shulker_box",
+    "purple_shulker_box",
+    "blue_shulker_box",
+    "brown_shulker_box",
+    "green_shulker_box",
+    "red_shulker_box",
+    "black_shulker_box",
+    "white_glazed_terracotta",
+    "orange_glazed_terracotta",
+    "magenta_glazed_terracotta",
+    "light_blue_glazed_terracotta",
+    "yellow_glazed_terracotta",
+    "lime_glazed_terracotta",
+    "pink_glazed_terracotta",
+    "gray_glazed_terracotta",
+    "light_gray_glazed_terracotta",
+    "cyan_glazed_terracotta",
+    "purple_glazed_terracotta",
+    "blue_glazed_terracotta",
+    "brown_glazed_terracotta",
+    "green_glazed_terracotta",
+    "red_glazed_terracotta",
+    "black_glazed_terracotta",
+    "white_concrete",
+    "orange_concrete",
+    "magenta_concrete",
+    "light_blue_concrete",
+    "yellow_concrete",
+    "lime_concrete",
+    "pink_concrete",
+    "gray_concrete",
+    "light_gray_concrete",
+    "cyan_concrete",
+    "purple_concrete",
+    "blue_concrete",
+    "brown_concrete",
+    "green_concrete",
+    "red_concrete",
+    "black_concrete",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+    "turtle_egg",
+    "dead_tube_coral_block",
+    "dead_brain_coral_block",
+    "dead_bubble_coral_block",
+    "dead_fire_coral_block",
+    "dead_horn_coral_block",
+    "tube_coral_block",
+    "brain_coral_block",
+    "bubble_coral_block",
+    "fire_coral_block",
+    "horn_coral_block",
+    "tube_coral",
+    "brain_coral",
+    "bubble_coral",
+    "fire_coral",
+    "horn_coral",
+    "dead_brain_coral",
+    "dead_bubble_coral",
+    "dead_fire_coral",
+    "dead_horn_coral",
+    "dead_tube_coral",
+    "tube_coral_fan",
+    "brain_coral_fan",
+    "bubble_coral_fan",
+    "fire_coral_fan",
+    "horn_coral_fan",
+    "dead_tube_coral_fan",
+    "dead_brain_coral_fan",
+    "dead_bubble_coral_fan",
+    "dead_fire_coral_fan",
+    "dead_horn_coral_fan",
+    "blue_ice",
+    "conduit",
+    "polished_granite_stairs",
+    "smooth_red_sandstone_stairs",
+    "mossy_stone_brick_stairs",
+    "polished_diorite_stairs",
+    "mossy_cobblestone_stairs",
+    "end_stone_brick_stairs",
+    "stone_stairs",
+    "smooth_sandstone_stairs",
+    "smooth_quartz_stairs",
+    "granite_stairs",
+    "andesite_stairs",
+    "red_nether_brick_stairs",
+    "polished_andesite_stairs",
+    "diorite_stairs",
+    "polished_granite_slab",
+    "smooth_red_sandstone_slab",
+    "mossy_stone_brick_slab",
+    "polished_diorite_slab",
+    "mossy_cobblestone_slab",
+    "end_stone_brick_slab",
+    "smooth_sandstone_slab",
+    "smooth_quartz_slab",
+    "granite_slab",
+    "andesite_slab",
+    "red_nether_brick_slab",
+    "polished_andesite_slab",
+    "diorite_slab",
+    "scaffolding",
+    "iron_door",
+    "oak_door",
+    "spruce_door",
+    "birch_door",
+    "jungle_door",
+    "acacia_door",
+    "dark_oak_door",
+    "repeater",
+    "comparator",
+    "structure_block",
+    "jigsaw",
+    "composter",
+    "turtle_helmet",
+    "scute",
+    "iron_shovel",
+    "iron_pickaxe",
+    "iron_axe",
+    "flint_and_steel",
+    "apple",
+    "bow",
+    "arrow",
+    "coal",
+    "charcoal",
+    "diamond",
+    "iron_ingot",
+    "gold_ingot",
+    "iron_sword",
+    "wooden_sword",
+    "wooden_shovel",
+    "wooden_pickaxe",
+    "wooden_axe",
+    "stone_sword",
+    "stone_shovel",
+    "stone_pickaxe",
+    "stone_axe",
+    "diamond_sword",
+    "diamond_shovel",
+    "diamond_pickaxe",
+    "diamond_axe",
+    "stick",
+    "bowl",
+    "mushroom_stew",
+    "golden_sword",
+    "golden_shovel",
+    "golden_pickaxe",
+    "golden_axe",
+    "string",
+    "feather",
+    "gunpowder",
+    "wooden_hoe",
+    "stone_hoe",
+    "iron_hoe",
+    "diamond_hoe",
+    "golden_hoe",
+    "wheat_seeds",
+    "wheat",
+    "bread",
+    "leather_helmet",
+    "leather_chestplate",
+    "leather_leggings",
+    "leather_boots",
+    "chainmail_helmet",
+    "chainmail_chestplate",
+    "chainmail_leggings",
+    "chainmail_boots",
+    "iron_helmet",
+    "iron_chestplate",
+    "iron_leggings",
+    "iron_boots",
+    "diamond_helmet",
+    "diamond_chestplate",
+    "diamond_leggings",
+    "diamond_boots",
+    "golden_helmet",
+    "golden_chestplate",
+    "golden_leggings",
+    "golden_boots",
+    "flint",
+    "porkchop",
+    "cooked_porkchop",
+    "painting",
+    "golden_apple",
+    "enchanted_golden_apple",
+    "oak_sign",
+    "spruce_sign",
+    "birch_sign",
+    "jungle_sign",
+    "acacia_sign",
+    "dark_oak_sign",
+    "bucket",
+    "water_bucket",
+    "lava_bucket",
+    "minecart",
+    "saddle",
+    "redstone",
+    "snowball",
+    "oak_boat",
+    "leather",
+    "milk_bucket",
+    "pufferfish_bucket",
+    "salmon_bucket",
+    "cod_bucket",
+    "tropical_fish_bucket",
+    "brick",
+    "clay_ball",
+    "sugar_cane",
+    "kelp",
+    "dried_kelp_block",
+    "bamboo",
+    "paper",
+    "book",
+    "slime_ball",
+    "chest_minecart",
+    "furnace_minecart",
+    "egg",
+    "compass",
+    "fishing_rod",
+    "clock",
+    "glowstone_dust",
+    "cod",
+    "salmon",
+    "tropical_fish",
+    "pufferfish",
+    "cooked_cod",
+    "cooked_salmon",
+    "ink_sac",
+    "red_dye",
+    "green_dye",
+    "cocoa_beans",
+    "lapis_lazuli",
+    "purple_dye",
+    "cyan_dye",
+    "light_gray_dye",
+    "gray_dye",
+    "pink_dye",
+    "lime_dye",
+    "yellow_dye",
+    "light_blue_dye",
+    "magenta_dye",
+    "orange_dye",
+    "bone_meal",
+    "blue_dye",
+    "brown_dye",
+    "black_dye",
+    "white_dye",
+    "bone",
+    "sugar",
+    "cake",
+    "white_bed",
+    "orange_bed",
+    "magenta_bed",
+    "light_blue_bed",
+    "yellow_bed",
+    "lime_bed",
+    "pink_bed",
+    "gray_bed",
+    "light_gray_bed",
+    "cyan_bed",
+    "purple_bed",
+    "blue_bed",
+    "brown_bed",
+    "green_bed",
+    "red_bed",
+    "black_bed",
+    "cookie",
+    "filled_map",
+    "shears",
+    "melon_slice",
+    "dried_kelp",
+    "pumpkin_seeds",
+    "melon_seeds",
+    "beef",
+    "cooked_beef",
+    "chicken",
+    "cooked_chicken",
+    "rotten_flesh",
+    "ender_pearl",
+    "blaze_rod",
+    "ghast_tear",
+    "gold_nugget",
+    "nether_wart",
+    "potion",
+    "glass_bottle",
+    "spider_eye",
+    "fermented_spider_eye",
+    "blaze_powder",
+    "magma_cream",
+    "brewing_stand",
+    "cauldron",
+    "ender_eye",
+    "glistering_melon_slice",
+    "bat_spawn_egg",
+    "bee_spawn_egg",
+    "blaze_spawn_egg",
+    "cat_spawn_egg",
+    "cave_spider_spawn_egg",
+    "chicken_spawn_egg",
+    "cod_spawn_egg",
+    "cow_spawn_egg",
+    "creeper_spawn_egg",
+    "dolphin_spawn_egg",
+    "donkey_spawn_egg",
+    "drowned_spawn_egg",
+    "elder_guardian_spawn_egg",
+    "enderman_spawn_egg",
+    "endermite_spawn_egg",
+    "evoker_spawn_egg",
+    "fox_spawn_egg",
+    "ghast_spawn_egg",
+    "guardian_spawn_egg",
+    "horse_spawn_egg",
+    "husk_spawn_egg",
+    "llama_spawn_egg",
+    "magma_cube_spawn_egg",
+    "mooshroom_spawn_egg",
+    "mule_spawn_egg",
+    "ocelot_spawn_egg",
+    "panda_spawn_egg",
+    "parrot_spawn_egg",
+    "phantom_spawn_egg",
+    "pig_spawn_egg",
+    "pillager_spawn_egg",
+    "polar_bear_spawn_egg",
+    "pufferfish_spawn_egg",
+    "rabbit_spawn_egg",
+    "ravager_spawn_egg",
+    "salmon_spawn_egg",
+    "sheep_spawn_egg",
+    "shulker_spawn_egg",
+    "silverfish_spawn_egg",
+    "skeleton_spawn_egg",
+    "skeleton_horse_spawn_egg",
+    "slime_spawn_egg",
+    "spider_spawn_egg",
+    "squid_spawn_egg",
+    "stray_spawn_egg",
+    "trader_llama_spawn_egg",
+    "tropical_fish_spawn_egg",
+    "turtle_spawn_egg",
+    "vex_spawn_egg",
+    "villager_spawn_egg",
+    "vindicator_spawn_egg",
+    "wandering_trader_spawn_egg",
+    "witch_spawn_egg",
+    "wither_skeleton_spawn_egg",
+    "wolf_spawn_egg",
+    "zombie_spawn_egg",
+    "zombie_horse_spawn_egg",
+    "zombie_pigman_spawn_egg",
+    "zombie_villager_spawn_egg",
+    "experience_bottle",
+    "fire_charge",
+    "writable_book",
+    "written_book",
+    "emerald",
+    "item_frame",
+    "flower_pot",
+    "carrot",
+    "potato",
+    "baked_potato",
+    "poisonous_potato",
+    "map",
+    "golden_carrot",
+    "skeleton_skull",
+    "wither_skeleton_skull",
+    "player_head",
+    "zombie_head",
+    "creeper_head",
+    "dragon_head",
+    "carrot_on_a_stick",
+    "nether_star",
+    "pumpkin_pie",
+    "firework_rocket",
+    "firework_star",
+    "enchanted_book",
+    "nether_brick",
+    "quartz",
+    "tnt_minecart",
+    "hopper_minecart",
+    "prismarine_shard",
+    "prismarine_crystals",
+    "rabbit",
+    "cooked_rabbit",
+    "rabbit_stew",
+    "rabbit_foot",
+    "rabbit_hide",
+    "armor_stand",
+    "iron_horse_armor",
+    "golden_horse_armor",
+    "diamond_horse_armor",
+    "leather_horse_armor",
+    "lead",
+    "name_tag",
+    "command_block_minecart",
+    "mutton",
+    "cooked_mutton",
+    "white_banner",
+    "orange_banner",
+    "magenta_banner",
+    "light_blue_banner",
+    "yellow_banner",
+    "lime_banner",
+    "pink_banner",
+    "gray_banner",
+    "light_gray_banner",
+    "cyan_banner",
+    "purple_banner",
+    "blue_banner",
+    "brown_banner",
+    "green_banner",
+    "red_banner",
+    "black_banner",
+    "end_crystal",
+    "chorus_fruit",
+    "popped_chorus_fruit",
+    "beetroot",
+    "beetroot_seeds",
+    "beetroot_soup",
+    "dragon_breath",
+    "splash_potion",
+    "spectral_arrow",
+    "tipped_arrow",
+    "lingering_potion",
+    "shield",
+    "elytra",
+    "spruce_boat",
+    "birch_boat",
+    "jungle_boat",
+    "acacia_boat",
+    "dark_oak_boat",
+    "totem_of_undying",
+    "shulker_shell",
+    "iron_nugget",
+    "knowledge_book",
+    "debug_stick",
+    "music_disc_13",
+    "music_disc_cat",
+    "music_disc_blocks",
+    "music_disc_chirp",
+    "music_disc_far",
+    "music_disc_mall",
+    "music_disc_mellohi",
+    "music_disc_stal",
+    "music_disc_strad",
+    "music_disc_ward",
+    "music_disc_11",
+    "music_disc_wait",
+    "trident",
+    "phantom_membrane",
+    "nautilus_shell",
+    "heart_of_the_sea",
+    "crossbow",
+    "suspicious_stew",
+    "loom",
+    "flower_banner_pattern",
+    "creeper_banner_pattern",
+    "skull_banner_pattern",
+    "mojang_banner_pattern",
+    "globe_banner_pattern",
+    "barrel",
+    "smoker",
+    "blast_furnace",
+    "cartography_table",
+    "fletching_table",
+    "grindstone",
+    "lectern",
+    "smithing_table",
+    "stonecutter",
+    "bell",
+    "lantern",
+    "sweet_berries",
+    "campfire",
+    "honeycomb",
+    "bee_nest",
+    "beehive",
+    "honey_bottle",
+    "honey_block",
+    "honeycomb_block",
+];
+
+const ITEMS_1_16: &[&str] = &[
+    "air",
+    "stone",
+    "granite",
+    "polished_granite",
+    "diorite",
+    "polished_diorite",
+    "andesite",
+    "polished_andesite",
+    "grass_block",
+    "dirt",
+    "coarse_dirt",
+    "podzol",
+    "crimson_nylium",
+    "warped_nylium",
+    "cobblestone",
+    "oak_planks",
+    "spruce_planks",
+    "birch_planks",
+    "jungle_planks",
+    "acacia_planks",
+    "dark_oak_planks",
+    "crimson_planks",
+    "warped_planks",
+    "oak_sapling",
+    "spruce_sapling",
+    "birch_sapling",
+    "jungle_sapling",
+    "acacia_sapling",
+    "dark_oak_sapling",
+    "bedrock",
+    "sand",
+    "red_sand",
+    "gravel",
+    "gold_ore",
+    "iron_ore",
+    "coal_ore",
+    "nether_gold_ore",
+    "oak_log",
+    "spruce_log",
+    "birch_log",
+    "jungle_log",
+    "acacia_log",
+    "dark_oak_log",
+    "crimson_stem",
+    "warped_stem",
+    "stripped_oak_log",
+    "stripped_spruce_log",
+    "stripped_birch_log",
+    "stripped_jungle_log",
+    "stripped_acacia_log",
+    "stripped_dark_oak_log",
+    "stripped_crimson_stem",
+    "stripped_warped_stem",
+    "stripped_oak_wood",
+    "stripped_spruce_wood",
+    "stripped_birch_wood",
+    "stripped_jungle_wood",
+    "stripped_acacia_wood",
+    "stripped_dark_oak_wood",
+    "stripped_crimson_hyphae",
+    "stripped_warped_hyphae",
+    "oak_wood",
+    "spruce_wood",
+    "birch_wood",
+    "jungle_wood",
+    "acacia_wood",
+    "dark_oak_wood",
+    "crimson_hyphae",
+    "warped_hyphae",
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "sponge",
+    "wet_sponge",
+    "glass",
+    "lapis_ore",
+    "lapis_block",
+    "dispenser",
+    "sandstone",
+    "chiseled_sandstone",
+    "cut_sandstone",
+    "note_block",
+    "powered_rail",
+    "detector_rail",
+    "sticky_piston",
+    "cobweb",
+    "grass",
+    "fern",
+    "dead_bush",
+    "seagrass",
+    "sea_pickle",
+    "piston",
+    "white_wool",
+    "orange_wool",
+    "magenta_wool",
+    "light_blue_wool",
+    "yellow_wool",
+    "lime_wool",
+    "pink_wool",
+    "gray_wool",
+    "light_gray_wool",
+    "cyan_wool",
+    "purple_wool",
+    "blue_wool",
+    "brown_wool",
+    "green_wool",
+    "red_wool",
+    "black_wool",
+    "dandelion",
+    "poppy",
+    "blue_orchid",
+    "allium",
+    "azure_bluet",
+    "red_tulip",
+    "orange_tulip",
+    "white_tulip",
+    "pink_tulip",
+    "oxeye_daisy",
+    "cornflower",
+    "lily_of_the_valley",
+    "wither_rose",
+    "brown_mushroom",
+    "red_mushroom",
+    "crimson_fungus",
+    "warped_fungus",
+    "crimson_roots",
+    "warped_roots",
+    "nether_sprouts",
+    "weeping_vines",
+    "twisting_vines",
+    "sugar_cane",
+    "kelp",
+    "bamboo",
+    "gold_block",
+    "iron_block",
+    "oak_slab",
+    "spruce_slab",
+    "birch_slab",
+    "jungle_slab",
+    "acacia_slab",
+    "dark_oak_slab",
+    "crimson_slab",
+    "warped_slab",
+    "stone_slab",
+    "smooth_stone_slab",
+    "sandstone_slab",
+    "cut_sandstone_slab",
+    "petrified_oak_slab",
+    "cobblestone_slab",
+    "brick_slab",
+    "stone_brick_slab",
+    "nether_brick_slab",
+    "quartz_slab",
+    "red_sandstone_slab",
+    "cut_red_sandstone_slab",
+    "purpur_slab",
+    "prismarine_slab",
+    "prismarine_brick_slab",
+    "dark_prismarine_slab",
+    "smooth_quartz",
+    "smooth_red_sandstone",
+    "smooth_sandstone",
+    "smooth_stone",
+    "bricks",
+    "tnt",
+    "bookshelf",
+    "mossy_cobblestone",
+    "obsidian",
+    "torch",
+    "end_rod",
+    "chorus_plant",
+    "chorus_flower",
+    "purpur_block",
+    "purpur_pillar",
+    "purpur_stairs",
+    "spawner",
+    "oak_stairs",
+    "chest",
+    "diamond_ore",
+    "diamond_block",
+    "crafting_table",
+    "farmland",
+    "furnace",
+    "ladder",
+    "rail",
+    "cobblestone_stairs",
+    "lever",
+    "stone_pressure_plate",
+    "polished_blackstone_pressure_plate",
+    "oak_pressure_plate",
+    "spruce_pressure_plate",
+    "birch_pressure_plate",
+    "jungle_pressure_plate",
+    "acacia_pressure_plate",
+    "dark_oak_pressure_plate",
+    "crimson_pressure_plate",
+    "warped_pressure_plate",
+    "redstone_ore",
+    "redstone_torch",
+    "snow",
+    "ice",
+    "snow_block",
+    "cactus",
+    "clay",
+    "jukebox",
+    "oak_fence",
+    "spruce_fence",
+    "birch_fence",
+    "jungle_fence",
+    "acacia_fence",
+    "dark_oak_fence",
+    "crimson_fence",
+    "warped_fence",
+    "pumpkin",
+    "carved_pumpkin",
+    "netherrack",
+    "soul_sand",
+    "soul_soil",
+    "basalt",
+    "polished_basalt",
+    "soul_torch",
+    "glowstone",
+    "jack_o_lantern",
+    "oak_trapdoor",
+    "spruce_trapdoor",
+    "birch_trapdoor",
+    "jungle_trapdoor",
+    "acacia_trapdoor",
+    "dark_oak_trapdoor",
+    "crimson_trapdoor",
+    "warped_trapdoor",
+    "infested_stone",
+    "infested_cobblestone",
+    "infested_stone_bricks",
+    "infested_mossy_stone_bricks",
+    "infested_cracked_stone_bricks",
+    "infested_chiseled_stone_bricks",
+    "stone_bricks",
+    "mossy_stone_bricks",
+    "cracked_stone_bricks",
+    "chiseled_stone_bricks",
+    "brown_mushroom_block",
+    "red_mushroom_block",
+    "mushroom_stem",
+    "iron_bars",
+    "chain",
+    "glass_pane",
+    "melon",
+    "vine",
+    "oak_fence_gate",
+    "spruce_fence_gate",
+    "birch_fence_gate",
+    "jungle_fence_gate",
+    "acacia_fence_gate",
+    "dark_oak_fence_gate",
+    "crimson_fence_gate",
+    "warped_fence_gate",
+    "brick_stairs",
+    "stone_brick_stairs",
+    "mycelium",
+    "lily_pad",
+    "nether_bricks",
+    "cracked_nether_bricks",
+    "chiseled_nether_bricks",
+    "nether_brick_fence",
+    "nether_brick_stairs",
+    "enchanting_table",
+    "end_portal_frame",
+    "end_stone",
+    "end_stone_bricks",
+    "dragon_egg",
+    "redstone_lamp",
+    "sandstone_stairs",
+    "emerald_ore",
+    "ender_chest",
+    "tripwire_hook",
+    "emerald_block",
+    "spruce_stairs",
+    "birch_stairs",
+    "jungle_stairs",
+    "crimson_stairs",
+    "warped_stairs",
+    "command_block",
+    "beacon",
+    "cobblestone_wall",
+    "mossy_cobblestone_wall",
+    "brick_wall",
+    "prismarine_wall",
+    "red_sandstone_wall",
+    "mossy_stone_brick_wall",
+    "granite_wall",
+    "stone_brick_wall",
+    "nether_brick_wall",
+    "andesite_wall",
+    "red_nether_brick_wall",
+    "sandstone_wall",
+    "end_stone_brick_wall",
+    "diorite_wall",
+    "blackstone_wall",
+    "polished_blackstone_wall",
+    "polished_blackstone_brick_wall",
+    "stone_button",
+    "oak_button",
+    "spruce_button",
+    "birch_button",
+    "jungle_button",
+    "acacia_button",
+    "dark_oak_button",
+    "crimson_button",
+    "warped_button",
+    "polished_blackstone_button",
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "trapped_chest",
+    "light_weighted_pressure_plate",
+    "heavy_weighted_pressure_plate",
+    "daylight_detector",
+    "redstone_block",
+    "nether_quartz_ore",
+    "hopper",
+    "chiseled_quartz_block",
+    "quartz_block",
+    "quartz_bricks",
+    "quartz_pillar",
+    "quartz_stairs",
+    "activator_rail",
+    "dropper",
+    "white_terracotta",
+    "orange_terracotta",
+    "magenta_terracotta",
+    "light_blue_terracotta",
+    "yellow_terracotta",
+    "lime_terracotta",
+    "pink_terracotta",
+    "gray_terracotta",
+    "light_gray_terracotta",
+    "cyan_terracotta",
+    "purple_terracotta",
+    "blue_terracotta",
+    "brown_terracotta",
+    "green_terracotta",
+    "red_terracotta",
+    "black_terracotta",
+    "barrier",
+    "iron_trapdoor",
+    "hay_block",
+    "white_carpet",
+    "orange_carpet",
+    "magenta_carpet",
+    "light_blue_carpet",
+    "yellow_carpet",
+    "lime_carpet",
+    "pink_carpet",
+    "gray_carpet",
+    "light_gray_carpet",
+    "cyan_carpet",
+    "purple_carpet",
+    "blue_carpet",
+    "brown_carpet",
+    "green_carpet",
+    "red_carpet",
+    "black_carpet",
+    "terracotta",
+    "coal_block",
+    "packed_ice",
+    "acacia_stairs",
+    "dark_oak_stairs",
+    "slime_block",
+    "grass_path",
+    "sunflower",
+    "lilac",
+    "rose_bush",
+    "peony",
+    "tall_grass",
+    "large_fern",
+    "white_stained_glass",
+    "orange_stained_glass",
+    "magenta_stained_glass",
+    "light_blue_stained_glass",
+    "yellow_stained_glass",
+    "lime_stained_glass",
+    "pink_stained_glass",
+    "gray_stained_glass",
+    "light_gray_stained_glass",
+    "cyan_stained_glass",
+    "purple_stained_glass",
+    "blue_stained_glass",
+    "brown_stained_glass",
+    "green_stained_glass",
+    "red_stained_glass",
+    "black_stained_glass",
+    "white_stained_glass_pane",
+    "orange_stained_glass_pane",
+    "magenta_stained_glass_pane",
+    "light_blue_stained_glass_pane",
+    "yellow_stained_glass_pane",
+    "lime_stained_glass_pane",
+    "pink_stained_glass_pane",
+    "gray_stained_glass_pane",
+    "light_gray_stained_glass_pane",
+    "cyan_stained_glass_pane",
+    "purple_stained_glass_pane",
+    "blue_stained_glass_pane",
+    "brown_stained_glass_pane",
+    "green_stained_glass_pane",
+    "red_stained_glass_pane",
+    "black_stained_glass_pane",
+    "prismarine",
+    "prismarine_bricks",
+    "dark_prismarine",
+    "prismarine_stairs",
+    "prismarine_brick_stairs",
+    "dark_prismarine_stairs",
+    "sea_lantern",
+    "red_sandstone",
+    "chiseled_red_sandstone",
+    "cut_red_sandstone",
+    "red_sandstone_stairs",
+    "repeating_command_block",
+    "chain_command_block",
+    "magma_block",
+    "nether_wart_block",
+    "warped_wart_block",
+    "red_nether_bricks",
+    "bone_block",
+    "structure_void",
+    "observer",
+    "shulker_box",
+    "white_shulker_box",
+    "orange_shulker_box",
+    "magenta_shulker_box",
+    "light_blue_shulker_box",
+    "yellow_shulker_box",
+    "lime_shulker_box",
+    "pink_shulker_box",
+    "gray_shulker_box",
+    "light_gray_shulker_box",
+    "cyan_shulker_box",
+    "purple_shulker_box",
+    "blue_shulker_box",
+    "brown_shulker_box",
+    "green_shulker_box",
+    "red_shulker_box",
+    "black_shulker_box",
+    "white_glazed_terracotta",
+    "orange_glazed_terracotta",
+    "magenta_glazed_terracotta",
+    "light_blue_glazed_terracotta",
+    "yellow_glazed_terracotta",
+    "lime_glazed_terracotta",
+    "pink_glazed_terracotta",
+    "gray_glazed_terracotta",
+    "light_gray_glazed_terracotta",
+    "cyan_glazed_terracotta",
+    "purple_glazed_terracotta",
+    "blue_glazed_terracotta",
+    "brown_glazed_terracotta",
+    "green_glazed_terracotta",
+    "red_glazed_terracotta",
+    "black_glazed_terracotta",
+    "white_concrete",
+    "orange_concrete",
+    "magenta_concrete",
+    "light_blue_concrete",
+    "yellow_concrete",
+    "lime_concrete",
+    "pink_concrete",
+    "gray_concrete",
+    "light_gray_concrete",
+    "cyan_concrete",
+    "purple_concrete",
+    "blue_concrete",
+    "brown_concrete",
+    "green_concrete",
+    "red_concrete",
+    "black_concrete",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+    "turtle_egg",
+    "dead_tube_coral_block",
+    "dead_brain_coral_block",
+    "dead_bubble_coral_block",
+    "dead_fire_coral_block",
+    "dead_horn_coral_block",
+    "tube_coral_block",
+    "brain_coral_block",
+    "bubble_coral_block",
+    "fire_coral_block",
+    "horn_coral_block",
+    "tube_coral",
+    "brain_coral",
+    "bubble_coral",
+    "fire_coral",
+    "horn_coral",
+    "dead_brain_coral",
+    "dead_bubble_coral",
+    "dead_fire_coral",
+    "dead_horn_coral",
+    "dead_tube_coral",
+    "tube_coral_fan",
+    "brain_coral_fan",
+    "bubble_coral_fan",
+    "fire_coral_fan",
+    "horn_coral_fan",
+    "dead_tube_coral_fan",
+    "dead_brain_coral_fan",
+    "dead_bubble_coral_fan",
+    "dead_fire_coral_fan",
+    "dead_horn_coral_fan",
+    "blue_ice",
+    "conduit",
+    "polished_granite_stairs",
+    "smooth_red_sandstone_stairs",
+    "mossy_stone_brick_stairs",
+    "polished_diorite_stairs",
+    "mossy_cobblestone_stairs",
+    "end_stone_brick_stairs",
+    "stone_stairs",
+    "smooth_sandstone_stairs",
+    "smooth_quartz_stairs",
+    "granite_stairs",
+    "andesite_stairs",
+    "red_nether_brick_stairs",
+    "polished_andesite_stairs",
+    "diorite_stairs",
+    "polished_granite_slab",
+    "smooth_red_sandstone_slab",
+    "mossy_stone_brick_slab",
+    "polished_diorite_slab",
+    "mossy_cobblestone_slab",
+    "end_stone_brick_slab",
+    "smooth_sandstone_slab",
+    "smooth_quartz_slab",
+    "granite_slab",
+    "andesite_slab",
+    "red_nether_brick_slab",
+    "polished_andesite_slab",
+    "diorite_slab",
+    "scaffolding",
+    "iron_door",
+    "oak_door",
+    "spruce_door",
+    "birch_door",
+    "jungle_door",
+    "acacia_door",
+    "dark_oak_door",
+    "crimson_door",
+    "warped_door",
+    "repeater",
+    "comparator",
+    "structure_block",
+    "jigsaw",
+    "turtle_helmet",
+    "scute",
+    "flint_and_steel",
+    "apple",
+    "bow",
+    "arrow",
+    "coal",
+    "charcoal",
+    "diamond",
+    "iron_ingot",
+    "gold_ingot",
+    "netherite_ingot",
+    "netherite_scrap",
+    "wooden_sword",
+    "wooden_shovel",
+    "wooden_pickaxe",
+    "wooden_axe",
+    "wooden_hoe",
+    "stone_sword",
+    "stone_shovel",
+    "stone_pickaxe",
+    "stone_axe",
+    "stone_hoe",
+    "golden_sword",
+    "golden_shovel",
+    "golden_pickaxe",
+    "golden_axe",
+    "golden_hoe",
+    "iron_sword",
+    "iron_shovel",
+    "iron_pickaxe",
+    "iron_axe",
+    "iron_hoe",
+    "diamond_sword",
+    "diamond_shovel",
+    "diamond_pickaxe",
+    "diamond_axe",
+    "diamond_hoe",
+    "netherite_sword",
+    "netherite_shovel",
+    "netherite_pickaxe",
+    "netherite_axe",
+    "netherite_hoe",
+    "stick",
+    "bowl",
+    "mushroom_stew",
+    "string",
+    "feather",
+    "gunpowder",
+    "wheat_seeds",
+    "wheat",
+    "bread",
+    "leather_helmet",
+    "leather_chestplate",
+    "leather_leggings",
+    "leather_boots",
+    "chainmail_helmet",
+    "chainmail_chestplate",
+    "chainmail_leggings",
+    "chainmail_boots",
+    "iron_helmet",
+    "iron_chestplate",
+    "iron_leggings",
+    "iron_boots",
+    "diamond_helmet",
+    "diamond_chestplate",
+    "diamond_leggings",
+    "diamond_boots",
+    "golden_helmet",
+    "golden_chestplate",
+    "golden_leggings",
+    "golden_boots",
+    "netherite_helmet",
+    "netherite_chestplate",
+    "netherite_leggings",
+    "netherite_boots",
+    "flint",
+    "porkchop",
+    "cooked_porkchop",
+    "painting",
+    "golden_apple",
+    "enchanted_golden_apple",
+    "oak_sign",
+    "spruce_sign",
+    "birch_sign",
+    "jungle_sign",
+    "acacia_sign",
+    "dark_oak_sign",
+    "crimson_sign",
+    "warped_sign",
+    "bucket",
+    "water_bucket",
+    "lava_bucket",
+    "minecart",
+    "saddle",
+    "redstone",
+    "snowball",
+    "oak_boat",
+    "leather",
+    "milk_bucket",
+    "pufferfish_bucket",
+    "salmon_bucket",
+    "cod_bucket",
+    "tropical_fish_bucket",
+    "brick",
+    "clay_ball",
+    "dried_kelp_block",
+    "paper",
+    "book",
+    "slime_ball",
+    "chest_minecart",
+    "furnace_minecart",
+    "egg",
+    "compass",
+    "fishing_rod",
+    "clock",
+    "glowstone_dust",
+    "cod",
+    "salmon",
+    "tropical_fish",
+    "pufferfish",
+    "cooked_cod",
+    "cooked_salmon",
+    "ink_sac",
+    "cocoa_beans",
+    "lapis_lazuli",
+    "white_dye",
+    "orange_dye",
+    "magenta_dye",
+    "light_blue_dye",
+    "yellow_dye",
+    "lime_dye",
+    "pink_dye",
+    "gray_dye",
+    "light_gray_dye",
+    "cyan_dye",
+    "purple_dye",
+    "blue_dye",
+    "brown_dye",
+    "green_dye",
+    "red_dye",
+    "black_dye",
+    "bone_meal",
+    "bone",
+    "sugar",
+    "cake",
+    "white_bed",
+    "orange_bed",
+    "magenta_bed",
+    "light_blue_bed",
+    "yellow_bed",
+    "lime_bed",
+    "pink_bed",
+    "gray_bed",
+    "light_gray_bed",
+    "cyan_bed",
+    "purple_bed",
+    "blue_bed",
+    "brown_bed",
+    "green_bed",
+    "red_bed",
+    "black_bed",
+    "cookie",
+    "filled_map",
+    "shears",
+    "melon_slice",
+    "dried_kelp",
+    "pumpkin_seeds",
+    "melon_seeds",
+    "beef",
+    "cooked_beef",
+    "chicken",
+    "cooked_chicken",
+    "rotten_flesh",
+    "ender_pearl",
+    "blaze_rod",
+    "ghast_tear",
+    "gold_nugget",
+    "nether_wart",
+    "potion",
+    "glass_bottle",
+    "spider_eye",
+    "fermented_spider_eye",
+    "blaze_powder",
+    "magma_cream",
+    "brewing_stand",
+    "cauldron",
+    "ender_eye",
+    "glistering_melon_slice",
+    "bat_spawn_egg",
+    "bee_spawn_egg",
+    "blaze_spawn_egg",
+    "cat_spawn_egg",
+    "cave_spider_spawn_egg",
+    "chicken_spawn_egg",
+    "cod_spawn_egg",
+    "cow_spawn_egg",
+    "creeper_spawn_egg",
+    "dolphin_spawn_egg",
+    "donkey_spawn_egg",
+    "drowned_spawn_egg",
+    "elder_guardian_spawn_egg",
+    "enderman_spawn_egg",
+    "endermite_spawn_egg",
+    "evoker_spawn_egg",
+    "fox_spawn_egg",
+    "ghast_spawn_egg",
+    "guardian_spawn_egg",
+    "hoglin_spawn_egg",
+    "horse_spawn_egg",
+    "husk_spawn_egg",
+    "llama_spawn_egg",
+    "magma_cube_spawn_egg",
+    "mooshroom_spawn_egg",
+    "mule_spawn_egg",
+    "ocelot_spawn_egg",
+    "panda_spawn_egg",
+    "parrot_spawn_egg",
+    "phantom_spawn_egg",
+    "pig_spawn_egg",
+    "piglin_spawn_egg",
+    "pillager_spawn_egg",
+    "polar_bear_spawn_egg",
+    "pufferfish_spawn_egg",
+    "rabbit_spawn_egg",
+    "ravager_spawn_egg",
+    "salmon_spawn_egg",
+    "sheep_spawn_egg",
+    "shulker_spawn_egg",
+    "silverfish_spawn_egg",
+    "skeleton_spawn_egg",
+    "skeleton_horse_spawn_egg",
+    "slime_spawn_egg",
+    "spider_spawn_egg",
+    "squid_spawn_egg",
+    "stray_spawn_egg",
+    "strider_spawn_egg",
+    "trader_llama_spawn_egg",
+    "tropical_fish_spawn_egg",
+    "turtle_spawn_egg",
+    "vex_spawn_egg",
+    "villager_spawn_egg",
+    "vindicator_spawn_egg",
+    "wandering_trader_spawn_egg",
+    "witch_spawn_egg",
+    "wither_skeleton_spawn_egg",
+    "wolf_spawn_egg",
+    "zoglin_spawn_egg",
+    "zombie_spawn_egg",
+    "zombie_horse_spawn_egg",
+    "zombie_villager_spawn_egg",
+    "zombified_piglin_spawn_egg",
+    "experience_bottle",
+    "fire_charge",
+    "writable_book",
+    "written_book",
+    "emerald",
+    "item_frame",
+    "flower_pot",
+    "carrot",
+    "potato",
+    "baked_potato",
+    "poisonous_potato",
+    "map",
+    "golden_carrot",
+    "skeleton_skull",
+    "wither_skeleton_skull",
+    "player_head",
+    "zombie_head",
+    "creeper_head",
+    "dragon_head",
+    "carrot_on_a_stick",
+    "warped_fungus_on_a_stick",
+    "nether_star",
+    "pumpkin_pie",
+    "firework_rocket",
+    "firework_star",
+    "enchanted_book",
+    "nether_brick",
+    "quartz",
+    "tnt_minecart",
+    "hopper_minecart",
+    "prismarine_shard",
+    "prismarine_crystals",
+    "rabbit",
+    "cooked_rabbit",
+    "rabbit_stew",
+    "rabbit_foot",
+    "rabbit_hide",
+    "armor_stand",
+    "iron_horse_armor",
+    "golden_horse_armor",
+    "diamond_horse_armor",
+    "leather_horse_armor",
+    "lead",
+    "name_tag",
+    "command_block_minecart",
+    "mutton",
+    "cooked_mutton",
+    "white_banner",
+    "orange_banner",
+    "magenta_banner",
+    "light_blue_banner",
+    "yellow_banner",
+    "lime_banner",
+    "pink_banner",
+    "gray_banner",
+    "light_gray_banner",
+    "cyan_banner",
+    "purple_banner",
+    "blue_banner",
+    "brown_banner",
+    "green_banner",
+    "red_banner",
+    "black_banner",
+    "end_crystal",
+    "chorus_fruit",
+    "popped_chorus_fruit",
+    "beetroot",
+    "beetroot_seeds",
+    "beetroot_soup",
+    "dragon_breath",
+    "splash_potion",
+    "spectral_arrow",
+    "tipped_arrow",
+    "lingering_potion",
+    "shield",
+    "elytra",
+    "spruce_boat",
+    "birch_boat",
+    "jungle_boat",
+    "acacia_boat",
+    "dark_oak_boat",
+    "totem_of_undying",
+    "shulker_shell",
+    "iron_nugget",
+    "knowledge_book",
+    "debug_stick",
+    "music_disc_13",
+    "music_disc_cat",
+    "music_disc_blocks",
+    "music_disc_chirp",
+    "music_disc_far",
+    "music_disc_mall",
+    "music_disc_mellohi",
+    "music_disc_stal",
+    "music_disc_strad",
+    "music_disc_ward",
+    "music_disc_11",
+    "music_disc_wait",
+    "music_disc_pigstep",
+    "trident",
+    "phantom_membrane",
+    "nautilus_shell",
+    "heart_of_the_sea",
+    "crossbow",
+    "suspicious_stew",
+    "loom",
+    "flower_banner_pattern",
+    "creeper_banner_pattern",
+    "skull_banner_pattern",
+    "mojang_banner_pattern",
+    "globe_banner_pattern",
+    "piglin_banner_pattern",
+    "composter",
+    "barrel",
+    "smoker",
+    "blast_furnace",
+    "cartography_table",
+    "fletching_table",
+    "grindstone",
+    "lectern",
+    "smithing_table",
+    "stonecutter",
+    "bell",
+    "lantern",
+    "soul_lantern",
+    "sweet_berries",
+    "campfire",
+    "soul_campfire",
+    "shroomlight",
+    "honeycomb",
+    "bee_nest",
+    "beehive",
+    "honey_bottle",
+    "honey_block",
+    "honeycomb_block",
+    "lodestone",
+    "netherite_block",
+    "ancient_debris",
+    "target",
+    "crying_obsidian",
+    "blackstone",
+    "blackstone_slab",
+    "blackstone_stairs",
+    "gilded_blackstone",
+    "polished_blackstone",
+    "polished_blackstone_slab",
+    "polished_blackstone_stairs",
+    "chiseled_polished_blackstone",
+    "polished_blackstone_bricks",
+    "polished_blackstone_brick_slab",
+    "polished_blackstone_brick_stairs",
+    "cracked_polished_blackstone_bricks",
+    "respawn_anchor",
+];
+
+const ITEMS_1_16_2: &[&str] = &[
+    "air",
+    "stone",
+    "granite",
+    "polished_granite",
+    "diorite",
+    "polished_diorite",
+    "andesite",
+    "polished_andesite",
+    "grass_block",
+    "dirt",
+    "coarse_dirt",
+    "podzol",
+    "crimson_nylium",
+    "warped_nylium",
+    "cobblestone",
+    "oak_planks",
+    "spruce_planks",
+    "birch_planks",
+    "jungle_planks",
+    "acacia_planks",
+    "dark_oak_planks",
+    "crimson_planks",
+    "warped_planks",
+    "oak_sapling",
+    "spruce_sapling",
+    "birch_sapling",
+    "jungle_sapling",
+    "acacia_sapling",
+    "dark_oak_sapling",
+    "bedrock",
+    "sand",
+    "red_sand",
+    "gravel",
+    "gold_ore",
+    "iron_ore",
+    "coal_ore",
+    "nether_gold_ore",
+    "oak_log",
+    "spruce_log",
+    "birch_log",
+    "jungle_log",
+    "acacia_log",
+    "dark_oak_log",
+    "crimson_stem",
+    "warped_stem",
+    "stripped_oak_log",
+    "stripped_spruce_log",
+    "stripped_birch_log",
+    "stripped_jungle_log",
+    "stripped_acacia_log",
+    "stripped_dark_oak_log",
+    "stripped_crimson_stem",
+    "stripped_warped_stem",
+    "stripped_oak_wood",
+    "stripped_spruce_wood",
+    "stripped_birch_wood",
+    "stripped_jungle_wood",
+    "stripped_acacia_wood",
+    "stripped_dark_oak_wood",
+    "stripped_crimson_hyphae",
+    "stripped_warped_hyphae",
+    "oak_wood",
+    "spruce_wood",
+    "birch_wood",
+    "jungle_wood",
+    "acacia_wood",
+    "dark_oak_wood",
+    "crimson_hyphae",
+    "warped_hyphae",
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "sponge",
+    "wet_sponge",
+    "glass",
+    "lapis_ore",
+    "lapis_block",
+    "dispenser",
+    "sandstone",
+    "chiseled_sandstone",
+    "cut_sandstone",
+    "note_block",
+    "powered_rail",
+    "detector_rail",
+    "sticky_piston",
+    "cobweb",
+    "grass",
+    "fern",
+    "dead_bush",
+    "seagrass",
+    "sea_pickle",
+    "piston",
+    "white_wool",
+    "orange_wool",
+    "magenta_wool",
+    "light_blue_wool",
+    "yellow_wool",
+    "lime_wool",
+    "pink_wool",
+    "gray_wool",
+    "light_gray_wool",
+    "cyan_wool",
+    "purple_wool",
+    "blue_wool",
+    "brown_wool",
+    "green_wool",
+    "red_wool",
+    "black_wool",
+    "dandelion",
+    "poppy",
+    "blue_orchid",
+    "allium",
+    "azure_bluet",
+    "red_tulip",
+    "orange_tulip",
+    "white_tulip",
+    "pink_tulip",
+    "oxeye_daisy",
+    "cornflower",
+    "lily_of_the_valley",
+    "wither_rose",
+    "brown_mushroom",
+    "red_mushroom",
+    "crimson_fungus",
+    "warped_fungus",
+    "crimson_roots",
+    "warped_roots",
+    "nether_sprouts",
+    "weeping_vines",
+    "twisting_vines",
+    "sugar_cane",
+    "kelp",
+    "bamboo",
+    "gold_block",
+    "iron_block",
+    "oak_slab",
+    "spruce_slab",
+    "birch_slab",
+    "jungle_slab",
+    "acacia_slab",
+    "dark_oak_slab",
+    "crimson_slab",
+    "warped_slab",
+    "stone_slab",
+    "smooth_stone_slab",
+    "sandstone_slab",
+    "cut_sandstone_slab",
+    "petrified_oak_slab",
+    "cobblestone_slab",
+    "brick_slab",
+    "stone_brick_slab",
+    "nether_brick_slab",
+    "quartz_slab",
+    "red_sandstone_slab",
+    "cut_red_sandstone_slab",
+    "purpur_slab",
+    "prismarine_slab",
+    "prismarine_brick_slab",
+    "dark_prismarine_slab",
+    "smooth_quartz",
+    "smooth_red_sandstone",
+    "smooth_sandstone",
+    "smooth_stone",
+    "bricks",
+    "tnt",
+    "bookshelf",
+    "mossy_cobblestone",
+    "obsidian",
+    "torch",
+    "end_rod",
+    "chorus_plant",
+    "chorus_flower",
+    "purpur_block",
+    "purpur_pillar",
+    "purpur_stairs",
+    "spawner",
+    "oak_stairs",
+    "chest",
+    "diamond_ore",
+    "diamond_block",
+    "crafting_table",
+    "farmland",
+    "furnace",
+    "ladder",
+    "rail",
+    "cobblestone_stairs",
+    "lever",
+    "stone_pressure_plate",
+    "polished_blackstone_pressure_plate",
+    "oak_pressure_plate",
+    "spruce_pressure_plate",
+    "birch_pressure_plate",
+    "jungle_pressure_plate",
+    "acacia_pressure_plate",
+    "dark_oak_pressure_plate",
+    "crimson_pressure_plate",
+    "warped_pressure_plate",
+    "redstone_ore",
+    "redstone_torch",
+    "snow",
+    "ice",
+    "snow_block",
+    "cactus",
+    "clay",
+    "jukebox",
+    "oak_fence",
+    "spruce_fence",
+    "birch_fence",
+    "jungle_fence",
+    "acacia_fence",
+    "dark_oak_fence",
+    "crimson_fence",
+    "warped_fence",
+    "pumpkin",
+    "carved_pumpkin",
+    "netherrack",
+    "soul_sand",
+    "soul_soil",
+    "basalt",
+    "polished_basalt",
+    "soul_torch",
+    "glowstone",
+    "jack_o_lantern",
+    "oak_trapdoor",
+    "spruce_trapdoor",
+    "birch_trapdoor",
+    "jungle_trapdoor",
+    "acacia_trapdoor",
+    "dark_oak_trapdoor",
+    "crimson_trapdoor",
+    "warped_trapdoor",
+    "infested_stone",
+    "infested_cobblestone",
+    "infested_stone_bricks",
+    "infested_mossy_stone_bricks",
+    "infested_cracked_stone_bricks",
+    "infested_chiseled_stone_bricks",
+    "stone_bricks",
+    "mossy_stone_bricks",
+    "cracked_stone_bricks",
+    "chiseled_stone_bricks",
+    "brown_mushroom_block",
+    "red_mushroom_block",
+    "mushroom_stem",
+    "iron_bars",
+    "chain",
+    "glass_pane",
+    "melon",
+    "vine",
+    "oak_fence_gate",
+    "spruce_fence_gate",
+    "birch_fence_gate",
+    "jungle_fence_gate",
+    "acacia_fence_gate",
+    "dark_oak_fence_gate",
+    "crimson_fence_gate",
+    "warped_fence_gate",
+    "brick_stairs",
+    "stone_brick_stairs",
+    "mycelium",
+    "lily_pad",
+    "nether_bricks",
+    "cracked_nether_bricks",
+    "chiseled_nether_bricks",
+    "nether_brick_fence",
+    "nether_brick_stairs",
+    "enchanting_table",
+    "end_portal_frame",
+    "end_stone",
+    "end_stone_bricks",
+    "dragon_egg",
+    "redstone_lamp",
+    "sandstone_stairs",
+    "emerald_ore",
+    "ender_chest",
+    "tripwire_hook",
+    "emerald_block",
+    "spruce_stairs",
+    "birch_stairs",
+    "jungle_stairs",
+    "crimson_stairs",
+    "warped_stairs",
+    "command_block",
+    "beacon",
+    "cobblestone_wall",
+    "mossy_cobblestone_wall",
+    "brick_wall",
+    "prismarine_wall",
+    "red_sandstone_wall",
+    "mossy_stone_brick_wall",
+    "granite_wall",
+    "stone_brick_wall",
+    "nether_brick_wall",
+    "andesite_wall",
+    "red_nether_brick_wall",
+    "sandstone_wall",
+    "end_stone_brick_wall",
+    "diorite_wall",
+    "blackstone_wall",
+    "polished_blackstone_wall",
+    "polished_blackstone_brick_wall",
+    "stone_button",
+    "oak_button",
+    "spruce_button",
+    "birch_button",
+    "jungle_button",
+    "acacia_button",
+    "dark_oak_button",
+    "crimson_button",
+    "warped_button",
+    "polished_blackstone_button",
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "trapped_chest",
+    "light_weighted_pressure_plate",
+    "heavy_weighted_pressure_plate",
+    "daylight_detector",
+    "redstone_block",
+    "nether_quartz_ore",
+    "hopper",
+    "chiseled_quartz_block",
+    "quartz_block",
+    "quartz_bricks",
+    "quartz_pillar",
+    "quartz_stairs",
+    "activator_rail",
+    "dropper",
+    "white_terracotta",
+    "orange_terracotta",
+    "magenta_terracotta",
+    "light_blue_terracotta",
+    "yellow_terracotta",
+    "lime_terracotta",
+    "pink_terracotta",
+    "gray_terracotta",
+    "light_gray_terracotta",
+    "cyan_terracotta",
+    "purple_terracotta",
+    "blue_terracotta",
+    "brown_terracotta",
+    "green_terracotta",
+    "red_terracotta",
+    "black_terracotta",
+    "barrier",
+    "iron_trapdoor",
+    "hay_block",
+    "white_carpet",
+    "orange_carpet",
+    "magenta_carpet",
+    "light_blue_carpet",
+    "yellow_carpet",
+    "lime_carpet",
+    "pink_carpet",
+    "gray_carpet",
+    "light_gray_carpet",
+    "cyan_carpet",
+    "purple_carpet",
+    "blue_carpet",
+    "brown_carpet",
+    "green_carpet",
+    "red_carpet",
+    "black_carpet",
+    "terracotta",
+    "coal_block",
+    "packed_ice",
+    "acacia_stairs",
+    "dark_oak_stairs",
+    "slime_block",
+    "grass_path",
+    "sunflower",
+    "lilac",
+    "rose_bush",
+    "peony",
+    "tall_grass",
+    "large_fern",
+    "white_stained_glass",
+    "orange_stained_glass",
+    "magenta_stained_glass",
+    "light_blue_stained_glass",
+    "yellow_stained_glass",
+    "lime_stained_glass",
+    "pink_stained_glass",
+    "gray_stained_glass",
+    "light_gray_stained_glass",
+    "cyan_stained_glass",
+    "purple_stained_glass",
+    "blue_stained_glass",
+    "brown_stained_glass",
+    "green_stained_glass",
+    "red_stained_glass",
+    "black_stained_glass",
+    "white_stained_glass_pane",
+    "orange_stained_glass_pane",
+    "magenta_stained_glass_pane",
+    "light_blue_stained_glass_pane",
+    "yellow_stained_glass_pane",
+    "lime_stained_glass_pane",
+    "pink_stained_glass_pane",
+    "gray_stained_glass_pane",
+    "light_gray_stained_glass_pane",
+    "cyan_stained_glass_pane",
+    "purple_stained_glass_pane",
+    "blue_stained_glass_pane",
+    "brown_stained_glass_pane",
+    "green_stained_glass_pane",
+    "red_stained_glass_pane",
+    "black_stained_glass_pane",
+    "prismarine",
+    "prismarine_bricks",
+    "dark_prismarine",
+    "prismarine_stairs",
+    "prismarine_brick_stairs",
+    "dark_prismarine_stairs",
+    "sea_lantern",
+    "red_sandstone",
+    "chiseled_red_sandstone",
+    "cut_red_sandstone",
+    "red_sandstone_stairs",
+    "repeating_command_block",
+    "chain_command_block",
+    "magma_block",
+    "nether_wart_block",
+    "warped_wart_block",
+    "red_nether_bricks",
+    "bone_block",
+    "structure_void",
+    "observer",
+    "shulker_box",
+    "white_shulker_box",
+    "orange_shulker_box",
+    "magenta_shulker_box",
+    "light_blue_shulker_box",
+    "yellow_shulker_box",
+    "lime_shulker_box",
+    "pink_shulker_box",
+    "gray_shulker_box",
+    "light_gray_shulker_box",
+    "cyan_shulker_box",
+    "purple_shulker_box",
+    "blue_shulker_box",
+    "brown_shulker_box",
+    "green_shulker_box",
+    "red_shulker_box",
+    "black_shulker_box",
+    "white_glazed_terracotta",
+    "orange_glazed_terracotta",
+    "magenta_glazed_terracotta",
+    "light_blue_glazed_terracotta",
+    "yellow_glazed_terracotta",
+    "lime_glazed_terracotta",
+    "pink_glazed_terracotta",
+    "gray_glazed_terracotta",
+    "light_gray_glazed_terracotta",
+    "cyan_glazed_terracotta",
+    "purple_glazed_terracotta",
+    "blue_glazed_terracotta",
+    "brown_glazed_terracotta",
+    "green_glazed_terracotta",
+    "red_glazed_terracotta",
+    "black_glazed_terracotta",
+    "white_concrete",
+    "orange_concrete",
+    "magenta_concrete",
+    "light_blue_concrete",
+    "yellow_concrete",
+    "lime_concrete",
+    "pink_concrete",
+    "gray_concrete",
+    "light_gray_concrete",
+    "cyan_concrete",
+    "purple_concrete",
+    "blue_concrete",
+    "brown_concrete",
+    "green_concrete",
+    "red_concrete",
+    "black_concrete",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+    "turtle_egg",
+    "dead_tube_coral_block",
+    "dead_brain_coral_block",
+    "dead_bubble_coral_block",
+    "dead_fire_coral_block",
+    "dead_horn_coral_block",
+    "tube_coral_block",
+    "brain_coral_block",
+    "bubble_coral_block",
+    "fire_coral_block",
+    "horn_coral_block",
+    "tube_coral",
+    "brain_coral",
+    "bubble_coral",
+    "fire_coral",
+    "horn_coral",
+    "dead_brain_coral",
+    "dead_bubble_coral",
+    "dead_fire_coral",
+    "dead_horn_coral",
+    "dead_tube_coral",
+    "tube_coral_fan",
+    "brain_coral_fan",
+    "bubble_coral_fan",
+    "fire_coral_fan",
+    "horn_coral_fan",
+    "dead_tube_coral_fan",
+    "dead_brain_coral_fan",
+    "dead_bubble_coral_fan",
+    "dead_fire_coral_fan",
+    "dead_horn_coral_fan",
+    "blue_ice",
+    "conduit",
+    "polished_granite_stairs",
+    "smooth_red_sandstone_stairs",
+    "mossy_stone_brick_stairs",
+    "polished_diorite_stairs",
+    "mossy_cobblestone_stairs",
+    "end_stone_brick_stairs",
+    "stone_stairs",
+    "smooth_sandstone_stairs",
+    "smooth_quartz_stairs",
+    "granite_stairs",
+    "andesite_stairs",
+    "red_nether_brick_stairs",
+    "polished_andesite_stairs",
+    "diorite_stairs",
+    "polished_granite_slab",
+    "smooth_red_sandstone_slab",
+    "mossy_stone_brick_slab",
+    "polished_diorite_slab",
+    "mossy_cobblestone_slab",
+    "end_stone_brick_slab",
+    "smooth_sandstone_slab",
+    "smooth_quartz_slab",
+    "granite_slab",
+    "andesite_slab",
+    "red_nether_brick_slab",
+    "polished_andesite_slab",
+    "diorite_slab",
+    "scaffolding",
+    "iron_door",
+    "oak_door",
+    "spruce_door",
+    "birch_door",
+    "jungle_door",
+    "acacia_door",
+    "dark_oak_door",
+    "crimson_door",
+    "warped_door",
+    "repeater",
+    "comparator",
+    "structure_block",
+    "jigsaw",
+    "turtle_helmet",
+    "scute",
+    "flint_and_steel",
+    "apple",
+    "bow",
+    "arrow",
+    "coal",
+    "charcoal",
+    "diamond",
+    "iron_ingot",
+    "gold_ingot",
+    "netherite_ingot",
+    "netherite_scrap",
+    "wooden_sword",
+    "wooden_shovel",
+    "wooden_pickaxe",
+    "wooden_axe",
+    "wooden_hoe",
+    "stone_sword",
+    "stone_shovel",
+    "stone_pickaxe",
+    "stone_axe",
+    "stone_hoe",
+    "golden_sword",
+    "golden_shovel",
+    "golden_pickaxe",
+    "golden_axe",
+    "golden_hoe",
+    "iron_sword",
+    "iron_shovel",
+    "iron_pickaxe",
+    "iron_axe",
+    "iron_hoe",
+    "diamond_sword",
+    "diamond_shovel",
+    "diamond_pickaxe",
+    "diamond_axe",
+    "diamond_hoe",
+    "netherite_sword",
+    "netherite_shovel",
+    "netherite_pickaxe",
+    "netherite_axe",
+    "netherite_hoe",
+    "stick",
+    "bowl",
+    "mushroom_stew",
+    "string",
+    "feather",
+    "gunpowder",
+    "wheat_seeds",
+    "wheat",
+    "bread",
+    "leather_helmet",
+    "leather_chestplate",
+    "leather_leggings",
+    "leather_boots",
+    "chainmail_helmet",
+    "chainmail_chestplate",
+    "chainmail_leggings",
+    "chainmail_boots",
+    "iron_helmet",
+    "iron_chestplate",
+    "iron_leggings",
+    "iron_boots",
+    "diamond_helmet",
+    "diamond_chestplate",
+    "diamond_leggings",
+    "diamond_boots",
+    "golden_helmet",
+    "golden_chestplate",
+    "golden_leggings",
+    "golden_boots",
+    "netherite_helmet",
+    "netherite_chestplate",
+    "netherite_leggings",
+    "netherite_boots",
+    "flint",
+    "porkchop",
+    "cooked_porkchop",
+    "painting",
+    "golden_apple",
+    "enchanted_golden_apple",
+    "oak_sign",
+    "spruce_sign",
+    "birch_sign",
+    "jungle_sign",
+    "acacia_sign",
+    "dark_oak_sign",
+    "crimson_sign",
+    "warped_sign",
+    "bucket",
+    "water_bucket",
+    "lava_bucket",
+    "minecart",
+    "saddle",
+    "redstone",
+    "snowball",
+    "oak_boat",
+    "leather",
+    "milk_bucket",
+    "pufferfish_bucket",
+    "salmon_bucket",
+    "cod_bucket",
+    "tropical_fish_bucket",
+    "brick",
+    "clay_ball",
+    "dried_kelp_block",
+    "paper",
+    "book",
+    "slime_ball",
+    "chest_minecart",
+    "furnace_minecart",
+    "egg",
+    "compass",
+    "fishing_rod",
+    "clock",
+    "glowstone_dust",
+    "cod",
+    "salmon",
+    "tropical_fish",
+    "pufferfish",
+    "cooked_cod",
+    "cooked_salmon",
+    "ink_sac",
+    "cocoa_beans",
+    "lapis_lazuli",
+    "white_dye",
+    "orange_dye",
+    "magenta_dye",
+    "light_blue_dye",
+    "yellow_dye",
+    "lime_dye",
+    "pink_dye",
+    "gray_dye",
+    "light_gray_dye",
+    "cyan_dye",
+    "purple_dye",
+    "blue_dye",
+    "brown_dye",
+    "green_dye",
+    "red_dye",
+    "black_dye",
+    "bone_meal",
+    "bone",
+    "sugar",
+    "cake",
+    "white_bed",
+    "orange_bed",
+    "magenta_bed",
+    "light_blue_bed",
+    "yellow_bed",
+    "lime_bed",
+    "pink_bed",
+    "gray_bed",
+    "light_gray_bed",
+    "cyan_bed",
+    "purple_bed",
+    "blue_bed",
+    "brown_bed",
+    "green_bed",
+    "red_bed",
+    "black_bed",
+    "cookie",
+    "filled_map",
+    "shears",
+    "melon_slice",
+    "dried_kelp",
+    "pumpkin_seeds",
+    "melon_seeds",
+    "beef",
+    "cooked_beef",
+    "chicken",
+    "cooked_chicken",
+    "rotten_flesh",
+    "ender_pearl",
+    "blaze_rod",
+    "ghast_tear",
+    "gold_nugget",
+    "nether_wart",
+    "potion",
+    "glass_bottle",
+    "spider_eye",
+    "fermented_spider_eye",
+    "blaze_powder",
+    "magma_cream",
+    "brewing_stand",
+    "cauldron",
+    "ender_eye",
+    "glistering_melon_slice",
+    "bat_spawn_egg",
+    "bee_spawn_egg",
+    "blaze_spawn_egg",
+    "cat_spawn_egg",
+    "cave_spider_spawn_egg",
+    "chicken_spawn_egg",
+    "cod_spawn_egg",
+    "cow_spawn_egg",
+    "creeper_spawn_egg",
+    "dolphin_spawn_egg",
+    "donkey_spawn_egg",
+    "drowned_spawn_egg",
+    "elder_guardian_spawn_egg",
+    "enderman_spawn_egg",
+    "endermite_spawn_egg",
+    "evoker_spawn_egg",
+    "fox_spawn_egg",
+    "ghast_spawn_egg",
+    "guardian_spawn_egg",
+    "hoglin_spawn_egg",
+    "horse_spawn_egg",
+    "husk_spawn_egg",
+    "llama_spawn_egg",
+    "magma_cube_spawn_egg",
+    "mooshroom_spawn_egg",
+    "mule_spawn_egg",
+    "ocelot_spawn_egg",
+    "panda_spawn_egg",
+    "parrot_spawn_egg",
+    "phantom_spawn_egg",
+    "pig_spawn_egg",
+    "piglin_spawn_egg",
+    "piglin_brute_spawn_egg",
+    "pillager_spawn_egg",
+    "polar_bear_spawn_egg",
+    "pufferfish_spawn_egg",
+    "rabbit_spawn_egg",
+    "ravager_spawn_egg",
+    "salmon_spawn_egg",
+    "sheep_spawn_egg",
+    "shulker_spawn_egg",
+    "silverfish_spawn_egg",
+    "skeleton_spawn_egg",
+    "skeleton_horse_spawn_egg",
+    "slime_spawn_egg",
+    "spider_spawn_egg",
+    "squid_spawn_egg",
+    "stray_spawn_egg",
+    "strider_spawn_egg",
+    "trader_llama_spawn_egg",
+    "tropical_fish_spawn_egg",
+    "turtle_spawn_egg",
+    "vex_spawn_egg",
+    "villager_spawn_egg",
+    "vindicator_spawn_egg",
+    "wandering_trader_spawn_egg",
+    "witch_spawn_egg",
+    "wither_skeleton_spawn_egg",
+    "wolf_spawn_egg",
+    "zoglin_spawn_egg",
+    "zombie_spawn_egg",
+    "zombie_horse_spawn_egg",
+    "zombie_villager_spawn_egg",
+    "zombified_piglin_spawn_egg",
+    "experience_bottle",
+    "fire_charge",
+    "writable_book",
+    "written_book",
+    "emerald",
+    "item_frame",
+    "flower_pot",
+    "carrot",
+    "potato",
+    "baked_potato",
+    "poisonous_potato",
+    "map",
+    "golden_carrot",
+    "skeleton_skull",
+    "wither_skeleton_skull",
+    "player_head",
+    "zombie_head",
+    "creeper_head",
+    "dragon_head",
+    "carrot_on_a_stick",
+    "warped_fungus_on_a_stick",
+    "nether_star",
+    "pumpkin_pie",
+    "firework_rocket",
+    "firework_star",
+    "enchanted_book",
+    "nether_brick",
+    "quartz",
+    "tnt_minecart",
+    "hopper_minecart",
+    "prismarine_shard",
+    "prismarine_crystals",
+    "rabbit",
+    "cooked_rabbit",
+    "rabbit_stew",
+    "rabbit_foot",
+    "rabbit_hide",
+    "armor_stand",
+    "iron_horse_armor",
+    "golden_horse_armor",
+    "diamond_horse_armor",
+    "leather_horse_armor",
+    "lead",
+    "name_tag",
+    "command_block_minecart",
+    "mutton",
+    "cooked_mutton",
+    "white_banner",
+    "orange_banner",
+    "magenta_banner",
+    "light_blue_banner",
+    "yellow_banner",
+    "lime_banner",
+    "pink_banner",
+    "gray_banner",
+    "light_gray_banner",
+    "cyan_banner",
+    "purple_banner",
+    "blue_banner",
+    "brown_banner",
+    "green_banner",
+    "red_banner",
+    "black_banner",
+    "end_crystal",
+    "chorus_fruit",
+    "popped_chorus_fruit",
+    "beetroot",
+    "beetroot_seeds",
+    "beetroot_soup",
+    "dragon_breath",
+    "splash_potion",
+    "spectral_arrow",
+    "tipped_arrow",
+    "lingering_potion",
+    "shield",
+    "elytra",
+    "spruce_boat",
+    "birch_boat",
+    "jungle_boat",
+    "acacia_boat",
+    "dark_oak_boat",
+    "totem_of_undying",
+    "shulker_shell",
+    "iron_nugget",
+    "knowledge_book",
+    "debug_stick",
+    "music_disc_13",
+    "music_disc_cat",
+    "music_disc_blocks",
+    "music_disc_chirp",
+    "music_disc_far",
+    "music_disc_mall",
+    "music_disc_mellohi",
+    "music_disc_stal",
+    "music_disc_strad",
+    "music_disc_ward",
+    "music_disc_11",
+    "music_disc_wait",
+    "music_disc_pigstep",
+    "trident",
+    "phantom_membrane",
+    "nautilus_shell",
+    "heart_of_the_sea",
+    "crossbow",
+    "suspicious_stew",
+    "loom",
+    "flower_banner_pattern",
+    "creeper_banner_pattern",
+    "skull_banner_pattern",
+    "mojang_banner_pattern",
+    "globe_banner_pattern",
+    "piglin_banner_pattern",
+    "composter",
+    "barrel",
+    "smoker",
+    "blast_furnace",
+    "cartography_table",
+    "fletching_table",
+    "grindstone",
+    "lectern",
+    "smithing_table",
+    "stonecutter",
+    "bell",
+    "lantern",
+    "soul_lantern",
+    "sweet_berries",
+    "campfire",
+    "soul_campfire",
+    "shroomlight",
+    "honeycomb",
+    "bee_nest",
+    "beehive",
+    "honey_bottle",
+    "honey_block",
+    "honeycomb_block",
+    "lodestone",
+    "netherite_block",
+    "ancient_debris",
+    "target",
+    "crying_obsidian",
+    "blackstone",
+    "blackstone_slab",
+    "blackstone_stairs",
+    "gilded_blackstone",
+    "polished_blackstone",
+    "polished_blackstone_slab",
+    "polished_blackstone_stairs",
+    "chiseled_polished_blackstone",
+    "polished_blackstone_bricks",
+    "polished_blackstone_brick_slab",
+    "polished_blackstone_brick_stairs",
+    "cracked_polished_blackstone_bricks",
+    "respawn_anchor",
+];
+
+const ITEMS_1_17: &[&str] = &[
+    "air",
+    "stone",
+    "granite",
+    "polished_granite",
+    "diorite",
+    "polished_diorite",
+    "andesite",
+    "polished_andesite",
+    "deepslate",
+    "cobbled_deepslate",
+    "polished_deepslate",
+    "calcite",
+    "tuff",
+    "dripstone_block",
+    "grass_block",
+    "dirt",
+    "coarse_dirt",
+    "podzol",
+    "rooted_dirt",
+    "crimson_nylium",
+    "warped_nylium",
+    "cobblestone",
+    "oak_planks",
+    "spruce_planks",
+    "birch_planks",
+    "jungle_planks",
+    "acacia_planks",
+    "dark_oak_planks",
+    "crimson_planks",
+    "warped_planks",
+    "oak_sapling",
+    "spruce_sapling",
+    "birch_sapling",
+    "jungle_sapling",
+    "acacia_sapling",
+    "dark_oak_sapling",
+    "bedrock",
+    "sand",
+    "red_sand",
+    "gravel",
+    "coal_ore",
+    "deepslate_coal_ore",
+    "iron_ore",
+    "deepslate_iron_ore",
+    "copper_ore",
+    "deepslate_copper_ore",
+    "gold_ore",
+    "deepslate_gold_ore",
+    "redstone_ore",
+    "deepslate_redstone_ore",
+    "emerald_ore",
+    "deepslate_emerald_ore",
+    "lapis_ore",
+    "deepslate_lapis_ore",
+    "diamond_ore",
+    "deepslate_diamond_ore",
+    "nether_gold_ore",
+    "nether_quartz_ore",
+    "ancient_debris",
+    "coal_block",
+    "raw_iron_block",
+    "raw_copper_block",
+    "raw_gold_block",
+    "amethyst_block",
+    "budding_amethyst",
+    "iron_block",
+    "copper_block",
+    "gold_block",
+    "diamond_block",
+    "netherite_block",
+    "exposed_copper",
+    "weathered_copper",
+    "oxidized_copper",
+    "cut_copper",
+    "exposed_cut_copper",
+    "weathered_cut_copper",
+    "oxidized_cut_copper",
+    "cut_copper_stairs",
+    "exposed_cut_copper_stairs",
+    "weathered_cut_copper_stairs",
+    "oxidized_cut_copper_stairs",
+    "cut_copper_slab",
+    "exposed_cut_copper_slab",
+    "weathered_cut_copper_slab",
+    "oxidized_cut_copper_slab",
+    "waxed_copper_block",
+    "waxed_exposed_copper",
+    "waxed_weathered_copper",
+    "waxed_oxidized_copper",
+    "waxed_cut_copper",
+    "waxed_exposed_cut_copper",
+    "waxed_weathered_cut_copper",
+    "waxed_oxidized_cut_copper",
+    "waxed_cut_copper_stairs",
+    "waxed_exposed_cut_copper_stairs",
+    "waxed_weathered_cut_copper_stairs",
+    "waxed_oxidized_cut_copper_stairs",
+    "waxed_cut_copper_slab",
+    "waxed_exposed_cut_copper_slab",
+    "waxed_weathered_cut_copper_slab",
+    "waxed_oxidized_cut_copper_slab",
+    "oak_log",
+    "spruce_log",
+    "birch_log",
+    "jungle_log",
+    "acacia_log",
+    "dark_oak_log",
+    "crimson_stem",
+    "warped_stem",
+    "stripped_oak_log",
+    "stripped_spruce_log",
+    "stripped_birch_log",
+    "stripped_jungle_log",
+    "stripped_acacia_log",
+    "stripped_dark_oak_log",
+    "stripped_crimson_stem",
+    "stripped_warped_stem",
+    "stripped_oak_wood",
+    "stripped_spruce_wood",
+    "stripped_birch_wood",
+    "stripped_jungle_wood",
+    "stripped_acacia_wood",
+    "stripped_dark_oak_wood",
+    "stripped_crimson_hyphae",
+    "stripped_warped_hyphae",
+    "oak_wood",
+    "spruce_wood",
+    "birch_wood",
+    "jungle_wood",
+    "acacia_wood",
+    "dark_oak_wood",
+    "crimson_hyphae",
+    "warped_hyphae",
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "azalea_leaves",
+    "flowering_azalea_leaves",
+    "sponge",
+    "wet_sponge",
+    "glass",
+    "tinted_glass",
+    "lapis_block",
+    "sandstone",
+    "chiseled_sandstone",
+    "cut_sandstone",
+    "cobweb",
+    "grass",
+    "fern",
+    "azalea",
+    "flowering_azalea",
+    "dead_bush",
+    "seagrass",
+    "sea_pickle",
+    "white_wool",
+    "orange_wool",
+    "magenta_wool",
+    "light_blue_wool",
+    "yellow_wool",
+    "lime_wool",
+    "pink_wool",
+    "gray_wool",
+    "light_gray_wool",
+    "cyan_wool",
+    "purple_wool",
+    "blue_wool",
+    "brown_wool",
+    "green_wool",
+    "red_wool",
+    "black_wool",
+    "dandelion",
+    "poppy",
+    "blue_orchid",
+    "allium",
+    "azure_bluet",
+    "red_tulip",
+    "orange_tulip",
+    "white_tulip",
+    "pink_tulip",
+    "oxeye_daisy",
+    "cornflower",
+    "lily_of_the_valley",
+    "wither_rose",
+    "spore_blossom",
+    "brown_mushroom",
+    "red_mushroom",
+    "crimson_fungus",
+    "warped_fungus",
+    "crimson_roots",
+    "warped_roots",
+    "nether_sprouts",
+    "weeping_vines",
+    "twisting_vines",
+    "sugar_cane",
+    "kelp",
+    "moss_carpet",
+    "moss_block",
+    "hanging_roots",
+    "big_dripleaf",
+    "small_dripleaf",
+    "bamboo",
+    "oak_slab",
+    "spruce_slab",
+    "birch_slab",
+    "jungle_slab",
+    "acacia_slab",
+    "dark_oak_slab",
+    "crimson_slab",
+    "warped_slab",
+    "stone_slab",
+    "smooth_stone_slab",
+    "sandstone_slab",
+    "cut_sandstone_slab",
+    "petrified_oak_slab",
+    "cobblestone_slab",
+    "brick_slab",
+    "stone_brick_slab",
+    "nether_brick_slab",
+    "quartz_slab",
+    "red_sandstone_slab",
+    "cut_red_sandstone_slab",
+    "purpur_slab",
+    "prismarine_slab",
+    "prismarine_brick_slab",
+    "dark_prismarine_slab",
+    "smooth_quartz",
+    "smooth_red_sandstone",
+    "smooth_sandstone",
+    "smooth_stone",
+    "bricks",
+    "bookshelf",
+    "mossy_cobblestone",
+    "obsidian",
+    "torch",
+    "end_rod",
+    "chorus_plant",
+    "chorus_flower",
+    "purpur_block",
+    "purpur_pillar",
+    "purpur_stairs",
+    "spawner",
+    "oak_stairs",
+    "chest",
+    "crafting_table",
+    "farmland",
+    "furnace",
+    "ladder",
+    "cobblestone_stairs",
+    "snow",
+    "ice",
+    "snow_block",
+    "cactus",
+    "clay",
+    "jukebox",
+    "oak_fence",
+    "spruce_fence",
+    "birch_fence",
+    "jungle_fence",
+    "acacia_fence",
+    "dark_oak_fence",
+    "crimson_fence",
+    "warped_fence",
+    "pumpkin",
+    "carved_pumpkin",
+    "jack_o_lantern",
+    "netherrack",
+    "soul_sand",
+    "soul_soil",
+    "basalt",
+    "polished_basalt",
+    "smooth_basalt",
+    "soul_torch",
+    "glowstone",
+    "infested_stone",
+    "infested_cobblestone",
+    "infested_stone_bricks",
+    "infested_mossy_stone_bricks",
+    "infested_cracked_stone_bricks",
+    "infested_chiseled_stone_bricks",
+    "infested_deepslate",
+    "stone_bricks",
+    "mossy_stone_bricks",
+    "cracked_stone_bricks",
+    "chiseled_stone_bricks",
+    "deepslate_bricks",
+    "cracked_deepslate_bricks",
+    "deepslate_tiles",
+    "cracked_deepslate_tiles",
+    "chiseled_deepslate",
+    "brown_mushroom_block",
+    "red_mushroom_block",
+    "mushroom_stem",
+    "iron_bars",
+    "chain",
+    "glass_pane",
+    "melon",
+    "vine",
+    "glow_lichen",
+    "brick_stairs",
+    "stone_brick_stairs",
+    "mycelium",
+    "lily_pad",
+    "nether_bricks",
+    "cracked_nether_bricks",
+    "chiseled_nether_bricks",
+    "nether_brick_fence",
+    "nether_brick_stairs",
+    "enchanting_table",
+    "end_portal_frame",
+    "end_stone",
+    "end_stone_bricks",
+    "dragon_egg",
+    "sandstone_stairs",
+    "ender_chest",
+    "emerald_block",
+    "spruce_stairs",
+    "birch_stairs",
+    "jungle_stairs",
+    "crimson_stairs",
+    "warped_stairs",
+    "command_block",
+    "beacon",
+    "cobblestone_wall",
+    "mossy_cobblestone_wall",
+    "brick_wall",
+    "prismarine_wall",
+    "red_sandstone_wall",
+    "mossy_stone_brick_wall",
+    "granite_wall",
+    "stone_brick_wall",
+    "nether_brick_wall",
+    "andesite_wall",
+    "red_nether_brick_wall",
+    "sandstone_wall",
+    "end_stone_brick_wall",
+    "diorite_wall",
+    "blackstone_wall",
+    "polished_blackstone_wall",
+    "polished_blackstone_brick_wall",
+    "cobbled_deepslate_wall",
+    "polished_deepslate_wall",
+    "deepslate_brick_wall",
+    "deepslate_tile_wall",
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "chiseled_quartz_block",
+    "quartz_block",
+    "quartz_bricks",
+    "quartz_pillar",
+    "quartz_stairs",
+    "white_terracotta",
+    "orange_terracotta",
+    "magenta_terracotta",
+    "light_blue_terracotta",
+    "yellow_terracotta",
+    "lime_terracotta",
+    "pink_terracotta",
+    "gray_terracotta",
+    "light_gray_terracotta",
+    "cyan_terracotta",
+    "purple_terracotta",
+    "blue_terracotta",
+    "brown_terracotta",
+    "green_terracotta",
+    "red_terracotta",
+    "black_terracotta",
+    "barrier",
+    "light",
+    "hay_block",
+    "white_carpet",
+    "orange_carpet",
+    "magenta_carpet",
+    "light_blue_carpet",
+    "yellow_carpet",
+    "lime_carpet",
+    "pink_carpet",
+    "gray_carpet",
+    "light_gray_carpet",
+    "cyan_carpet",
+    "purple_carpet",
+    "blue_carpet",
+    "brown_carpet",
+    "green_carpet",
+    "red_carpet",
+    "black_carpet",
+    "terracotta",
+    "packed_ice",
+    "acacia_stairs",
+    "dark_oak_stairs",
+    "dirt_path",
+    "sunflower",
+    "lilac",
+    "rose_bush",
+    "peony",
+    "tall_grass",
+    "large_fern",
+    "white_stained_glass",
+    "orange_stained_glass",
+    "magenta_stained_glass",
+    "light_blue_stained_glass",
+    "yellow_stained_glass",
+    "lime_stained_glass",
+    "pink_stained_glass",
+    "gray_stained_glass",
+    "light_gray_stained_glass",
+    "cyan_stained_glass",
+    "purple_stained_glass",
+    "blue_stained_glass",
+    "brown_stained_glass",
+    "green_stained_glass",
+    "red_stained_glass",
+    "black_stained_glass",
+    "white_stained_glass_pane",
+    "orange_stained_glass_pane",
+    "magenta_stained_glass_pane",
+    "light_blue_stained_glass_pane",
+    "yellow_stained_glass_pane",
+    "lime_stained_glass_pane",
+    "pink_stained_glass_pane",
+    "gray_stained_glass_pane",
+    "light_gray_stained_glass_pane",
+    "cyan_stained_glass_pane",
+    "purple_stained_glass_pane",
+    "blue_stained_glass_pane",
+    "brown_stained_glass_pane",
+    "green_stained_glass_pane",
+    "red_stained_glass_pane",
+    "black_stained_glass_pane",
+    "prismarine",
+    "prismarine_bricks",
+    "dark_prismarine",
+    "prismarine_stairs",
+    "prismarine_brick_stairs",
+    "dark_prismarine_stairs",
+    "sea_lantern",
+    "red_sandstone",
+    "chiseled_red_sandstone",
+    "cut_red_sandstone",
+    "red_sandstone_stairs",
+    "repeating_command_block",
+    "chain_command_block",
+    "magma_block",
+    "nether_wart_block",
+    "warped_wart_block",
+    "red_nether_bricks",
+    "bone_block",
+    "structure_void",
+    "shulker_box",
+    "white_shulker_box",
+    "orange_shulker_box",
+    "magenta_shulker_box",
+    "light_blue_shulker_box",
+    "yellow_shulker_box",
+    "lime_shulker_box",
+    "pink_shulker_box",
+    "gray_shulker_box",
+    "light_gray_shulker_box",
+    "cyan_shulker_box",
+    "purple_shulker_box",
+    "blue_shulker_box",
+    "brown_shulker_box",
+    "green_shulker_box",
+    "red_shulker_box",
+    "black_shulker_box",
+    "white_glazed_terracotta",
+    "orange_glazed_terracotta",
+    "magenta_glazed_terracotta",
+    "light_blue_glazed_terracotta",
+    "yellow_glazed_terracotta",
+    "lime_glazed_terracotta",
+    "pink_glazed_terracotta",
+    "gray_glazed_terracotta",
+    "light_gray_glazed_terracotta",
+    "cyan_glazed_terracotta",
+    "purple_glazed_terracotta",
+    "blue_glazed_terracotta",
+    "brown_glazed_terracotta",
+    "green_glazed_terracotta",
+    "red_glazed_terracotta",
+    "black_glazed_terracotta",
+    "white_concrete",
+    "orange_concrete",
+    "magenta_concrete",
+    "light_blue_concrete",
+    "yellow_concrete",
+    "lime_concrete",
+    "pink_concrete",
+    "gray_concrete",
+    "light_gray_concrete",
+    "cyan_concrete",
+    "purple_concrete",
+    "blue_concrete",
+    "brown_concrete",
+    "green_concrete",
+    "red_concrete",
+    "black_concrete",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+    "turtle_egg",
+    "dead_tube_coral_block",
+    "dead_brain_coral_block",
+    "dead_bubble_coral_block",
+    "dead_fire_coral_block",
+    "dead_horn_coral_block",
+    "tube_coral_block",
+    "brain_coral_block",
+    "bubble_coral_block",
+    "fire_coral_block",
+    "horn_coral_block",
+    "tube_coral",
+    "brain_coral",
+    "bubble_coral",
+    "fire_coral",
+    "horn_coral",
+    "dead_brain_coral",
+    "dead_bubble_coral",
+    "dead_fire_coral",
+    "dead_horn_coral",
+    "dead_tube_coral",
+    "tube_coral_fan",
+    "brain_coral_fan",
+    "bubble_coral_fan",
+    "fire_coral_fan",
+    "horn_coral_fan",
+    "dead_tube_coral_fan",
+    "dead_brain_coral_fan",
+    "dead_bubble_coral_fan",
+    "dead_fire_coral_fan",
+    "dead_horn_coral_fan",
+    "blue_ice",
+    "conduit",
+    "polished_granite_stairs",
+    "smooth_red_sandstone_stairs",
+    "mossy_stone_brick_stairs",
+    "polished_diorite_stairs",
+    "mossy_cobblestone_stairs",
+    "end_stone_brick_stairs",
+    "stone_stairs",
+    "smooth_sandstone_stairs",
+    "smooth_quartz_stairs",
+    "granite_stairs",
+    "andesite_stairs",
+    "red_nether_brick_stairs",
+    "polished_andesite_stairs",
+    "diorite_stairs",
+    "cobbled_deepslate_stairs",
+    "polished_deepslate_stairs",
+    "deepslate_brick_stairs",
+    "deepslate_tile_stairs",
+    "polished_granite_slab",
+    "smooth_red_sandstone_slab",
+    "mossy_stone_brick_slab",
+    "polished_diorite_slab",
+    "mossy_cobblestone_slab",
+    "end_stone_brick_slab",
+    "smooth_sandstone_slab",
+    "smooth_quartz_slab",
+    "granite_slab",
+    "andesite_slab",
+    "red_nether_brick_slab",
+    "polished_andesite_slab",
+    "diorite_slab",
+    "cobbled_deepslate_slab",
+    "polished_deepslate_slab",
+    "deepslate_brick_slab",
+    "deepslate_tile_slab",
+    "scaffolding",
+    "redstone",
+    "redstone_torch",
+    "redstone_block",
+    "repeater",
+    "comparator",
+    "piston",
+    "sticky_piston",
+    "slime_block",
+    "honey_block",
+    "observer",
+    "hopper",
+    "dispenser",
+    "dropper",
+    "lectern",
+    "target",
+    "lever",
+    "lightning_rod",
+    "daylight_detector",
+    "sculk_sensor",
+    "tripwire_hook",
+    "trapped_chest",
+    "tnt",
+    "redstone_lamp",
+    "note_block",
+    "stone_button",
+    "polished_blackstone_button",
+    "oak_button",
+    "spruce_button",
+    "birch_button",
+    "jungle_button",
+    "acacia_button",
+    "dark_oak_button",
+    "crimson_button",
+    "warped_button",
+    "stone_pressure_plate",
+    "polished_blackstone_pressure_plate",
+    "light_weighted_pressure_plate",
+    "heavy_weighted_pressure_plate",
+    "oak_pressure_plate",
+    "spruce_pressure_plate",
+    "birch_pressure_plate",
+    "jungle_pressure_plate",
+    "acacia_pressure_plate",
+    "dark_oak_pressure_plate",
+    "crimson_pressure_plate",
+    "warped_pressure_plate",
+    "iron_door",
+    "oak_door",
+    "spruce_door",
+    "birch_door",
+    "jungle_door",
+    "acacia_door",
+    "dark_oak_door",
+    "crimson_door",
+    "warped_door",
+    "iron_trapdoor",
+    "oak_trapdoor",
+    "spruce_trapdoor",
+    "birch_trapdoor",
+    "jungle_trapdoor",
+    "acacia_trapdoor",
+    "dark_oak_trapdoor",
+    "crimson_trapdoor",
+    "warped_trapdoor",
+    "oak_fence_gate",
+    "spruce_fence_gate",
+    "birch_fence_gate",
+    "jungle_fence_gate",
+    "acacia_fence_gate",
+    "dark_oak_fence_gate",
+    "crimson_fence_gate",
+    "warped_fence_gate",
+    "powered_rail",
+    "detector_rail",
+    "rail",
+    "activator_rail",
+    "saddle",
+    "minecart",
+    "chest_minecart",
+    "furnace_minecart",
+    "tnt_minecart",
+    "hopper_minecart",
+    "carrot_on_a_stick",
+    "warped_fungus_on_a_stick",
+    "elytra",
+    "oak_boat",
+    "spruce_boat",
+    "birch_boat",
+    "jungle_boat",
+    "acacia_boat",
+    "dark_oak_boat",
+    "structure_block",
+    "jigsaw",
+    "turtle_helmet",
+    "scute",
+    "flint_and_steel",
+    "apple",
+    "bow",
+    "arrow",
+    "coal",
+    "charcoal",
+    "diamond",
+    "emerald",
+    "lapis_lazuli",
+    "quartz",
+    "amethyst_shard",
+    "raw_iron",
+    "iron_ingot",
+    "raw_copper",
+    "copper_ingot",
+    "raw_gold",
+    "gold_ingot",
+    "netherite_ingot",
+    "netherite_scrap",
+    "wooden_sword",
+    "wooden_shovel",
+    "wooden_pickaxe",
+    "wooden_axe",
+    "wooden_hoe",
+    "stone_sword",
+    "stone_shovel",
+    "stone_pickaxe",
+    "stone_axe",
+    "stone_hoe",
+    "golden_sword",
+    "golden_shovel",
+    "golden_pickaxe",
+    "golden_axe",
+    "golden_hoe",
+    "iron_sword",
+    "iron_shovel",
+    "iron_pickaxe",
+    "iron_axe",
+    "iron_hoe",
+    "diamond_sword",
+    "diamond_shovel",
+    "diamond_pickaxe",
+    "diamond_axe",
+    "diamond_hoe",
+    "netherite_sword",
+    "netherite_shovel",
+    "netherite_pickaxe",
+    "netherite_axe",
+    "netherite_hoe",
+    "stick",
+    "bowl",
+    "mushroom_stew",
+    "string",
+    "feather",
+    "gunpowder",
+    "wheat_seeds",
+    "wheat",
+    "bread",
+    "leather_helmet",
+    "leather_chestplate",
+    "leather_leggings",
+    "leather_boots",
+    "chainmail_helmet",
+    "chainmail_chestplate",
+    "chainmail_leggings",
+    "chainmail_boots",
+    "iron_helmet",
+    "iron_chestplate",
+    "iron_leggings",
+    "iron_boots",
+    "diamond_helmet",
+    "diamond_chestplate",
+    "diamond_leggings",
+    "diamond_boots",
+    "golden_helmet",
+    "golden_chestplate",
+    "golden_leggings",
+    "golden_boots",
+    "netherite_helmet",
+    "netherite_chestplate",
+    "netherite_leggings",
+    "netherite_boots",
+    "flint",
+    "porkchop",
+    "cooked_porkchop",
+    "painting",
+    "golden_apple",
+    "enchanted_golden_apple",
+    "oak_sign",
+    "spruce_sign",
+    "birch_sign",
+    "jungle_sign",
+    "acacia_sign",
+    "dark_oak_sign",
+    "crimson_sign",
+    "warped_sign",
+    "bucket",
+    "water_bucket",
+    "lava_bucket",
+    "powder_snow_bucket",
+    "snowball",
+    "leather",
+    "milk_bucket",
+    "pufferfish_bucket",
+    "salmon_bucket",
+    "cod_bucket",
+    "tropical_fish_bucket",
+    "axolotl_bucket",
+    "brick",
+    "clay_ball",
+    "dried_kelp_block",
+    "paper",
+    "book",
+    "slime_ball",
+    "egg",
+    "compass",
+    "bundle",
+    "fishing_rod",
+    "clock",
+    "spyglass",
+    "glowstone_dust",
+    "cod",
+    "salmon",
+    "tropical_fish",
+    "pufferfish",
+    "cooked_cod",
+    "cooked_salmon",
+    "ink_sac",
+    "glow_ink_sac",
+    "cocoa_beans",
+    "white_dye",
+    "orange_dye",
+    "magenta_dye",
+    "light_blue_dye",
+    "yellow_dye",
+    "lime_dye",
+    "pink_dye",
+    "gray_dye",
+    "light_gray_dye",
+    "cyan_dye",
+    "purple_dye",
+    "blue_dye",
+    "brown_dye",
+    "green_dye",
+    "red_dye",
+    "black_dye",
+    "bone_meal",
+    "bone",
+    "sugar",
+    "cake",
+    "white_bed",
+    "orange_bed",
+    "magenta_bed",
+    "light_blue_bed",
+    "yellow_bed",
+    "lime_bed",
+    "pink_bed",
+    "gray_bed",
+    "light_gray_bed",
+    "cyan_bed",
+    "purple_bed",
+    "blue_bed",
+    "brown_bed",
+    "green_bed",
+    "red_bed",
+    "black_bed",
+    "cookie",
+    "filled_map",
+    "shears",
+    "melon_slice",
+    "dried_kelp",
+    "pumpkin_seeds",
+    "melon_seeds",
+    "beef",
+    "cooked_beef",
+    "chicken",
+    "cooked_chicken",
+    "rotten_flesh",
+    "ender_pearl",
+    "blaze_rod",
+    "ghast_tear",
+    "gold_nugget",
+    "nether_wart",
+    "potion",
+    "glass_bottle",
+    "spider_eye",
+    "fermented_spider_eye",
+    "blaze_powder",
+    "magma_cream",
+    "brewing_stand",
+    "cauldron",
+    "ender_eye",
+    "glistering_melon_slice",
+    "axolotl_spawn_egg",
+    "bat_spawn_egg",
+    "bee_spawn_egg",
+    "blaze_spawn_egg",
+    "cat_spawn_egg",
+    "cave_spider_spawn_egg",
+    "chicken_spawn_egg",
+    "cod_spawn_egg",
+    "cow_spawn_egg",
+    "creeper_spawn_egg",
+    "dolphin_spawn_egg",
+    "donkey_spawn_egg",
+    "drowned_spawn_egg",
+    "elder_guardian_spawn_egg",
+    "enderman_spawn_egg",
+    "endermite_spawn_egg",
+    "evoker_spawn_egg",
+    "fox_spawn_egg",
+    "ghast_spawn_egg",
+    "glow_squid_spawn_egg",
+    "goat_spawn_egg",
+    "guardian_spawn_egg",
+    "hoglin_spawn_egg",
+    "horse_spawn_egg",
+    "husk_spawn_egg",
+    "llama_spawn_egg",
+    "magma_cube_spawn_egg",
+    "mooshroom_spawn_egg",
+    "mule_spawn_egg",
+    "ocelot_spawn_egg",
+    "panda_spawn_egg",
+    "parrot_spawn_egg",
+    "phantom_spawn_egg",
+    "pig_spawn_egg",
+    "piglin_spawn_egg",
+    "piglin_brute_spawn_egg",
+    "pillager_spawn_egg",
+    "polar_bear_spawn_egg",
+    "pufferfish_spawn_egg",
+    "rabbit_spawn_egg",
+    "ravager_spawn_egg",
+    "salmon_spawn_egg",
+    "sheep_spawn_egg",
+    "shulker_spawn_egg",
+    "silverfish_spawn_egg",
+    "skeleton_spawn_egg",
+    "skeleton_horse_spawn_egg",
+    "slime_spawn_egg",
+    "spider_spawn_egg",
+    "squid_spawn_egg",
+    "stray_spawn_egg",
+    "strider_spawn_egg",
+    "trader_llama_spawn_egg",
+    "tropical_fish_spawn_egg",
+    "turtle_spawn_egg",
+    "vex_spawn_egg",
+    "villager_spawn_egg",
+    "vindicator_spawn_egg",
+    "wandering_trader_spawn_egg",
+    "witch_spawn_egg",
+    "wither_skeleton_spawn_egg",
+    "wolf_spawn_egg",
+    "zoglin_spawn_egg",
+    "zombie_spawn_egg",
+    "zombie_horse_spawn_egg",
+    "zombie_villager_spawn_egg",
+    "zombified_piglin_spawn_egg",
+    "experience_bottle",
+    "fire_charge",
+    "writable_book",
+    "written_book",
+    "item_frame",
+    "glow_item_frame",
+    "flower_pot",
+    "carrot",
+    "potato",
+    "baked_potato",
+    "poisonous_potato",
+    "map",
+    "golden_carrot",
+    "skeleton_skull",
+    "wither_skeleton_skull",
+    "player_head",
+    "zombie_head",
+    "creeper_head",
+    "dragon_head",
+    "nether_star",
+    "pumpkin_pie",
+    "firework_rocket",
+    "firework_star",
+    "enchanted_book",
+    "nether_brick",
+    "prismarine_shard",
+    "prismarine_crystals",
+    "rabbit",
+    "cooked_rabbit",
+    "rabbit_stew",
+    "rabbit_foot",
+    "rabbit_hide",
+    "armor_stand",
+    "iron_horse_armor",
+    "golden_horse_armor",
+    "diamond_horse_armor",
+    "leather_horse_armor",
+    "lead",
+    "name_tag",
+    "command_block_minecart",
+    "mutton",
+    "cooked_mutton",
+    "white_banner",
+    "orange_banner",
+    "magenta_banner",
+    "light_blue_banner",
+    "yellow_banner",
+    "lime_banner",
+    "pink_banner",
+    "gray_banner",
+    "light_gray_banner",
+    "cyan_banner",
+    "purple_banner",
+    "blue_banner",
+    "brown_banner",
+    "green_banner",
+    "red_banner",
+    "black_banner",
+    "end_crystal",
+    "chorus_fruit",
+    "popped_chorus_fruit",
+    "beetroot",
+    "beetroot_seeds",
+    "beetroot_soup",
+    "dragon_breath",
+    "splash_potion",
+    "spectral_arrow",
+    "tipped_arrow",
+    "lingering_potion",
+    "shield",
+    "totem_of_undying",
+    "shulker_shell",
+    "iron_nugget",
+    "knowledge_book",
+    "debug_stick",
+    "music_disc_13",
+    "music_disc_cat",
+    "music_disc_blocks",
+    "music_disc_chirp",
+    "music_disc_far",
+    "music_disc_mall",
+    "music_disc_mellohi",
+    "music_disc_stal",
+    "music_disc_strad",
+    "music_disc_ward",
+    "music_disc_11",
+    "music_disc_wait",
+    "music_disc_pigstep",
+    "trident",
+    "phantom_membrane",
+    "nautilus_shell",
+    "heart_of_the_sea",
+    "crossbow",
+    "suspicious_stew",
+    "loom",
+    "flower_banner_pattern",
+    "creeper_banner_pattern",
+    "skull_banner_pattern",
+    "mojang_banner_pattern",
+    "globe_banner_pattern",
+    "piglin_banner_pattern",
+    "composter",
+    "barrel",
+    "smoker",
+    "blast_furnace",
+    "cartography_table",
+    "fletching_table",
+    "grindstone",
+    "smithing_table",
+    "stonecutter",
+    "bell",
+    "lantern",
+    "soul_lantern",
+    "sweet_berries",
+    "glow_berries",
+    "campfire",
+    "soul_campfire",
+    "shroomlight",
+    "honeycomb",
+    "bee_nest",
+    "beehive",
+    "honey_bottle",
+    "honeycomb_block",
+    "lodestone",
+    "crying_obsidian",
+    "blackstone",
+    "blackstone_slab",
+    "blackstone_stairs",
+    "gilded_blackstone",
+    "polished_blackstone",
+    "polished_blackstone_slab",
+    "polished_blackstone_stairs",
+    "chiseled_polished_blackstone",
+    "polished_blackstone_bricks",
+    "polished_blackstone_brick_slab",
+    "polished_blackstone_brick_stairs",
+    "cracked_polished_blackstone_bricks",
+    "respawn_anchor",
+    "candle",
+    "white_candle",
+    "orange_candle",
+    "magenta_candle",
+    "light_blue_candle",
+    "yellow_candle",
+    "lime_candle",
+    "pink_candle",
+    "gray_candle",
+    "light_gray_candle",
+    "cyan_candle",
+    "purple_candle",
+    "blue_candle",
+    "brown_candle",
+    "green_candle",
+    "red_candle",
+    "black_candle",
+    "small_amethyst_bud",
+    "medium_amethyst_bud",
+    "large_amethyst_bud",
+    "amethyst_cluster",
+    "pointed_dripstone",
+];
+
+const ITEMS_1_18: &[&str] = &[
+    "air",
+    "stone",
+    "granite",
+    "polished_granite",
+    "diorite",
+    "polished_diorite",
+    "andesite",
+    "polished_andesite",
+    "deepslate",
+    "cobbled_deepslate",
+    "polished_deepslate",
+    "calcite",
+    "tuff",
+    "dripstone_block",
+    "grass_block",
+    "dirt",
+    "coarse_dirt",
+    "podzol",
+    "rooted_dirt",
+    "crimson_nylium",
+    "warped_nylium",
+    "cobblestone",
+    "oak_planks",
+    "spruce_planks",
+    "birch_planks",
+    "jungle_planks",
+    "acacia_planks",
+    "dark_oak_planks",
+    "crimson_planks",
+    "warped_planks",
+    "oak_sapling",
+    "spruce_sapling",
+    "birch_sapling",
+    "jungle_sapling",
+    "acacia_sapling",
+    "dark_oak_sapling",
+    "bedrock",
+    "sand",
+    "red_sand",
+    "gravel",
+    "coal_ore",
+    "deepslate_coal_ore",
+    "iron_ore",
+    "deepslate_iron_ore",
+    "copper_ore",
+    "deepslate_copper_ore",
+    "gold_ore",
+    "deepslate_gold_ore",
+    "redstone_ore",
+    "deepslate_redstone_ore",
+    "emerald_ore",
+    "deepslate_emerald_ore",
+    "lapis_ore",
+    "deepslate_lapis_ore",
+    "diamond_ore",
+    "deepslate_diamond_ore",
+    "nether_gold_ore",
+    "nether_quartz_ore",
+    "ancient_debris",
+    "coal_block",
+    "raw_iron_block",
+    "raw_copper_block",
+    "raw_gold_block",
+    "amethyst_block",
+    "budding_amethyst",
+    "iron_block",
+    "copper_block",
+    "gold_block",
+    "diamond_block",
+    "netherite_block",
+    "exposed_copper",
+    "weathered_copper",
+    "oxidized_copper",
+    "cut_copper",
+    "exposed_cut_copper",
+    "weathered_cut_copper",
+    "oxidized_cut_copper",
+    "cut_copper_stairs",
+    "exposed_cut_copper_stairs",
+    "weathered_cut_copper_stairs",
+    "oxidized_cut_copper_stairs",
+    "cut_copper_slab",
+    "exposed_cut_copper_slab",
+    "weathered_cut_copper_slab",
+    "oxidized_cut_copper_slab",
+    "waxed_copper_block",
+    "waxed_exposed_copper",
+    "waxed_weathered_copper",
+    "waxed_oxidized_copper",
+    "waxed_cut_copper",
+    "waxed_exposed_cut_copper",
+    "waxed_weathered_cut_copper",
+    "waxed_oxidized_cut_copper",
+    "waxed_cut_copper_stairs",
+    "waxed_exposed_cut_copper_stairs",
+    "waxed_weathered_cut_copper_stairs",
+    "waxed_oxidized_cut_copper_stairs",
+    "waxed_cut_copper_slab",
+    "waxed_exposed_cut_copper_slab",
+    "waxed_weathered_cut_copper_slab",
+    "waxed_oxidized_cut_copper_slab",
+    "oak_log",
+    "spruce_log",
+    "birch_log",
+    "jungle_log",
+    "acacia_log",
+    "dark_oak_log",
+    "crimson_stem",
+    "warped_stem",
+    "stripped_oak_log",
+    "stripped_spruce_log",
+    "stripped_birch_log",
+    "stripped_jungle_log",
+    "stripped_acacia_log",
+    "stripped_dark_oak_log",
+    "stripped_crimson_stem",
+    "stripped_warped_stem",
+    "stripped_oak_wood",
+    "stripped_spruce_wood",
+    "stripped_birch_wood",
+    "stripped_jungle_wood",
+    "stripped_acacia_wood",
+    "stripped_dark_oak_wood",
+    "stripped_crimson_hyphae",
+    "stripped_warped_hyphae",
+    "oak_wood",
+    "spruce_wood",
+    "birch_wood",
+    "jungle_wood",
+    "acacia_wood",
+    "dark_oak_wood",
+    "crimson_hyphae",
+    "warped_hyphae",
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "azalea_leaves",
+    "flowering_azalea_leaves",
+    "sponge",
+    "wet_sponge",
+    "glass",
+    "tinted_glass",
+    "lapis_block",
+    "sandstone",
+    "chiseled_sandstone",
+    "cut_sandstone",
+    "cobweb",
+    "grass",
+    "fern",
+    "azalea",
+    "flowering_azalea",
+    "dead_bush",
+    "seagrass",
+    "sea_pickle",
+    "white_wool",
+    "orange_wool",
+    "magenta_wool",
+    "light_blue_wool",
+    "yellow_wool",
+    "lime_wool",
+    "pink_wool",
+    "gray_wool",
+    "light_gray_wool",
+    "cyan_wool",
+    "purple_wool",
+    "blue_wool",
+    "brown_wool",
+    "green_wool",
+    "red_wool",
+    "black_wool",
+    "dandelion",
+    "poppy",
+    "blue_orchid",
+    "allium",
+    "azure_bluet",
+    "red_tulip",
+    "orange_tulip",
+    "white_tulip",
+    "pink_tulip",
+    "oxeye_daisy",
+    "cornflower",
+    "lily_of_the_valley",
+    "wither_rose",
+    "spore_blossom",
+    "brown_mushroom",
+    "red_mushroom",
+    "crimson_fungus",
+    "warped_fungus",
+    "crimson_roots",
+    "warped_roots",
+    "nether_sprouts",
+    "weeping_vines",
+    "twisting_vines",
+    "sugar_cane",
+    "kelp",
+    "moss_carpet",
+    "moss_block",
+    "hanging_roots",
+    "big_dripleaf",
+    "small_dripleaf",
+    "bamboo",
+    "oak_slab",
+    "spruce_slab",
+    "birch_slab",
+    "jungle_slab",
+    "acacia_slab",
+    "dark_oak_slab",
+    "crimson_slab",
+    "warped_slab",
+    "stone_slab",
+    "smooth_stone_slab",
+    "sandstone_slab",
+    "cut_sandstone_slab",
+    "petrified_oak_slab",
+    "cobblestone_slab",
+    "brick_slab",
+    "stone_brick_slab",
+    "nether_brick_slab",
+    "quartz_slab",
+    "red_sandstone_slab",
+    "cut_red_sandstone_slab",
+    "purpur_slab",
+    "prismarine_slab",
+    "prismarine_brick_slab",
+    "dark_prismarine_slab",
+    "smooth_quartz",
+    "smooth_red_sandstone",
+    "smooth_sandstone",
+    "smooth_stone",
+    "bricks",
+    "bookshelf",
+    "mossy_cobblestone",
+    "obsidian",
+    "torch",
+    "end_rod",
+    "chorus_plant",
+    "chorus_flower",
+    "purpur_block",
+    "purpur_pillar",
+    "purpur_stairs",
+    "spawner",
+    "oak_stairs",
+    "chest",
+    "crafting_table",
+    "farmland",
+    "furnace",
+    "ladder",
+    "cobblestone_stairs",
+    "snow",
+    "ice",
+    "snow_block",
+    "cactus",
+    "clay",
+    "jukebox",
+    "oak_fence",
+    "spruce_fence",
+    "birch_fence",
+    "jungle_fence",
+    "acacia_fence",
+    "dark_oak_fence",
+    "crimson_fence",
+    "warped_fence",
+    "pumpkin",
+    "carved_pumpkin",
+    "jack_o_lantern",
+    "netherrack",
+    "soul_sand",
+    "soul_soil",
+    "basalt",
+    "polished_basalt",
+    "smooth_basalt",
+    "soul_torch",
+    "glowstone",
+    "infested_stone",
+    "infested_cobblestone",
+    "infested_stone_bricks",
+    "infested_mossy_stone_bricks",
+    "infested_cracked_stone_bricks",
+    "infested_chiseled_stone_bricks",
+    "infested_deepslate",
+    "stone_bricks",
+    "mossy_stone_bricks",
+    "cracked_stone_bricks",
+    "chiseled_stone_bricks",
+    "deepslate_bricks",
+    "cracked_deepslate_bricks",
+    "deepslate_tiles",
+    "cracked_deepslate_tiles",
+    "chiseled_deepslate",
+    "brown_mushroom_block",
+    "red_mushroom_block",
+    "mushroom_stem",
+    "iron_bars",
+    "chain",
+    "glass_pane",
+    "melon",
+    "vine",
+    "glow_lichen",
+    "brick_stairs",
+    "stone_brick_stairs",
+    "mycelium",
+    "lily_pad",
+    "nether_bricks",
+    "cracked_nether_bricks",
+    "chiseled_nether_bricks",
+    "nether_brick_fence",
+    "nether_brick_stairs",
+    "enchanting_table",
+    "end_portal_frame",
+    "end_stone",
+    "end_stone_bricks",
+    "dragon_egg",
+    "sandstone_stairs",
+    "ender_chest",
+    "emerald_block",
+    "spruce_stairs",
+    "birch_stairs",
+    "jungle_stairs",
+    "crimson_stairs",
+    "warped_stairs",
+    "command_block",
+    "beacon",
+    "cobblestone_wall",
+    "mossy_cobblestone_wall",
+    "brick_wall",
+    "prismarine_wall",
+    "red_sandstone_wall",
+    "mossy_stone_brick_wall",
+    "granite_wall",
+    "stone_brick_wall",
+    "nether_brick_wall",
+    "andesite_wall",
+    "red_nether_brick_wall",
+    "sandstone_wall",
+    "end_stone_brick_wall",
+    "diorite_wall",
+    "blackstone_wall",
+    "polished_blackstone_wall",
+    "polished_blackstone_brick_wall",
+    "cobbled_deepslate_wall",
+    "polished_deepslate_wall",
+    "deepslate_brick_wall",
+    "deepslate_tile_wall",
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "chiseled_quartz_block",
+    "quartz_block",
+    "quartz_bricks",
+    "quartz_pillar",
+    "quartz_stairs",
+    "white_terracotta",
+    "orange_terracotta",
+    "magenta_terracotta",
+    "light_blue_terracotta",
+    "yellow_terracotta",
+    "lime_terracotta",
+    "pink_terracotta",
+    "gray_terracotta",
+    "light_gray_terracotta",
+    "cyan_terracotta",
+    "purple_terracotta",
+    "blue_terracotta",
+    "brown_terracotta",
+    "green_terracotta",
+    "red_terracotta",
+    "black_terracotta",
+    "barrier",
+    "light",
+    "hay_block",
+    "white_carpet",
+    "orange_carpet",
+    "magenta_carpet",
+    "light_blue_carpet",
+    "yellow_carpet",
+    "lime_carpet",
+    "pink_carpet",
+    "gray_carpet",
+    "light_gray_carpet",
+    "cyan_carpet",
+    "purple_carpet",
+    "blue_carpet",
+    "brown_carpet",
+    "green_carpet",
+    "red_carpet",
+    "black_carpet",
+    "terracotta",
+    "packed_ice",
+    "acacia_stairs",
+    "dark_oak_stairs",
+    "dirt_path",
+    "sunflower",
+    "lilac",
+    "rose_bush",
+    "peony",
+    "tall_grass",
+    "large_fern",
+    "white_stained_glass",
+    "orange_stained_glass",
+    "magenta_stained_glass",
+    "light_blue_stained_glass",
+    "yellow_stained_glass",
+    "lime_stained_glass",
+    "pink_stained_glass",
+    "gray_stained_glass",
+    "light_gray_stained_glass",
+    "cyan_stained_glass",
+    "purple_stained_glass",
+    "blue_stained_glass",
+    "brown_stained_glass",
+    "green_stained_glass",
+    "red_stained_glass",
+    "black_stained_glass",
+    "white_stained_glass_pane",
+    "orange_stained_glass_pane",
+    "magenta_stained_glass_pane",
+    "light_blue_stained_glass_pane",
+    "yellow_stained_glass_pane",
+    "lime_stained_glass_pane",
+    "pink_stained_glass_pane",
+    "gray_stained_glass_pane",
+    "light_gray_stained_glass_pane",
+    "cyan_stained_glass_pane",
+    "purple_stained_glass_pane",
+    "blue_stained_glass_pane",
+    "brown_stained_glass_pane",
+    "green_stained_glass_pane",
+    "red_stained_glass_pane",
+    "black_stained_glass_pane",
+    "prismarine",
+    "prismarine_bricks",
+    "dark_prismarine",
+    "prismarine_stairs",
+    "prismarine_brick_stairs",
+    "dark_prismarine_stairs",
+    "sea_lantern",
+    "red_sandstone",
+    "chiseled_red_sandstone",
+    "cut_red_sandstone",
+    "red_sandstone_stairs",
+    "repeating_command_block",
+    "chain_command_block",
+    "magma_block",
+    "nether_wart_block",
+    "warped_wart_block",
+    "red_nether_bricks",
+    "bone_block",
+    "structure_void",
+    "shulker_box",
+    "white_shulker_box",
+    "orange_shulker_box",
+    "magenta_shulker_box",
+    "light_blue_shulker_box",
+    "yellow_shulker_box",
+    "lime_shulker_box",
+    "pink_shulker_box",
+    "gray_shulker_box",
+    "light_gray_shulker_box",
+    "cyan_shulker_box",
+    "purple_shulker_box",
+    "blue_shulker_box",
+    "brown_shulker_box",
+    "green_shulker_box",
+    "red_shulker_box",
+    "black_shulker_box",
+    "white_glazed_terracotta",
+    "orange_glazed_terracotta",
+    "magenta_glazed_terracotta",
+    "light_blue_glazed_terracotta",
+    "yellow_glazed_terracotta",
+    "lime_glazed_terracotta",
+    "pink_glazed_terracotta",
+    "gray_glazed_terracotta",
+    "light_gray_glazed_terracotta",
+    "cyan_glazed_terracotta",
+    "purple_glazed_terracotta",
+    "blue_glazed_terracotta",
+    "brown_glazed_terracotta",
+    "green_glazed_terracotta",
+    "red_glazed_terracotta",
+    "black_glazed_terracotta",
+    "white_concrete",
+    "orange_concrete",
+    "magenta_concrete",
+    "light_blue_concrete",
+    "yellow_concrete",
+    "lime_concrete",
+    "pink_concrete",
+    "gray_concrete",
+    "light_gray_concrete",
+    "cyan_concrete",
+    "purple_concrete",
+    "blue_concrete",
+    "brown_concrete",
+    "green_concrete",
+    "red_concrete",
+    "black_concrete",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+    "turtle_egg",
+    "dead_tube_coral_block",
+    "dead_brain_coral_block",
+    "dead_bubble_coral_block",
+    "dead_fire_coral_block",
+    "dead_horn_coral_block",
+    "tube_coral_block",
+    "brain_coral_block",
+    "bubble_coral_block",
+    "fire_coral_block",
+    "horn_coral_block",
+    "tube_coral",
+    "brain_coral",
+    "bubble_coral",
+    "fire_coral",
+    "horn_coral",
+    "dead_brain_coral",
+    "dead_bubble_coral",
+    "dead_fire_coral",
+    "dead_horn_coral",
+    "dead_tube_coral",
+    "tube_coral_fan",
+    "brain_coral_fan",
+    "bubble_coral_fan",
+    "fire_coral_fan",
+    "horn_coral_fan",
+    "dead_tube_coral_fan",
+    "dead_brain_coral_fan",
+    "dead_bubble_coral_fan",
+    "dead_fire_coral_fan",
+    "dead_horn_coral_fan",
+    "blue_ice",
+    "conduit",
+    "polished_granite_stairs",
+    "smooth_red_sandstone_stairs",
+    "mossy_stone_brick_stairs",
+    "polished_diorite_stairs",
+    "mossy_cobblestone_stairs",
+    "end_stone_brick_stairs",
+    "stone_stairs",
+    "smooth_sandstone_stairs",
+    "smooth_quartz_stairs",
+    "granite_stairs",
+    "andesite_stairs",
+    "red_nether_brick_stairs",
+    "polished_andesite_stairs",
+    "diorite_stairs",
+    "cobbled_deepslate_stairs",
+    "polished_deepslate_stairs",
+    "deepslate_brick_stairs",
+    "deepslate_tile_stairs",
+    "polished_granite_slab",
+    "smooth_red_sandstone_slab",
+    "mossy_stone_brick_slab",
+    "polished_diorite_slab",
+    "mossy_cobblestone_slab",
+    "end_stone_brick_slab",
+    "smooth_sandstone_slab",
+    "smooth_quartz_slab",
+    "granite_slab",
+    "andesite_slab",
+    "red_nether_brick_slab",
+    "polished_andesite_slab",
+    "diorite_slab",
+    "cobbled_deepslate_slab",
+    "polished_deepslate_slab",
+    "deepslate_brick_slab",
+    "deepslate_tile_slab",
+    "scaffolding",
+    "redstone",
+    "redstone_torch",
+    "redstone_block",
+    "repeater",
+    "comparator",
+    "piston",
+    "sticky_piston",
+    "slime_block",
+    "honey_block",
+    "observer",
+    "hopper",
+    "dispenser",
+    "dropper",
+    "lectern",
+    "target",
+    "lever",
+    "lightning_rod",
+    "daylight_detector",
+    "sculk_sensor",
+    "tripwire_hook",
+    "trapped_chest",
+    "tnt",
+    "redstone_lamp",
+    "note_block",
+    "stone_button",
+    "polished_blackstone_button",
+    "oak_button",
+    "spruce_button",
+    "birch_button",
+    "jungle_button",
+    "acacia_button",
+    "dark_oak_button",
+    "crimson_button",
+    "warped_button",
+    "stone_pressure_plate",
+    "polished_blackstone_pressure_plate",
+    "light_weighted_pressure_plate",
+    "heavy_weighted_pressure_plate",
+    "oak_pressure_plate",
+    "spruce_pressure_plate",
+    "birch_pressure_plate",
+    "jungle_pressure_plate",
+    "acacia_pressure_plate",
+    "dark_oak_pressure_plate",
+    "crimson_pressure_plate",
+    "warped_pressure_plate",
+    "iron_door",
+    "oak_door",
+    "spruce_door",
+    "birch_door",
+    "jungle_door",
+    "acacia_door",
+    "dark_oak_door",
+    "crimson_door",
+    "warped_door",
+    "iron_trapdoor",
+    "oak_trapdoor",
+    "spruce_trapdoor",
+    "birch_trapdoor",
+    "jungle_trapdoor",
+    "acacia_trapdoor",
+    "dark_oak_trapdoor",
+    "crimson_trapdoor",
+    "warped_trapdoor",
+    "oak_fence_gate",
+    "spruce_fence_gate",
+    "birch_fence_gate",
+    "jungle_fence_gate",
+    "acacia_fence_gate",
+    "dark_oak_fence_gate",
+    "crimson_fence_gate",
+    "warped_fence_gate",
+    "powered_rail",
+    "detector_rail",
+    "rail",
+    "activator_rail",
+    "saddle",
+    "minecart",
+    "chest_minecart",
+    "furnace_minecart",
+    "tnt_minecart",
+    "hopper_minecart",
+    "carrot_on_a_stick",
+    "warped_fungus_on_a_stick",
+    "elytra",
+    "oak_boat",
+    "spruce_boat",
+    "birch_boat",
+    "jungle_boat",
+    "acacia_boat",
+    "dark_oak_boat",
+    "structure_block",
+    "jigsaw",
+    "turtle_helmet",
+    "scute",
+    "flint_and_steel",
+    "apple",
+    "bow",
+    "arrow",
+    "coal",
+    "charcoal",
+    "diamond",
+    "emerald",
+    "lapis_lazuli",
+    "quartz",
+    "amethyst_shard",
+    "raw_iron",
+    "iron_ingot",
+    "raw_copper",
+    "copper_ingot",
+    "raw_gold",
+    "gold_ingot",
+    "netherite_ingot",
+    "netherite_scrap",
+    "wooden_sword",
+    "wooden_shovel",
+    "wooden_pickaxe",
+    "wooden_axe",
+    "wooden_hoe",
+    "stone_sword",
+    "stone_shovel",
+    "stone_pickaxe",
+    "stone_axe",
+    "stone_hoe",
+    "golden_sword",
+    "golden_shovel",
+    "golden_pickaxe",
+    "golden_axe",
+    "golden_hoe",
+    "iron_sword",
+    "iron_shovel",
+    "iron_pickaxe",
+    "iron_axe",
+    "iron_hoe",
+    "diamond_sword",
+    "diamond_shovel",
+    "diamond_pickaxe",
+    "diamond_axe",
+    "diamond_hoe",
+    "netherite_sword",
+    "netherite_shovel",
+    "netherite_pickaxe",
+    "netherite_axe",
+    "netherite_hoe",
+    "stick",
+    "bowl",
+    "mushroom_stew",
+    "string",
+    "feather",
+    "gunpowder",
+    "wheat_seeds",
+    "wheat",
+    "bread",
+    "leather_helmet",
+    "leather_chestplate",
+    "leather_leggings",
+    "leather_boots",
+    "chainmail_helmet",
+    "chainmail_chestplate",
+    "chainmail_leggings",
+    "chainmail_boots",
+    "iron_helmet",
+    "iron_chestplate",
+    "iron_leggings",
+    "iron_boots",
+    "diamond_helmet",
+    "diamond_chestplate",
+    "diamond_leggings",
+    "diamond_boots",
+    "golden_helmet",
+    "golden_chestplate",
+    "golden_leggings",
+    "golden_boots",
+    "netherite_helmet",
+    "netherite_chestplate",
+    "netherite_leggings",
+    "netherite_boots",
+    "flint",
+    "porkchop",
+    "cooked_porkchop",
+    "painting",
+    "golden_apple",
+    "enchanted_golden_apple",
+    "oak_sign",
+    "spruce_sign",
+    "birch_sign",
+    "jungle_sign",
+    "acacia_sign",
+    "dark_oak_sign",
+    "crimson_sign",
+    "warped_sign",
+    "bucket",
+    "water_bucket",
+    "lava_bucket",
+    "powder_snow_bucket",
+    "snowball",
+    "leather",
+    "milk_bucket",
+    "pufferfish_bucket",
+    "salmon_bucket",
+    "cod_bucket",
+    "tropical_fish_bucket",
+    "axolotl_bucket",
+    "brick",
+    "clay_ball",
+    "dried_kelp_block",
+    "paper",
+    "book",
+    "slime_ball",
+    "egg",
+    "compass",
+    "bundle",
+    "fishing_rod",
+    "clock",
+    "spyglass",
+    "glowstone_dust",
+    "cod",
+    "salmon",
+    "tropical_fish",
+    "pufferfish",
+    "cooked_cod",
+    "cooked_salmon",
+    "ink_sac",
+    "glow_ink_sac",
+    "cocoa_beans",
+    "white_dye",
+    "orange_dye",
+    "magenta_dye",
+    "light_blue_dye",
+    "yellow_dye",
+    "lime_dye",
+    "pink_dye",
+    "gray_dye",
+    "light_gray_dye",
+    "cyan_dye",
+    "purple_dye",
+    "blue_dye",
+    "brown_dye",
+    "green_dye",
+    "red_dye",
+    "black_dye",
+    "bone_meal",
+    "bone",
+    "sugar",
+    "cake",
+    "white_bed",
+    "orange_bed",
+    "magenta_bed",
+    "light_blue_bed",
+    "yellow_bed",
+    "lime_bed",
+    "pink_bed",
+    "gray_bed",
+    "light_gray_bed",
+    "cyan_bed",
+    "purple_bed",
+    "blue_bed",
+    "brown_bed",
+    "green_bed",
+    "red_bed",
+    "black_bed",
+    "cookie",
+    "filled_map",
+    "shears",
+    "melon_slice",
+    "dried_kelp",
+    "pumpkin_seeds",
+    "melon_seeds",
+    "beef",
+    "cooked_beef",
+    "chicken",
+    "cooked_chicken",
+    "rotten_flesh",
+    "ender_pearl",
+    "blaze_rod",
+    "ghast_tear",
+    "gold_nugget",
+    "nether_wart",
+    "potion",
+    "glass_bottle",
+    "spider_eye",
+    "fermented_spider_eye",
+    "blaze_powder",
+    "magma_cream",
+    "brewing_stand",
+    "cauldron",
+    "ender_eye",
+    "glistering_melon_slice",
+    "axolotl_spawn_egg",
+    "bat_spawn_egg",
+    "bee_spawn_egg",
+    "blaze_spawn_egg",
+    "cat_spawn_egg",
+    "cave_spider_spawn_egg",
+    "chicken_spawn_egg",
+    "cod_spawn_egg",
+    "cow_spawn_egg",
+    "creeper_spawn_egg",
+    "dolphin_spawn_egg",
+    "donkey_spawn_egg",
+    "drowned_spawn_egg",
+    "elder_guardian_spawn_egg",
+    "enderman_spawn_egg",
+    "endermite_spawn_egg",
+    "evoker_spawn_egg",
+    "fox_spawn_egg",
+    "ghast_spawn_egg",
+    "glow_squid_spawn_egg",
+    "goat_spawn_egg",
+    "guardian_spawn_egg",
+    "hoglin_spawn_egg",
+    "horse_spawn_egg",
+    "husk_spawn_egg",
+    "llama_spawn_egg",
+    "magma_cube_spawn_egg",
+    "mooshroom_spawn_egg",
+    "mule_spawn_egg",
+    "ocelot_spawn_egg",
+    "panda_spawn_egg",
+    "parrot_spawn_egg",
+    "phantom_spawn_egg",
+    "pig_spawn_egg",
+    "piglin_spawn_egg",
+    "piglin_brute_spawn_egg",
+    "pillager_spawn_egg",
+    "polar_bear_spawn_egg",
+    "pufferfish_spawn_egg",
+    "rabbit_spawn_egg",
+    "ravager_spawn_egg",
+    "salmon_spawn_egg",
+    "sheep_spawn_egg",
+    "shulker_spawn_egg",
+    "silverfish_spawn_egg",
+    "skeleton_spawn_egg",
+    "skeleton_horse_spawn_egg",
+    "slime_spawn_egg",
+    "spider_spawn_egg",
+    "squid_spawn_egg",
+    "stray_spawn_egg",
+    "strider_spawn_egg",
+    "trader_llama_spawn_egg",
+    "tropical_fish_spawn_egg",
+    "turtle_spawn_egg",
+    "vex_spawn_egg",
+    "villager_spawn_egg",
+    "vindicator_spawn_egg",
+    "wandering_trader_spawn_egg",
+    "witch_spawn_egg",
+    "wither_skeleton_spawn_egg",
+    "wolf_spawn_egg",
+    "zoglin_spawn_egg",
+    "zombie_spawn_egg",
+    "zombie_horse_spawn_egg",
+    "zombie_villager_spawn_egg",
+    "zombified_piglin_spawn_egg",
+    "experience_bottle",
+    "fire_charge",
+    "writable_book",
+    "written_book",
+    "item_frame",
+    "glow_item_frame",
+    "flower_pot",
+    "carrot",
+    "potato",
+    "baked_potato",
+    "poisonous_potato",
+    "map",
+    "golden_carrot",
+    "skeleton_skull",
+    "wither_skeleton_skull",
+    "player_head",
+    "zombie_head",
+    "creeper_head",
+    "dragon_head",
+    "nether_star",
+    "pumpkin_pie",
+    "firework_rocket",
+    "firework_star",
+    "enchanted_book",
+    "nether_brick",
+    "prismarine_shard",
+    "prismarine_crystals",
+    "rabbit",
+    "cooked_rabbit",
+    "rabbit_stew",
+    "rabbit_foot",
+    "rabbit_hide",
+    "armor_stand",
+    "iron_horse_armor",
+    "golden_horse_armor",
+    "diamond_horse_armor",
+    "leather_horse_armor",
+    "lead",
+    "name_tag",
+    "command_block_minecart",
+    "mutton",
+    "cooked_mutton",
+    "white_banner",
+    "orange_banner",
+    "magenta_banner",
+    "light_blue_banner",
+    "yellow_banner",
+    "lime_banner",
+    "pink_banner",
+    "gray_banner",
+    "light_gray_banner",
+    "cyan_banner",
+    "purple_banner",
+    "blue_banner",
+    "brown_banner",
+    "green_banner",
+    "red_banner",
+    "black_banner",
+    "end_crystal",
+    "chorus_fruit",
+    "popped_chorus_fruit",
+    "beetroot",
+    "beetroot_seeds",
+    "beetroot_soup",
+    "dragon_breath",
+    "splash_potion",
+    "spectral_arrow",
+    "tipped_arrow",
+    "lingering_potion",
+    "shield",
+    "totem_of_undying",
+    "shulker_shell",
+    "iron_nugget",
+    "knowledge_book",
+    "debug_stick",
+    "music_disc_13",
+    "music_disc_cat",
+    "music_disc_blocks",
+    "music_disc_chirp",
+    "music_disc_far",
+    "music_disc_mall",
+    "music_disc_mellohi",
+    "music_disc_stal",
+    "music_disc_strad",
+    "music_disc_ward",
+    "music_disc_11",
+    "music_disc_wait",
+    "music_disc_otherside",
+    "music_disc_pigstep",
+    "trident",
+    "phantom_membrane",
+    "nautilus_shell",
+    "heart_of_the_sea",
+    "crossbow",
+    "suspicious_stew",
+    "loom",
+    "flower_banner_pattern",
+    "creeper_banner_pattern",
+    "skull_banner_pattern",
+    "mojang_banner_pattern",
+    "globe_banner_pattern",
+    "piglin_banner_pattern",
+    "composter",
+    "barrel",
+    "smoker",
+    "blast_furnace",
+    "cartography_table",
+    "fletching_table",
+    "grindstone",
+    "smithing_table",
+    "stonecutter",
+    "bell",
+    "lantern",
+    "soul_lantern",
+    "sweet_berries",
+    "glow_berries",
+    "campfire",
+    "soul_campfire",
+    "shroomlight",
+    "honeycomb",
+    "bee_nest",
+    "beehive",
+    "honey_bottle",
+    "honeycomb_block",
+    "lodestone",
+    "crying_obsidian",
+    "blackstone",
+    "blackstone_slab",
+    "blackstone_stairs",
+    "gilded_blackstone",
+    "polished_blackstone",
+    "polished_blackstone_slab",
+    "polished_blackstone_stairs",
+    "chiseled_polished_blackstone",
+    "polished_blackstone_bricks",
+    "polished_blackstone_brick_slab",
+    "polished_blackstone_brick_stairs",
+    "cracked_polished_blackstone_bricks",
+    "respawn_anchor",
+    "candle",
+    "white_candle",
+    "orange_candle",
+    "magenta_candle",
+    "light_blue_candle",
+    "yellow_candle",
+    "lime_candle",
+    "pink_candle",
+    "gray_candle",
+    "light_gray_candle",
+    "cyan_candle",
+    "purple_candle",
+    "blue_candle",
+    "brown_candle",
+    "green_candle",
+    "red_candle",
+    "black_candle",
+    "small_amethyst_bud",
+    "medium_amethyst_bud",
+    "large_amethyst_bud",
+    "amethyst_cluster",
+    "pointed_dripstone",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_per_version() {
+        assert_eq!(item_name(404, 1), Some("stone"));
+        assert_eq!(item_name(404, 472), Some("iron_shovel"));
+        assert_eq!(item_name(452, 789), Some("heart_of_the_sea"));
+        assert_eq!(item_name(498, 589), Some("oak_sign"));
+        assert_eq!(item_name(578, 698), Some("bee_spawn_egg"));
+        assert_eq!(item_name(736, 821), Some("zombified_piglin_spawn_egg"));
+        assert_eq!(item_name(754, 791), Some("piglin_brute_spawn_egg"));
+        assert_eq!(item_name(756, 1027), Some("music_disc_pigstep"));
+        assert_eq!(item_name(758, 1027), Some("music_disc_otherside"));
+        assert_eq!(item_name(758, 1100), Some("pointed_dripstone"));
+        assert_eq!(item_name(758, 1101), None);
+        assert_eq!(item_name(758, -1), None);
+    }
+
+    #[test]
+    fn legacy_items_are_registered() {
+        for &(name, _, _) in LEGACY_ITEMS {
+            assert!(
+                [ITEMS_1_13, ITEMS_1_14, ITEMS_1_17]
+                    .iter()
+                    .any(|items| items.contains(&name)),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn legacy_ids() {
+        assert_eq!(legacy_item("stone"), Some((1, 0)));
+        assert_eq!(legacy_item("dark_oak_wood"), Some((162, 13)));
+        assert_eq!(legacy_item("white_banner"), Some((425, 15)));
+        assert_eq!(legacy_item("yellow_dye"), Some((351, 11)));
+        assert_eq!(legacy_item("music_disc_wait"), Some((2267, 0)));
+        assert_eq!(legacy_item("trident"), None);
+    }
+}
@@ -1,3 +1,5 @@
+pub mod item;
+mod item_registry;
 pub mod liquid;
 
 use crate::render;
@@ -7,7 +9,7 @@ use crate::world;
 use crate::world::block::{Block, TintType};
 use byteorder::{NativeEndian, WriteBytesExt};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::sync::{Arc, RwLock};
 
@@ -27,6 +29,10 @@ pub struct Factory {
 
     grass_colors: image::DynamicImage,
     foliage_colors: image::DynamicImage,
+
+    item_icons: HashMap<(i32, isize, isize), String>,
+    rendered_icons: HashSet<String>,
+    item_id_map: Option<(i32, world::block::VanillaIDMap)>,
 }
 
 #[derive(PartialEq, Eq, Hash, Clone)]
@@ -69,6 +75,10 @@ impl Factory {
             textures,
 
             models: HashMap::with_hasher(BuildHasherDefault::default()),
+
+            item_icons: HashMap::new(),
+            rendered_icons: HashSet::new(),
+            item_id_map: None,
         }
     }
 
@@ -90,6 +100,7 @@ impl Factory {
         self.models.clear();
         self.grass_colors = Factory::load_biome_colors(self.resources.clone(), "grass");
         self.foliage_colors = Factory::load_biome_colors(self.resources.clone(), "foliage");
+        self.clear_item_icons();
     }
 
     fn get_model<R: Rng, W: Write>(
@@ -882,7 +893,6 @@ struct RawModel {
 
     #[allow(dead_code)]
     display: HashMap<String, ModelDisplay, BuildHasherDefault<FNVHash>>,
-    #[allow(dead_code)]
     builtin: BuiltinType,
 }
 
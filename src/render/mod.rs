@@ -80,6 +80,12 @@ pub struct Renderer {
     // Light renderering
     pub light_level: f32,
     pub sky_offset: f32,
+    /// Pulls the fog in close around the camera, set by boss bars
+    pub boss_fog: bool,
+    /// Surrounds the camera with black fog a few blocks away
+    pub blindness: bool,
+    /// Scales the field of view, widened while flying or moving fast
+    pub fov_modifier: f32,
     skin_request: mpsc::Sender<String>,
     skin_reply: mpsc::Receiver<(String, Option<image::DynamicImage>)>,
 }
@@ -121,6 +127,8 @@ init_shader! {
             required texture => "textures",
             required light_level => "lightLevel",
             required sky_offset => "skyOffset",
+            required fog_color => "fogColor",
+            required fog_distance => "fogDistance",
         },
     }
 }
@@ -143,10 +151,18 @@ init_shader! {
             required texture => "textures",
             required light_level => "lightLevel",
             required sky_offset => "skyOffset",
+            required fog_color => "fogColor",
+            required fog_distance => "fogDistance",
         },
     }
 }
 
+// Distances the boss fog starts and becomes solid at, the fog is
+// disabled by an end distance of zero
+const BOSS_FOG: (f32, f32) = (10.0, 96.0);
+const NO_FOG: (f32, f32) = (0.0, 0.0);
+const BLIND_FOG: (f32, f32) = (1.25, 5.0);
+
 impl Renderer {
     pub fn new(res: Arc<RwLock<resources::Manager>>, shader_version: &str) -> Renderer {
         let version = { res.read().unwrap().version() };
@@ -236,6 +252,9 @@ impl Renderer {
 
             light_level: 0.8,
             sky_offset: 1.0,
+            boss_fog: false,
+            blindness: false,
+            fov_modifier: 1.0,
             skin_request: skin_req,
             skin_reply,
         }
@@ -264,19 +283,19 @@ impl Renderer {
             self.height = height;
             gl::viewport(0, 0, width as i32, height as i32);
 
-            let fovy = cgmath::Rad::from(cgmath::Deg(90.0_f32));
-            let aspect = (width as f32 / height as f32).max(1.0);
-
-            self.perspective_matrix = cgmath::Matrix4::from(cgmath::PerspectiveFov {
-                fovy,
-                aspect,
-                near: 0.1f32,
-                far: 500.0f32,
-            });
-
             self.init_trans(width, height);
         }
 
+        let fovy = cgmath::Rad::from(cgmath::Deg(90.0_f32 * self.fov_modifier));
+        let aspect = (width as f32 / height as f32).max(1.0);
+
+        self.perspective_matrix = cgmath::Matrix4::from(cgmath::PerspectiveFov {
+            fovy,
+            aspect,
+            near: 0.1f32,
+            far: 500.0f32,
+        });
+
         self.view_vector = cgmath::Vector3::new(
             ((self.camera.yaw - PI64 / 2.0).cos() * -self.camera.pitch.cos()) as f32,
             (-self.camera.pitch.sin()) as f32,
@@ -320,12 +339,17 @@ impl Renderer {
         gl::enable(gl::MULTISAMPLE);
 
         let time_offset = self.sky_offset * 0.9;
-        gl::clear_color(
-            (122.0 / 255.0) * time_offset,
-            (165.0 / 255.0) * time_offset,
-            (247.0 / 255.0) * time_offset,
-            1.0,
-        );
+        let (sky_color, fog_distance) = if self.blindness {
+            ((0.0, 0.0, 0.0), BLIND_FOG)
+        } else {
+            let sky_color = (
+                (122.0 / 255.0) * time_offset,
+                (165.0 / 255.0) * time_offset,
+                (247.0 / 255.0) * time_offset,
+            );
+            (sky_color, if self.boss_fog { BOSS_FOG } else { NO_FOG })
+        };
+        gl::clear_color(sky_color.0, sky_color.1, sky_color.2, 1.0);
         gl::clear(gl::ClearFlags::Color | gl::ClearFlags::Depth);
 
         // Chunk rendering
@@ -340,6 +364,12 @@ impl Renderer {
         self.chunk_shader.texture.set_int(0);
         self.chunk_shader.light_level.set_float(self.light_level);
         self.chunk_shader.sky_offset.set_float(self.sky_offset);
+        self.chunk_shader
+            .fog_color
+            .set_float3(sky_color.0, sky_color.1, sky_color.2);
+        self.chunk_shader
+            .fog_distance
+            .set_float2(fog_distance.0, fog_distance.1);
 
         for (pos, info) in world.get_render_list() {
             if let Some(solid) = info.solid.as_ref() {
@@ -396,6 +426,12 @@ impl Renderer {
         self.chunk_shader_alpha
             .sky_offset
             .set_float(self.sky_offset);
+        self.chunk_shader_alpha
+            .fog_color
+            .set_float3(sky_color.0, sky_color.1, sky_color.2);
+        self.chunk_shader_alpha
+            .fog_distance
+            .set_float2(fog_distance.0, fog_distance.1);
 
         // Copy the depth buffer
         trans.main.bind_read();
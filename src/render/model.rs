@@ -446,15 +446,18 @@ pub struct FormatState<'a> {
 
 impl<'a> FormatState<'a> {
     pub fn build(&mut self, c: &Component, color: format::Color) {
-        match *c {
-            format::Component::Text(ref txt) => {
-                let col = FormatState::get_color(&txt.modifier, color);
-                self.append_text(&txt.text, col);
-                let modi = &txt.modifier;
-                if let Some(ref extra) = modi.extra {
-                    for e in extra {
-                        self.build(e, col);
-                    }
+        let txt = c.resolve(&|key| self.renderer.ui.translate(key));
+        self.build_text(&txt, color);
+    }
+
+    fn build_text(&mut self, txt: &format::TextComponent, color: format::Color) {
+        let col = FormatState::get_color(&txt.modifier, color);
+        self.append_text(&txt.text, col);
+        if let Some(ref extra) = txt.modifier.extra {
+            for e in extra {
+                // Resolving leaves nothing but text components
+                if let format::Component::Text(ref e) = *e {
+                    self.build_text(e, col);
                 }
             }
         }
@@ -496,3 +499,36 @@ impl<'a> FormatState<'a> {
         modi.color.unwrap_or(color)
     }
 }
+
+/// Appends the text drawn above an entity, centered on the origin with
+/// its shadow behind it.
+pub fn append_name_tag(verts: &mut Vec<Vertex>, renderer: &mut super::Renderer, name: &Component) {
+    let mut state = FormatState {
+        width: 0.0,
+        offset: 0.0,
+        text: Vec::new(),
+        renderer,
+        y_scale: 0.16,
+        x_scale: 0.01,
+    };
+    state.build(name, format::Color::White);
+    let width = state.width;
+    // Center align text
+    for vert in &mut state.text {
+        vert.x += width * 0.5;
+    }
+    // The shadow is a darker copy of the text behind it
+    let mut shadow = state.text.clone();
+    for vert in &mut shadow {
+        vert.r /= 4;
+        vert.g /= 4;
+        vert.b /= 4;
+    }
+    verts.extend_from_slice(&shadow);
+    for vert in &mut state.text {
+        vert.x -= 0.01;
+        vert.y -= 0.01;
+        vert.z -= 0.05;
+    }
+    verts.extend_from_slice(&state.text);
+}
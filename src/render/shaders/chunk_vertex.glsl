@@ -15,13 +15,16 @@ out vec4 vTextureInfo;
 out vec2 vTextureOffset;
 out float vAtlas;
 out vec3 vLighting;
+out float vDistance;
 
 #include get_light
 
 void main() {
     vec3 pos = vec3(aPosition.x, -aPosition.y, aPosition.z);
     vec3 o = vec3(float(offset.x), -float(offset.y) / 4096.0, float(offset.z));
-    gl_Position = perspectiveMatrix * cameraMatrix * vec4(pos + o * 16.0, 1.0);
+    vec4 viewPos = cameraMatrix * vec4(pos + o * 16.0, 1.0);
+    gl_Position = perspectiveMatrix * viewPos;
+    vDistance = length(viewPos.xyz);
 
     vColor = aColor;
     vTextureInfo = aTextureInfo;
@@ -24,6 +24,9 @@ use std::sync::{Arc, RwLock};
 
 const UI_WIDTH: f64 = 854.0;
 const UI_HEIGHT: f64 = 480.0;
+// Always loaded first so keys missing from other languages still
+// have a translation
+const DEFAULT_LOCALE: &str = "en_us";
 
 pub struct UIState {
     textures: Arc<RwLock<render::TextureManager>>,
@@ -48,6 +51,10 @@ pub struct UIState {
     char_map: HashMap<char, char>,
     page_width: f64,
     page_height: f64,
+
+    // Language
+    locale: String,
+    translations: HashMap<String, String>,
 }
 
 init_shader! {
@@ -135,6 +142,9 @@ impl UIState {
             char_map,
             page_width: 0.0,
             page_height: 0.0,
+
+            locale: DEFAULT_LOCALE.to_owned(),
+            translations: HashMap::new(),
         };
         state.load_font();
         state
@@ -146,6 +156,7 @@ impl UIState {
             if self.version != version {
                 self.version = version;
                 self.load_font();
+                self.load_lang();
             }
         }
         // Prevent clipping with the world
@@ -257,6 +268,49 @@ impl UIState {
         (info.1 - info.0) as f64
     }
 
+    /// Changes the language translations are looked up in, reloading
+    /// them if it differs from the current one.
+    pub fn set_locale(&mut self, locale: &str) {
+        let locale = locale.to_lowercase();
+        if self.locale != locale {
+            self.locale = locale;
+            self.load_lang();
+        }
+    }
+
+    /// Returns the current language's text for the translation key.
+    pub fn translate(&self, key: &str) -> Option<String> {
+        self.translations.get(key).cloned()
+    }
+
+    fn load_lang(&mut self) {
+        self.translations.clear();
+        let res = self.resources.read().unwrap();
+        let mut locales = vec![DEFAULT_LOCALE];
+        if self.locale != DEFAULT_LOCALE {
+            locales.push(&self.locale);
+        }
+        for locale in locales {
+            for name in &lang_file_names(locale) {
+                // Lower priority packs first so higher ones replace
+                // their translations
+                for mut file in res.open_all("minecraft", name).into_iter().rev() {
+                    let mut data = String::new();
+                    if file.read_to_string(&mut data).is_err() {
+                        continue;
+                    }
+                    if name.ends_with(".json") {
+                        if let Ok(values) = serde_json::from_str::<HashMap<String, String>>(&data) {
+                            self.translations.extend(values);
+                        }
+                    } else {
+                        self.translations.extend(parse_legacy_lang(&data));
+                    }
+                }
+            }
+        }
+    }
+
     fn load_font(&mut self) {
         for page in &mut self.font_pages {
             *page = None;
@@ -420,6 +474,26 @@ impl UIState {
     }
 }
 
+/// Files the locale's translations can be in. 1.13+ uses json files,
+/// older versions key=value files which used upper case countries
+/// before 1.11.
+fn lang_file_names(locale: &str) -> [String; 3] {
+    let (lang, country) = locale.split_once('_').unwrap_or((locale, ""));
+    [
+        format!("lang/{}.json", locale),
+        format!("lang/{}.lang", locale),
+        format!("lang/{}_{}.lang", lang, country.to_uppercase()),
+    ]
+}
+
+/// Reads the translations from a key=value language file.
+fn parse_legacy_lang(data: &str) -> impl Iterator<Item = (String, String)> + '_ {
+    data.lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+}
+
 pub struct UIText {
     pub elements: Vec<UIElement>,
     pub width: f64,
@@ -575,3 +649,34 @@ impl UIElement {
         buf.write_u8(self.a);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lang_files() {
+        assert_eq!(
+            lang_file_names("en_us"),
+            [
+                "lang/en_us.json".to_owned(),
+                "lang/en_us.lang".to_owned(),
+                "lang/en_US.lang".to_owned(),
+            ]
+        );
+        assert_eq!(lang_file_names("lol")[2], "lang/lol_.lang");
+    }
+
+    #[test]
+    fn legacy_lang() {
+        let data = "# Comment\nchat.type.text=<%s> %s\n\nmath=1+1=2\nbroken line\n";
+        let translations: Vec<_> = parse_legacy_lang(data).collect();
+        assert_eq!(
+            translations,
+            vec![
+                ("chat.type.text".to_owned(), "<%s> %s".to_owned()),
+                ("math".to_owned(), "1+1=2".to_owned()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::format::Component;
+use crate::render;
+use crate::ui;
+use std::sync::{Arc, RwLock};
+
+/// Shown while the player is dead, closed once the server respawns them.
+pub struct Death {
+    elements: Option<UIElements>,
+    message: Arc<RwLock<Option<Component>>>,
+    shown_message: Option<Component>,
+}
+
+struct UIElements {
+    _background: ui::ImageRef,
+    _title: ui::TextRef,
+    _message: Option<ui::FormattedRef>,
+    _respawn: ui::ButtonRef,
+    _title_screen: ui::ButtonRef,
+}
+
+impl Death {
+    pub fn new(message: Arc<RwLock<Option<Component>>>) -> Death {
+        Death {
+            elements: None,
+            message,
+            shown_message: None,
+        }
+    }
+
+    fn create_message(
+        ui_container: &mut ui::Container,
+        message: Option<Component>,
+    ) -> Option<ui::FormattedRef> {
+        message.map(|message| {
+            ui::FormattedBuilder::new()
+                .text(message)
+                .position(0.0, -40.0)
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .create(ui_container)
+        })
+    }
+}
+
+impl super::Screen for Death {
+    fn on_active(&mut self, _renderer: &mut render::Renderer, ui_container: &mut ui::Container) {
+        let background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(0.0, 0.0)
+            .size(854.0, 480.0)
+            .colour((128, 0, 0, 100))
+            .create(ui_container);
+
+        let title = ui::TextBuilder::new()
+            .text("You died!")
+            .position(0.0, -100.0)
+            .scale_x(2.0)
+            .scale_y(2.0)
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+
+        self.shown_message = self.message.read().unwrap().clone();
+        let message = Death::create_message(ui_container, self.shown_message.clone());
+
+        // Respawn
+        let respawn = ui::ButtonBuilder::new()
+            .position(0.0, 20.0)
+            .size(400.0, 40.0)
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+        {
+            let mut respawn = respawn.borrow_mut();
+            let txt = ui::TextBuilder::new()
+                .text("Respawn")
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .attach(&mut *respawn);
+            respawn.add_text(txt);
+            respawn.add_click_func(|btn, game| {
+                // The screen is closed once the server has respawned us
+                btn.disabled = true;
+                game.server.perform_respawn();
+                true
+            });
+        }
+
+        // Title screen
+        let title_screen = ui::ButtonBuilder::new()
+            .position(0.0, 70.0)
+            .size(400.0, 40.0)
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+        {
+            let mut title_screen = title_screen.borrow_mut();
+            let txt = ui::TextBuilder::new()
+                .text("Title screen")
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .attach(&mut *title_screen);
+            title_screen.add_text(txt);
+            title_screen.add_click_func(|_, game| {
+                game.server.disconnect(None);
+                game.screen_sys
+                    .replace_screen(Box::new(super::ServerList::new(None)));
+                true
+            });
+        }
+
+        self.elements = Some(UIElements {
+            _background: background,
+            _title: title,
+            _message: message,
+            _respawn: respawn,
+            _title_screen: title_screen,
+        });
+    }
+
+    fn on_deactive(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        // Clean up
+        self.elements = None
+    }
+
+    fn tick(
+        &mut self,
+        _delta: f64,
+        _renderer: &mut render::Renderer,
+        ui_container: &mut ui::Container,
+    ) -> Option<Box<dyn super::Screen>> {
+        // The server can send the message after the player's health
+        // has already run out
+        let message = self.message.read().unwrap();
+        if *message != self.shown_message {
+            self.shown_message = message.clone();
+            if let Some(elements) = self.elements.as_mut() {
+                elements._message = Death::create_message(ui_container, message.clone());
+            }
+        }
+        None
+    }
+
+    fn is_death_screen(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,425 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, RwLock};
+
+use crate::format;
+use crate::inventory::{self, WindowKind};
+use crate::item;
+use crate::model;
+use crate::render;
+use crate::ui;
+
+// Vanilla gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const SLOT_SIZE: f64 = 16.0 * SCALE;
+
+pub struct UIElements {
+    _background: ui::ImageRef,
+    _window: ui::ImageRef,
+    _parts: Vec<ui::ImageRef>,
+    _title: Option<ui::FormattedRef>,
+    progress: Vec<ui::ImageRef>,
+    slots: Vec<ui::ImageRef>,
+    items: Vec<Option<ItemElements>>,
+    _cursor: Option<ItemElements>,
+}
+
+/// The elements drawing an item stack in a slot.
+pub struct ItemElements {
+    _icon: ui::ImageRef,
+    _count: Option<ui::TextRef>,
+}
+
+/// How a window is drawn, in texture pixels.
+struct Layout {
+    texture: &'static str,
+    width: f64,
+    height: f64,
+    /// Regions of the texture making up the window, as source x, y,
+    /// width and height followed by the y position to draw it at.
+    parts: Vec<(f64, f64, f64, f64, f64)>,
+    /// Position of every slot of the window in order.
+    slots: Vec<(f64, f64)>,
+    show_title: bool,
+}
+
+impl Layout {
+    fn new(window: &inventory::Window) -> Layout {
+        let container_slots = window.player_start();
+        let (texture, height, container, player_top) = match window.kind {
+            WindowKind::Player => {
+                let mut slots = vec![(154.0, 28.0)];
+                slots.extend(grid(98.0, 18.0, 2, 4));
+                slots.extend(grid(8.0, 8.0, 1, 4));
+                let mut layout = Layout::simple("gui/container/inventory", 166.0, slots, 84.0);
+                // Off hand
+                if window.slots.len() > layout.slots.len() {
+                    layout.slots.push((77.0, 62.0));
+                }
+                layout.show_title = false;
+                return layout;
+            }
+            WindowKind::CraftingTable => {
+                let mut slots = vec![(124.0, 35.0)];
+                slots.extend(grid(30.0, 17.0, 3, 9));
+                ("gui/container/crafting_table", 166.0, slots, 84.0)
+            }
+            WindowKind::Furnace => (
+                "gui/container/furnace",
+                166.0,
+                vec![(56.0, 17.0), (56.0, 53.0), (116.0, 35.0)],
+                84.0,
+            ),
+            WindowKind::Dispenser => (
+                "gui/container/dispenser",
+                166.0,
+                grid(62.0, 17.0, 3, 9),
+                84.0,
+            ),
+            WindowKind::Hopper => ("gui/container/hopper", 133.0, grid(44.0, 20.0, 5, 5), 51.0),
+            WindowKind::BrewingStand => (
+                "gui/container/brewing_stand",
+                166.0,
+                vec![
+                    (56.0, 51.0),
+                    (79.0, 58.0),
+                    (102.0, 51.0),
+                    (79.0, 17.0),
+                    (17.0, 17.0),
+                ],
+                84.0,
+            ),
+            WindowKind::Chest(_) | WindowKind::Other => {
+                // Built from the top of the large chest texture and the
+                // player's inventory at its bottom
+                let rows = (container_slots + 8) / 9;
+                let top = rows as f64 * 18.0 + 17.0;
+                let mut slots = grid(8.0, 18.0, 9, container_slots);
+                slots.extend(player_slots(top + 14.0));
+                return Layout {
+                    texture: "gui/container/generic_54",
+                    width: 176.0,
+                    height: top + 96.0,
+                    parts: vec![(0.0, 0.0, 176.0, top, 0.0), (0.0, 126.0, 176.0, 96.0, top)],
+                    slots,
+                    show_title: true,
+                };
+            }
+        };
+        let container = container.into_iter().take(container_slots).collect();
+        Layout::simple(texture, height, container, player_top)
+    }
+
+    /// A layout drawn from a single region of its texture with the
+    /// player's inventory below the container's slots.
+    fn simple(
+        texture: &'static str,
+        height: f64,
+        mut slots: Vec<(f64, f64)>,
+        player_top: f64,
+    ) -> Layout {
+        slots.extend(player_slots(player_top));
+        Layout {
+            texture,
+            width: 176.0,
+            height,
+            parts: vec![(0.0, 0.0, 176.0, height, 0.0)],
+            slots,
+            show_title: true,
+        }
+    }
+}
+
+/// Positions of `count` slots laid out in rows of `width`.
+fn grid(x: f64, y: f64, width: usize, count: usize) -> Vec<(f64, f64)> {
+    (0..count)
+        .map(|i| (x + (i % width) as f64 * 18.0, y + (i / width) as f64 * 18.0))
+        .collect()
+}
+
+/// Positions of the main inventory followed by the hotbar, starting at
+/// the given height.
+fn player_slots(top: f64) -> Vec<(f64, f64)> {
+    let mut slots = grid(8.0, top, 9, 27);
+    slots.extend(grid(8.0, top + 58.0, 9, 9));
+    slots
+}
+
+/// Moves an image to draw the given region of its texture at the
+/// given position, in texture pixels.
+fn set_region(image: &ui::ImageRef, src: (f64, f64, f64, f64), x: f64, y: f64) {
+    let mut image = image.borrow_mut();
+    image.x = x * SCALE;
+    image.y = y * SCALE;
+    image.width = src.2 * SCALE;
+    image.height = src.3 * SCALE;
+    image.texture_coords = (src.0 / 256.0, src.1 / 256.0, src.2 / 256.0, src.3 / 256.0);
+}
+
+/// Draws an item stack inside a slot sized element, moved down and to
+/// the right by `offset`.
+pub fn create_item(
+    parent: &ui::ImageRef,
+    models: &Arc<RwLock<model::Factory>>,
+    protocol_version: i32,
+    stack: &item::Stack,
+    offset: f64,
+) -> ItemElements {
+    let texture = model::Factory::get_item_icon(models, stack, protocol_version);
+    let mut parent = parent.borrow_mut();
+    let icon = ui::ImageBuilder::new()
+        .texture(texture)
+        .position(offset, offset)
+        .size(SLOT_SIZE, SLOT_SIZE)
+        .create(&mut *parent);
+    let count = if stack.count > 1 {
+        Some(
+            ui::TextBuilder::new()
+                .text(format!("{}", stack.count))
+                .position(-offset, -2.0 - offset)
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Right)
+                .create(&mut *parent),
+        )
+    } else {
+        None
+    };
+    ItemElements {
+        _icon: icon,
+        _count: count,
+    }
+}
+
+/// Shows the contents of an inventory window and lets the player
+/// move items around in it.
+pub struct InventoryWindow {
+    inventory: Arc<RwLock<inventory::Inventory>>,
+    models: Arc<RwLock<model::Factory>>,
+    window_id: u8,
+    elements: Option<UIElements>,
+    last_version: Option<usize>,
+}
+
+impl InventoryWindow {
+    pub fn new(
+        inventory: Arc<RwLock<inventory::Inventory>>,
+        models: Arc<RwLock<model::Factory>>,
+        window_id: u8,
+    ) -> InventoryWindow {
+        InventoryWindow {
+            inventory,
+            models,
+            window_id,
+            elements: None,
+            last_version: None,
+        }
+    }
+
+    fn update_progress(&self, inventory: &inventory::Inventory, window: &inventory::Window) {
+        let progress = &self.elements.as_ref().unwrap().progress;
+        match window.kind {
+            WindowKind::Furnace => {
+                let (fuel, cooked) = inventory.furnace_progress(self.window_id);
+                let flame = if fuel > 0.0 {
+                    (fuel * 13.0).round() + 1.0
+                } else {
+                    0.0
+                };
+                set_region(
+                    &progress[0],
+                    (176.0, 13.0 - flame, 14.0, flame),
+                    56.0,
+                    49.0 - flame,
+                );
+                let arrow = (cooked * 24.0).round() + 1.0;
+                set_region(&progress[1], (176.0, 14.0, arrow, 16.0), 79.0, 34.0);
+            }
+            WindowKind::BrewingStand => {
+                let brew_time = window.property(0) as f64;
+                let arrow = if brew_time > 0.0 {
+                    (28.0 * (1.0 - brew_time / 400.0)).round()
+                } else {
+                    0.0
+                };
+                set_region(&progress[0], (176.0, 0.0, 9.0, arrow), 97.0, 16.0);
+                // Fuel was added in 1.9, older servers never send it
+                let fuel = ((18 * window.property(1) as i32 + 19) / 20).clamp(0, 18) as f64;
+                set_region(&progress[1], (176.0, 29.0, fuel, 4.0), 60.0, 44.0);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl super::Screen for InventoryWindow {
+    fn init(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        self.inventory.write().unwrap().open(self.window_id);
+    }
+
+    fn deinit(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        self.inventory.write().unwrap().close(self.window_id);
+    }
+
+    fn on_active(&mut self, _renderer: &mut render::Renderer, ui_container: &mut ui::Container) {
+        let inventory = self.inventory.clone();
+        let inventory = inventory.read().unwrap();
+        let window = match inventory.window(self.window_id) {
+            Some(window) => window,
+            None => return,
+        };
+        let layout = Layout::new(window);
+
+        let background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(0.0, 0.0)
+            .size(854.0, 480.0)
+            .colour((0, 0, 0, 100))
+            .create(ui_container);
+
+        let window_image = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .size(layout.width * SCALE, layout.height * SCALE)
+            .colour((0, 0, 0, 0))
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+        window_image.borrow_mut().add_hover_func(|_, over, game| {
+            game.server.inventory.write().unwrap().set_over_window(over);
+            false
+        });
+
+        let mut parts = vec![];
+        for &(x, y, width, height, top) in &layout.parts {
+            let part = ui::ImageBuilder::new()
+                .texture(layout.texture)
+                .size(width * SCALE, height * SCALE)
+                .create(&mut *window_image.borrow_mut());
+            set_region(&part, (x, y, width, height), 0.0, top);
+            parts.push(part);
+        }
+
+        let title = match window.title.clone() {
+            Some(mut title) if layout.show_title => {
+                let modifier = title.modifier_mut();
+                if modifier.color.is_none() {
+                    modifier.color = Some(format::Color::DarkGray);
+                }
+                Some(
+                    ui::FormattedBuilder::new()
+                        .text(title)
+                        .position(8.0 * SCALE, 6.0 * SCALE)
+                        .create(&mut *window_image.borrow_mut()),
+                )
+            }
+            _ => None,
+        };
+
+        let progress_count = match window.kind {
+            WindowKind::Furnace | WindowKind::BrewingStand => 2,
+            _ => 0,
+        };
+        let progress = (0..progress_count)
+            .map(|_| {
+                ui::ImageBuilder::new()
+                    .texture(layout.texture)
+                    .size(0.0, 0.0)
+                    .create(&mut *window_image.borrow_mut())
+            })
+            .collect();
+
+        let mut slots = vec![];
+        for (i, &(x, y)) in layout.slots.iter().take(window.slots.len()).enumerate() {
+            let slot = ui::ImageBuilder::new()
+                .texture("steven:solid")
+                .position(x * SCALE, y * SCALE)
+                .size(SLOT_SIZE, SLOT_SIZE)
+                .colour((255, 255, 255, 0))
+                .create(&mut *window_image.borrow_mut());
+            slot.borrow_mut().add_hover_func(move |_, over, game| {
+                game.server
+                    .inventory
+                    .write()
+                    .unwrap()
+                    .hover_slot(i as i16, over);
+                false
+            });
+            slots.push(slot);
+        }
+
+        self.elements = Some(UIElements {
+            _background: background,
+            _window: window_image,
+            _parts: parts,
+            _title: title,
+            progress,
+            items: (0..slots.len()).map(|_| None).collect(),
+            slots,
+            _cursor: None,
+        });
+        self.last_version = None;
+    }
+
+    fn on_deactive(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        self.elements = None;
+    }
+
+    fn tick(
+        &mut self,
+        _delta: f64,
+        _renderer: &mut render::Renderer,
+        _ui_container: &mut ui::Container,
+    ) -> Option<Box<dyn super::Screen>> {
+        let inventory = self.inventory.clone();
+        let inventory = inventory.read().unwrap();
+        if self.elements.is_none() || self.last_version == Some(inventory.version()) {
+            return None;
+        }
+        self.last_version = Some(inventory.version());
+
+        let window = match inventory.window(self.window_id) {
+            Some(window) => window,
+            None => return None,
+        };
+        self.update_progress(&inventory, window);
+
+        let models = &self.models;
+        let protocol_version = inventory.protocol_version();
+        let elements = self.elements.as_mut().unwrap();
+        let hovered = inventory.hovered_slot();
+        for (i, slot) in elements.slots.iter().enumerate() {
+            slot.borrow_mut().colour.3 = if hovered == Some(i as i16) { 80 } else { 0 };
+            elements.items[i] = window
+                .get(i as i16)
+                .map(|stack| create_item(slot, models, protocol_version, stack, 0.0));
+        }
+
+        // The held item is drawn over the hovered slot, offset slightly
+        // so the slot's own contents stay visible.
+        elements._cursor = match (&inventory.cursor, hovered) {
+            (Some(stack), Some(slot)) => elements
+                .slots
+                .get(slot as usize)
+                .map(|slot| create_item(slot, models, protocol_version, stack, 8.0)),
+            _ => None,
+        };
+        None
+    }
+
+    fn is_closable(&self) -> bool {
+        true
+    }
+
+    fn window_id(&self) -> Option<u8> {
+        Some(self.window_id)
+    }
+}
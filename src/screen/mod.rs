@@ -18,8 +18,10 @@ mod login;
 pub use self::login::*;
 
 pub mod connecting;
+pub mod death;
 pub mod delete_server;
 pub mod edit_server;
+pub mod inventory;
 
 pub mod settings_menu;
 pub use self::settings_menu::{AudioSettingsMenu, SettingsMenu, VideoSettingsMenu};
@@ -50,6 +52,15 @@ pub trait Screen {
     fn is_closable(&self) -> bool {
         false
     }
+
+    // The inventory window shown by the screen, if any
+    fn window_id(&self) -> Option<u8> {
+        None
+    }
+
+    fn is_death_screen(&self) -> bool {
+        false
+    }
 }
 
 struct ScreenInfo {
@@ -83,11 +94,28 @@ impl ScreenSystem {
         }
     }
 
+    /// Removes every screen matching `f`, even ones below the current screen.
+    pub fn remove_screens<F: Fn(&dyn Screen) -> bool>(&mut self, f: F) {
+        let mut i = 0;
+        while i < self.screens.len() {
+            if f(&*self.screens[i].screen) {
+                let screen = self.screens.remove(i);
+                self.remove_queue.push(screen);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
     pub fn replace_screen(&mut self, screen: Box<dyn Screen>) {
         self.pop_screen();
         self.add_screen(screen);
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.screens.is_empty()
+    }
+
     pub fn is_current_closable(&self) -> bool {
         if let Some(last) = self.screens.last() {
             last.screen.is_closable()
@@ -148,3 +176,87 @@ impl ScreenSystem {
         current.screen.on_scroll(x, y);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum Dummy {
+        Menu,
+        Window(u8),
+        Death,
+    }
+
+    impl Screen for Dummy {
+        fn on_active(&mut self, _renderer: &mut render::Renderer, _ui: &mut ui::Container) {}
+        fn on_deactive(&mut self, _renderer: &mut render::Renderer, _ui: &mut ui::Container) {}
+
+        fn tick(
+            &mut self,
+            _delta: f64,
+            _renderer: &mut render::Renderer,
+            _ui_container: &mut ui::Container,
+        ) -> Option<Box<dyn Screen>> {
+            None
+        }
+
+        fn window_id(&self) -> Option<u8> {
+            match *self {
+                Dummy::Window(id) => Some(id),
+                _ => None,
+            }
+        }
+
+        fn is_death_screen(&self) -> bool {
+            matches!(self, Dummy::Death)
+        }
+    }
+
+    fn window_ids(screen_sys: &ScreenSystem) -> Vec<Option<u8>> {
+        screen_sys
+            .screens
+            .iter()
+            .map(|v| v.screen.window_id())
+            .collect()
+    }
+
+    #[test]
+    fn remove_screens_below_current() {
+        let mut screen_sys = ScreenSystem::new();
+        screen_sys.add_screen(Box::new(Dummy::Menu));
+        screen_sys.add_screen(Box::new(Dummy::Window(1)));
+        screen_sys.add_screen(Box::new(Dummy::Menu));
+
+        screen_sys.remove_screens(|screen| screen.window_id() == Some(2));
+        assert_eq!(window_ids(&screen_sys), vec![None, Some(1), None]);
+
+        screen_sys.remove_screens(|screen| screen.window_id() == Some(1));
+        assert_eq!(window_ids(&screen_sys), vec![None, None]);
+        assert_eq!(screen_sys.remove_queue.len(), 1);
+        assert!(!screen_sys.is_empty());
+
+        screen_sys.remove_screens(|_| true);
+        assert!(screen_sys.is_empty());
+    }
+
+    #[test]
+    fn respawn_keeps_windows_over_death_screen() {
+        let mut screen_sys = ScreenSystem::new();
+        screen_sys.add_screen(Box::new(Dummy::Death));
+        screen_sys.add_screen(Box::new(Dummy::Window(3)));
+
+        screen_sys.remove_screens(|screen| screen.is_death_screen());
+        assert_eq!(window_ids(&screen_sys), vec![Some(3)]);
+    }
+
+    #[test]
+    fn closing_window_keeps_death_screen() {
+        let mut screen_sys = ScreenSystem::new();
+        screen_sys.add_screen(Box::new(Dummy::Window(3)));
+        screen_sys.add_screen(Box::new(Dummy::Death));
+
+        screen_sys.remove_screens(|screen| screen.window_id() == Some(3));
+        assert_eq!(screen_sys.screens.len(), 1);
+        assert!(screen_sys.screens[0].screen.is_death_screen());
+    }
+}
@@ -0,0 +1,435 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::format::{self, Component, TextComponent};
+use crate::protocol::packet::{CommandNode, CommandProperty};
+use crate::protocol::VarInt;
+
+const NODE_TYPE_MASK: u8 = 0x03;
+const NODE_LITERAL: u8 = 1;
+const NODE_ARGUMENT: u8 = 2;
+
+// Arguments are coloured in turn so neighbouring ones can be told apart
+const ARGUMENT_COLORS: [format::Color; 5] = [
+    format::Color::Aqua,
+    format::Color::Yellow,
+    format::Color::Green,
+    format::Color::LightPurple,
+    format::Color::Gold,
+];
+
+// Token types of string arguments
+const STRING_QUOTABLE: i32 = 1;
+const STRING_GREEDY: i32 = 2;
+
+#[derive(Debug)]
+enum NodeKind {
+    Root,
+    Literal(String),
+    Argument(CommandProperty),
+}
+
+#[derive(Debug)]
+struct Node {
+    kind: NodeKind,
+    children: Vec<usize>,
+    redirect: Option<usize>,
+}
+
+/// The commands the server accepts, as sent in its command graph.
+/// Each node is either a literal word or an argument, and the command
+/// continues with the node's children or those of the node it
+/// redirects to.
+#[derive(Debug)]
+pub struct CommandTree {
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+/// What is known about a partially typed command.
+#[derive(Debug)]
+pub struct Parsed {
+    /// The command coloured by the part of the graph each word matched,
+    /// with anything that can't be parsed in red.
+    pub highlight: Component,
+    /// Byte offset of the word being typed, where completions start.
+    pub start: usize,
+    /// Literal words that could complete the word being typed.
+    pub suggestions: Vec<String>,
+    /// Whether the word being typed could be an argument, whose
+    /// completions only the server knows.
+    pub ask_server: bool,
+}
+
+impl CommandTree {
+    pub fn new(nodes: &[CommandNode], root: i32) -> CommandTree {
+        let count = nodes.len();
+        let index = |v: &VarInt| Some(v.0 as usize).filter(|&v| v < count);
+        let nodes = nodes
+            .iter()
+            .map(|node| Node {
+                kind: match node.flags & NODE_TYPE_MASK {
+                    NODE_LITERAL => NodeKind::Literal(node.name.clone().unwrap_or_default()),
+                    NODE_ARGUMENT => NodeKind::Argument(
+                        node.properties.clone().unwrap_or(CommandProperty::Message),
+                    ),
+                    _ => NodeKind::Root,
+                },
+                children: node.children.data.iter().filter_map(index).collect(),
+                redirect: node.redirect_node.as_ref().and_then(index),
+            })
+            .collect();
+        CommandTree {
+            nodes,
+            root: root as usize,
+        }
+    }
+
+    /// Returns the nodes that can follow the given one.
+    fn children(&self, node: usize) -> &[usize] {
+        let node = match self.nodes.get(node) {
+            Some(node) => node,
+            None => return &[],
+        };
+        match node.redirect {
+            Some(redirect) => &self.nodes[redirect].children,
+            None => &node.children,
+        }
+    }
+
+    /// Parses chat input starting with a `/`, returning `None` for
+    /// normal chat messages.
+    pub fn parse(&self, input: &str) -> Option<Parsed> {
+        let command = input.strip_prefix('/')?;
+        let mut parts = vec![TextComponent::new("/")];
+        let mut node = self.root;
+        let mut pos = 0;
+        let mut arguments = 0;
+
+        loop {
+            let rest = &command[pos..];
+            let children = self.children(node);
+            // Only a word followed by a space is finished, anything
+            // else may still change as it is typed
+            let next = children.iter().find_map(|&child| {
+                let len = self.consume(child, rest)?;
+                if rest[len..].starts_with(' ') {
+                    Some((child, len))
+                } else {
+                    None
+                }
+            });
+            if let Some((child, len)) = next {
+                parts.push(self.part(child, &rest[..len], &mut arguments));
+                parts.push(TextComponent::new(" "));
+                pos += len + 1;
+                node = child;
+                continue;
+            }
+
+            let typed = children
+                .iter()
+                .find(|&&child| self.consume(child, rest) == Some(rest.len()));
+            let mut part = match typed {
+                Some(&child) => self.part(child, rest, &mut arguments),
+                None => TextComponent::new(rest),
+            };
+            let suggestions: Vec<String> = children
+                .iter()
+                .filter_map(|&child| match &self.nodes[child].kind {
+                    NodeKind::Literal(name) if name.starts_with(rest) => Some(name.clone()),
+                    _ => None,
+                })
+                .collect();
+            if typed.is_none() && suggestions.is_empty() && !rest.is_empty() {
+                part.modifier.color = Some(format::Color::Red);
+            }
+            let ask_server = children
+                .iter()
+                .any(|&child| matches!(self.nodes[child].kind, NodeKind::Argument(_)));
+            parts.push(part);
+
+            let mut highlight = TextComponent::new("");
+            highlight.modifier.extra = Some(parts.into_iter().map(Component::Text).collect());
+            return Some(Parsed {
+                highlight: Component::Text(highlight),
+                start: pos + 1,
+                suggestions,
+                ask_server,
+            });
+        }
+    }
+
+    fn part(&self, node: usize, text: &str, arguments: &mut usize) -> TextComponent {
+        let mut part = TextComponent::new(text);
+        if let NodeKind::Argument(_) = self.nodes[node].kind {
+            part.modifier.color = Some(ARGUMENT_COLORS[*arguments % ARGUMENT_COLORS.len()]);
+            *arguments += 1;
+        }
+        part
+    }
+
+    /// Returns the length of the start of the input the node would
+    /// accept, if any.
+    fn consume(&self, node: usize, input: &str) -> Option<usize> {
+        match &self.nodes[node].kind {
+            NodeKind::Root => None,
+            NodeKind::Literal(name) => {
+                if input.starts_with(name.as_str())
+                    && (input.len() == name.len() || input[name.len()..].starts_with(' '))
+                {
+                    Some(name.len())
+                } else {
+                    None
+                }
+            }
+            NodeKind::Argument(property) => consume_argument(property, input),
+        }
+    }
+}
+
+fn consume_argument(property: &CommandProperty, input: &str) -> Option<usize> {
+    let len = match property {
+        CommandProperty::BlockPos | CommandProperty::Vec3 => words(input, 3),
+        CommandProperty::ColumnPos | CommandProperty::Vec2 | CommandProperty::Rotation => {
+            words(input, 2)
+        }
+        CommandProperty::Message => input.len(),
+        CommandProperty::String { token_type } if token_type.0 == STRING_GREEDY => input.len(),
+        CommandProperty::String { token_type }
+            if token_type.0 == STRING_QUOTABLE && input.starts_with('"') =>
+        {
+            quoted(input)
+        }
+        _ => word(input),
+    };
+    if len == 0 {
+        return None;
+    }
+    let text = &input[..len];
+    let valid = match property {
+        CommandProperty::Bool => text == "true" || text == "false",
+        CommandProperty::Integer { min, max, .. } => text.parse::<i32>().map_or(false, |v| {
+            min.map_or(true, |m| v >= m) && max.map_or(true, |m| v <= m)
+        }),
+        CommandProperty::Float { min, max, .. } => text.parse::<f32>().map_or(false, |v| {
+            min.map_or(true, |m| v >= m) && max.map_or(true, |m| v <= m)
+        }),
+        CommandProperty::Double { min, max, .. } => text.parse::<f64>().map_or(false, |v| {
+            min.map_or(true, |m| v >= m) && max.map_or(true, |m| v <= m)
+        }),
+        _ => true,
+    };
+    if valid {
+        Some(len)
+    } else {
+        None
+    }
+}
+
+/// Returns the length of the first word, keeping spaces inside
+/// brackets and quotes as part of it, such as in selectors and nbt.
+fn word(input: &str) -> usize {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in input.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' | '{' | '(' => depth += 1,
+                ']' | '}' | ')' => depth -= 1,
+                ' ' if depth <= 0 => return i,
+                _ => {}
+            },
+        }
+    }
+    input.len()
+}
+
+/// Returns the length of the given number of words, or of the whole
+/// input if it has fewer.
+fn words(input: &str, count: usize) -> usize {
+    let mut len = 0;
+    for i in 0..count {
+        if i > 0 {
+            if !input[len..].starts_with(' ') {
+                return input.len();
+            }
+            len += 1;
+        }
+        len += word(&input[len..]);
+    }
+    len
+}
+
+/// Returns the length of a quoted phrase including its quotes, or of
+/// the whole input if the closing quote hasn't been typed yet.
+fn quoted(input: &str) -> usize {
+    let mut escaped = false;
+    for (i, c) in input.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return i + 1,
+            _ => {}
+        }
+    }
+    input.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::LenPrefixed;
+
+    fn node(flags: u8, children: &[i32], name: Option<&str>) -> CommandNode {
+        CommandNode {
+            flags,
+            children: LenPrefixed::new(children.iter().map(|&v| VarInt(v)).collect()),
+            name: name.map(|v| v.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn argument(children: &[i32], name: &str, property: CommandProperty) -> CommandNode {
+        CommandNode {
+            properties: Some(property),
+            ..node(NODE_ARGUMENT, children, Some(name))
+        }
+    }
+
+    // /tp <x y z>, /give <target> <item> [<count>], /say <message>
+    // and /execute run ..., which redirects back to the root
+    fn tree() -> CommandTree {
+        let nodes = vec![
+            node(0, &[1, 3, 7, 9], None),
+            node(NODE_LITERAL, &[2], Some("tp")),
+            argument(&[], "location", CommandProperty::BlockPos),
+            node(NODE_LITERAL, &[4], Some("give")),
+            argument(&[5], "target", CommandProperty::Entity { flags: 0 }),
+            argument(&[6], "item", CommandProperty::ItemStack),
+            argument(
+                &[],
+                "count",
+                CommandProperty::Integer {
+                    flags: 1,
+                    min: Some(1),
+                    max: None,
+                },
+            ),
+            node(NODE_LITERAL, &[8], Some("say")),
+            argument(&[], "message", CommandProperty::Message),
+            node(NODE_LITERAL, &[10], Some("execute")),
+            CommandNode {
+                redirect_node: Some(VarInt(0)),
+                ..node(NODE_LITERAL | 0x08, &[], Some("run"))
+            },
+        ];
+        CommandTree::new(&nodes, 0)
+    }
+
+    fn colors(parsed: &Parsed) -> Vec<(String, Option<format::Color>)> {
+        match &parsed.highlight {
+            Component::Text(text) => text
+                .modifier
+                .extra
+                .iter()
+                .flatten()
+                .filter_map(|part| match part {
+                    Component::Text(part) if part.text != " " => {
+                        Some((part.text.clone(), part.modifier.color))
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => panic!("highlight should be text"),
+        }
+    }
+
+    #[test]
+    fn suggests_literals() {
+        let tree = tree();
+        assert!(tree.parse("hello /tp").is_none());
+
+        let parsed = tree.parse("/").unwrap();
+        assert_eq!(parsed.start, 1);
+        assert_eq!(parsed.suggestions, vec!["tp", "give", "say", "execute"]);
+        assert!(!parsed.ask_server);
+
+        let parsed = tree.parse("/execute run t").unwrap();
+        assert_eq!(parsed.start, 13);
+        assert_eq!(parsed.suggestions, vec!["tp"]);
+
+        let parsed = tree.parse("/give ").unwrap();
+        assert!(parsed.suggestions.is_empty());
+        assert!(parsed.ask_server);
+    }
+
+    #[test]
+    fn highlights_arguments() {
+        let tree = tree();
+        let parsed = tree
+            .parse("/give @a[name=\"a b\"] stone{display:{Name:\"x y\"}} 2")
+            .unwrap();
+        assert_eq!(
+            colors(&parsed),
+            vec![
+                ("/".to_owned(), None),
+                ("give".to_owned(), None),
+                ("@a[name=\"a b\"]".to_owned(), Some(format::Color::Aqua)),
+                (
+                    "stone{display:{Name:\"x y\"}}".to_owned(),
+                    Some(format::Color::Yellow)
+                ),
+                ("2".to_owned(), Some(format::Color::Green)),
+            ]
+        );
+
+        let parsed = tree.parse("/tp ~ ~1 ~ extra").unwrap();
+        assert_eq!(
+            colors(&parsed)[2..],
+            [
+                ("~ ~1 ~".to_owned(), Some(format::Color::Aqua)),
+                ("extra".to_owned(), Some(format::Color::Red)),
+            ]
+        );
+
+        let parsed = tree.parse("/say hello there").unwrap();
+        assert_eq!(
+            colors(&parsed)[2..],
+            [("hello there".to_owned(), Some(format::Color::Aqua))]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        let tree = tree();
+        let parsed = tree.parse("/give @p stone 0").unwrap();
+        assert_eq!(
+            colors(&parsed)[4],
+            ("0".to_owned(), Some(format::Color::Red))
+        );
+
+        let parsed = tree.parse("/kill").unwrap();
+        assert_eq!(
+            colors(&parsed)[1],
+            ("kill".to_owned(), Some(format::Color::Red))
+        );
+    }
+}
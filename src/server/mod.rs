@@ -15,17 +15,19 @@
 use crate::ecs;
 use crate::entity;
 use crate::format;
+use crate::inventory;
+use crate::item;
+use crate::model;
 use crate::protocol::{self, forge, mojang, packet};
 use crate::render;
 use crate::resources;
 use crate::settings::Stevenkey;
 use crate::shared::{Axis, Position};
 use crate::types::hash::FNVHash;
-use crate::types::Gamemode;
+use crate::types::{self, Gamemode};
 use crate::world;
 use crate::world::block;
 use cgmath::prelude::*;
-use instant::Instant;
 use log::{debug, error, info, warn};
 use rand::{self, Rng};
 use std::collections::HashMap;
@@ -35,7 +37,9 @@ use std::sync::mpsc;
 use std::sync::{Arc, RwLock};
 use std::thread;
 
+pub mod commands;
 pub mod plugin_messages;
+pub mod scoreboard;
 mod sun;
 pub mod target;
 
@@ -72,25 +76,164 @@ pub struct Server {
     //
     pub player: Option<ecs::Entity>,
     entity_map: HashMap<i32, ecs::Entity, BuildHasherDefault<FNVHash>>,
-    players: HashMap<protocol::UUID, PlayerInfo, BuildHasherDefault<FNVHash>>,
+    pub players: HashMap<protocol::UUID, PlayerInfo, BuildHasherDefault<FNVHash>>,
+    pub player_list_header: Option<format::Component>,
+    pub player_list_footer: Option<format::Component>,
+    pub scoreboard: scoreboard::Scoreboard,
+    pub boss_bars: Vec<BossBar>,
+    // How far boss bars have darkened the sky, fading in and out
+    sky_darkness: f32,
 
     tick_timer: f64,
     entity_tick_timer: f64,
-    pub received_chat_at: Option<Instant>,
+    pub received_chat: Vec<format::Component>,
+    pub received_titles: Vec<TitleAction>,
+    pub commands: Option<commands::CommandTree>,
+    pub completions: Option<Completions>,
+    completion_request: Option<(i32, String)>,
+    last_completion_id: i32,
+    sent_client_settings: Option<ClientSettings>,
+    pub inventory: Arc<RwLock<inventory::Inventory>>,
+    pub status: PlayerStatus,
+    abilities: Abilities,
+    dead: bool,
+    death_message: Arc<RwLock<Option<format::Component>>>,
 
     sun_model: Option<sun::SunModel>,
     target_info: target::Info,
 }
 
+/// The local player's health, food and experience as last sent by
+/// the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerStatus {
+    pub health: f32,
+    pub max_health: f32,
+    pub absorption: f32,
+    pub food: i32,
+    pub saturation: f32,
+    pub experience_bar: f32,
+    pub level: i32,
+    pub total_experience: i32,
+    /// Armor points from the armor attribute, only sent by 1.9+ servers
+    pub armor: i32,
+}
+
+/// A bar shown at the top of the screen, normally the health of a
+/// boss. Bars are listed in the order the server added them.
+#[derive(Clone, PartialEq)]
+pub struct BossBar {
+    pub uuid: protocol::UUID,
+    pub title: format::Component,
+    pub health: f32,
+    /// Index of the colour in gui/bars: pink, blue, red, green,
+    /// yellow, purple or white.
+    pub color: i32,
+    /// 0 for a plain bar, otherwise 1 to 4 for 6, 10, 12 or 20 notches.
+    pub style: i32,
+    pub flags: u8,
+}
+
+/// A change to the title, subtitle or action bar text shown in the
+/// middle of the screen.
+pub enum TitleAction {
+    Title(format::Component),
+    Subtitle(format::Component),
+    ActionBar(format::Component),
+    /// Fade in, stay and fade out durations in ticks.
+    Times(i32, i32, i32),
+    /// Hides the current title.
+    Clear,
+    /// Hides the current title and resets the subtitle and times.
+    Reset,
+}
+
+/// Completions sent by the server for text typed into chat.
+pub struct Completions {
+    /// The text the completions were requested for.
+    pub text: String,
+    /// Byte offset into the text from which the matches replace it.
+    pub start: usize,
+    pub matches: Vec<String>,
+}
+
+/// The client's preferences that the server is told about.
+#[derive(Clone, PartialEq)]
+pub struct ClientSettings {
+    pub locale: String,
+    pub view_distance: u8,
+    /// 0 for all chat messages, 1 for commands only and 2 for none.
+    pub chat_mode: u8,
+    pub chat_colors: bool,
+    /// Bit mask of the skin layers shown to other players.
+    pub displayed_skin_parts: u8,
+    /// 0 for the left hand and 1 for the right.
+    pub main_hand: u8,
+}
+
+impl ClientSettings {
+    /// Returns the locale as the server expects it, they were sent as
+    /// en_US before 1.11.
+    fn sent_locale(&self, protocol_version: i32) -> String {
+        if protocol_version >= 315 {
+            return self.locale.clone();
+        }
+        match self.locale.split_once('_') {
+            Some((language, region)) => format!("{}_{}", language, region.to_uppercase()),
+            None => self.locale.clone(),
+        }
+    }
+}
+
+impl Default for PlayerStatus {
+    fn default() -> PlayerStatus {
+        PlayerStatus {
+            health: 20.0,
+            max_health: 20.0,
+            absorption: 0.0,
+            food: 20,
+            saturation: 5.0,
+            experience_bar: 0.0,
+            level: 0,
+            total_experience: 0,
+            armor: 0,
+        }
+    }
+}
+
+/// The movement speed modifier vanilla servers add while a player is
+/// sprinting.
+const SPRINTING_MODIFIER: &str = "662a6b8d-da3e-4c1c-8813-96ea6d0bf87d";
+
+/// The local player's abilities as last sent by the server. Older
+/// servers expect all of them back when the player starts or stops
+/// flying.
+#[derive(Debug, Clone, Copy)]
+struct Abilities {
+    flags: u8,
+    flying_speed: f32,
+    walking_speed: f32,
+}
+
+impl Default for Abilities {
+    fn default() -> Abilities {
+        Abilities {
+            flags: 0,
+            flying_speed: 0.05,
+            walking_speed: 0.1,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct PlayerInfo {
-    name: String,
-    uuid: protocol::UUID,
-    skin_url: Option<String>,
+    pub name: String,
+    pub uuid: protocol::UUID,
+    pub skin_url: Option<String>,
 
-    display_name: Option<format::Component>,
-    ping: i32,
-    gamemode: Gamemode,
+    pub display_name: Option<format::Component>,
+    pub ping: i32,
+    pub gamemode: Gamemode,
 }
 
 macro_rules! handle_packet {
@@ -492,10 +635,26 @@ impl Server {
             player: None,
             entity_map: HashMap::with_hasher(BuildHasherDefault::default()),
             players: HashMap::with_hasher(BuildHasherDefault::default()),
+            player_list_header: None,
+            player_list_footer: None,
+            scoreboard: scoreboard::Scoreboard::new(),
+            boss_bars: vec![],
+            sky_darkness: 0.0,
 
             tick_timer: 0.0,
             entity_tick_timer: 0.0,
-            received_chat_at: None,
+            received_chat: vec![],
+            received_titles: vec![],
+            commands: None,
+            completions: None,
+            completion_request: None,
+            last_completion_id: 0,
+            sent_client_settings: None,
+            inventory: Arc::new(RwLock::new(inventory::Inventory::new(protocol_version))),
+            status: Default::default(),
+            abilities: Default::default(),
+            dead: false,
+            death_message: Arc::new(RwLock::new(None)),
             sun_model: None,
 
             target_info: target::Info::new(),
@@ -515,12 +674,27 @@ impl Server {
         self.conn.read().unwrap().is_some()
     }
 
-    pub fn tick(&mut self, renderer: &mut render::Renderer, delta: f64) {
+    pub fn tick(
+        &mut self,
+        renderer: &mut render::Renderer,
+        models: &Arc<RwLock<model::Factory>>,
+        delta: f64,
+    ) {
         let version = self.resources.read().unwrap().version();
         if version != self.version {
             self.version = version;
             self.world.flag_dirty_all();
         }
+        // Entities draw items with the same models as the inventory
+        let world_entity = self.entities.get_world();
+        if self
+            .entities
+            .get_component_direct::<Arc<RwLock<model::Factory>>>(world_entity)
+            .is_none()
+        {
+            self.entities
+                .add_component_direct(world_entity, models.clone());
+        }
         // TODO: Check if the world type actually needs a sun
         if self.sun_model.is_none() {
             self.sun_model = Some(sun::SunModel::new(renderer));
@@ -537,6 +711,13 @@ impl Server {
         }
         self.entity_tick(renderer, delta);
 
+        let closed = self.inventory.write().unwrap().take_closed();
+        if let Some(id) = closed {
+            if self.is_connected() {
+                self.write_packet(packet::play::serverbound::CloseWindow { id });
+            }
+        }
+
         self.tick_timer += delta;
         while self.tick_timer >= 3.0 && self.is_connected() {
             self.minecraft_tick();
@@ -631,6 +812,17 @@ impl Server {
                             UpdateSign_u16 => on_sign_update_u16,
                             PlayerInfo => on_player_info,
                             PlayerInfo_String => on_player_info_string,
+                            PlayerListHeaderFooter => on_player_list_header_footer,
+                            ScoreboardObjective => on_scoreboard_objective,
+                            ScoreboardObjective_NoMode => on_scoreboard_objective_nomode,
+                            UpdateScore_VarInt => on_update_score_varint,
+                            UpdateScore_u8 => on_update_score_u8,
+                            UpdateScore_i32 => on_update_score_i32,
+                            ScoreboardDisplay => on_scoreboard_display,
+                            Teams_VarInt => on_teams_varint,
+                            Teams_u8 => on_teams_u8,
+                            Teams_NoCollision => on_teams_nocollision,
+                            Teams_NoVisColor => on_teams_noviscolor,
                             ServerMessage_NoPosition => on_servermessage_noposition,
                             ServerMessage_Position => on_servermessage_position,
                             ServerMessage_Sender => on_servermessage_sender,
@@ -638,6 +830,23 @@ impl Server {
                             // Entities
                             EntityDestroy => on_entity_destroy,
                             EntityDestroy_u8 => on_entity_destroy_u8,
+                            SpawnMob_NoMeta => on_spawn_mob_nometa,
+                            SpawnMob_WithMeta => on_spawn_mob_withmeta,
+                            SpawnMob_u8 => on_spawn_mob_u8,
+                            SpawnMob_u8_i32 => on_spawn_mob_u8_i32,
+                            SpawnMob_u8_i32_NoUUID => on_spawn_mob_u8_i32_nouuid,
+                            SpawnObject => on_spawn_object,
+                            SpawnObject_i32 => on_spawn_object_i32,
+                            SpawnObject_i32_NoUUID => on_spawn_object_i32_nouuid,
+                            SpawnObject_VarInt => on_spawn_object_varint,
+                            SpawnGlobalEntity => on_spawn_global_entity,
+                            SpawnGlobalEntity_i32 => on_spawn_global_entity_i32,
+                            SpawnExperienceOrb => on_spawn_experience_orb,
+                            SpawnExperienceOrb_i32 => on_spawn_experience_orb_i32,
+                            SpawnPainting_VarInt => on_spawn_painting_varint,
+                            SpawnPainting_String => on_spawn_painting_string,
+                            SpawnPainting_NoUUID => on_spawn_painting_nouuid,
+                            SpawnPainting_NoUUID_i32 => on_spawn_painting_nouuid_i32,
                             SpawnPlayer_f64_NoMeta => on_player_spawn_f64_nometa,
                             SpawnPlayer_f64 => on_player_spawn_f64,
                             SpawnPlayer_i32 => on_player_spawn_i32,
@@ -654,6 +863,57 @@ impl Server {
                             EntityLookAndMove_i16 => on_entity_look_and_move_i16,
                             EntityLookAndMove_i8 => on_entity_look_and_move_i8,
                             EntityLookAndMove_i8_i32_NoGround => on_entity_look_and_move_i8_i32_noground,
+                            WindowItems_StateCarry => on_window_items_statecarry,
+                            WindowItems_i16 => on_window_items_i16,
+                            WindowSetSlot_State => on_window_set_slot_state,
+                            WindowSetSlot => on_window_set_slot,
+                            ConfirmTransaction => on_confirm_transaction,
+                            WindowPing => on_window_ping,
+                            WindowOpen => on_window_open,
+                            WindowOpen_u8 => on_window_open_u8,
+                            WindowOpen_VarInt => on_window_open_varint,
+                            WindowOpenHorse => on_window_open_horse,
+                            WindowProperty => on_window_property,
+                            WindowClose => on_window_close,
+                            SetCurrentHotbarSlot => on_set_current_hotbar_slot,
+                            CombatEvent => on_combat_event,
+                            CombatEventDeath => on_combat_event_death,
+                            UpdateHealth => on_update_health,
+                            BossBar => on_boss_bar,
+                            Title => on_title,
+                            Title_notext => on_title_notext,
+                            Title_notext_component => on_title_notext_component,
+                            TitleText => on_title_text,
+                            TitleSubtitle => on_title_subtitle,
+                            TitleTimes => on_title_times,
+                            ClearTitles => on_clear_titles,
+                            ActionBar => on_action_bar,
+                            DeclareCommands => on_declare_commands,
+                            TabCompleteReply => on_tab_complete_reply,
+                            TabCompleteReply_Transaction => on_tab_complete_reply_transaction,
+                            UpdateHealth_u16 => on_update_health_u16,
+                            SetExperience => on_set_experience,
+                            SetExperience_i16 => on_set_experience_i16,
+                            EntityProperties_VarIntVarInt => on_entity_properties_varintvarint,
+                            EntityProperties_VarInt => on_entity_properties_varint,
+                            EntityProperties_i32 => on_entity_properties_i32,
+                            EntityMetadata => on_entity_metadata,
+                            EntityEquipment_Array => on_entity_equipment_array,
+                            EntityEquipment_VarInt => on_entity_equipment_varint,
+                            EntityEquipment_u16 => on_entity_equipment_u16,
+                            EntityEquipment_u16_i32 => on_entity_equipment_u16_i32,
+                            EntityMetadata_i32 => on_entity_metadata_i32,
+                            SetPassengers => on_set_passengers,
+                            EntityAttach => on_entity_attach,
+                            EntityAttach_leashed => on_entity_attach_leashed,
+                            VehicleTeleport => on_vehicle_teleport,
+                            PlayerAbilities => on_player_abilities,
+                            EntityEffect_VarInt => on_entity_effect_varint,
+                            EntityEffect_i8 => on_entity_effect_i8,
+                            EntityEffect_i32 => on_entity_effect_i32,
+                            EntityRemoveEffect_VarInt => on_entity_remove_effect_varint,
+                            EntityRemoveEffect_i8 => on_entity_remove_effect_i8,
+                            EntityRemoveEffect_i32 => on_entity_remove_effect_i32,
                         }
                     },
                     Err(err) => panic!("Err: {:?}", err),
@@ -705,7 +965,63 @@ impl Server {
         } else {
             self.world_time = self.world_time_target;
         }
-        renderer.sky_offset = self.calculate_sky_offset();
+
+        // Boss bars can darken the sky, which fades in quickly and out
+        // slowly, and bring the fog in closer
+        let ticks = (delta / 3.0) as f32;
+        if self.boss_bars.iter().any(|bar| bar.flags & 0x1 != 0) {
+            self.sky_darkness = (self.sky_darkness + 0.05 * ticks).min(1.0);
+        } else {
+            self.sky_darkness = (self.sky_darkness - 0.0125 * ticks).max(0.0);
+        }
+        // The fog flag moved from 0x2 to 0x4 in 1.10, 0x2 now being
+        // the dragon's music
+        let fog_flag = if self.protocol_version >= 210 {
+            0x4
+        } else {
+            0x2
+        };
+        renderer.boss_fog = self.boss_bars.iter().any(|bar| bar.flags & fog_flag != 0);
+        renderer.sky_offset = self.calculate_sky_offset() * (1.0 - 0.3 * self.sky_darkness);
+
+        // Blindness hides everything past a few blocks and night vision
+        // lights up everything, flickering as it runs out
+        let effects = self.player_effects();
+        renderer.blindness = effects.map_or(false, |v| {
+            v.get(entity::effects::EffectKind::Blindness).is_some()
+        });
+        let night_vision = effects
+            .and_then(|v| v.get(entity::effects::EffectKind::NightVision))
+            .map_or(0.0, |effect| {
+                if effect.duration > 200 {
+                    1.0
+                } else {
+                    0.7 + 0.3 * (effect.duration as f32 * std::f32::consts::PI * 0.2).sin()
+                }
+            });
+        renderer.light_level = 0.8 + 0.2 * night_vision;
+
+        // The view eases towards its new width
+        let fov_modifier = self.fov_modifier();
+        renderer.fov_modifier += (fov_modifier - renderer.fov_modifier) * (0.5 * ticks).min(1.0);
+    }
+
+    /// Returns the local player's `fov_modifier`, or no change without
+    /// a player.
+    fn fov_modifier(&self) -> f32 {
+        let movement = match self
+            .player
+            .and_then(|player| self.entities.get_component(player, self.player_movement))
+        {
+            Some(movement) => movement,
+            None => return 1.0,
+        };
+        fov_modifier(
+            movement.flying,
+            movement.sprinting,
+            movement.walking_speed,
+            self.abilities.walking_speed,
+        )
     }
 
     fn calculate_sky_offset(&self) -> f32 {
@@ -734,6 +1050,14 @@ impl Server {
     pub fn minecraft_tick(&mut self) {
         use std::f32::consts::PI;
         if let Some(player) = self.player {
+            if self
+                .entities
+                .get_component_direct::<entity::vehicle::Riding>(player)
+                .is_some()
+            {
+                self.send_vehicle_input(player);
+                return;
+            }
             let movement = self
                 .entities
                 .get_component_mut(player, self.player_movement)
@@ -758,6 +1082,11 @@ impl Server {
                 on_ground
             };
 
+            if movement.toggled_flight {
+                movement.toggled_flight = false;
+                self.send_flying(movement.flying);
+            }
+
             // Sync our position to the server
             // Use the smaller packets when possible
             if self.protocol_version >= 47 {
@@ -785,6 +1114,104 @@ impl Server {
         }
     }
 
+    /// Tells the server the player started or stopped flying.
+    fn send_flying(&mut self, flying: bool) {
+        let mut flags = self.abilities.flags & !0x02;
+        if flying {
+            flags |= 0x02;
+        }
+        // 1.16 stopped sending anything but whether the player is flying
+        if self.protocol_version >= 735 {
+            self.write_packet(packet::play::serverbound::ClientAbilities_u8 {
+                flags: flags & 0x02,
+            });
+        } else {
+            self.write_packet(packet::play::serverbound::ClientAbilities_f32 {
+                flags,
+                flying_speed: self.abilities.flying_speed,
+                walking_speed: self.abilities.walking_speed,
+            });
+        }
+        self.abilities.flags = flags;
+    }
+
+    /// Sends how the player is steering while riding instead of their
+    /// position, which is up to the vehicle.
+    fn send_vehicle_input(&mut self, player: ecs::Entity) {
+        use std::f32::consts::PI;
+        let movement = self
+            .entities
+            .get_component(player, self.player_movement)
+            .unwrap();
+        let rotation = self.entities.get_component(player, self.rotation).unwrap();
+        self.write_packet(packet::play::serverbound::PlayerLook {
+            yaw: -(rotation.yaw as f32) * (180.0 / PI),
+            pitch: (-rotation.pitch as f32) * (180.0 / PI) + 180.0,
+            on_ground: false,
+        });
+
+        let left = movement.is_key_pressed(Stevenkey::Left);
+        let right = movement.is_key_pressed(Stevenkey::Right);
+        let forward = movement.is_key_pressed(Stevenkey::Forward);
+        let backward = movement.is_key_pressed(Stevenkey::Backward);
+        let sideways = (left as i32 - right as i32) as f32;
+        let forwards = (forward as i32 - backward as i32) as f32;
+        let jump = movement.is_key_pressed(Stevenkey::Jump);
+        // Sneaking gets off the vehicle
+        let unmount = movement.is_key_pressed(Stevenkey::Sneak);
+        if self.protocol_version >= 47 {
+            let mut flags = 0;
+            if jump {
+                flags |= 0x1;
+            }
+            if unmount {
+                flags |= 0x2;
+            }
+            self.write_packet(packet::play::serverbound::SteerVehicle {
+                sideways,
+                forward: forwards,
+                flags,
+            });
+        } else {
+            self.write_packet(packet::play::serverbound::SteerVehicle_jump_unmount {
+                sideways,
+                forward: forwards,
+                jump,
+                unmount,
+            });
+        }
+
+        if let Some(vehicle) =
+            entity::vehicle::steered_vehicle(&self.entities, player, self.protocol_version)
+        {
+            let position = self
+                .entities
+                .get_component(vehicle, self.target_position)
+                .unwrap();
+            let rotation = self
+                .entities
+                .get_component(vehicle, self.target_rotation)
+                .unwrap();
+            self.write_packet(packet::play::serverbound::VehicleMove {
+                x: position.position.x,
+                y: position.position.y,
+                z: position.position.z,
+                yaw: -(rotation.yaw as f32) * (180.0 / PI),
+                pitch: -(rotation.pitch as f32) * (180.0 / PI),
+            });
+            let is_boat = self
+                .entities
+                .get_component_direct::<entity::kind::EntityKind>(vehicle)
+                .map_or(false, entity::vehicle::is_boat);
+            if is_boat {
+                self.write_packet(packet::play::serverbound::SteerBoat {
+                    left_paddle_turning: (right && !left) || forward,
+                    right_paddle_turning: (left && !right) || forward,
+                });
+            }
+        }
+    }
+
     pub fn key_press(&mut self, down: bool, key: Stevenkey) {
         if let Some(player) = self.player {
             if let Some(movement) = self
@@ -890,7 +1317,7 @@ impl Server {
                             Direction::East => 5,
                             _ => unreachable!(),
                         },
-                        hand: None,
+                        hand: self.inventory.read().unwrap().held_item().cloned(),
                         cursor_x: (at.x * 16.0) as u8,
                         cursor_y: (at.y * 16.0) as u8,
                         cursor_z: (at.z * 16.0) as u8,
@@ -910,7 +1337,7 @@ impl Server {
                                 Direction::East => 5,
                                 _ => unreachable!(),
                             },
-                            hand: None,
+                            hand: self.inventory.read().unwrap().held_item().cloned(),
                             cursor_x: (at.x * 16.0) as u8,
                             cursor_y: (at.y * 16.0) as u8,
                             cursor_z: (at.z * 16.0) as u8,
@@ -921,6 +1348,299 @@ impl Server {
         }
     }
 
+    pub fn send_chat(&mut self, message: &str) {
+        // Servers kick clients sending messages longer than they allow
+        let max_len = if self.protocol_version >= 315 {
+            256
+        } else {
+            100
+        };
+        let message = message.chars().take(max_len).collect::<String>();
+        self.write_packet(packet::play::serverbound::ChatMessage { message });
+    }
+
+    /// Asks the server how the text typed into chat could be
+    /// completed, the reply is stored in `completions`.
+    pub fn request_completions(&mut self, text: &str) {
+        self.last_completion_id += 1;
+        self.completion_request = Some((self.last_completion_id, text.to_owned()));
+        let text = text.to_owned();
+        if self.protocol_version >= 404 {
+            self.write_packet(packet::play::serverbound::TabComplete_Transaction {
+                transaction_id: protocol::VarInt(self.last_completion_id),
+                text,
+            });
+        } else if self.protocol_version >= 107 {
+            self.write_packet(packet::play::serverbound::TabComplete {
+                text,
+                assume_command: false,
+                has_target: false,
+                target: None,
+            });
+        } else if self.protocol_version >= 47 {
+            self.write_packet(packet::play::serverbound::TabComplete_NoAssume {
+                text,
+                has_target: false,
+                target: None,
+            });
+        } else {
+            self.write_packet(packet::play::serverbound::TabComplete_NoAssume_NoTarget { text });
+        }
+    }
+
+    /// Lets the server know about the client's settings once joined
+    /// and whenever they change.
+    pub fn set_client_settings(&mut self, settings: ClientSettings) {
+        if self.player.is_none() || self.sent_client_settings.as_ref() == Some(&settings) {
+            return;
+        }
+        let locale = settings.sent_locale(self.protocol_version);
+        let view_distance = settings.view_distance;
+        let chat_mode = settings.chat_mode;
+        let chat_colors = settings.chat_colors;
+        let displayed_skin_parts = settings.displayed_skin_parts;
+        let main_hand = protocol::VarInt(settings.main_hand as i32);
+        if self.protocol_version >= 757 {
+            self.write_packet(
+                packet::play::serverbound::ClientSettings_Filtering_ServerListings {
+                    locale,
+                    view_distance,
+                    chat_mode: protocol::VarInt(chat_mode as i32),
+                    chat_colors,
+                    displayed_skin_parts,
+                    main_hand,
+                    disable_text_filtering: false,
+                    // Vanilla's default, letting the player be shown in
+                    // the server list's sample
+                    allow_server_listings: true,
+                },
+            );
+        } else if self.protocol_version >= 756 {
+            self.write_packet(packet::play::serverbound::ClientSettings_Filtering {
+                locale,
+                view_distance,
+                chat_mode: protocol::VarInt(chat_mode as i32),
+                chat_colors,
+                displayed_skin_parts,
+                main_hand,
+                disable_text_filtering: false,
+            });
+        } else if self.protocol_version >= 107 {
+            self.write_packet(packet::play::serverbound::ClientSettings {
+                locale,
+                view_distance,
+                chat_mode: protocol::VarInt(chat_mode as i32),
+                chat_colors,
+                displayed_skin_parts,
+                main_hand,
+            });
+        } else if self.protocol_version >= 74 {
+            self.write_packet(packet::play::serverbound::ClientSettings_u8 {
+                locale,
+                view_distance,
+                chat_mode,
+                chat_colors,
+                displayed_skin_parts,
+                main_hand,
+            });
+        } else if self.protocol_version >= 47 {
+            self.write_packet(packet::play::serverbound::ClientSettings_u8_Handsfree {
+                locale,
+                view_distance,
+                chat_mode,
+                chat_colors,
+                displayed_skin_parts,
+            });
+        } else {
+            self.write_packet(
+                packet::play::serverbound::ClientSettings_u8_Handsfree_Difficulty {
+                    locale,
+                    view_distance,
+                    chat_mode,
+                    chat_colors,
+                    // Normal, only used in singleplayer
+                    difficulty: 2,
+                    // Only the cape could be hidden
+                    displayed_skin_parts: displayed_skin_parts & 0x01,
+                },
+            );
+        }
+        self.sent_client_settings = Some(settings);
+    }
+
+    pub fn gamemode(&self) -> Gamemode {
+        self.player
+            .and_then(|player| self.entities.get_component(player, self.gamemode))
+            .cloned()
+            .unwrap_or(Gamemode::Survival)
+    }
+
+    /// Returns the armor points of the local player.
+    pub fn armor_points(&self) -> i32 {
+        if self.protocol_version >= 107 {
+            self.status.armor
+        } else {
+            self.inventory.read().unwrap().legacy_armor_points()
+        }
+    }
+
+    /// Selects a hotbar slot, letting the server know if it changed.
+    pub fn select_hotbar_slot(&mut self, index: u8) {
+        let changed = self.inventory.write().unwrap().select_slot(index);
+        if changed {
+            self.write_packet(packet::play::serverbound::HeldItemChange { slot: index as i16 });
+        }
+    }
+
+    /// Moves the hotbar selection by the given number of slots,
+    /// wrapping around at either end.
+    pub fn scroll_hotbar(&mut self, offset: i32) {
+        let selected = self.inventory.read().unwrap().selected_slot() as i32;
+        self.select_hotbar_slot((selected + offset).rem_euclid(9) as u8);
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.dead
+    }
+
+    /// Returns the message describing how the player last died, which
+    /// can arrive after the player has already been shown as dead.
+    pub fn death_message(&self) -> Arc<RwLock<Option<format::Component>>> {
+        self.death_message.clone()
+    }
+
+    /// Asks the server to respawn the player after dying.
+    pub fn perform_respawn(&mut self) {
+        if self.protocol_version >= 47 {
+            self.write_packet(packet::play::serverbound::ClientStatus {
+                action_id: protocol::VarInt(0),
+            });
+        } else {
+            self.write_packet(packet::play::serverbound::ClientStatus_u8 { action_id: 0 });
+        }
+    }
+
+    fn is_local_player(&self, entity_id: i32) -> bool {
+        self.player.is_some() && self.entity_map.get(&entity_id).cloned() == self.player
+    }
+
+    /// Returns the entity with the id unless the client moves it itself,
+    /// as it does the vehicle the player is steering.
+    fn server_moved_entity(&self, entity_id: i32) -> Option<ecs::Entity> {
+        let entity = *self.entity_map.get(&entity_id)?;
+        let steered = self.player.and_then(|player| {
+            entity::vehicle::steered_vehicle(&self.entities, player, self.protocol_version)
+        });
+        if steered == Some(entity) {
+            None
+        } else {
+            Some(entity)
+        }
+    }
+
+    pub fn is_inventory_open(&self) -> bool {
+        self.inventory.read().unwrap().open_window().is_some()
+    }
+
+    /// Handles a mouse button over the open window, `button` is 0 for
+    /// the left button and 1 for the right.
+    pub fn on_inventory_mouse(&mut self, button: u8, pressed: bool, shift: bool) {
+        let clicks = {
+            let mut inventory = self.inventory.write().unwrap();
+            let id = match inventory.open_window() {
+                Some(id) => id,
+                None => return,
+            };
+            match (pressed, inventory.hovered_slot()) {
+                (true, _) if inventory.is_dragging() => vec![],
+                (true, Some(slot)) if shift => inventory
+                    .click(id, slot, button, inventory::ClickMode::QuickMove)
+                    .into_iter()
+                    .collect(),
+                (true, Some(slot)) if inventory.cursor.is_some() => {
+                    // Placing is done on release in case this turns into a drag
+                    inventory.begin_drag(slot, button);
+                    vec![]
+                }
+                (true, Some(slot)) => inventory
+                    .click(id, slot, button, inventory::ClickMode::Pickup)
+                    .into_iter()
+                    .collect(),
+                (true, None) if !inventory.is_over_window() => inventory
+                    .click(
+                        id,
+                        inventory::OUTSIDE_SLOT,
+                        button,
+                        inventory::ClickMode::Pickup,
+                    )
+                    .into_iter()
+                    .collect(),
+                (true, None) => vec![],
+                (false, _) => inventory.end_drag(),
+            }
+        };
+        for click in clicks {
+            self.send_click(click);
+        }
+    }
+
+    /// Swaps the hovered slot of the open window with the given hotbar slot.
+    pub fn on_inventory_hotbar_key(&mut self, index: u8) {
+        let click = {
+            let mut inventory = self.inventory.write().unwrap();
+            match (inventory.open_window(), inventory.hovered_slot()) {
+                (Some(id), Some(slot)) => {
+                    inventory.click(id, slot, index, inventory::ClickMode::Swap)
+                }
+                _ => None,
+            }
+        };
+        if let Some(click) = click {
+            self.send_click(click);
+        }
+    }
+
+    fn send_click(&mut self, click: inventory::Click) {
+        if self.protocol_version >= 756 {
+            self.write_packet(packet::play::serverbound::ClickWindow_State {
+                id: click.window_id,
+                slot: click.slot,
+                state: protocol::VarInt(click.state_id),
+                button: click.button,
+                mode: protocol::VarInt(click.mode.id() as i32),
+                slots: protocol::LenPrefixed::new(
+                    click
+                        .changed
+                        .into_iter()
+                        .map(|(slot_number, slot_data)| packet::NumberedSlot {
+                            slot_number,
+                            slot_data,
+                        })
+                        .collect(),
+                ),
+                clicked_item: click.carried,
+            });
+        } else if self.protocol_version >= 107 {
+            self.write_packet(packet::play::serverbound::ClickWindow {
+                id: click.window_id,
+                slot: click.slot,
+                button: click.button,
+                action_number: click.action_number as u16,
+                mode: protocol::VarInt(click.mode.id() as i32),
+                clicked_item: click.clicked_item,
+            });
+        } else {
+            self.write_packet(packet::play::serverbound::ClickWindow_u8 {
+                id: click.window_id,
+                slot: click.slot,
+                button: click.button,
+                action_number: click.action_number as u16,
+                mode: click.mode.id(),
+                clicked_item: click.clicked_item,
+            });
+        }
+    }
+
     pub fn write_packet<T: protocol::PacketType>(&self, p: T) {
         let mut conn = self.conn.write().unwrap();
         let _ = conn.as_mut().unwrap().write_packet(p); // TODO handle errors
@@ -1135,18 +1855,12 @@ impl Server {
                 .unwrap();
             model.set_skin(info.skin_url.clone());
         }
-        *self
-            .entities
-            .get_component_mut(player, self.gamemode)
-            .unwrap() = gamemode;
-        // TODO: Temp
-        self.entities
-            .get_component_mut(player, self.player_movement)
-            .unwrap()
-            .flying = gamemode.can_fly();
+        self.set_gamemode(player, gamemode);
 
         self.entity_map.insert(entity_id, player);
         self.player = Some(player);
+        // Joining another world through a proxy needs them again
+        self.sent_client_settings = None;
 
         // Let the server know who we are
         let brand = plugin_messages::Brand {
@@ -1178,18 +1892,16 @@ impl Server {
 
     fn respawn(&mut self, gamemode_u8: u8) {
         self.world = world::World::new(self.protocol_version);
+        self.dead = false;
+        *self.death_message.write().unwrap() = None;
         let gamemode = Gamemode::from_int((gamemode_u8 & 0x7) as i32);
 
         if let Some(player) = self.player {
-            *self
-                .entities
-                .get_component_mut(player, self.gamemode)
-                .unwrap() = gamemode;
-            // TODO: Temp
+            self.set_gamemode(player, gamemode);
+            // Respawning is a new player to the server, without any of
+            // the old one's effects
             self.entities
-                .get_component_mut(player, self.player_movement)
-                .unwrap()
-                .flying = gamemode.can_fly();
+                .remove_component_direct::<entity::effects::Effects>(player);
         }
     }
 
@@ -1212,22 +1924,49 @@ impl Server {
         if game_state.reason == 3 {
             if let Some(player) = self.player {
                 let gamemode = Gamemode::from_int(game_state.value as i32);
-                *self
-                    .entities
-                    .get_component_mut(player, self.gamemode)
-                    .unwrap() = gamemode;
-                // TODO: Temp
-                self.entities
-                    .get_component_mut(player, self.player_movement)
-                    .unwrap()
-                    .flying = gamemode.can_fly();
+                self.set_gamemode(player, gamemode);
             }
         }
     }
 
+    /// Changes the local player's gamemode. Flight follows what the
+    /// gamemode allows until the server sends the player's abilities.
+    fn set_gamemode(&mut self, player: ecs::Entity, gamemode: Gamemode) {
+        *self
+            .entities
+            .get_component_mut(player, self.gamemode)
+            .unwrap() = gamemode;
+        let movement = self
+            .entities
+            .get_component_mut(player, self.player_movement)
+            .unwrap();
+        movement.can_fly = gamemode.can_fly();
+        movement.flying &= movement.can_fly;
+        movement.want_to_fly = movement.flying;
+    }
+
+    fn on_player_abilities(&mut self, abilities: packet::play::clientbound::PlayerAbilities) {
+        self.abilities = Abilities {
+            flags: abilities.flags,
+            flying_speed: abilities.flying_speed,
+            walking_speed: abilities.walking_speed,
+        };
+        if let Some(player) = self.player {
+            let movement = self
+                .entities
+                .get_component_mut(player, self.player_movement)
+                .unwrap();
+            movement.flying = abilities.flags & 0x02 != 0;
+            movement.want_to_fly = movement.flying;
+            movement.can_fly = abilities.flags & 0x04 != 0;
+            movement.flying_speed = f64::from(abilities.flying_speed);
+        }
+    }
+
     fn on_entity_destroy(&mut self, entity_destroy: packet::play::clientbound::EntityDestroy) {
         for id in entity_destroy.entity_ids.data {
             if let Some(entity) = self.entity_map.remove(&id.0) {
+                entity::vehicle::remove(&mut self.entities, entity);
                 self.entities.remove_entity(entity);
             }
         }
@@ -1239,11 +1978,90 @@ impl Server {
     ) {
         for id in entity_destroy.entity_ids.data {
             if let Some(entity) = self.entity_map.remove(&id) {
+                entity::vehicle::remove(&mut self.entities, entity);
                 self.entities.remove_entity(entity);
             }
         }
     }
 
+    fn on_set_passengers(&mut self, set_passengers: packet::play::clientbound::SetPassengers) {
+        let vehicle = match self.entity_map.get(&set_passengers.entity_id.0) {
+            Some(vehicle) => *vehicle,
+            None => return,
+        };
+        let passengers = set_passengers
+            .passengers
+            .data
+            .iter()
+            .filter_map(|id| self.entity_map.get(&id.0).copied())
+            .collect();
+        entity::vehicle::set_passengers(&mut self.entities, vehicle, passengers);
+    }
+
+    fn on_entity_attach(&mut self, attach: packet::play::clientbound::EntityAttach) {
+        // Since 1.9 this is only used for leads
+        self.on_entity_mount(attach.entity_id, attach.vehicle, true)
+    }
+
+    fn on_entity_attach_leashed(
+        &mut self,
+        attach: packet::play::clientbound::EntityAttach_leashed,
+    ) {
+        self.on_entity_mount(attach.entity_id, attach.vehicle, attach.leash)
+    }
+
+    fn on_entity_mount(&mut self, entity_id: i32, vehicle: i32, leash: bool) {
+        // Leads aren't drawn yet
+        if leash {
+            return;
+        }
+        let entity = match self.entity_map.get(&entity_id) {
+            Some(entity) => *entity,
+            None => return,
+        };
+        if vehicle == -1 {
+            entity::vehicle::dismount(&mut self.entities, entity);
+        } else if let Some(vehicle) = self.entity_map.get(&vehicle) {
+            entity::vehicle::mount(&mut self.entities, entity, *vehicle);
+        }
+    }
+
+    fn on_vehicle_teleport(&mut self, teleport: packet::play::clientbound::VehicleTeleport) {
+        use std::f64::consts::PI;
+        let vehicle = match self.player.and_then(|player| {
+            self.entities
+                .get_component_direct::<entity::vehicle::Riding>(player)
+        }) {
+            Some(riding) => riding.vehicle,
+            None => return,
+        };
+        // The server disagreed with where we moved it, jump straight
+        // back instead of sliding there
+        let position = cgmath::Vector3::new(teleport.x, teleport.y, teleport.z);
+        if let Some(current) = self.entities.get_component_mut(vehicle, self.position) {
+            current.position = position;
+        }
+        if let Some(target) = self
+            .entities
+            .get_component_mut(vehicle, self.target_position)
+        {
+            target.position = position;
+        }
+        let yaw = -(teleport.yaw as f64) * (PI / 180.0);
+        let pitch = -(teleport.pitch as f64) * (PI / 180.0);
+        if let Some(rotation) = self.entities.get_component_mut(vehicle, self.rotation) {
+            rotation.yaw = yaw;
+            rotation.pitch = pitch;
+        }
+        if let Some(rotation) = self
+            .entities
+            .get_component_mut(vehicle, self.target_rotation)
+        {
+            rotation.yaw = yaw;
+            rotation.pitch = pitch;
+        }
+    }
+
     fn on_entity_teleport_f64(
         &mut self,
         entity_telport: packet::play::clientbound::EntityTeleport_f64,
@@ -1301,14 +2119,14 @@ impl Server {
         _on_ground: bool,
     ) {
         use std::f64::consts::PI;
-        if let Some(entity) = self.entity_map.get(&entity_id) {
+        if let Some(entity) = self.server_moved_entity(entity_id) {
             let target_position = self
                 .entities
-                .get_component_mut(*entity, self.target_position)
+                .get_component_mut(entity, self.target_position)
                 .unwrap();
             let target_rotation = self
                 .entities
-                .get_component_mut(*entity, self.target_rotation)
+                .get_component_mut(entity, self.target_rotation)
                 .unwrap();
             target_position.position.x = x;
             target_position.position.y = y;
@@ -1349,10 +2167,10 @@ impl Server {
     }
 
     fn on_entity_move(&mut self, entity_id: i32, delta_x: f64, delta_y: f64, delta_z: f64) {
-        if let Some(entity) = self.entity_map.get(&entity_id) {
+        if let Some(entity) = self.server_moved_entity(entity_id) {
             let position = self
                 .entities
-                .get_component_mut(*entity, self.target_position)
+                .get_component_mut(entity, self.target_position)
                 .unwrap();
             position.position.x += delta_x;
             position.position.y += delta_y;
@@ -1362,10 +2180,10 @@ impl Server {
 
     fn on_entity_look(&mut self, entity_id: i32, yaw: f64, pitch: f64) {
         use std::f64::consts::PI;
-        if let Some(entity) = self.entity_map.get(&entity_id) {
+        if let Some(entity) = self.server_moved_entity(entity_id) {
             let rotation = self
                 .entities
-                .get_component_mut(*entity, self.target_rotation)
+                .get_component_mut(entity, self.target_rotation)
                 .unwrap();
             rotation.yaw = -(yaw / 256.0) * PI * 2.0;
             rotation.pitch = -(pitch / 256.0) * PI * 2.0;
@@ -1435,14 +2253,14 @@ impl Server {
         pitch: f64,
     ) {
         use std::f64::consts::PI;
-        if let Some(entity) = self.entity_map.get(&entity_id) {
+        if let Some(entity) = self.server_moved_entity(entity_id) {
             let position = self
                 .entities
-                .get_component_mut(*entity, self.target_position)
+                .get_component_mut(entity, self.target_position)
                 .unwrap();
             let rotation = self
                 .entities
-                .get_component_mut(*entity, self.target_rotation)
+                .get_component_mut(entity, self.target_rotation)
                 .unwrap();
             position.position.x += delta_x;
             position.position.y += delta_y;
@@ -1476,7 +2294,8 @@ impl Server {
             spawn.z,
             spawn.yaw as f64,
             spawn.pitch as f64,
-        )
+        );
+        self.on_entity_metadata_update(spawn.entity_id.0, spawn.metadata);
     }
 
     fn on_player_spawn_i32(&mut self, spawn: packet::play::clientbound::SpawnPlayer_i32) {
@@ -1488,7 +2307,8 @@ impl Server {
             f64::from(spawn.z),
             spawn.yaw as f64,
             spawn.pitch as f64,
-        )
+        );
+        self.on_entity_metadata_update(spawn.entity_id.0, spawn.metadata);
     }
 
     fn on_player_spawn_i32_helditem(
@@ -1503,7 +2323,8 @@ impl Server {
             f64::from(spawn.z),
             spawn.yaw as f64,
             spawn.pitch as f64,
-        )
+        );
+        self.on_entity_metadata_update(spawn.entity_id.0, spawn.metadata);
     }
 
     fn on_player_spawn_i32_helditem_string(
@@ -1530,7 +2351,8 @@ impl Server {
             f64::from(spawn.z),
             spawn.yaw as f64,
             spawn.pitch as f64,
-        )
+        );
+        self.on_entity_metadata_update(spawn.entity_id.0, spawn.metadata);
     }
 
     fn on_player_spawn(
@@ -1545,6 +2367,7 @@ impl Server {
     ) {
         use std::f64::consts::PI;
         if let Some(entity) = self.entity_map.remove(&entity_id) {
+            entity::vehicle::remove(&mut self.entities, entity);
             self.entities.remove_entity(entity);
         }
         let entity = entity::player::create_remote(
@@ -1585,26 +2408,333 @@ impl Server {
             model.set_skin(info.skin_url.clone());
         }
         self.entity_map.insert(entity_id, entity);
+        self.update_name_tags();
     }
 
-    fn on_teleport_player_withdismount(
-        &mut self,
-        teleport: packet::play::clientbound::TeleportPlayer_WithDismount,
-    ) {
-        self.on_teleport_player(
-            teleport.x,
-            teleport.y,
-            teleport.z,
-            teleport.yaw as f64,
-            teleport.pitch as f64,
-            teleport.flags,
-            Some(teleport.teleport_id),
+    fn on_spawn_mob_nometa(&mut self, spawn: packet::play::clientbound::SpawnMob_NoMeta) {
+        self.on_spawn_mob(
+            spawn.entity_id.0,
+            spawn.ty.0,
+            spawn.x,
+            spawn.y,
+            spawn.z,
+            spawn.yaw as f64,
+            spawn.pitch as f64,
         )
     }
 
-    fn on_teleport_player_withconfirm(
-        &mut self,
-        teleport: packet::play::clientbound::TeleportPlayer_WithConfirm,
+    fn on_spawn_mob_withmeta(&mut self, spawn: packet::play::clientbound::SpawnMob_WithMeta) {
+        self.on_spawn_mob(
+            spawn.entity_id.0,
+            spawn.ty.0,
+            spawn.x,
+            spawn.y,
+            spawn.z,
+            spawn.yaw as f64,
+            spawn.pitch as f64,
+        );
+        self.on_entity_metadata_update(spawn.entity_id.0, spawn.metadata);
+    }
+
+    fn on_spawn_mob_u8(&mut self, spawn: packet::play::clientbound::SpawnMob_u8) {
+        self.on_spawn_mob(
+            spawn.entity_id.0,
+            spawn.ty as i32,
+            spawn.x,
+            spawn.y,
+            spawn.z,
+            spawn.yaw as f64,
+            spawn.pitch as f64,
+        );
+        self.on_entity_metadata_update(spawn.entity_id.0, spawn.metadata);
+    }
+
+    fn on_spawn_mob_u8_i32(&mut self, spawn: packet::play::clientbound::SpawnMob_u8_i32) {
+        self.on_spawn_mob(
+            spawn.entity_id.0,
+            spawn.ty as i32,
+            f64::from(spawn.x),
+            f64::from(spawn.y),
+            f64::from(spawn.z),
+            spawn.yaw as f64,
+            spawn.pitch as f64,
+        );
+        self.on_entity_metadata_update(spawn.entity_id.0, spawn.metadata);
+    }
+
+    fn on_spawn_mob_u8_i32_nouuid(
+        &mut self,
+        spawn: packet::play::clientbound::SpawnMob_u8_i32_NoUUID,
+    ) {
+        self.on_spawn_mob(
+            spawn.entity_id.0,
+            spawn.ty as i32,
+            f64::from(spawn.x),
+            f64::from(spawn.y),
+            f64::from(spawn.z),
+            spawn.yaw as f64,
+            spawn.pitch as f64,
+        );
+        self.on_entity_metadata_update(spawn.entity_id.0, spawn.metadata);
+    }
+
+    fn on_spawn_mob(
+        &mut self,
+        entity_id: i32,
+        ty: i32,
+        x: f64,
+        y: f64,
+        z: f64,
+        yaw: f64,
+        pitch: f64,
+    ) {
+        let kind = entity::kind::EntityKind::from_mob_id(self.protocol_version, ty);
+        if kind.is_none() {
+            debug!("Unknown mob type {} for entity {}", ty, entity_id);
+        }
+        self.on_spawn_entity(entity_id, kind, x, y, z, yaw, pitch);
+    }
+
+    fn on_spawn_object(&mut self, spawn: packet::play::clientbound::SpawnObject) {
+        self.on_spawn_object_type(
+            spawn.entity_id.0,
+            spawn.ty as i32,
+            spawn.data,
+            spawn.x,
+            spawn.y,
+            spawn.z,
+            spawn.yaw as f64,
+            spawn.pitch as f64,
+        )
+    }
+
+    fn on_spawn_object_i32(&mut self, spawn: packet::play::clientbound::SpawnObject_i32) {
+        self.on_spawn_object_type(
+            spawn.entity_id.0,
+            spawn.ty as i32,
+            spawn.data,
+            f64::from(spawn.x),
+            f64::from(spawn.y),
+            f64::from(spawn.z),
+            spawn.yaw as f64,
+            spawn.pitch as f64,
+        )
+    }
+
+    fn on_spawn_object_i32_nouuid(
+        &mut self,
+        spawn: packet::play::clientbound::SpawnObject_i32_NoUUID,
+    ) {
+        self.on_spawn_object_type(
+            spawn.entity_id.0,
+            spawn.ty as i32,
+            spawn.data,
+            f64::from(spawn.x),
+            f64::from(spawn.y),
+            f64::from(spawn.z),
+            spawn.yaw as f64,
+            spawn.pitch as f64,
+        )
+    }
+
+    fn on_spawn_object_varint(&mut self, spawn: packet::play::clientbound::SpawnObject_VarInt) {
+        self.on_spawn_object_type(
+            spawn.entity_id.0,
+            spawn.ty.0,
+            spawn.data,
+            spawn.x,
+            spawn.y,
+            spawn.z,
+            spawn.yaw as f64,
+            spawn.pitch as f64,
+        )
+    }
+
+    fn on_spawn_object_type(
+        &mut self,
+        entity_id: i32,
+        ty: i32,
+        data: i32,
+        x: f64,
+        y: f64,
+        z: f64,
+        yaw: f64,
+        pitch: f64,
+    ) {
+        let kind = entity::kind::EntityKind::from_object_id(self.protocol_version, ty, data);
+        if kind.is_none() {
+            debug!("Unknown object type {} for entity {}", ty, entity_id);
+        }
+        self.on_spawn_entity(entity_id, kind, x, y, z, yaw, pitch);
+    }
+
+    fn on_spawn_global_entity(&mut self, spawn: packet::play::clientbound::SpawnGlobalEntity) {
+        self.on_spawn_global_entity_type(spawn.entity_id.0, spawn.ty, spawn.x, spawn.y, spawn.z)
+    }
+
+    fn on_spawn_global_entity_i32(
+        &mut self,
+        spawn: packet::play::clientbound::SpawnGlobalEntity_i32,
+    ) {
+        self.on_spawn_global_entity_type(
+            spawn.entity_id.0,
+            spawn.ty,
+            f64::from(spawn.x),
+            f64::from(spawn.y),
+            f64::from(spawn.z),
+        )
+    }
+
+    fn on_spawn_global_entity_type(&mut self, entity_id: i32, ty: u8, x: f64, y: f64, z: f64) {
+        // Lightning is the only global entity
+        if ty != 1 {
+            debug!("Unknown global entity type {} for entity {}", ty, entity_id);
+            return;
+        }
+        self.on_spawn_entity(
+            entity_id,
+            entity::kind::EntityKind::by_name("lightning_bolt"),
+            x,
+            y,
+            z,
+            0.0,
+            0.0,
+        );
+    }
+
+    fn on_spawn_experience_orb(&mut self, spawn: packet::play::clientbound::SpawnExperienceOrb) {
+        self.on_spawn_entity(
+            spawn.entity_id.0,
+            entity::kind::EntityKind::by_name("experience_orb"),
+            spawn.x,
+            spawn.y,
+            spawn.z,
+            0.0,
+            0.0,
+        )
+    }
+
+    fn on_spawn_experience_orb_i32(
+        &mut self,
+        spawn: packet::play::clientbound::SpawnExperienceOrb_i32,
+    ) {
+        self.on_spawn_entity(
+            spawn.entity_id.0,
+            entity::kind::EntityKind::by_name("experience_orb"),
+            f64::from(spawn.x),
+            f64::from(spawn.y),
+            f64::from(spawn.z),
+            0.0,
+            0.0,
+        )
+    }
+
+    fn on_spawn_painting_varint(&mut self, spawn: packet::play::clientbound::SpawnPainting_VarInt) {
+        self.on_spawn_painting(spawn.entity_id.0, spawn.location, spawn.direction as i32)
+    }
+
+    fn on_spawn_painting_string(&mut self, spawn: packet::play::clientbound::SpawnPainting_String) {
+        self.on_spawn_painting(spawn.entity_id.0, spawn.location, spawn.direction as i32)
+    }
+
+    fn on_spawn_painting_nouuid(&mut self, spawn: packet::play::clientbound::SpawnPainting_NoUUID) {
+        self.on_spawn_painting(spawn.entity_id.0, spawn.location, spawn.direction as i32)
+    }
+
+    fn on_spawn_painting_nouuid_i32(
+        &mut self,
+        spawn: packet::play::clientbound::SpawnPainting_NoUUID_i32,
+    ) {
+        self.on_spawn_painting(
+            spawn.entity_id.0,
+            Position::new(spawn.x, spawn.y, spawn.z),
+            spawn.direction,
+        )
+    }
+
+    fn on_spawn_painting(&mut self, entity_id: i32, location: Position, direction: i32) {
+        // Directions go south, west, north and east, a quarter turn each
+        self.on_spawn_entity(
+            entity_id,
+            entity::kind::EntityKind::by_name("painting"),
+            location.x as f64 + 0.5,
+            location.y as f64,
+            location.z as f64 + 0.5,
+            direction as f64 * 64.0,
+            0.0,
+        )
+    }
+
+    /// Adds an entity other than a player to the world, replacing any
+    /// already using its id.
+    fn on_spawn_entity(
+        &mut self,
+        entity_id: i32,
+        kind: Option<entity::kind::EntityKind>,
+        x: f64,
+        y: f64,
+        z: f64,
+        yaw: f64,
+        pitch: f64,
+    ) {
+        use std::f64::consts::PI;
+        if let Some(entity) = self.entity_map.remove(&entity_id) {
+            entity::vehicle::remove(&mut self.entities, entity);
+            self.entities.remove_entity(entity);
+        }
+        // Markers and lightning bolts have nothing to draw
+        let kind = match kind {
+            Some(kind) if kind.width > 0.0 => kind,
+            _ => return,
+        };
+        let entity = entity::placeholder::create(&mut self.entities, kind);
+        let position = self
+            .entities
+            .get_component_mut(entity, self.position)
+            .unwrap();
+        let target_position = self
+            .entities
+            .get_component_mut(entity, self.target_position)
+            .unwrap();
+        let rotation = self
+            .entities
+            .get_component_mut(entity, self.rotation)
+            .unwrap();
+        let target_rotation = self
+            .entities
+            .get_component_mut(entity, self.target_rotation)
+            .unwrap();
+        position.position.x = x;
+        position.position.y = y;
+        position.position.z = z;
+        target_position.position.x = x;
+        target_position.position.y = y;
+        target_position.position.z = z;
+        rotation.yaw = -(yaw / 256.0) * PI * 2.0;
+        rotation.pitch = -(pitch / 256.0) * PI * 2.0;
+        target_rotation.yaw = rotation.yaw;
+        target_rotation.pitch = rotation.pitch;
+        self.entity_map.insert(entity_id, entity);
+    }
+
+    fn on_teleport_player_withdismount(
+        &mut self,
+        teleport: packet::play::clientbound::TeleportPlayer_WithDismount,
+    ) {
+        self.on_teleport_player(
+            teleport.x,
+            teleport.y,
+            teleport.z,
+            teleport.yaw as f64,
+            teleport.pitch as f64,
+            teleport.flags,
+            Some(teleport.teleport_id),
+        )
+    }
+
+    fn on_teleport_player_withconfirm(
+        &mut self,
+        teleport: packet::play::clientbound::TeleportPlayer_WithConfirm,
     ) {
         self.on_teleport_player(
             teleport.x,
@@ -1787,178 +2917,926 @@ impl Server {
         }
     }
 
-    fn on_sign_update(&mut self, mut update_sign: packet::play::clientbound::UpdateSign) {
-        format::convert_legacy(&mut update_sign.line1);
-        format::convert_legacy(&mut update_sign.line2);
-        format::convert_legacy(&mut update_sign.line3);
-        format::convert_legacy(&mut update_sign.line4);
-        self.world
-            .add_block_entity_action(world::BlockEntityAction::UpdateSignText(Box::new((
-                update_sign.location,
-                update_sign.line1,
-                update_sign.line2,
-                update_sign.line3,
-                update_sign.line4,
-            ))));
+    fn on_sign_update(&mut self, mut update_sign: packet::play::clientbound::UpdateSign) {
+        format::convert_legacy(&mut update_sign.line1);
+        format::convert_legacy(&mut update_sign.line2);
+        format::convert_legacy(&mut update_sign.line3);
+        format::convert_legacy(&mut update_sign.line4);
+        self.world
+            .add_block_entity_action(world::BlockEntityAction::UpdateSignText(Box::new((
+                update_sign.location,
+                update_sign.line1,
+                update_sign.line2,
+                update_sign.line3,
+                update_sign.line4,
+            ))));
+    }
+
+    fn on_sign_update_u16(&mut self, mut update_sign: packet::play::clientbound::UpdateSign_u16) {
+        format::convert_legacy(&mut update_sign.line1);
+        format::convert_legacy(&mut update_sign.line2);
+        format::convert_legacy(&mut update_sign.line3);
+        format::convert_legacy(&mut update_sign.line4);
+        self.world
+            .add_block_entity_action(world::BlockEntityAction::UpdateSignText(Box::new((
+                Position::new(update_sign.x, update_sign.y as i32, update_sign.z),
+                update_sign.line1,
+                update_sign.line2,
+                update_sign.line3,
+                update_sign.line4,
+            ))));
+    }
+
+    fn on_player_info_string(
+        &mut self,
+        _player_info: packet::play::clientbound::PlayerInfo_String,
+    ) {
+        // TODO: track online players, for 1.7.10 - this is for the <tab> online player list
+        // self.players in 1.7.10 will be only spawned players (within client range)
+        /*
+        if player_info.online {
+            self.players.entry(uuid.clone()).or_insert(PlayerInfo {
+                name: player_info.name.clone(),
+                uuid,
+                skin_url: None,
+
+                display_name: None,
+                ping: player_info.ping as i32,
+                gamemode: Gamemode::from_int(0),
+            });
+        } else {
+            self.players.remove(&uuid);
+        }
+        */
+    }
+
+    fn on_player_info(&mut self, player_info: packet::play::clientbound::PlayerInfo) {
+        use crate::protocol::packet::PlayerDetail::*;
+        for detail in player_info.inner.players {
+            match detail {
+                Add {
+                    name,
+                    uuid,
+                    properties,
+                    display,
+                    gamemode,
+                    ping,
+                } => {
+                    let info = self.players.entry(uuid.clone()).or_insert(PlayerInfo {
+                        name: name.clone(),
+                        uuid,
+                        skin_url: None,
+
+                        display_name: display.clone(),
+                        ping: ping.0,
+                        gamemode: Gamemode::from_int(gamemode.0),
+                    });
+                    // Re-set the props of the player in case of dodgy server implementations
+                    info.name = name;
+                    info.display_name = display;
+                    info.ping = ping.0;
+                    info.gamemode = Gamemode::from_int(gamemode.0);
+                    for prop in properties {
+                        if prop.name != "textures" {
+                            continue;
+                        }
+                        // Ideally we would check the signature of the blob to
+                        // verify it was from Mojang and not faked by the server
+                        // but this requires the public key which is distributed
+                        // authlib. We could download authlib on startup and extract
+                        // the key but this seems like overkill compared to just
+                        // whitelisting Mojang's texture servers instead.
+                        let skin_blob_result = &base64::decode(&prop.value);
+                        let skin_blob = match skin_blob_result {
+                            Ok(val) => val,
+                            Err(err) => {
+                                error!("Failed to decode skin blob, {:?}", err);
+                                continue;
+                            }
+                        };
+                        let skin_blob: serde_json::Value = match serde_json::from_slice(skin_blob) {
+                            Ok(val) => val,
+                            Err(err) => {
+                                error!("Failed to parse skin blob, {:?}", err);
+                                continue;
+                            }
+                        };
+                        if let Some(skin_url) = skin_blob
+                            .pointer("/textures/SKIN/url")
+                            .and_then(|v| v.as_str())
+                        {
+                            info.skin_url = Some(skin_url.to_owned());
+                        }
+                    }
+
+                    // Refresh our own skin when the server sends it to us.
+                    // The join game packet can come before this packet meaning
+                    // we may not have the skin in time for spawning ourselves.
+                    // This isn't an issue for other players because this packet
+                    // must come before the spawn player packet.
+                    if info.uuid == self.uuid {
+                        let model = self
+                            .entities
+                            .get_component_mut_direct::<entity::player::PlayerModel>(
+                                self.player.unwrap(),
+                            )
+                            .unwrap();
+                        model.set_skin(info.skin_url.clone());
+                    }
+                }
+                UpdateGamemode { uuid, gamemode } => {
+                    if let Some(info) = self.players.get_mut(&uuid) {
+                        info.gamemode = Gamemode::from_int(gamemode.0);
+                    }
+                }
+                UpdateLatency { uuid, ping } => {
+                    if let Some(info) = self.players.get_mut(&uuid) {
+                        info.ping = ping.0;
+                    }
+                }
+                UpdateDisplayName { uuid, display } => {
+                    if let Some(info) = self.players.get_mut(&uuid) {
+                        info.display_name = display;
+                    }
+                }
+                Remove { uuid } => {
+                    self.players.remove(&uuid);
+                }
+            }
+        }
+    }
+
+    fn on_player_list_header_footer(
+        &mut self,
+        header_footer: packet::play::clientbound::PlayerListHeaderFooter,
+    ) {
+        // Servers clear these by sending empty components
+        let non_empty = |c: format::Component| Some(c).filter(|c| !c.to_string().is_empty());
+        self.player_list_header = non_empty(header_footer.header);
+        self.player_list_footer = non_empty(header_footer.footer);
+    }
+
+    /// Returns the name of the local player, once the server has sent
+    /// it in the player list.
+    pub fn player_name(&self) -> Option<&str> {
+        self.players.get(&self.uuid).map(|info| info.name.as_str())
+    }
+
+    /// Parses text sent by the server, which is json from 1.13 onwards
+    /// and a string with legacy formatting codes before.
+    fn parse_text(&self, text: &str) -> format::Component {
+        if self.protocol_version >= 404 {
+            format::Component::from_string(text)
+        } else {
+            let mut component = format::Component::Text(format::TextComponent::new(text));
+            format::convert_legacy(&mut component);
+            component
+        }
+    }
+
+    fn on_boss_bar(&mut self, boss_bar: packet::play::clientbound::BossBar) {
+        update_boss_bars(&mut self.boss_bars, boss_bar);
+    }
+
+    fn on_scoreboard_objective(
+        &mut self,
+        objective: packet::play::clientbound::ScoreboardObjective,
+    ) {
+        self.on_scoreboard_objective_change(&objective.name, objective.mode, &objective.value);
+    }
+
+    fn on_scoreboard_objective_nomode(
+        &mut self,
+        objective: packet::play::clientbound::ScoreboardObjective_NoMode,
+    ) {
+        // 1.7 sends the mode where later versions send the type
+        self.on_scoreboard_objective_change(&objective.name, objective.ty, &objective.value);
+    }
+
+    fn on_scoreboard_objective_change(&mut self, name: &str, mode: u8, display_name: &str) {
+        match mode {
+            0 | 2 => {
+                let display_name = self.parse_text(display_name);
+                self.scoreboard.update_objective(name, display_name);
+            }
+            1 => self.scoreboard.remove_objective(name),
+            _ => {}
+        }
+    }
+
+    fn on_update_score_varint(&mut self, score: packet::play::clientbound::UpdateScore_VarInt) {
+        self.on_update_score(
+            &score.name,
+            score.action.0,
+            &score.object_name,
+            score.value.map(|v| v.0),
+        );
+    }
+
+    fn on_update_score_u8(&mut self, score: packet::play::clientbound::UpdateScore_u8) {
+        self.on_update_score(
+            &score.name,
+            score.action as i32,
+            &score.object_name,
+            score.value.map(|v| v.0),
+        );
+    }
+
+    fn on_update_score_i32(&mut self, score: packet::play::clientbound::UpdateScore_i32) {
+        self.on_update_score(
+            &score.name,
+            score.action as i32,
+            &score.object_name,
+            score.value,
+        );
+    }
+
+    fn on_update_score(&mut self, entry: &str, action: i32, objective: &str, value: Option<i32>) {
+        if action == 1 {
+            // No objective removes the entry from all of them
+            let objective = Some(objective).filter(|v| !v.is_empty());
+            self.scoreboard.remove_score(entry, objective);
+        } else if let Some(value) = value {
+            self.scoreboard.set_score(entry, objective, value);
+        }
+    }
+
+    fn on_scoreboard_display(&mut self, display: packet::play::clientbound::ScoreboardDisplay) {
+        self.scoreboard
+            .set_display_slot(display.position, &display.name);
+    }
+
+    fn on_teams_varint(&mut self, teams: packet::play::clientbound::Teams_VarInt) {
+        let team = match teams.mode {
+            0 | 2 => Some(scoreboard::Team {
+                prefix: teams.prefix.unwrap_or_default(),
+                suffix: teams.suffix.unwrap_or_default(),
+                json: true,
+                color: teams
+                    .formatting
+                    .and_then(|v| scoreboard::color_from_index(v.0)),
+                name_tag_visibility: teams.name_tag_visibility.unwrap_or_default(),
+            }),
+            _ => None,
+        };
+        self.on_teams(&teams.name, teams.mode, team, teams.players.map(|v| v.data));
+    }
+
+    fn on_teams_u8(&mut self, teams: packet::play::clientbound::Teams_u8) {
+        let team = match teams.mode {
+            0 | 2 => Some(scoreboard::Team {
+                prefix: teams.prefix.unwrap_or_default(),
+                suffix: teams.suffix.unwrap_or_default(),
+                json: false,
+                color: teams
+                    .color
+                    .and_then(|v| scoreboard::color_from_index(v as i32)),
+                name_tag_visibility: teams.name_tag_visibility.unwrap_or_default(),
+            }),
+            _ => None,
+        };
+        self.on_teams(&teams.name, teams.mode, team, teams.players.map(|v| v.data));
+    }
+
+    fn on_teams_nocollision(&mut self, teams: packet::play::clientbound::Teams_NoCollision) {
+        let team = match teams.mode {
+            0 | 2 => Some(scoreboard::Team {
+                prefix: teams.prefix.unwrap_or_default(),
+                suffix: teams.suffix.unwrap_or_default(),
+                json: false,
+                color: teams
+                    .color
+                    .and_then(|v| scoreboard::color_from_index(v as i32)),
+                name_tag_visibility: teams.name_tag_visibility.unwrap_or_default(),
+            }),
+            _ => None,
+        };
+        self.on_teams(&teams.name, teams.mode, team, teams.players.map(|v| v.data));
+    }
+
+    fn on_teams_noviscolor(&mut self, teams: packet::play::clientbound::Teams_NoVisColor) {
+        let team = match teams.mode {
+            0 | 2 => Some(scoreboard::Team {
+                prefix: teams.prefix.unwrap_or_default(),
+                suffix: teams.suffix.unwrap_or_default(),
+                json: false,
+                color: None,
+                name_tag_visibility: String::new(),
+            }),
+            _ => None,
+        };
+        self.on_teams(&teams.name, teams.mode, team, teams.players.map(|v| v.data));
+    }
+
+    fn on_teams(
+        &mut self,
+        name: &str,
+        mode: u8,
+        team: Option<scoreboard::Team>,
+        players: Option<Vec<String>>,
+    ) {
+        if let Some(team) = team {
+            self.scoreboard.update_team(name, team);
+        }
+        let players = players.unwrap_or_default();
+        match mode {
+            0 | 3 => self.scoreboard.add_team_players(name, &players),
+            1 => self.scoreboard.remove_team(name),
+            4 => self.scoreboard.remove_team_players(name, &players),
+            _ => {}
+        }
+        self.update_name_tags();
+    }
+
+    /// Applies the teams' formatting and visibility to the name tags of
+    /// the players around us.
+    fn update_name_tags(&mut self) {
+        let viewer = self.player_name().map(|name| name.to_owned());
+        for &entity in self.entity_map.values() {
+            if let Some(model) = self
+                .entities
+                .get_component_mut_direct::<entity::player::PlayerModel>(entity)
+            {
+                let name_tag = self.scoreboard.name_tag(model.name(), viewer.as_deref());
+                model.set_name_tag(name_tag);
+            }
+        }
+    }
+
+    fn on_servermessage_noposition(
+        &mut self,
+        m: packet::play::clientbound::ServerMessage_NoPosition,
+    ) {
+        self.on_servermessage(&m.message, None, None);
+    }
+
+    fn on_servermessage_position(&mut self, m: packet::play::clientbound::ServerMessage_Position) {
+        self.on_servermessage(&m.message, Some(m.position), None);
+    }
+
+    fn on_servermessage_sender(&mut self, m: packet::play::clientbound::ServerMessage_Sender) {
+        self.on_servermessage(&m.message, Some(m.position), Some(m.sender));
+    }
+
+    fn on_servermessage(
+        &mut self,
+        message: &format::Component,
+        position: Option<u8>,
+        _sender: Option<protocol::UUID>,
+    ) {
+        if position == Some(2) {
+            self.received_titles
+                .push(TitleAction::ActionBar(message.clone()));
+            return;
+        }
+        info!("Received chat message: {}", message);
+        self.received_chat.push(message.clone());
+    }
+
+    fn on_title(&mut self, title: packet::play::clientbound::Title) {
+        let action = match title.action.0 {
+            0 => title.title.map(TitleAction::Title),
+            1 => title.sub_title.map(TitleAction::Subtitle),
+            2 => title
+                .action_bar_text
+                .map(|text| TitleAction::ActionBar(format::Component::from_string(&text))),
+            3 => Some(TitleAction::Times(
+                title.fade_in.unwrap_or_default(),
+                title.fade_stay.unwrap_or_default(),
+                title.fade_out.unwrap_or_default(),
+            )),
+            4 => Some(TitleAction::Clear),
+            5 => Some(TitleAction::Reset),
+            _ => None,
+        };
+        self.received_titles.extend(action);
+    }
+
+    // Before 1.11 the action bar was only set through chat messages,
+    // which shifts the remaining actions down by one
+    fn on_title_notext(&mut self, title: packet::play::clientbound::Title_notext) {
+        let action = match title.action.0 {
+            0 => title.title.map(TitleAction::Title),
+            1 => title.sub_title.map(TitleAction::Subtitle),
+            2 => Some(TitleAction::Times(
+                title.fade_in.unwrap_or_default(),
+                title.fade_stay.unwrap_or_default(),
+                title.fade_out.unwrap_or_default(),
+            )),
+            3 => Some(TitleAction::Clear),
+            4 => Some(TitleAction::Reset),
+            _ => None,
+        };
+        self.received_titles.extend(action);
+    }
+
+    fn on_title_notext_component(
+        &mut self,
+        title: packet::play::clientbound::Title_notext_component,
+    ) {
+        self.on_title_notext(packet::play::clientbound::Title_notext {
+            action: title.action,
+            title: title.title,
+            sub_title: title.sub_title,
+            fade_in: title.fade_in,
+            fade_stay: title.fade_stay,
+            fade_out: title.fade_out,
+        });
+    }
+
+    fn on_title_text(&mut self, title: packet::play::clientbound::TitleText) {
+        self.received_titles
+            .push(TitleAction::Title(format::Component::from_string(
+                &title.title,
+            )));
+    }
+
+    fn on_title_subtitle(&mut self, subtitle: packet::play::clientbound::TitleSubtitle) {
+        self.received_titles
+            .push(TitleAction::Subtitle(format::Component::from_string(
+                &subtitle.subtitle_text,
+            )));
+    }
+
+    fn on_title_times(&mut self, times: packet::play::clientbound::TitleTimes) {
+        self.received_titles.push(TitleAction::Times(
+            times.fade_in,
+            times.stay,
+            times.fade_out,
+        ));
+    }
+
+    fn on_clear_titles(&mut self, clear: packet::play::clientbound::ClearTitles) {
+        self.received_titles.push(if clear.reset {
+            TitleAction::Reset
+        } else {
+            TitleAction::Clear
+        });
+    }
+
+    fn on_action_bar(&mut self, action_bar: packet::play::clientbound::ActionBar) {
+        self.received_titles
+            .push(TitleAction::ActionBar(format::Component::from_string(
+                &action_bar.text,
+            )));
+    }
+
+    fn on_declare_commands(&mut self, commands: packet::play::clientbound::DeclareCommands) {
+        self.commands = Some(commands::CommandTree::new(
+            &commands.nodes.data,
+            commands.root_index.0,
+        ));
+    }
+
+    fn on_tab_complete_reply(&mut self, reply: packet::play::clientbound::TabCompleteReply) {
+        let text = match self.completion_request.take() {
+            Some((_, text)) => text,
+            None => return,
+        };
+        // Older servers only complete the last word
+        let start = text.rfind(' ').map_or(0, |v| v + 1);
+        self.completions = Some(Completions {
+            text,
+            start,
+            matches: reply.matches.data,
+        });
+    }
+
+    fn on_tab_complete_reply_transaction(
+        &mut self,
+        reply: packet::play::clientbound::TabCompleteReply_Transaction,
+    ) {
+        // Replies to requests for text that has since changed are dropped
+        let text = match self.completion_request.take() {
+            Some((id, text)) if id == reply.transaction_id.0 => text,
+            request => {
+                self.completion_request = request;
+                return;
+            }
+        };
+        // The range is counted in characters
+        let start = text
+            .char_indices()
+            .nth(reply.start.0.max(0) as usize)
+            .map_or(text.len(), |(i, _)| i);
+        self.completions = Some(Completions {
+            text,
+            start,
+            matches: reply.matches.data.into_iter().map(|v| v.text).collect(),
+        });
+    }
+
+    fn on_window_items_statecarry(
+        &mut self,
+        items: packet::play::clientbound::WindowItems_StateCarry,
+    ) {
+        self.inventory.write().unwrap().set_items(
+            items.id,
+            items.items.data,
+            Some(items.state_id.0),
+            Some(items.carried_item),
+        );
+    }
+
+    fn on_window_items_i16(&mut self, items: packet::play::clientbound::WindowItems_i16) {
+        self.inventory
+            .write()
+            .unwrap()
+            .set_items(items.id, items.items.data, None, None);
+    }
+
+    fn on_window_set_slot_state(
+        &mut self,
+        set_slot: packet::play::clientbound::WindowSetSlot_State,
+    ) {
+        self.inventory.write().unwrap().set_slot(
+            set_slot.id,
+            set_slot.property,
+            set_slot.item,
+            Some(set_slot.state_id.0),
+        );
+    }
+
+    fn on_window_set_slot(&mut self, set_slot: packet::play::clientbound::WindowSetSlot) {
+        self.inventory.write().unwrap().set_slot(
+            set_slot.id,
+            set_slot.property,
+            set_slot.item,
+            None,
+        );
+    }
+
+    fn on_confirm_transaction(&mut self, confirm: packet::play::clientbound::ConfirmTransaction) {
+        // A rejected click has to be acknowledged before the server accepts
+        // any more, it then resends the window's contents to undo the
+        // prediction.
+        if !confirm.accepted {
+            self.write_packet(packet::play::serverbound::ConfirmTransactionServerbound {
+                id: confirm.id,
+                action_number: confirm.action_number,
+                accepted: true,
+            });
+        }
+    }
+
+    fn on_window_ping(&mut self, ping: packet::play::clientbound::WindowPing) {
+        self.write_packet(packet::play::serverbound::WindowPong { id: ping.id });
+    }
+
+    fn on_window_open(&mut self, open: packet::play::clientbound::WindowOpen) {
+        let (kind, slots) = inventory::WindowKind::from_name(&open.ty, open.slot_count as usize);
+        self.inventory
+            .write()
+            .unwrap()
+            .open_container(open.id, kind, slots, Some(open.title));
+    }
+
+    fn on_window_open_u8(&mut self, open: packet::play::clientbound::WindowOpen_u8) {
+        let (kind, slots) =
+            inventory::WindowKind::from_legacy_id(open.ty, open.slot_count as usize);
+        // 1.7 windows use the default title unless told otherwise
+        let title = if open.use_provided_window_title {
+            Some(open.title)
+        } else {
+            None
+        };
+        self.inventory
+            .write()
+            .unwrap()
+            .open_container(open.id, kind, slots, title);
+    }
+
+    fn on_window_open_varint(&mut self, open: packet::play::clientbound::WindowOpen_VarInt) {
+        let (kind, slots) =
+            inventory::WindowKind::from_registry_id(open.ty.0, self.protocol_version);
+        self.inventory.write().unwrap().open_container(
+            open.id.0 as u8,
+            kind,
+            slots,
+            Some(open.title),
+        );
+    }
+
+    fn on_window_open_horse(&mut self, open: packet::play::clientbound::WindowOpenHorse) {
+        self.inventory.write().unwrap().open_container(
+            open.window_id,
+            inventory::WindowKind::Other,
+            open.number_of_slots.0 as usize,
+            None,
+        );
+    }
+
+    fn on_window_property(&mut self, property: packet::play::clientbound::WindowProperty) {
+        self.inventory.write().unwrap().set_property(
+            property.id,
+            property.property,
+            property.value,
+        );
+    }
+
+    fn on_window_close(&mut self, close: packet::play::clientbound::WindowClose) {
+        self.inventory.write().unwrap().close_from_server(close.id);
+    }
+
+    fn on_set_current_hotbar_slot(
+        &mut self,
+        slot: packet::play::clientbound::SetCurrentHotbarSlot,
+    ) {
+        self.inventory.write().unwrap().select_slot(slot.slot);
+    }
+
+    fn on_combat_event(&mut self, event: packet::play::clientbound::CombatEvent) {
+        // Only the entity dead event has a player id
+        if let Some(player_id) = event.player_id {
+            if self.is_local_player(player_id.0) {
+                self.on_death(event.message);
+            }
+        }
+    }
+
+    fn on_combat_event_death(&mut self, death: packet::play::clientbound::CombatEventDeath) {
+        if self.is_local_player(death.player_id.0) {
+            self.on_death(Some(format::Component::from_string(&death.message)));
+        }
+    }
+
+    fn on_death(&mut self, message: Option<format::Component>) {
+        self.dead = true;
+        *self.death_message.write().unwrap() = message;
+    }
+
+    fn on_update_health(&mut self, health: packet::play::clientbound::UpdateHealth) {
+        self.set_health(health.health, health.food.0, health.food_saturation);
+    }
+
+    fn on_update_health_u16(&mut self, health: packet::play::clientbound::UpdateHealth_u16) {
+        self.set_health(health.health, health.food as i32, health.food_saturation);
+    }
+
+    fn set_health(&mut self, health: f32, food: i32, saturation: f32) {
+        if health <= 0.0 {
+            self.dead = true;
+        } else if self.status.health <= 0.0 {
+            // Respawned without being sent to a new world
+            self.dead = false;
+            *self.death_message.write().unwrap() = None;
+        }
+        self.status.health = health;
+        self.status.food = food;
+        self.status.saturation = saturation;
+    }
+
+    fn on_set_experience(&mut self, experience: packet::play::clientbound::SetExperience) {
+        self.status.experience_bar = experience.experience_bar;
+        self.status.level = experience.level.0;
+        self.status.total_experience = experience.total_experience.0;
+    }
+
+    fn on_set_experience_i16(&mut self, experience: packet::play::clientbound::SetExperience_i16) {
+        self.status.experience_bar = experience.experience_bar;
+        self.status.level = experience.level as i32;
+        self.status.total_experience = experience.total_experience as i32;
+    }
+
+    fn on_entity_properties_varintvarint(
+        &mut self,
+        properties: packet::play::clientbound::EntityProperties_VarIntVarInt,
+    ) {
+        for property in properties.properties.data {
+            self.on_entity_property(
+                properties.entity_id.0,
+                &property.key,
+                property.value,
+                &property.modifiers.data,
+            );
+        }
+    }
+
+    fn on_entity_properties_varint(
+        &mut self,
+        properties: packet::play::clientbound::EntityProperties_VarInt,
+    ) {
+        for property in properties.properties.data {
+            self.on_entity_property(
+                properties.entity_id.0,
+                &property.key,
+                property.value,
+                &property.modifiers.data,
+            );
+        }
+    }
+
+    fn on_entity_properties_i32(
+        &mut self,
+        properties: packet::play::clientbound::EntityProperties_i32,
+    ) {
+        for property in properties.properties.data {
+            self.on_entity_property(
+                properties.entity_id,
+                &property.key,
+                property.value,
+                &property.modifiers.data,
+            );
+        }
+    }
+
+    fn on_entity_property(
+        &mut self,
+        entity_id: i32,
+        key: &str,
+        base: f64,
+        modifiers: &[packet::PropertyModifier],
+    ) {
+        let player = match self.player {
+            Some(player) if self.is_local_player(entity_id) => player,
+            _ => return,
+        };
+        let value = attribute_value(base, modifiers);
+        // 1.16 namespaced the keys and switched them to snake case
+        match key.trim_start_matches("minecraft:") {
+            "generic.maxHealth" | "generic.max_health" => self.status.max_health = value as f32,
+            "generic.armor" => self.status.armor = value as i32,
+            "generic.movementSpeed" | "generic.movement_speed" => {
+                self.entities
+                    .get_component_mut(player, self.player_movement)
+                    .unwrap()
+                    .walking_speed = value;
+            }
+            _ => {}
+        }
+    }
+
+    fn on_entity_metadata(&mut self, metadata: packet::play::clientbound::EntityMetadata) {
+        self.on_entity_metadata_update(metadata.entity_id.0, metadata.metadata);
     }
 
-    fn on_sign_update_u16(&mut self, mut update_sign: packet::play::clientbound::UpdateSign_u16) {
-        format::convert_legacy(&mut update_sign.line1);
-        format::convert_legacy(&mut update_sign.line2);
-        format::convert_legacy(&mut update_sign.line3);
-        format::convert_legacy(&mut update_sign.line4);
-        self.world
-            .add_block_entity_action(world::BlockEntityAction::UpdateSignText(Box::new((
-                Position::new(update_sign.x, update_sign.y as i32, update_sign.z),
-                update_sign.line1,
-                update_sign.line2,
-                update_sign.line3,
-                update_sign.line4,
-            ))));
+    fn on_entity_metadata_i32(&mut self, metadata: packet::play::clientbound::EntityMetadata_i32) {
+        self.on_entity_metadata_update(metadata.entity_id, metadata.metadata);
     }
 
-    fn on_player_info_string(
+    fn on_entity_metadata_update(&mut self, entity_id: i32, metadata: types::Metadata) {
+        if let Some(&entity) = self.entity_map.get(&entity_id) {
+            entity::metadata::apply(&mut self.entities, entity, self.protocol_version, &metadata);
+        }
+        if !self.is_local_player(entity_id) {
+            return;
+        }
+        // The index of the player's extra hearts moves as fields are
+        // added to the entity classes above it
+        let absorption_index = match self.protocol_version {
+            v if v >= 755 => 15,
+            v if v >= 573 => 14,
+            v if v >= 477 => 13,
+            v if v >= 210 => 11,
+            v if v >= 107 => 10,
+            _ => 17,
+        };
+        if let Some(types::Value::Float(absorption)) = metadata.get_raw(absorption_index) {
+            self.status.absorption = *absorption;
+        }
+    }
+
+    fn on_entity_equipment_array(
         &mut self,
-        _player_info: packet::play::clientbound::PlayerInfo_String,
+        equipment: packet::play::clientbound::EntityEquipment_Array,
     ) {
-        // TODO: track online players, for 1.7.10 - this is for the <tab> online player list
-        // self.players in 1.7.10 will be only spawned players (within client range)
-        /*
-        if player_info.online {
-            self.players.entry(uuid.clone()).or_insert(PlayerInfo {
-                name: player_info.name.clone(),
-                uuid,
-                skin_url: None,
+        for slot in equipment.equipments.equipments {
+            self.on_entity_equipment(equipment.entity_id.0, slot.slot as i32, slot.item);
+        }
+    }
 
-                display_name: None,
-                ping: player_info.ping as i32,
-                gamemode: Gamemode::from_int(0),
-            });
-        } else {
-            self.players.remove(&uuid);
+    fn on_entity_equipment_varint(
+        &mut self,
+        equipment: packet::play::clientbound::EntityEquipment_VarInt,
+    ) {
+        self.on_entity_equipment(equipment.entity_id.0, equipment.slot.0, equipment.item);
+    }
+
+    fn on_entity_equipment_u16(
+        &mut self,
+        equipment: packet::play::clientbound::EntityEquipment_u16,
+    ) {
+        self.on_entity_equipment(equipment.entity_id.0, equipment.slot as i32, equipment.item);
+    }
+
+    fn on_entity_equipment_u16_i32(
+        &mut self,
+        equipment: packet::play::clientbound::EntityEquipment_u16_i32,
+    ) {
+        self.on_entity_equipment(equipment.entity_id, equipment.slot as i32, equipment.item);
+    }
+
+    fn on_entity_equipment(&mut self, entity_id: i32, slot: i32, item: Option<item::Stack>) {
+        let slot = match entity::equipment::EquipmentSlot::from_id(self.protocol_version, slot) {
+            Some(slot) => slot,
+            None => return,
+        };
+        if let Some(&entity) = self.entity_map.get(&entity_id) {
+            if let Some(equipment) = self
+                .entities
+                .get_component_mut_direct::<entity::equipment::Equipment>(entity)
+            {
+                equipment.set(slot, item);
+            }
         }
-        */
     }
 
-    fn on_player_info(&mut self, player_info: packet::play::clientbound::PlayerInfo) {
-        use crate::protocol::packet::PlayerDetail::*;
-        for detail in player_info.inner.players {
-            match detail {
-                Add {
-                    name,
-                    uuid,
-                    properties,
-                    display,
-                    gamemode,
-                    ping,
-                } => {
-                    let info = self.players.entry(uuid.clone()).or_insert(PlayerInfo {
-                        name: name.clone(),
-                        uuid,
-                        skin_url: None,
+    fn on_entity_effect_varint(&mut self, effect: packet::play::clientbound::EntityEffect_VarInt) {
+        self.on_entity_effect(
+            effect.entity_id.0,
+            effect.effect_id.0,
+            effect.amplifier,
+            effect.duration.0,
+        )
+    }
 
-                        display_name: display.clone(),
-                        ping: ping.0,
-                        gamemode: Gamemode::from_int(gamemode.0),
-                    });
-                    // Re-set the props of the player in case of dodgy server implementations
-                    info.name = name;
-                    info.display_name = display;
-                    info.ping = ping.0;
-                    info.gamemode = Gamemode::from_int(gamemode.0);
-                    for prop in properties {
-                        if prop.name != "textures" {
-                            continue;
-                        }
-                        // Ideally we would check the signature of the blob to
-                        // verify it was from Mojang and not faked by the server
-                        // but this requires the public key which is distributed
-                        // authlib. We could download authlib on startup and extract
-                        // the key but this seems like overkill compared to just
-                        // whitelisting Mojang's texture servers instead.
-                        let skin_blob_result = &base64::decode(&prop.value);
-                        let skin_blob = match skin_blob_result {
-                            Ok(val) => val,
-                            Err(err) => {
-                                error!("Failed to decode skin blob, {:?}", err);
-                                continue;
-                            }
-                        };
-                        let skin_blob: serde_json::Value = match serde_json::from_slice(skin_blob) {
-                            Ok(val) => val,
-                            Err(err) => {
-                                error!("Failed to parse skin blob, {:?}", err);
-                                continue;
-                            }
-                        };
-                        if let Some(skin_url) = skin_blob
-                            .pointer("/textures/SKIN/url")
-                            .and_then(|v| v.as_str())
-                        {
-                            info.skin_url = Some(skin_url.to_owned());
-                        }
-                    }
+    fn on_entity_effect_i8(&mut self, effect: packet::play::clientbound::EntityEffect_i8) {
+        self.on_entity_effect(
+            effect.entity_id.0,
+            i32::from(effect.effect_id),
+            effect.amplifier,
+            effect.duration.0,
+        )
+    }
 
-                    // Refresh our own skin when the server sends it to us.
-                    // The join game packet can come before this packet meaning
-                    // we may not have the skin in time for spawning ourselves.
-                    // This isn't an issue for other players because this packet
-                    // must come before the spawn player packet.
-                    if info.uuid == self.uuid {
-                        let model = self
-                            .entities
-                            .get_component_mut_direct::<entity::player::PlayerModel>(
-                                self.player.unwrap(),
-                            )
-                            .unwrap();
-                        model.set_skin(info.skin_url.clone());
-                    }
-                }
-                UpdateGamemode { uuid, gamemode } => {
-                    if let Some(info) = self.players.get_mut(&uuid) {
-                        info.gamemode = Gamemode::from_int(gamemode.0);
-                    }
-                }
-                UpdateLatency { uuid, ping } => {
-                    if let Some(info) = self.players.get_mut(&uuid) {
-                        info.ping = ping.0;
-                    }
-                }
-                UpdateDisplayName { uuid, display } => {
-                    if let Some(info) = self.players.get_mut(&uuid) {
-                        info.display_name = display;
-                    }
-                }
-                Remove { uuid } => {
-                    self.players.remove(&uuid);
-                }
+    fn on_entity_effect_i32(&mut self, effect: packet::play::clientbound::EntityEffect_i32) {
+        self.on_entity_effect(
+            effect.entity_id,
+            i32::from(effect.effect_id),
+            effect.amplifier,
+            i32::from(effect.duration),
+        )
+    }
+
+    fn on_entity_effect(&mut self, entity_id: i32, effect_id: i32, amplifier: i8, duration: i32) {
+        let kind = match entity::effects::EffectKind::from_id(effect_id) {
+            Some(kind) => kind,
+            None => {
+                debug!("Unknown effect {}", effect_id);
+                return;
             }
+        };
+        let entity = match self.entity_map.get(&entity_id) {
+            Some(entity) => *entity,
+            None => return,
+        };
+        let effect = entity::effects::Effect {
+            kind,
+            amplifier: amplifier as u8,
+            duration,
+        };
+        if let Some(effects) = self
+            .entities
+            .get_component_mut_direct::<entity::effects::Effects>(entity)
+        {
+            effects.add(effect);
+        } else {
+            let mut effects = entity::effects::Effects::new();
+            effects.add(effect);
+            self.entities.add_component_direct(entity, effects);
         }
     }
 
-    fn on_servermessage_noposition(
+    fn on_entity_remove_effect_varint(
         &mut self,
-        m: packet::play::clientbound::ServerMessage_NoPosition,
+        effect: packet::play::clientbound::EntityRemoveEffect_VarInt,
     ) {
-        self.on_servermessage(&m.message, None, None);
-    }
-
-    fn on_servermessage_position(&mut self, m: packet::play::clientbound::ServerMessage_Position) {
-        self.on_servermessage(&m.message, Some(m.position), None);
+        self.on_entity_remove_effect(effect.entity_id.0, effect.effect_id.0)
     }
 
-    fn on_servermessage_sender(&mut self, m: packet::play::clientbound::ServerMessage_Sender) {
-        self.on_servermessage(&m.message, Some(m.position), Some(m.sender));
+    fn on_entity_remove_effect_i8(
+        &mut self,
+        effect: packet::play::clientbound::EntityRemoveEffect_i8,
+    ) {
+        self.on_entity_remove_effect(effect.entity_id.0, i32::from(effect.effect_id))
     }
 
-    fn on_servermessage(
+    fn on_entity_remove_effect_i32(
         &mut self,
-        message: &format::Component,
-        _position: Option<u8>,
-        _sender: Option<protocol::UUID>,
+        effect: packet::play::clientbound::EntityRemoveEffect_i32,
     ) {
-        info!("Received chat message: {}", message);
-        self.received_chat_at = Some(Instant::now());
+        self.on_entity_remove_effect(effect.entity_id, i32::from(effect.effect_id))
+    }
+
+    fn on_entity_remove_effect(&mut self, entity_id: i32, effect_id: i32) {
+        let kind = match entity::effects::EffectKind::from_id(effect_id) {
+            Some(kind) => kind,
+            None => return,
+        };
+        if let Some(&entity) = self.entity_map.get(&entity_id) {
+            if let Some(effects) = self
+                .entities
+                .get_component_mut_direct::<entity::effects::Effects>(entity)
+            {
+                effects.remove(kind);
+            }
+        }
+    }
+
+    /// Returns the effects on the player, if they have any.
+    pub fn player_effects(&self) -> Option<&entity::effects::Effects> {
+        self.player
+            .and_then(|player| self.entities.get_component_direct(player))
     }
 
     fn load_block_entities(&mut self, block_entities: Vec<Option<crate::nbt::NamedTag>>) {
@@ -2352,3 +4230,205 @@ fn calculate_relative_teleport(flag: TeleportFlag, flags: u8, base: f64, val: f6
         base + val
     }
 }
+
+/// Returns how much wider than normal the player's view is. Flying
+/// widens it, as does moving faster than the walking speed the server
+/// gave the player.
+fn fov_modifier(flying: bool, sprinting: bool, movement_speed: f64, walking_speed: f32) -> f32 {
+    let walking_speed = f64::from(walking_speed);
+    if walking_speed <= 0.0 {
+        return 1.0;
+    }
+    let mut modifier = 1.0;
+    if flying {
+        modifier *= 1.1;
+    }
+    let speed = if sprinting {
+        movement_speed * 1.3
+    } else {
+        movement_speed
+    };
+    modifier *= (speed / walking_speed + 1.0) / 2.0;
+    (modifier as f32).clamp(0.1, 1.5)
+}
+
+/// Applies a boss bar packet to the list of shown bars. Adding a bar
+/// that is already shown replaces it, and updates for unknown bars are
+/// ignored.
+fn update_boss_bars(boss_bars: &mut Vec<BossBar>, boss_bar: packet::play::clientbound::BossBar) {
+    if boss_bar.action.0 == 1 {
+        boss_bars.retain(|bar| bar.uuid != boss_bar.uuid);
+        return;
+    }
+    if boss_bar.action.0 == 0 {
+        boss_bars.retain(|bar| bar.uuid != boss_bar.uuid);
+        boss_bars.push(BossBar {
+            uuid: boss_bar.uuid,
+            title: boss_bar.title,
+            health: boss_bar.health,
+            color: boss_bar.color.0,
+            style: boss_bar.style.0,
+            flags: boss_bar.flags,
+        });
+        return;
+    }
+    let bar = match boss_bars.iter_mut().find(|bar| bar.uuid == boss_bar.uuid) {
+        Some(bar) => bar,
+        None => return,
+    };
+    match boss_bar.action.0 {
+        2 => bar.health = boss_bar.health,
+        3 => bar.title = boss_bar.title,
+        4 => {
+            bar.color = boss_bar.color.0;
+            bar.style = boss_bar.style.0;
+        }
+        5 => bar.flags = boss_bar.flags,
+        _ => {}
+    }
+}
+
+/// Applies an attribute's modifiers to its base value.
+fn attribute_value(base: f64, modifiers: &[packet::PropertyModifier]) -> f64 {
+    // Sprinting is applied by the player's own movement, whatever
+    // the server thinks they are doing
+    let sprinting: protocol::UUID = SPRINTING_MODIFIER.parse().unwrap();
+    let modifiers: Vec<_> = modifiers.iter().filter(|v| v.uuid != sprinting).collect();
+    // Modifiers add to the base, then add multiples of that sum,
+    // then multiply the result
+    let mut value = base;
+    for modifier in modifiers.iter().filter(|v| v.operation == 0) {
+        value += modifier.amount;
+    }
+    let sum = value;
+    for modifier in modifiers.iter().filter(|v| v.operation == 1) {
+        value += sum * modifier.amount;
+    }
+    for modifier in modifiers.iter().filter(|v| v.operation == 2) {
+        value *= 1.0 + modifier.amount;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modifier(uuid: &str, amount: f64, operation: i8) -> packet::PropertyModifier {
+        packet::PropertyModifier {
+            uuid: uuid.parse().unwrap(),
+            amount,
+            operation,
+        }
+    }
+
+    #[test]
+    fn attribute_modifiers() {
+        let uuid = "91aeaa56-376b-4498-935b-2f7f68070635";
+        assert_eq!(attribute_value(0.1, &[]), 0.1);
+        assert_eq!(attribute_value(20.0, &[modifier(uuid, 4.0, 0)]), 24.0);
+        // Multiples of the sum are added together before multiplying
+        let modifiers = [
+            modifier(uuid, 0.5, 2),
+            modifier(uuid, 0.25, 1),
+            modifier(uuid, 2.0, 0),
+            modifier(uuid, 0.25, 1),
+        ];
+        assert_eq!(attribute_value(2.0, &modifiers), 9.0);
+        assert_eq!(
+            attribute_value(1.0, &[modifier(uuid, 1.0, 2), modifier(uuid, 0.5, 2)]),
+            3.0
+        );
+    }
+
+    #[test]
+    fn sprinting_modifier_ignored() {
+        let modifiers = [
+            modifier(SPRINTING_MODIFIER, 0.3, 2),
+            // Speed II
+            modifier("91aeaa56-376b-4498-935b-2f7f68070635", 0.4, 2),
+        ];
+        assert!((attribute_value(0.1, &modifiers) - 0.14).abs() < 1e-9);
+    }
+
+    #[test]
+    fn view_widening() {
+        assert_eq!(fov_modifier(false, false, 0.1, 0.1), 1.0);
+        assert!((fov_modifier(true, false, 0.1, 0.1) - 1.1).abs() < 1e-6);
+        assert!((fov_modifier(false, true, 0.1, 0.1) - 1.15).abs() < 1e-6);
+        // Slowness narrows the view, but only so far
+        assert!((fov_modifier(false, false, 0.05, 0.1) - 0.75).abs() < 1e-6);
+        assert_eq!(fov_modifier(false, false, 0.0, 0.1), 0.5);
+        assert_eq!(fov_modifier(true, true, 1.0, 0.1), 1.5);
+        // Servers can take the player's walking speed away entirely
+        assert_eq!(fov_modifier(false, true, 0.1, 0.0), 1.0);
+    }
+
+    fn boss_bar_packet(
+        uuid: &str,
+        action: i32,
+        title: &str,
+        health: f32,
+    ) -> packet::play::clientbound::BossBar {
+        packet::play::clientbound::BossBar {
+            uuid: uuid.parse().unwrap(),
+            action: protocol::VarInt(action),
+            title: format::Component::Text(format::TextComponent::new(title)),
+            health,
+            color: protocol::VarInt(1),
+            style: protocol::VarInt(2),
+            flags: 0,
+        }
+    }
+
+    #[test]
+    fn boss_bar_updates() {
+        let first = "91aeaa56-376b-4498-935b-2f7f68070635";
+        let second = "0c2ee4b2-4e2a-4f3c-a0d6-5f5bbf1f2d11";
+        let mut bars = vec![];
+        update_boss_bars(&mut bars, boss_bar_packet(first, 0, "Dragon", 1.0));
+        update_boss_bars(&mut bars, boss_bar_packet(second, 0, "Wither", 1.0));
+        assert_eq!(bars.len(), 2);
+        assert_eq!((bars[0].color, bars[0].style), (1, 2));
+
+        update_boss_bars(&mut bars, boss_bar_packet(first, 2, "", 0.25));
+        assert_eq!(bars[0].health, 0.25);
+        assert_eq!(bars[1].health, 1.0);
+        update_boss_bars(&mut bars, boss_bar_packet(second, 3, "Raid", 0.0));
+        assert_eq!(
+            bars[1].title,
+            format::Component::Text(format::TextComponent::new("Raid"))
+        );
+        assert_eq!(bars[1].health, 1.0);
+
+        // Adding a bar that already exists replaces it at the bottom
+        update_boss_bars(&mut bars, boss_bar_packet(first, 0, "Dragon", 0.5));
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[1].uuid, first.parse().unwrap());
+        assert_eq!(bars[1].health, 0.5);
+
+        update_boss_bars(&mut bars, boss_bar_packet(second, 1, "", 0.0));
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].uuid, first.parse().unwrap());
+        // Bars the server never added are ignored
+        update_boss_bars(&mut bars, boss_bar_packet(second, 2, "", 0.0));
+        assert_eq!(bars.len(), 1);
+    }
+
+    #[test]
+    fn locale_case_per_version() {
+        let settings = |locale: &str| ClientSettings {
+            locale: locale.to_owned(),
+            view_distance: 8,
+            chat_mode: 0,
+            chat_colors: true,
+            displayed_skin_parts: 0x7f,
+            main_hand: 1,
+        };
+        assert_eq!(settings("en_us").sent_locale(340), "en_us");
+        assert_eq!(settings("en_us").sent_locale(315), "en_us");
+        assert_eq!(settings("en_us").sent_locale(210), "en_US");
+        assert_eq!(settings("pt_br").sent_locale(5), "pt_BR");
+        assert_eq!(settings("lol").sent_locale(47), "lol");
+    }
+}
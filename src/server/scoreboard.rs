@@ -0,0 +1,334 @@
+use crate::format::{self, Component, TextComponent};
+use std::collections::HashMap;
+
+// Display slots 0 and 2 are the list and below name objectives, 3 to
+// 18 are sidebars only shown to members of teams of the matching colour.
+const SLOT_SIDEBAR: u8 = 1;
+const SLOT_TEAM_SIDEBAR: u8 = 3;
+
+// The most scores the sidebar shows, the lowest ones are dropped
+const MAX_SIDEBAR_SCORES: usize = 15;
+
+pub struct Objective {
+    pub display_name: Component,
+    scores: HashMap<String, i32>,
+}
+
+pub struct Team {
+    pub prefix: String,
+    pub suffix: String,
+    /// Whether the prefix and suffix are json components rather than
+    /// legacy formatted strings.
+    pub json: bool,
+    pub color: Option<format::Color>,
+    pub name_tag_visibility: String,
+}
+
+impl Team {
+    /// Returns the name with the team's prefix, suffix and colour
+    /// applied.
+    pub fn format_name(&self, name: &str) -> Component {
+        if !self.json {
+            // The prefix's colour codes carry on into the name
+            let mut text = Component::Text(TextComponent::new(&format!(
+                "{}{}{}",
+                self.prefix, name, self.suffix
+            )));
+            format::convert_legacy(&mut text);
+            return text;
+        }
+        let mut name = TextComponent::new(name);
+        name.modifier.color = self.color;
+        let mut text = TextComponent::new("");
+        text.modifier.extra = Some(vec![
+            Component::from_string(&self.prefix),
+            Component::Text(name),
+            Component::from_string(&self.suffix),
+        ]);
+        Component::Text(text)
+    }
+}
+
+/// Objectives, their scores and the teams players belong to, as sent by
+/// the server.
+#[derive(Default)]
+pub struct Scoreboard {
+    objectives: HashMap<String, Objective>,
+    display_slots: HashMap<u8, String>,
+    teams: HashMap<String, Team>,
+    player_teams: HashMap<String, String>,
+}
+
+impl Scoreboard {
+    pub fn new() -> Scoreboard {
+        Default::default()
+    }
+
+    /// Creates the objective, or updates it if it already exists.
+    pub fn update_objective(&mut self, name: &str, display_name: Component) {
+        let objective = self
+            .objectives
+            .entry(name.to_owned())
+            .or_insert_with(|| Objective {
+                display_name: Default::default(),
+                scores: HashMap::new(),
+            });
+        objective.display_name = display_name;
+    }
+
+    pub fn remove_objective(&mut self, name: &str) {
+        self.objectives.remove(name);
+        self.display_slots.retain(|_, objective| objective != name);
+    }
+
+    pub fn set_display_slot(&mut self, slot: u8, objective: &str) {
+        if objective.is_empty() {
+            self.display_slots.remove(&slot);
+        } else {
+            self.display_slots.insert(slot, objective.to_owned());
+        }
+    }
+
+    pub fn set_score(&mut self, entry: &str, objective: &str, value: i32) {
+        if let Some(objective) = self.objectives.get_mut(objective) {
+            objective.scores.insert(entry.to_owned(), value);
+        }
+    }
+
+    /// Removes the entry's score from the objective, or from every
+    /// objective if none is given.
+    pub fn remove_score(&mut self, entry: &str, objective: Option<&str>) {
+        for (name, obj) in &mut self.objectives {
+            if objective.map_or(true, |objective| objective == name) {
+                obj.scores.remove(entry);
+            }
+        }
+    }
+
+    /// Returns the objective shown in the display slot.
+    pub fn displayed(&self, slot: u8) -> Option<&Objective> {
+        self.display_slots
+            .get(&slot)
+            .and_then(|name| self.objectives.get(name))
+    }
+
+    /// Returns the objective shown in the sidebar of the given player,
+    /// preferring the one for their team's colour.
+    pub fn sidebar(&self, player: &str) -> Option<&Objective> {
+        self.team_of(player)
+            .and_then(|team| team.color)
+            .and_then(color_index)
+            .and_then(|index| self.displayed(SLOT_TEAM_SIDEBAR + index))
+            .or_else(|| self.displayed(SLOT_SIDEBAR))
+    }
+
+    /// Creates the team, or replaces its info if it already exists.
+    pub fn update_team(&mut self, name: &str, team: Team) {
+        self.teams.insert(name.to_owned(), team);
+    }
+
+    pub fn remove_team(&mut self, name: &str) {
+        self.teams.remove(name);
+        self.player_teams.retain(|_, team| team != name);
+    }
+
+    /// Adds the players to the team, taking them out of their previous
+    /// team.
+    pub fn add_team_players(&mut self, name: &str, players: &[String]) {
+        for player in players {
+            self.player_teams.insert(player.clone(), name.to_owned());
+        }
+    }
+
+    pub fn remove_team_players(&mut self, name: &str, players: &[String]) {
+        for player in players {
+            if self
+                .player_teams
+                .get(player)
+                .map_or(false, |team| team == name)
+            {
+                self.player_teams.remove(player);
+            }
+        }
+    }
+
+    /// Returns the name of the team the player or score entry is in.
+    pub fn team_name(&self, player: &str) -> Option<&str> {
+        self.player_teams.get(player).map(|name| name.as_str())
+    }
+
+    pub fn team_of(&self, player: &str) -> Option<&Team> {
+        self.player_teams
+            .get(player)
+            .and_then(|name| self.teams.get(name))
+    }
+
+    /// Returns the name formatted by the team it's in, if any.
+    pub fn format_name(&self, name: &str) -> Component {
+        match self.team_of(name) {
+            Some(team) => team.format_name(name),
+            None => Component::Text(TextComponent::new(name)),
+        }
+    }
+
+    /// Returns the name tag the viewer sees above the player, empty if
+    /// the player's team hides it from them.
+    pub fn name_tag(&self, player: &str, viewer: Option<&str>) -> Component {
+        let team = self.team_name(player);
+        let same_team = viewer.and_then(|viewer| self.team_name(viewer)) == team;
+        let hidden = match self.team_of(player) {
+            Some(info) => match &*info.name_tag_visibility {
+                "never" => true,
+                "hideForOtherTeams" => !same_team,
+                "hideForOwnTeam" => same_team,
+                _ => false,
+            },
+            None => false,
+        };
+        if hidden {
+            Default::default()
+        } else {
+            self.format_name(player)
+        }
+    }
+}
+
+impl Objective {
+    /// Returns the scores as shown in the sidebar, highest first.
+    /// Entries starting with `#` are hidden.
+    pub fn sidebar_scores(&self) -> Vec<(&str, i32)> {
+        let mut scores: Vec<_> = self
+            .scores
+            .iter()
+            .filter(|(entry, _)| !entry.starts_with('#'))
+            .map(|(entry, score)| (entry.as_str(), *score))
+            .collect();
+        scores.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        scores.truncate(MAX_SIDEBAR_SCORES);
+        scores
+    }
+}
+
+/// Returns the colour for a legacy formatting code index, as used by
+/// teams.
+pub fn color_from_index(index: i32) -> Option<format::Color> {
+    use format::Color::*;
+    Some(match index {
+        0 => Black,
+        1 => DarkBlue,
+        2 => DarkGreen,
+        3 => DarkAqua,
+        4 => DarkRed,
+        5 => DarkPurple,
+        6 => Gold,
+        7 => Gray,
+        8 => DarkGray,
+        9 => Blue,
+        10 => Green,
+        11 => Aqua,
+        12 => Red,
+        13 => LightPurple,
+        14 => Yellow,
+        15 => White,
+        _ => return None,
+    })
+}
+
+fn color_index(color: format::Color) -> Option<u8> {
+    (0..16u8).find(|&i| color_from_index(i as i32) == Some(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(component: &Component) -> String {
+        format!("{}", component)
+    }
+
+    fn team(prefix: &str, suffix: &str, color: Option<format::Color>) -> Team {
+        Team {
+            prefix: prefix.to_owned(),
+            suffix: suffix.to_owned(),
+            json: false,
+            color,
+            name_tag_visibility: "always".to_owned(),
+        }
+    }
+
+    #[test]
+    fn sidebar_sorts_and_hides_scores() {
+        let mut scoreboard = Scoreboard::new();
+        scoreboard.update_objective("kills", Component::Text(TextComponent::new("Kills")));
+        scoreboard.set_display_slot(SLOT_SIDEBAR, "kills");
+        scoreboard.set_score("b", "kills", 3);
+        scoreboard.set_score("a", "kills", 3);
+        scoreboard.set_score("c", "kills", 10);
+        scoreboard.set_score("#hidden", "kills", 20);
+        scoreboard.set_score("d", "missing", 5);
+
+        let sidebar = scoreboard.sidebar("a").unwrap();
+        assert_eq!(
+            sidebar.sidebar_scores(),
+            vec![("c", 10), ("a", 3), ("b", 3)]
+        );
+
+        scoreboard.remove_score("c", None);
+        let sidebar = scoreboard.sidebar("a").unwrap();
+        assert_eq!(sidebar.sidebar_scores(), vec![("a", 3), ("b", 3)]);
+
+        scoreboard.remove_objective("kills");
+        assert!(scoreboard.sidebar("a").is_none());
+    }
+
+    #[test]
+    fn team_sidebar_preferred() {
+        let mut scoreboard = Scoreboard::new();
+        for name in ["all", "red"] {
+            scoreboard.update_objective(name, Component::Text(TextComponent::new(name)));
+        }
+        scoreboard.set_display_slot(SLOT_SIDEBAR, "all");
+        scoreboard.set_display_slot(SLOT_TEAM_SIDEBAR + 12, "red");
+        scoreboard.update_team("team", team("", "", color_from_index(12)));
+        scoreboard.add_team_players("team", &["steve".to_owned()]);
+
+        assert_eq!(
+            text(&scoreboard.sidebar("steve").unwrap().display_name),
+            "red"
+        );
+        assert_eq!(
+            text(&scoreboard.sidebar("alex").unwrap().display_name),
+            "all"
+        );
+    }
+
+    #[test]
+    fn team_membership_and_names() {
+        let mut scoreboard = Scoreboard::new();
+        scoreboard.update_team("blue", team("\u{a7}9[B] ", "!", None));
+        scoreboard.add_team_players("blue", &["steve".to_owned(), "alex".to_owned()]);
+        assert_eq!(scoreboard.team_name("steve"), Some("blue"));
+        assert_eq!(text(&scoreboard.format_name("steve")), "[B] steve!");
+        assert_eq!(text(&scoreboard.format_name("notch")), "notch");
+
+        assert_eq!(
+            text(&scoreboard.name_tag("steve", Some("notch"))),
+            "[B] steve!"
+        );
+
+        scoreboard.update_team(
+            "blue",
+            Team {
+                name_tag_visibility: "hideForOtherTeams".to_owned(),
+                ..team("", "", None)
+            },
+        );
+        assert_eq!(text(&scoreboard.name_tag("steve", Some("notch"))), "");
+        assert_eq!(text(&scoreboard.name_tag("steve", Some("alex"))), "steve");
+
+        scoreboard.remove_team_players("blue", &["steve".to_owned()]);
+        assert_eq!(scoreboard.team_name("steve"), None);
+        scoreboard.remove_team("blue");
+        assert_eq!(scoreboard.team_name("alex"), None);
+    }
+}
@@ -38,6 +38,61 @@ pub const CL_MASTER_VOLUME: console::CVar<i64> = console::CVar {
     default: &|| 100,
 };
 
+pub const CL_LOCALE: console::CVar<String> = console::CVar {
+    ty: PhantomData,
+    name: "cl_locale",
+    description: "Language used for text sent by servers, such as en_us",
+    mutable: true,
+    serializable: true,
+    default: &|| "en_us".to_owned(),
+};
+
+pub const R_RENDER_DISTANCE: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "r_render_distance",
+    description: "Number of chunks around the player the server should send",
+    mutable: true,
+    serializable: true,
+    default: &|| 8,
+};
+
+pub const CL_CHAT_MODE: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_chat_mode",
+    description: "Chat messages to receive: 0 for all, 1 for commands only and 2 for none",
+    mutable: true,
+    serializable: true,
+    default: &|| 0,
+};
+
+pub const CL_CHAT_COLORS: console::CVar<bool> = console::CVar {
+    ty: PhantomData,
+    name: "cl_chat_colors",
+    description: "Whether the server should send colours in chat messages",
+    mutable: true,
+    serializable: true,
+    default: &|| true,
+};
+
+pub const CL_SKIN_PARTS: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_skin_parts",
+    description: "Bit mask of the skin layers shown: cape, jacket, left sleeve, right sleeve, \
+                  left pants leg, right pants leg and hat",
+    mutable: true,
+    serializable: true,
+    default: &|| 0x7f,
+};
+
+pub const CL_MAIN_HAND: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_main_hand",
+    description: "The hand used to hold items: 0 for left and 1 for right",
+    mutable: true,
+    serializable: true,
+    default: &|| 1,
+};
+
 macro_rules! create_keybind {
     ($keycode:ident, $name:expr, $description:expr) => {
         console::CVar {
@@ -70,6 +125,13 @@ pub const CL_KEYBIND_SPRINT: console::CVar<i64> =
     create_keybind!(LControl, "cl_keybind_sprint", "Keybinding for sprinting");
 pub const CL_KEYBIND_JUMP: console::CVar<i64> =
     create_keybind!(Space, "cl_keybind_jump", "Keybinding for jumping");
+pub const CL_KEYBIND_CHAT: console::CVar<i64> =
+    create_keybind!(T, "cl_keybind_chat", "Keybinding for opening the chat");
+pub const CL_KEYBIND_PLAYER_LIST: console::CVar<i64> = create_keybind!(
+    Tab,
+    "cl_keybind_player_list",
+    "Keybinding for showing the player list"
+);
 
 pub const DOUBLE_JUMP_MS: u32 = 100;
 
@@ -78,6 +140,12 @@ pub fn register_vars(vars: &mut console::Vars) {
     vars.register(R_FOV);
     vars.register(R_VSYNC);
     vars.register(CL_MASTER_VOLUME);
+    vars.register(CL_LOCALE);
+    vars.register(R_RENDER_DISTANCE);
+    vars.register(CL_CHAT_MODE);
+    vars.register(CL_CHAT_COLORS);
+    vars.register(CL_SKIN_PARTS);
+    vars.register(CL_MAIN_HAND);
     vars.register(CL_KEYBIND_FORWARD);
     vars.register(CL_KEYBIND_BACKWARD);
     vars.register(CL_KEYBIND_LEFT);
@@ -86,6 +154,8 @@ pub fn register_vars(vars: &mut console::Vars) {
     vars.register(CL_KEYBIND_SNEAK);
     vars.register(CL_KEYBIND_SPRINT);
     vars.register(CL_KEYBIND_JUMP);
+    vars.register(CL_KEYBIND_CHAT);
+    vars.register(CL_KEYBIND_PLAYER_LIST);
 }
 
 #[derive(Hash, PartialEq, Eq, Debug)]
@@ -98,6 +168,8 @@ pub enum Stevenkey {
     Sneak,
     Sprint,
     Jump,
+    Chat,
+    PlayerList,
 }
 
 impl Stevenkey {
@@ -111,6 +183,8 @@ impl Stevenkey {
             Stevenkey::Sneak,
             Stevenkey::Sprint,
             Stevenkey::Jump,
+            Stevenkey::Chat,
+            Stevenkey::PlayerList,
         ]
     }
 
@@ -130,6 +204,8 @@ impl Stevenkey {
             Stevenkey::Sneak => CL_KEYBIND_SNEAK,
             Stevenkey::Sprint => CL_KEYBIND_SPRINT,
             Stevenkey::Jump => CL_KEYBIND_JUMP,
+            Stevenkey::Chat => CL_KEYBIND_CHAT,
+            Stevenkey::PlayerList => CL_KEYBIND_PLAYER_LIST,
         }
     }
 }
@@ -520,6 +520,8 @@ trait UIElement {
     ) {
     }
     fn key_type(&mut self, _game: &mut crate::Game, _c: char) {}
+    /// Called whenever the mouse moves, wherever it is.
+    fn hover_moved(&mut self, _r: &Region, _mx: f64, _my: f64, _sw: f64, _sh: f64) {}
     fn tick(&mut self, renderer: &mut render::Renderer);
 }
 
@@ -633,6 +635,7 @@ macro_rules! element {
 
             fn hover_at(&mut self, super_region: &Region, game: &mut crate::Game, mx: f64, my: f64, sw: f64, sh: f64) -> bool {
                 use std::mem;
+                self.hover_moved(super_region, mx, my, sw, sh);
                 let mut handle_self = true;
                 for e in &self.elements {
                     let r = Container::compute_draw_region(&e.1, sw, sh, &super_region);
@@ -1081,27 +1084,37 @@ element! {
         pub scale_x: f64,
         pub scale_y: f64,
         pub max_width: f64,
+        pub alpha: u8,
         priv text: format::Component,
         priv text_elements: Vec<Element>,
+        priv spans: Vec<Span>,
+        priv hovered: Option<usize>,
+        priv mouse: (f64, f64),
         priv last_text: format::Component,
         priv last_scale_x: f64,
         priv last_scale_y: f64,
         priv last_max_width: f64,
+        priv last_alpha: u8,
         priv dirty: bool,
     }
     builder FormattedBuilder {
         hardcode width = 0.0,
         hardcode height = 18.0,
         hardcode text_elements = vec![],
+        hardcode spans = vec![],
+        hardcode hovered = None,
+        hardcode mouse = (0.0, 0.0),
         hardcode last_text = Default::default(),
         hardcode last_scale_x = 0.0,
         hardcode last_scale_y = 0.0,
         hardcode last_max_width = -1.0,
+        hardcode last_alpha = 255,
         hardcode dirty = true,
         simple text: format::Component,
         optional scale_x: f64 = 1.0,
         optional scale_y: f64 = 1.0,
         optional max_width: f64 = -1.0,
+        optional alpha: u8 = 255,
     }
 }
 
@@ -1126,19 +1139,25 @@ impl UIElement for Formatted {
                     width: 0.0,
                     offset: 0.0,
                     text: Vec::new(),
+                    spans: Vec::new(),
+                    events: Default::default(),
                     max_width: self.max_width,
+                    alpha: self.alpha,
                     renderer,
                 };
                 state.build(&self.text, format::Color::White);
                 self.text_elements = state.text;
+                self.spans = state.spans;
+                self.hovered = None;
             }
 
+            let (text_sw, text_sh) = (sw * self.scale_x, sh * self.scale_y);
             for e in &self.text_elements {
                 if self.needs_rebuild {
                     e.force_rebuild();
                 }
-                let r = Container::compute_draw_region(e, sw, sh, r);
-                let data = e.draw(renderer, &r, sw, sh, width, height, delta);
+                let r = Container::compute_draw_region(e, text_sw, text_sh, r);
+                let data = e.draw(renderer, &r, text_sw, text_sh, width, height, delta);
                 self.data.extend_from_slice(&data);
             }
             self.super_draw(renderer, r, sw, sh, width, height, delta);
@@ -1147,6 +1166,7 @@ impl UIElement for Formatted {
             self.last_scale_x = self.scale_x;
             self.last_scale_y = self.scale_y;
             self.last_max_width = self.max_width;
+            self.last_alpha = self.alpha;
             self.dirty = false;
         }
         &mut self.data
@@ -1161,6 +1181,15 @@ impl UIElement for Formatted {
         }
     }
 
+    fn hover_moved(&mut self, r: &Region, mx: f64, my: f64, sw: f64, sh: f64) {
+        let x = (mx - r.x) / (sw * self.scale_x);
+        let y = (my - r.y) / (sh * self.scale_y);
+        self.hovered = self.spans.iter().position(|span| {
+            x >= span.x && x < span.x + span.width && y >= span.y && y < span.y + 18.0
+        });
+        self.mouse = (mx / sw, my / sh);
+    }
+
     fn get_size(&self) -> (f64, f64) {
         (
             (self.width + 2.0) * self.scale_x,
@@ -1173,6 +1202,7 @@ impl UIElement for Formatted {
             || self.last_scale_x != self.scale_x
             || self.last_scale_y != self.scale_y
             || self.last_max_width != self.max_width
+            || self.last_alpha != self.alpha
     }
 }
 
@@ -1182,6 +1212,17 @@ impl Formatted {
         self.dirty = true;
     }
 
+    /// Returns the events of the text under the mouse, if it has any.
+    pub fn hovered_events(&self) -> Option<&TextEvents> {
+        self.hovered.map(|i| &self.spans[i].events)
+    }
+
+    /// Returns where the mouse was last seen, in scaled screen
+    /// coordinates.
+    pub fn mouse_position(&self) -> (f64, f64) {
+        self.mouse
+    }
+
     pub fn compute_size(
         renderer: &render::Renderer,
         text: &format::Component,
@@ -1192,7 +1233,10 @@ impl Formatted {
             width: 0.0,
             offset: 0.0,
             text: Vec::new(),
+            spans: Vec::new(),
+            events: Default::default(),
             max_width,
+            alpha: 255,
             renderer,
         };
         state.build(text, format::Color::White);
@@ -1200,12 +1244,38 @@ impl Formatted {
     }
 }
 
+/// The click, hover and insertion events of some text, inherited from
+/// its parents if it doesn't have its own.
+#[derive(Clone, Default)]
+pub struct TextEvents {
+    pub click: Option<format::ClickEvent>,
+    pub hover: Option<format::HoverEvent>,
+    pub insertion: Option<String>,
+}
+
+impl TextEvents {
+    fn is_empty(&self) -> bool {
+        self.click.is_none() && self.hover.is_none() && self.insertion.is_none()
+    }
+}
+
+// A run of text on a single line with events attached
+struct Span {
+    x: f64,
+    y: f64,
+    width: f64,
+    events: TextEvents,
+}
+
 struct FormatState<'a> {
     max_width: f64,
+    alpha: u8,
     lines: usize,
     offset: f64,
     width: f64,
     text: Vec<Element>,
+    spans: Vec<Span>,
+    events: TextEvents,
     renderer: &'a render::Renderer,
 }
 
@@ -1217,18 +1287,44 @@ impl<'a> ElementHolder for FormatState<'a> {
 
 impl<'a> FormatState<'a> {
     fn build(&mut self, c: &format::Component, color: format::Color) {
-        match *c {
-            format::Component::Text(ref txt) => {
-                let col = FormatState::get_color(&txt.modifier, color);
-                self.append_text(&txt.text, col);
-                let modi = &txt.modifier;
-                if let Some(ref extra) = modi.extra {
-                    for e in extra {
-                        self.build(e, col);
-                    }
+        let txt = c.resolve(&|key| self.renderer.ui.translate(key));
+        self.build_text(&txt, color);
+    }
+
+    fn build_text(&mut self, txt: &format::TextComponent, color: format::Color) {
+        let col = FormatState::get_color(&txt.modifier, color);
+        let modi = &txt.modifier;
+        let parent_events = self.events.clone();
+        if modi.click_event.is_some() {
+            self.events.click = modi.click_event.clone();
+        }
+        if modi.hover_event.is_some() {
+            self.events.hover = modi.hover_event.clone();
+        }
+        if modi.insertion.is_some() {
+            self.events.insertion = modi.insertion.clone();
+        }
+        self.append_text(&txt.text, col);
+        if let Some(ref extra) = modi.extra {
+            for e in extra {
+                // Resolving leaves nothing but text components
+                if let format::Component::Text(ref e) = *e {
+                    self.build_text(e, col);
                 }
             }
         }
+        self.events = parent_events;
+    }
+
+    fn add_span(&mut self, width: f64) {
+        if !self.events.is_empty() {
+            self.spans.push(Span {
+                x: self.offset,
+                y: (self.lines * 18) as f64,
+                width,
+                events: self.events.clone(),
+            });
+        }
     }
 
     fn append_text(&mut self, txt: &str, color: format::Color) {
@@ -1241,8 +1337,9 @@ impl<'a> FormatState<'a> {
                 TextBuilder::new()
                     .text(&txt[last..i])
                     .position(self.offset, (self.lines * 18 + 1) as f64)
-                    .colour((rr, gg, bb, 255))
+                    .colour((rr, gg, bb, self.alpha))
                     .create(self);
+                self.add_span(width);
                 last = i;
                 if c == '\n' {
                     last += 1;
@@ -1262,9 +1359,11 @@ impl<'a> FormatState<'a> {
             TextBuilder::new()
                 .text(&txt[last..])
                 .position(self.offset, (self.lines * 18 + 1) as f64)
-                .colour((rr, gg, bb, 255))
+                .colour((rr, gg, bb, self.alpha))
                 .create(self);
-            self.offset += self.renderer.ui.size_of_string(&txt[last..]) + 2.0;
+            let width = self.renderer.ui.size_of_string(&txt[last..]) + 2.0;
+            self.add_span(width);
+            self.offset += width;
             if self.offset > self.width {
                 self.width = self.offset;
             }
@@ -1505,6 +1604,8 @@ element! {
         pub height: f64,
         priv button: Option<ButtonRef>,
         priv text: Option<TextRef>,
+        priv formatted: Option<FormattedRef>,
+        priv highlight: Option<(String, format::Component)>,
         priv was_focused: bool,
         priv cursor_tick: f64,
         priv submit_funcs: Vec<Box<SubmitFunc>>,
@@ -1512,6 +1613,8 @@ element! {
     builder TextBoxBuilder {
         hardcode button = None,
         hardcode text = None,
+        hardcode formatted = None,
+        hardcode highlight = None,
         hardcode was_focused = false,
         hardcode cursor_tick = 0.0,
         hardcode submit_funcs = vec![],
@@ -1589,13 +1692,41 @@ impl UIElement for TextBox {
                 self.cursor_tick -= 3000.0;
             }
             let mut text = self.transform_input();
+            // Highlighting is dropped as soon as the input changes until
+            // it is set again for the new input
+            let highlight = self
+                .highlight
+                .as_ref()
+                .filter(|(input, _)| !self.password && *input == self.input)
+                .map(|(_, highlight)| highlight.clone());
+            let cursor = self.focused && ((self.cursor_tick / 30.0) as i32) % 2 == 0;
             {
                 let mut btn = self.button.as_mut().unwrap().borrow_mut();
                 btn.width = self.width;
                 btn.height = self.height;
                 let mut txt = self.text.as_mut().unwrap().borrow_mut();
-                if self.focused && ((self.cursor_tick / 30.0) as i32) % 2 == 0 {
-                    text.push('|');
+                let mut formatted = self.formatted.as_mut().unwrap().borrow_mut();
+                let formatted_text = match highlight {
+                    Some(mut highlight) => {
+                        if cursor {
+                            highlight
+                                .modifier_mut()
+                                .extra
+                                .get_or_insert_with(Vec::new)
+                                .push(format::Component::Text(format::TextComponent::new("|")));
+                        }
+                        text.clear();
+                        highlight
+                    }
+                    None => {
+                        if cursor {
+                            text.push('|');
+                        }
+                        Default::default()
+                    }
+                };
+                if formatted.text != formatted_text {
+                    formatted.set_text(formatted_text);
                 }
                 txt.text = text;
             }
@@ -1635,10 +1766,24 @@ impl UIElement for TextBox {
                 .alignment(VAttach::Middle, HAttach::Left)
                 .attach(&mut *textbox),
         );
+        textbox.formatted = Some(
+            FormattedBuilder::new()
+                .text(Default::default())
+                .position(5.0, 0.0)
+                .draw_index(1)
+                .alignment(VAttach::Middle, HAttach::Left)
+                .attach(&mut *textbox),
+        );
     }
 }
 
 impl TextBox {
+    /// Shows the input formatted as the given component for as long
+    /// as it isn't changed.
+    pub fn set_highlight(&mut self, highlight: Option<format::Component>) {
+        self.highlight = highlight.map(|v| (self.input.clone(), v));
+    }
+
     pub fn add_submit_func<F: Fn(&mut TextBox, &mut crate::Game) + 'static>(&mut self, f: F) {
         self.submit_funcs.push(Box::new(f));
     }
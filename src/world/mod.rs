@@ -112,6 +112,10 @@ impl World {
         }
     }
 
+    pub fn protocol_version(&self) -> i32 {
+        self.protocol_version
+    }
+
     pub fn is_chunk_loaded(&self, x: i32, z: i32) -> bool {
         self.chunks.contains_key(&CPos(x, z))
     }